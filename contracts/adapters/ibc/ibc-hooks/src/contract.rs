@@ -1,25 +1,33 @@
 use crate::{
     error::{ContractError, ContractResult},
     state::{
-        ACK_ID_TO_RECOVER_ADDRESS, ENTRY_POINT_CONTRACT_ADDRESS, IN_PROGRESS_CHANNEL_ID,
-        IN_PROGRESS_RECOVER_ADDRESS,
+        ACK_ID_TO_CALLBACK, ACK_ID_TO_RECOVER_ADDRESS, ENTRY_POINT_CONTRACT_ADDRESS,
+        IN_PROGRESS_CALLBACK, IN_PROGRESS_CHANNEL_ID, IN_PROGRESS_RECOVER_ADDRESS,
     },
 };
 use cosmwasm_std::{
     entry_point, to_binary, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
     Reply, Response, SubMsg, SubMsgResult,
 };
+use cw2::set_contract_version;
 use ibc_proto::ibc::applications::transfer::v1::{MsgTransfer, MsgTransferResponse};
 use prost::Message;
-use serde_cw_value::Value;
 use skip::{
-    ibc::{AckID, ExecuteMsg, IbcInfo, IbcLifecycleComplete, InstantiateMsg, QueryMsg},
+    callback::{CallbackAckId, CallbackStatus, IbcTransferCallback, SkipCallback},
+    ibc::{
+        AckID, ConfigResponse, ExecuteMsg, IbcInfo, IbcLifecycleComplete, InstantiateMsg, QueryMsg,
+    },
+    memo::Memo,
+    migrate::{validate_migration, MigrateMsg},
     proto_coin::ProtoCoin,
     sudo::{OsmosisSudoMsg as SudoMsg, SudoType},
 };
 
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const IBC_MSG_TRANSFER_TYPE_URL: &str = "/ibc.applications.transfer.v1.MsgTransfer";
 const REPLY_ID: u64 = 1;
+const CALLBACK_REPLY_ID: u64 = 2;
 
 ///////////////////
 /// INSTANTIATE ///
@@ -32,6 +40,9 @@ pub fn instantiate(
     _info: MessageInfo,
     msg: InstantiateMsg,
 ) -> ContractResult<Response> {
+    // Store the contract name and version for migrations
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
     // Validate entry point contract address
     let checked_entry_point_contract_address =
         deps.api.addr_validate(&msg.entry_point_contract_address)?;
@@ -47,6 +58,33 @@ pub fn instantiate(
         ))
 }
 
+///////////////
+/// MIGRATE ///
+///////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> ContractResult<Response> {
+    // Validate the migration and get the version being migrated from. Contracts deployed
+    // before cw2 version tracking are recognized by their stored entry point contract address.
+    let from_version = validate_migration(
+        deps.storage,
+        CONTRACT_NAME,
+        CONTRACT_VERSION,
+        ENTRY_POINT_CONTRACT_ADDRESS.exists(deps.storage),
+    )?;
+
+    // No-op state migration: ACK_ID_TO_RECOVER_ADDRESS and IN_PROGRESS_CHANNEL_ID are
+    // unchanged since v0.2.1 and the callback state added in v0.3.0 starts out empty.
+
+    // Store the new contract version
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", from_version.to_string())
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
 ///////////////
 /// EXECUTE ///
 ///////////////
@@ -63,7 +101,16 @@ pub fn execute(
             info: ibc_info,
             coin,
             timeout_timestamp,
-        } => execute_ibc_transfer(deps, env, info, ibc_info, coin, timeout_timestamp),
+            callback_address,
+        } => execute_ibc_transfer(
+            deps,
+            env,
+            info,
+            ibc_info,
+            coin,
+            timeout_timestamp,
+            callback_address,
+        ),
     }
 }
 
@@ -77,6 +124,7 @@ fn execute_ibc_transfer(
     ibc_info: IbcInfo,
     coin: Coin,
     timeout_timestamp: u64,
+    callback_address: Option<String>,
 ) -> ContractResult<Response> {
     // Get entry point contract address from storage
     let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
@@ -97,6 +145,18 @@ fn execute_ibc_transfer(
         &ibc_info.recover_address, // This address is verified in entry point
     )?;
 
+    // Save the in progress callback to storage if there is a callback address,
+    // to be used in sudo handler
+    if let Some(callback_address) = callback_address {
+        IN_PROGRESS_CALLBACK.save(
+            deps.storage,
+            &IbcTransferCallback {
+                callback_address, // This address is verified in entry point
+                coin: coin.clone(),
+            },
+        )?;
+    }
+
     // Save in progress channel id to storage, to be used in sudo handler
     IN_PROGRESS_CHANNEL_ID.save(deps.storage, &ibc_info.source_channel)?;
 
@@ -140,6 +200,19 @@ fn execute_ibc_transfer(
 // ibc transfer from storage.
 #[entry_point]
 pub fn reply(deps: DepsMut, _env: Env, reply: Reply) -> ContractResult<Response> {
+    // Ignore the error of a failed callback sub message, so a failing
+    // callback contract never reverts the refund dispatched with it
+    if reply.id == CALLBACK_REPLY_ID {
+        return match reply.result {
+            SubMsgResult::Ok(_) => {
+                Ok(Response::new().add_attribute("action", "sub_msg_reply_callback_successful"))
+            }
+            SubMsgResult::Err(err) => Ok(Response::new()
+                .add_attribute("action", "sub_msg_reply_callback_failed")
+                .add_attribute("error", err)),
+        };
+    }
+
     // Error if the reply id is not the same as the one used in the sub message dispatched
     // This should never happen since we are using a constant reply id, but added in case
     // the wasm module doesn't behave as expected.
@@ -184,6 +257,13 @@ pub fn reply(deps: DepsMut, _env: Env, reply: Reply) -> ContractResult<Response>
     // Set the in progress recover address to storage, keyed by channel id and sequence id
     ACK_ID_TO_RECOVER_ADDRESS.save(deps.storage, ack_id, &in_progress_recover_address)?;
 
+    // Get and delete the in progress callback from storage, and set it
+    // to storage keyed by channel id and sequence id if there is one
+    if let Some(in_progress_callback) = IN_PROGRESS_CALLBACK.may_load(deps.storage)? {
+        IN_PROGRESS_CALLBACK.remove(deps.storage);
+        ACK_ID_TO_CALLBACK.save(deps.storage, ack_id, &in_progress_callback)?;
+    }
+
     Ok(Response::new().add_attribute("action", "sub_msg_reply_success"))
 }
 
@@ -194,6 +274,7 @@ pub fn reply(deps: DepsMut, _env: Env, reply: Reply) -> ContractResult<Response>
 // Handles the ibc callback from the ibc hooks module
 // Upon success, removes the in progress ibc transfer from storage and returns immediately.
 // Upon error or timeout, sends the attempted ibc transferred funds back to the user's recover address.
+// In all cases, sends the SkipCallback to the callback address if the ibc transfer has one.
 #[entry_point]
 pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> ContractResult<Response> {
     // Get the channel id, sequence id, and sudo type from the sudo message
@@ -206,12 +287,17 @@ pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> ContractResult<Response> {
         }) => {
             // Remove the AckID <> in progress ibc transfer from storage
             // and return immediately if the ibc transfer was successful
-            // since no further action is needed.
+            // since no further action is needed besides the callback.
             if success {
                 let ack_id: AckID = (&channel, sequence);
                 ACK_ID_TO_RECOVER_ADDRESS.remove(deps.storage, ack_id);
 
-                return Ok(Response::new().add_attribute("action", SudoType::Response));
+                return add_callback_msg(
+                    deps,
+                    Response::new().add_attribute("action", SudoType::Response),
+                    ack_id,
+                    CallbackStatus::Success,
+                );
             }
 
             (channel, sequence, SudoType::Error)
@@ -226,6 +312,12 @@ pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> ContractResult<Response> {
     let to_address = ACK_ID_TO_RECOVER_ADDRESS.load(deps.storage, ack_id)?;
     ACK_ID_TO_RECOVER_ADDRESS.remove(deps.storage, ack_id);
 
+    // Get the callback status from the sudo type
+    let callback_status = match sudo_type {
+        SudoType::Timeout => CallbackStatus::Timeout,
+        _ => CallbackStatus::Error,
+    };
+
     // Get all coins from contract's balance, which will be the the
     // failed ibc transfer coin and any leftover dust on the contract
     let amount = deps.querier.query_all_balances(env.contract.address)?;
@@ -238,40 +330,60 @@ pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> ContractResult<Response> {
     // Create bank send message to send funds back to user's recover address
     let bank_send_msg = BankMsg::Send { to_address, amount };
 
-    Ok(Response::new()
+    let response = Response::new()
         .add_message(bank_send_msg)
-        .add_attribute("action", sudo_type))
+        .add_attribute("action", sudo_type);
+
+    // Add the callback message after the refund, which never reverts the refund if it fails
+    add_callback_msg(deps, response, ack_id, callback_status)
 }
 
 ////////////////////////
 /// HELPER FUNCTIONS ///
 ////////////////////////
 
-// Verifies the given memo is empty or valid json, and then adds the necessary
-// key/value pair to trigger the ibc hooks callback logic.
+// Gets and removes the AckID <> in progress callback from storage, and adds the SkipCallback
+// message with the given status to the response if the ibc transfer has a callback address.
+// The callback message replies on error, so a failing callback is ignored.
+fn add_callback_msg(
+    deps: DepsMut,
+    response: Response,
+    ack_id: AckID,
+    status: CallbackStatus,
+) -> ContractResult<Response> {
+    let Some(callback) = ACK_ID_TO_CALLBACK.may_load(deps.storage, ack_id)? else {
+        return Ok(response);
+    };
+    ACK_ID_TO_CALLBACK.remove(deps.storage, ack_id);
+
+    // Create the callback message with the coin transferred and the ack id
+    let callback_msg = SkipCallback {
+        status,
+        amount_out: callback.coin,
+        ack_id: Some(CallbackAckId {
+            channel_id: ack_id.0.to_string(),
+            sequence_id: ack_id.1,
+        }),
+    }
+    .into_sub_msg(callback.callback_address, CALLBACK_REPLY_ID)?;
+
+    Ok(response
+        .add_submessage(callback_msg)
+        .add_attribute("action", "dispatch_skip_callback"))
+}
+
+// Verifies the given memo is empty or a valid json memo, and then sets the
+// ibc_callback key to trigger the ibc hooks callback logic.
 fn verify_and_create_memo(memo: String, contract_address: String) -> ContractResult<String> {
-    // If the memo given is empty, then set it to "{}" to avoid json parsing errors. Then,
-    // get Value object from json string, erroring if the memo was not null while not being valid json
-    let mut memo: Value = serde_json_wasm::from_str(if memo.is_empty() { "{}" } else { &memo })?;
+    // Parse and validate the memo, erroring if it is not empty while not being
+    // a valid json memo. The ibc hooks callback is only triggered by a json memo.
+    let memo = Memo::parse(&memo)?;
 
-    // Transform the Value object into a Value map representation of the json string
-    // and insert the necessary key value pair into the memo map to trigger
-    // the ibc hooks callback logic. That key value pair is:
+    // Set the ibc_callback key to the current contract address, overriding the
+    // value if the "ibc_callback" key was already set, and serialize the memo
+    // back into a json string. That key value pair is:
     // { "ibc_callback": <CALLBACK_CONTRACT_ADDRESS> }
-    //
-    // If the "ibc_callback" key was already set, this will override
-    // the value with the current contract address.
-    if let Value::Map(ref mut memo) = memo {
-        memo.insert(
-            Value::String("ibc_callback".to_string()),
-            Value::String(contract_address),
-        )
-    } else {
-        unreachable!()
-    };
-
-    // Transform the memo Value map back into a json string
-    let memo = serde_json_wasm::to_string(&memo)?;
+    let memo = memo.with_ibc_callback(contract_address).to_json_string()?;
 
     Ok(memo)
 }
@@ -283,6 +395,9 @@ fn verify_and_create_memo(memo: String, contract_address: String) -> ContractRes
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
     match msg {
+        QueryMsg::Config {} => to_binary(&ConfigResponse {
+            entry_point_contract_address: ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?,
+        }),
         QueryMsg::InProgressRecoverAddress {
             channel_id,
             sequence_id,
@@ -1,4 +1,5 @@
 use cosmwasm_std::{OverflowError, StdError};
+use skip::error::SkipError;
 use thiserror::Error;
 
 pub type ContractResult<T> = core::result::Result<T, ContractError>;
@@ -12,13 +13,10 @@ pub enum ContractError {
     Decode(#[from] prost::DecodeError),
 
     #[error(transparent)]
-    JsonDecode(#[from] serde_json_wasm::de::Error),
-
-    #[error(transparent)]
-    JsonEncode(#[from] serde_json_wasm::ser::Error),
+    Overflow(#[from] OverflowError),
 
     #[error(transparent)]
-    Overflow(#[from] OverflowError),
+    Skip(#[from] SkipError),
 
     #[error("IBC fees are not supported, vectors must be empty")]
     IbcFeesNotSupported,
@@ -20,11 +20,14 @@ Test Cases:
 Expect Response (Output Message Is Correct, In Progress Ibc Transfer Is Saved, No Error)
     - Empty String Memo
     - Override Already Set Ibc Callback Memo
-    - Add Ibc Callback Key/Value Pair To Other Key/Value In Memo
+    - Add Ibc Callback Key/Value Pair To Forward Memo
+    - Keep Unknown Keys When Adding Ibc Callback Key/Value Pair
 
 Expect Error
     - Unauthorized Caller (Only the stored entry point contract can call this function)
     - Non Empty String, Invalid Json Memo
+    - Plain Text Memo, Memo Is Not A Json Object
+    - Forward Memo With Invalid Channel
     - Non Empty IBC Fees, IBC Fees Not Supported
 
  */
@@ -128,7 +131,7 @@ struct Params {
             source_channel: "source_channel".to_string(),
             receiver: "receiver".to_string(),
             fee: None,
-            memo: r#"{"pfm":"example_value","wasm":"example_contract"}"#.to_string(),
+            memo: r#"{"forward":{"channel":"channel-1","port":"transfer","receiver":"receiver","retries":2,"timeout":"10m"}}"#.to_string(),
             recover_address: "recover_address".to_string(),
         },
         timeout_timestamp: 100,
@@ -147,7 +150,7 @@ struct Params {
                     receiver: "receiver".to_string(),
                     timeout_height: None,
                     timeout_timestamp: 100,
-                    memo: r#"{"ibc_callback":"ibc_transfer","pfm":"example_value","wasm":"example_contract"}"#.to_string(),
+                    memo: r#"{"forward":{"channel":"channel-1","port":"transfer","receiver":"receiver","retries":2,"timeout":"10m"},"ibc_callback":"ibc_transfer"}"#.to_string(),
                 }
                 .encode_to_vec().into(),
             },
@@ -157,7 +160,46 @@ struct Params {
         ],
         expected_error_string: "".to_string(),
     };
-    "Add Ibc Callback Key/Value Pair To Other Key/Value In Memo")]
+    "Add Ibc Callback Key/Value Pair To Forward Memo")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        ibc_adapter_contract_address: Addr::unchecked("ibc_transfer".to_string()),
+        coin: Coin::new(100, "osmo"),
+        ibc_info: IbcInfo {
+            source_channel: "source_channel".to_string(),
+            receiver: "receiver".to_string(),
+            fee: None,
+            memo: r#"{"forward":{"channel":"channel-1","port":"transfer","receiver":"receiver","retries":2,"timeout":"10m"},"src_callback":{"address":"contract"}}"#.to_string(),
+            recover_address: "recover_address".to_string(),
+        },
+        timeout_timestamp: 100,
+        expected_messages: vec![SubMsg {
+            id: 1,
+            msg: cosmwasm_std::CosmosMsg::Stargate {
+                type_url: "/ibc.applications.transfer.v1.MsgTransfer".to_string(),
+                value: MsgTransfer {
+                    source_port: "transfer".to_string(),
+                    source_channel: "source_channel".to_string(),
+                    token: Some(IbcCoin {
+                        denom: "osmo".to_string(),
+                        amount: "100".to_string(),
+                    }),
+                    sender: "ibc_transfer".to_string(),
+                    receiver: "receiver".to_string(),
+                    timeout_height: None,
+                    timeout_timestamp: 100,
+                    memo: r#"{"forward":{"channel":"channel-1","port":"transfer","receiver":"receiver","retries":2,"timeout":"10m"},"ibc_callback":"ibc_transfer","src_callback":{"address":"contract"}}"#.to_string(),
+                }
+                .encode_to_vec().into(),
+            },
+            gas_limit: None,
+            reply_on: Success,
+        }
+        ],
+        expected_error_string: "".to_string(),
+    };
+    "Keep Unknown Keys When Adding Ibc Callback Key/Value Pair")]
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
@@ -172,9 +214,43 @@ struct Params {
         },
         timeout_timestamp: 100,
         expected_messages: vec![],
-        expected_error_string: "Object key is not a string.".to_string(),
+        expected_error_string: "Invalid Memo Json: Object key is not a string.".to_string(),
     };
     "Non Empty String, Invalid Json Memo - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        ibc_adapter_contract_address: Addr::unchecked("ibc_transfer".to_string()),
+        coin: Coin::new(100, "osmo"),
+        ibc_info: IbcInfo {
+            source_channel: "source_channel".to_string(),
+            receiver: "receiver".to_string(),
+            fee: None,
+            memo: "memo".to_string(),
+            recover_address: "recover_address".to_string(),
+        },
+        timeout_timestamp: 100,
+        expected_messages: vec![],
+        expected_error_string: "Memo Is Not A Json Object".to_string(),
+    };
+    "Plain Text Memo - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        ibc_adapter_contract_address: Addr::unchecked("ibc_transfer".to_string()),
+        coin: Coin::new(100, "osmo"),
+        ibc_info: IbcInfo {
+            source_channel: "source_channel".to_string(),
+            receiver: "receiver".to_string(),
+            fee: None,
+            memo: r#"{"forward":{"channel":"transfer","port":"transfer","receiver":"receiver"}}"#.to_string(),
+            recover_address: "recover_address".to_string(),
+        },
+        timeout_timestamp: 100,
+        expected_messages: vec![],
+        expected_error_string: "Invalid Memo Forward Channel: transfer".to_string(),
+    };
+    "Forward Memo With Invalid Channel - Expect Error")]
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
@@ -238,6 +314,7 @@ fn test_execute_ibc_transfer(params: Params) -> ContractResult<()> {
             info: params.ibc_info.clone(),
             coin: params.coin.clone(),
             timeout_timestamp: params.timeout_timestamp,
+            callback_address: None,
         },
     );
 
@@ -1,12 +1,16 @@
 use cosmwasm_std::{
     testing::{mock_dependencies, mock_env},
-    Reply, StdError, SubMsgResponse, SubMsgResult,
+    Coin, Reply, StdError, SubMsgResponse, SubMsgResult,
 };
 use ibc_proto::ibc::applications::transfer::v1::MsgTransferResponse;
 use prost::Message;
+use skip::callback::IbcTransferCallback;
 use skip_api_ibc_adapter_ibc_hooks::{
     error::ContractResult,
-    state::{ACK_ID_TO_RECOVER_ADDRESS, IN_PROGRESS_CHANNEL_ID, IN_PROGRESS_RECOVER_ADDRESS},
+    state::{
+        ACK_ID_TO_CALLBACK, ACK_ID_TO_RECOVER_ADDRESS, IN_PROGRESS_CALLBACK,
+        IN_PROGRESS_CHANNEL_ID, IN_PROGRESS_RECOVER_ADDRESS,
+    },
 };
 use test_case::test_case;
 
@@ -151,7 +155,7 @@ struct Params {
         channel_id: "channel_id".to_string(),
         sequence_id: 1,
         reply: Reply {
-            id: 2,
+            id: 3,
             result: SubMsgResult::Err("".to_string()),
         },
         pre_reply_in_progress_recover_address: Some("recover_address".to_string()),
@@ -256,3 +260,95 @@ fn test_reply(params: Params) -> ContractResult<()> {
 
     Ok(())
 }
+
+// Test reply moves the in progress callback to the ack id to callback map
+#[test]
+fn test_reply_in_progress_callback() -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Store the in progress recover address, channel id and callback to state
+    IN_PROGRESS_RECOVER_ADDRESS.save(deps.as_mut().storage, &"recover_address".to_string())?;
+    IN_PROGRESS_CHANNEL_ID.save(deps.as_mut().storage, &"channel_id".to_string())?;
+    let callback = IbcTransferCallback {
+        callback_address: "callback_address".to_string(),
+        coin: Coin::new(100, "uosmo"),
+    };
+    IN_PROGRESS_CALLBACK.save(deps.as_mut().storage, &callback)?;
+
+    // Call reply with a successful ibc transfer sub message response
+    skip_api_ibc_adapter_ibc_hooks::contract::reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: 1,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: Some(
+                    MsgTransferResponse { sequence: 5 }
+                        .encode_to_vec()
+                        .as_slice()
+                        .into(),
+                ),
+            }),
+        },
+    )?;
+
+    // Verify the in progress callback was removed from storage
+    assert!(IN_PROGRESS_CALLBACK.may_load(&deps.storage)?.is_none());
+
+    // Verify the stored ack id to callback map entry is correct
+    assert_eq!(
+        ACK_ID_TO_CALLBACK.load(&deps.storage, ("channel_id", 5))?,
+        callback
+    );
+
+    Ok(())
+}
+
+// Test reply ignores the error of a failed callback sub message
+#[test]
+fn test_reply_failed_callback() -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Call reply with a failed callback sub message
+    let res = skip_api_ibc_adapter_ibc_hooks::contract::reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: 2,
+            result: SubMsgResult::Err("callback error".to_string()),
+        },
+    )?;
+
+    // Verify no messages are dispatched
+    assert!(res.messages.is_empty());
+
+    Ok(())
+}
+
+// Test reply ignores the result of a successful callback sub message
+#[test]
+fn test_reply_successful_callback() -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Call reply with a successful callback sub message
+    let res = skip_api_ibc_adapter_ibc_hooks::contract::reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: 2,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+    )?;
+
+    // Verify no messages are dispatched
+    assert!(res.messages.is_empty());
+
+    Ok(())
+}
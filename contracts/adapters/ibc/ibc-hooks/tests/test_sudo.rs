@@ -4,8 +4,15 @@ use cosmwasm_std::{
     ReplyOn::Never,
     StdError, SubMsg,
 };
-use skip::{ibc::IbcLifecycleComplete, sudo::OsmosisSudoMsg as SudoMsg};
-use skip_api_ibc_adapter_ibc_hooks::{error::ContractResult, state::ACK_ID_TO_RECOVER_ADDRESS};
+use skip::{
+    callback::{CallbackAckId, CallbackStatus, IbcTransferCallback, SkipCallback},
+    ibc::IbcLifecycleComplete,
+    sudo::OsmosisSudoMsg as SudoMsg,
+};
+use skip_api_ibc_adapter_ibc_hooks::{
+    error::ContractResult,
+    state::{ACK_ID_TO_CALLBACK, ACK_ID_TO_RECOVER_ADDRESS},
+};
 use test_case::test_case;
 
 /*
@@ -15,6 +22,9 @@ Expect Success
     - Sudo Response - Happy Path Response
     - Sudo Timeout - Send Failed Ibc Coin To Recover Address
     - Sudo Error - Send Failed Ibc Coin To Recover Address
+    - Sudo Response With Callback - Send Success Callback
+    - Sudo Timeout With Callback - Send Failed Ibc Coin And Timeout Callback
+    - Sudo Error With Callback - Send Failed Ibc Coin And Error Callback
 
 Expect Error
     - No In Progress Recover Address Mapped To Sudo Ack ID - Expect Error
@@ -29,6 +39,7 @@ struct Params {
     sequence_id: u64,
     sudo_msg: SudoMsg,
     stored_in_progress_recover_address: Option<String>,
+    stored_callback: Option<IbcTransferCallback>,
     expected_messages: Vec<SubMsg>,
     expected_error_string: String,
 }
@@ -46,6 +57,7 @@ struct Params {
             success: true,
         }),
         stored_in_progress_recover_address: Some("recover_address".to_string()),
+        stored_callback: None,
         expected_messages: vec![],
         expected_error_string: "".to_string(),
     };
@@ -60,6 +72,7 @@ struct Params {
             sequence: 1,
         }),
         stored_in_progress_recover_address: Some("recover_address".to_string()),
+        stored_callback: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -86,6 +99,7 @@ struct Params {
             success: false,
         }),
         stored_in_progress_recover_address: Some("recover_address".to_string()),
+        stored_callback: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -100,6 +114,109 @@ struct Params {
         expected_error_string: "".to_string(),
     };
     "Sudo Error - Send Failed Ibc Coin To Recover Address")]
+#[test_case(
+    Params {
+        contract_balance: vec![Coin::new(100, "uosmo")],
+        channel_id: "channel_id".to_string(),
+        sequence_id: 1,
+        sudo_msg: SudoMsg::IbcLifecycleComplete(IbcLifecycleComplete::IbcAck{
+            channel: "channel_id".to_string(),
+            sequence: 1,
+            ack: "".to_string(),
+            success: true,
+        }),
+        stored_in_progress_recover_address: Some("recover_address".to_string()),
+        stored_callback: Some(IbcTransferCallback {
+            callback_address: "callback_address".to_string(),
+            coin: Coin::new(90, "uosmo"),
+        }),
+        expected_messages: vec![
+            SkipCallback {
+                status: CallbackStatus::Success,
+                amount_out: Coin::new(90, "uosmo"),
+                ack_id: Some(CallbackAckId {
+                    channel_id: "channel_id".to_string(),
+                    sequence_id: 1,
+                }),
+            }.into_sub_msg("callback_address".to_string(), 2).unwrap(),
+        ],
+        expected_error_string: "".to_string(),
+    };
+    "Sudo Response With Callback - Send Success Callback")]
+#[test_case(
+    Params {
+        contract_balance: vec![Coin::new(100, "uosmo")],
+        channel_id: "channel_id".to_string(),
+        sequence_id: 1,
+        sudo_msg: SudoMsg::IbcLifecycleComplete(IbcLifecycleComplete::IbcTimeout{
+            channel: "channel_id".to_string(),
+            sequence: 1,
+        }),
+        stored_in_progress_recover_address: Some("recover_address".to_string()),
+        stored_callback: Some(IbcTransferCallback {
+            callback_address: "callback_address".to_string(),
+            coin: Coin::new(90, "uosmo"),
+        }),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "recover_address".to_string(),
+                    amount: vec![Coin::new(100, "uosmo")],
+                }.into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SkipCallback {
+                status: CallbackStatus::Timeout,
+                amount_out: Coin::new(90, "uosmo"),
+                ack_id: Some(CallbackAckId {
+                    channel_id: "channel_id".to_string(),
+                    sequence_id: 1,
+                }),
+            }.into_sub_msg("callback_address".to_string(), 2).unwrap(),
+        ],
+        expected_error_string: "".to_string(),
+    };
+    "Sudo Timeout With Callback - Send Failed Ibc Coin And Timeout Callback")]
+#[test_case(
+    Params {
+        contract_balance: vec![Coin::new(100, "uosmo")],
+        channel_id: "channel_id".to_string(),
+        sequence_id: 1,
+        sudo_msg: SudoMsg::IbcLifecycleComplete(IbcLifecycleComplete::IbcAck{
+            channel: "channel_id".to_string(),
+            sequence: 1,
+            ack: "".to_string(),
+            success: false,
+        }),
+        stored_in_progress_recover_address: Some("recover_address".to_string()),
+        stored_callback: Some(IbcTransferCallback {
+            callback_address: "callback_address".to_string(),
+            coin: Coin::new(90, "uosmo"),
+        }),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "recover_address".to_string(),
+                    amount: vec![Coin::new(100, "uosmo")],
+                }.into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SkipCallback {
+                status: CallbackStatus::Error,
+                amount_out: Coin::new(90, "uosmo"),
+                ack_id: Some(CallbackAckId {
+                    channel_id: "channel_id".to_string(),
+                    sequence_id: 1,
+                }),
+            }.into_sub_msg("callback_address".to_string(), 2).unwrap(),
+        ],
+        expected_error_string: "".to_string(),
+    };
+    "Sudo Error With Callback - Send Failed Ibc Coin And Error Callback")]
 #[test_case(
     Params {
         contract_balance: vec![Coin::new(100, "uosmo")],
@@ -112,6 +229,7 @@ struct Params {
             success: false,
         }),
         stored_in_progress_recover_address: None,
+        stored_callback: None,
         expected_messages: vec![],
         expected_error_string: "alloc::string::String not found".to_string(),
     };
@@ -128,6 +246,7 @@ struct Params {
             success: false,
         }),
         stored_in_progress_recover_address: Some("recover_address".to_string()),
+        stored_callback: None,
         expected_messages: vec![],
         expected_error_string: "Failed to receive ibc funds to refund the user".to_string(),
     };
@@ -152,6 +271,15 @@ fn test_sudo(params: Params) -> ContractResult<()> {
         )?;
     }
 
+    // Store the callback to state if it exists
+    if let Some(callback) = params.stored_callback.clone() {
+        ACK_ID_TO_CALLBACK.save(
+            deps.as_mut().storage,
+            (&params.channel_id, params.sequence_id),
+            &callback,
+        )?;
+    }
+
     // Call sudo with the given test parameters
     let res = skip_api_ibc_adapter_ibc_hooks::contract::sudo(deps.as_mut(), env, params.sudo_msg);
 
@@ -183,6 +311,11 @@ fn test_sudo(params: Params) -> ContractResult<()> {
                 ),
             };
 
+            // Verify the callback was removed from storage
+            assert!(ACK_ID_TO_CALLBACK
+                .may_load(&deps.storage, (&params.channel_id, params.sequence_id))?
+                .is_none());
+
             // Verify the messages in the response are correct
             assert_eq!(res.messages, params.expected_messages);
         }
@@ -1,21 +1,31 @@
 use crate::{
     error::{ContractError, ContractResult},
-    state::{ACK_ID_TO_RECOVER_ADDRESS, ENTRY_POINT_CONTRACT_ADDRESS, IN_PROGRESS_RECOVER_ADDRESS},
+    state::{
+        ACK_ID_TO_CALLBACK, ACK_ID_TO_RECOVER_ADDRESS, ENTRY_POINT_CONTRACT_ADDRESS,
+        IN_PROGRESS_CALLBACK, IN_PROGRESS_RECOVER_ADDRESS,
+    },
 };
 use cosmwasm_std::{
     entry_point, to_binary, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Reply,
     Response, SubMsg, SubMsgResult,
 };
+use cw2::set_contract_version;
 use neutron_proto::neutron::transfer::{MsgTransfer, MsgTransferResponse};
 use neutron_sdk::sudo::msg::{RequestPacket, TransferSudoMsg};
 use prost::Message;
 use skip::{
-    ibc::{AckID, ExecuteMsg, IbcInfo, InstantiateMsg, QueryMsg},
+    callback::{CallbackAckId, CallbackStatus, IbcTransferCallback, SkipCallback},
+    ibc::{AckID, ConfigResponse, ExecuteMsg, IbcInfo, InstantiateMsg, QueryMsg},
+    memo::validate_memo,
+    migrate::{validate_migration, MigrateMsg},
     proto_coin::ProtoCoin,
     sudo::SudoType,
 };
 
 const REPLY_ID: u64 = 1;
+const CALLBACK_REPLY_ID: u64 = 2;
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 ///////////////////
 /// INSTANTIATE ///
@@ -28,6 +38,9 @@ pub fn instantiate(
     _info: MessageInfo,
     msg: InstantiateMsg,
 ) -> ContractResult<Response> {
+    // Store the contract name and version for migrations
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
     // Validate entry point contract address
     let checked_entry_point_contract_address =
         deps.api.addr_validate(&msg.entry_point_contract_address)?;
@@ -43,6 +56,33 @@ pub fn instantiate(
         ))
 }
 
+///////////////
+/// MIGRATE ///
+///////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> ContractResult<Response> {
+    // Validate the migration and get the version being migrated from. Contracts deployed
+    // before cw2 version tracking are recognized by their stored entry point contract address.
+    let from_version = validate_migration(
+        deps.storage,
+        CONTRACT_NAME,
+        CONTRACT_VERSION,
+        ENTRY_POINT_CONTRACT_ADDRESS.exists(deps.storage),
+    )?;
+
+    // No-op state migration: ACK_ID_TO_RECOVER_ADDRESS is unchanged since v0.2.1
+    // and the callback state added in v0.3.0 starts out empty.
+
+    // Store the new contract version
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", from_version.to_string())
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
 ///////////////
 /// EXECUTE ///
 ///////////////
@@ -59,7 +99,16 @@ pub fn execute(
             info: ibc_info,
             coin,
             timeout_timestamp,
-        } => execute_ibc_transfer(deps, env, info, ibc_info, coin, timeout_timestamp),
+            callback_address,
+        } => execute_ibc_transfer(
+            deps,
+            env,
+            info,
+            ibc_info,
+            coin,
+            timeout_timestamp,
+            callback_address,
+        ),
     }
 }
 
@@ -73,6 +122,7 @@ fn execute_ibc_transfer(
     ibc_info: IbcInfo,
     coin: Coin,
     timeout_timestamp: u64,
+    callback_address: Option<String>,
 ) -> ContractResult<Response> {
     // Get entry point contract address from storage
     let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
@@ -88,11 +138,14 @@ fn execute_ibc_transfer(
         None => return Err(ContractError::IbcFeesRequired),
     };
 
+    // Validate the memo, erroring if it is a malformed json memo
+    validate_memo(&ibc_info.memo)?;
+
     // Create neutron ibc transfer message
     let msg = MsgTransfer {
         source_port: "transfer".to_string(),
         source_channel: ibc_info.source_channel,
-        token: Some(ProtoCoin(coin).into()),
+        token: Some(ProtoCoin(coin.clone()).into()),
         sender: env.contract.address.to_string(),
         receiver: ibc_info.receiver,
         timeout_height: None,
@@ -107,6 +160,18 @@ fn execute_ibc_transfer(
         &ibc_info.recover_address, // This address is verified in entry point
     )?;
 
+    // Save the in progress callback to storage if there is a callback address,
+    // to be used in sudo handler
+    if let Some(callback_address) = callback_address {
+        IN_PROGRESS_CALLBACK.save(
+            deps.storage,
+            &IbcTransferCallback {
+                callback_address, // This address is verified in entry point
+                coin,
+            },
+        )?;
+    }
+
     // Create sub message from neutron ibc transfer message to receive a reply
     let sub_msg = SubMsg::reply_on_success(msg, REPLY_ID);
 
@@ -126,6 +191,19 @@ fn execute_ibc_transfer(
 // ibc transfer from storage.
 #[entry_point]
 pub fn reply(deps: DepsMut, _env: Env, reply: Reply) -> ContractResult<Response> {
+    // Ignore the error of a failed callback sub message, so a failing
+    // callback contract never reverts the refund dispatched with it
+    if reply.id == CALLBACK_REPLY_ID {
+        return match reply.result {
+            SubMsgResult::Ok(_) => {
+                Ok(Response::new().add_attribute("action", "sub_msg_reply_callback_successful"))
+            }
+            SubMsgResult::Err(err) => Ok(Response::new()
+                .add_attribute("action", "sub_msg_reply_callback_failed")
+                .add_attribute("error", err)),
+        };
+    }
+
     // Error if the reply id is not the same as the one used in the sub message dispatched
     // This should never happen since we are using a constant reply id, but added in case
     // the wasm module doesn't behave as expected.
@@ -166,6 +244,13 @@ pub fn reply(deps: DepsMut, _env: Env, reply: Reply) -> ContractResult<Response>
     // Set the in progress recover address to storage, keyed by channel id and sequence id
     ACK_ID_TO_RECOVER_ADDRESS.save(deps.storage, ack_id, &in_progress_recover_address)?;
 
+    // Get and delete the in progress callback from storage, and set it
+    // to storage keyed by channel id and sequence id if there is one
+    if let Some(in_progress_callback) = IN_PROGRESS_CALLBACK.may_load(deps.storage)? {
+        IN_PROGRESS_CALLBACK.remove(deps.storage);
+        ACK_ID_TO_CALLBACK.save(deps.storage, ack_id, &in_progress_callback)?;
+    }
+
     Ok(Response::new().add_attribute("action", "sub_msg_reply_success"))
 }
 
@@ -174,14 +259,19 @@ pub fn reply(deps: DepsMut, _env: Env, reply: Reply) -> ContractResult<Response>
 ////////////
 
 // Handles the sudo acknowledgement from the neutron transfer module upon receiving
-// a packet acknowledge form the receiving chain of the ibc transfer
+// a packet acknowledge form the receiving chain of the ibc transfer, and sends the
+// SkipCallback to the callback address if the ibc transfer has one
 #[entry_point]
 pub fn sudo(deps: DepsMut, env: Env, msg: TransferSudoMsg) -> ContractResult<Response> {
-    // Get request and sudo type from sudo message
-    let (req, sudo_type) = match msg {
-        TransferSudoMsg::Response { request, .. } => (request, SudoType::Response),
-        TransferSudoMsg::Error { request, .. } => (request, SudoType::Error),
-        TransferSudoMsg::Timeout { request } => (request, SudoType::Timeout),
+    // Get request, sudo type and callback status from sudo message
+    let (req, sudo_type, callback_status) = match msg {
+        TransferSudoMsg::Response { request, .. } => {
+            (request, SudoType::Response, CallbackStatus::Success)
+        }
+        TransferSudoMsg::Error { request, .. } => (request, SudoType::Error, CallbackStatus::Error),
+        TransferSudoMsg::Timeout { request } => {
+            (request, SudoType::Timeout, CallbackStatus::Timeout)
+        }
     };
 
     // Get and remove the AckID <> in progress ibc transfer from storage
@@ -203,9 +293,33 @@ pub fn sudo(deps: DepsMut, env: Env, msg: TransferSudoMsg) -> ContractResult<Res
     // to the user's recover address.
     let bank_send_msg = BankMsg::Send { to_address, amount };
 
-    Ok(Response::new()
+    let mut response = Response::new()
         .add_message(bank_send_msg)
-        .add_attribute("action", sudo_type))
+        .add_attribute("action", sudo_type);
+
+    // Get and remove the AckID <> in progress callback from storage, and add the callback
+    // message after the refund if there is one. The callback message replies on error,
+    // so a failing callback never reverts the refund.
+    if let Some(callback) = ACK_ID_TO_CALLBACK.may_load(deps.storage, ack_id)? {
+        ACK_ID_TO_CALLBACK.remove(deps.storage, ack_id);
+
+        // Create the callback message with the coin transferred and the ack id
+        let callback_msg = SkipCallback {
+            status: callback_status,
+            amount_out: callback.coin,
+            ack_id: Some(CallbackAckId {
+                channel_id: ack_id.0.to_string(),
+                sequence_id: ack_id.1,
+            }),
+        }
+        .into_sub_msg(callback.callback_address, CALLBACK_REPLY_ID)?;
+
+        response = response
+            .add_submessage(callback_msg)
+            .add_attribute("action", "dispatch_skip_callback");
+    }
+
+    Ok(response)
 }
 
 ////////////////////////
@@ -213,7 +327,7 @@ pub fn sudo(deps: DepsMut, env: Env, msg: TransferSudoMsg) -> ContractResult<Res
 ////////////////////////
 
 // Helper function to get the ack_id (channel id, sequence id) from a RequestPacket
-fn get_ack_id(req: &RequestPacket) -> ContractResult<AckID> {
+fn get_ack_id(req: &RequestPacket) -> ContractResult<AckID<'_>> {
     // Get the channel id and sequence id from the request packet
     let channel_id = req
         .source_channel
@@ -232,6 +346,9 @@ fn get_ack_id(req: &RequestPacket) -> ContractResult<AckID> {
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
     match msg {
+        QueryMsg::Config {} => to_binary(&ConfigResponse {
+            entry_point_contract_address: ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?,
+        }),
         QueryMsg::InProgressRecoverAddress {
             channel_id,
             sequence_id,
@@ -1,4 +1,5 @@
 use cosmwasm_std::{OverflowError, StdError};
+use skip::error::SkipError;
 use thiserror::Error;
 
 pub type ContractResult<T> = core::result::Result<T, ContractError>;
@@ -14,6 +15,9 @@ pub enum ContractError {
     #[error(transparent)]
     Overflow(#[from] OverflowError),
 
+    #[error(transparent)]
+    Skip(#[from] SkipError),
+
     #[error("Unauthorized")]
     Unauthorized,
 
@@ -22,6 +22,7 @@ Expect Response
 Expect Error
     - Unauthorized Caller (Only the stored entry point contract can call this function)
     - No IBC Fees Provided (IBC fees are required for Osmosis)
+    - Json Memo Forwards And Calls A Contract
  */
 
 // Define test parameters
@@ -127,6 +128,30 @@ struct Params {
         expected_error_string: "IBC fees are required".to_string(),
     };
     "No IBC Fees Provided - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        ibc_adapter_contract_address: Addr::unchecked("ibc_transfer".to_string()),
+        coin: Coin::new(100, "osmo"),
+        ibc_info: IbcInfo {
+            source_channel: "source_channel".to_string(),
+            receiver: "receiver".to_string(),
+            fee: Some(IbcFee {
+                recv_fee: vec![],
+                ack_fee: vec![Coin {
+                    denom: "ntrn".to_string(),
+                    amount: Uint128::new(10),
+                }],
+                timeout_fee: vec![],
+            }),
+            memo: r#"{"forward":{"channel":"channel-1","port":"transfer","receiver":"receiver"},"wasm":{"contract":"contract","msg":{}}}"#.to_string(),
+            recover_address: "recover_address".to_string(),
+        },
+        timeout_timestamp: 100,
+        expected_messages: vec![],
+        expected_error_string: "Memo Cannot Both Forward And Call A Contract".to_string(),
+    };
+    "Json Memo Forwards And Calls A Contract - Expect Error")]
 fn test_execute_ibc_transfer(params: Params) -> ContractResult<()> {
     // Create mock dependencies
     let mut deps = mock_dependencies();
@@ -150,6 +175,7 @@ fn test_execute_ibc_transfer(params: Params) -> ContractResult<()> {
             info: params.ibc_info.clone(),
             coin: params.coin.clone(),
             timeout_timestamp: params.timeout_timestamp,
+            callback_address: None,
         },
     );
 
@@ -5,9 +5,10 @@ use cosmwasm_std::{
     StdError, SubMsg,
 };
 use neutron_sdk::sudo::msg::{RequestPacket, TransferSudoMsg};
+use skip::callback::{CallbackAckId, CallbackStatus, IbcTransferCallback, SkipCallback};
 use skip_api_ibc_adapter_neutron_transfer::{
     error::{ContractError, ContractResult},
-    state::ACK_ID_TO_RECOVER_ADDRESS,
+    state::{ACK_ID_TO_CALLBACK, ACK_ID_TO_RECOVER_ADDRESS},
 };
 use test_case::test_case;
 
@@ -20,6 +21,9 @@ Expect Success
     - Sudo Timeout - Send Ibc Coin And Ack Fee Different Denom
     - Sudo Error - Send Ibc Coin And Timeout Fee Same Denom
     - Sudo Error - Send Ibc Coin And Timeout Fee Different Denom
+    - Sudo Response With Callback - Send Timeout Fee And Success Callback
+    - Sudo Timeout With Callback - Send Ibc Coin And Timeout Callback
+    - Sudo Error With Callback - Send Ibc Coin And Error Callback
 
 Expect Error
     - No In Progress Recover Address Mapped To Sudo Ack ID - Expect Error
@@ -36,6 +40,7 @@ struct Params {
     sequence_id: u64,
     sudo_msg: TransferSudoMsg,
     stored_in_progress_recover_address: Option<String>,
+    stored_callback: Option<IbcTransferCallback>,
     expected_messages: Vec<SubMsg>,
     expected_error: Option<ContractError>,
 }
@@ -60,6 +65,7 @@ struct Params {
             data: Binary::from(b""),
         },
         stored_in_progress_recover_address: Some("recover_address".to_string()),
+        stored_callback: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -92,6 +98,7 @@ struct Params {
             },
         },
         stored_in_progress_recover_address: Some("recover_address".to_string()),
+        stored_callback: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -124,6 +131,7 @@ struct Params {
             },
         },
         stored_in_progress_recover_address: Some("recover_address".to_string()),
+        stored_callback: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -157,6 +165,7 @@ struct Params {
             details: "".to_string(),
         },
         stored_in_progress_recover_address: Some("recover_address".to_string()),
+        stored_callback: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -190,6 +199,7 @@ struct Params {
             details: "".to_string(),
         },
         stored_in_progress_recover_address: Some("recover_address".to_string()),
+        stored_callback: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -204,6 +214,140 @@ struct Params {
         expected_error: None,
     };
     "Sudo Error - Send Ibc Coin And Timeout Fee Different Denom")]
+#[test_case(
+    Params {
+        contract_balance: vec![Coin::new(100, "uosmo")],
+        channel_id: "channel_id".to_string(),
+        sequence_id: 1,
+        sudo_msg: TransferSudoMsg::Response {
+            request: RequestPacket {
+                sequence: Some(1),
+                source_port: None,
+                source_channel: Some("channel_id".to_string()),
+                destination_port: None,
+                destination_channel: None,
+                data: None,
+                timeout_height: None,
+                timeout_timestamp: None,
+            },
+            data: Binary::from(b""),
+        },
+        stored_in_progress_recover_address: Some("recover_address".to_string()),
+        stored_callback: Some(IbcTransferCallback {
+            callback_address: "callback_address".to_string(),
+            coin: Coin::new(90, "uosmo"),
+        }),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "recover_address".to_string(),
+                    amount: vec![Coin::new(100, "uosmo")],
+                }.into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SkipCallback {
+                status: CallbackStatus::Success,
+                amount_out: Coin::new(90, "uosmo"),
+                ack_id: Some(CallbackAckId {
+                    channel_id: "channel_id".to_string(),
+                    sequence_id: 1,
+                }),
+            }.into_sub_msg("callback_address".to_string(), 2).unwrap(),
+        ],
+        expected_error: None,
+    };
+    "Sudo Response With Callback - Send Timeout Fee And Success Callback")]
+#[test_case(
+    Params {
+        contract_balance: vec![Coin::new(100, "uosmo")],
+        channel_id: "channel_id".to_string(),
+        sequence_id: 1,
+        sudo_msg: TransferSudoMsg::Timeout {
+            request: RequestPacket {
+                sequence: Some(1),
+                source_port: None,
+                source_channel: Some("channel_id".to_string()),
+                destination_port: None,
+                destination_channel: None,
+                data: None,
+                timeout_height: None,
+                timeout_timestamp: None,
+            },
+        },
+        stored_in_progress_recover_address: Some("recover_address".to_string()),
+        stored_callback: Some(IbcTransferCallback {
+            callback_address: "callback_address".to_string(),
+            coin: Coin::new(90, "uosmo"),
+        }),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "recover_address".to_string(),
+                    amount: vec![Coin::new(100, "uosmo")],
+                }.into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SkipCallback {
+                status: CallbackStatus::Timeout,
+                amount_out: Coin::new(90, "uosmo"),
+                ack_id: Some(CallbackAckId {
+                    channel_id: "channel_id".to_string(),
+                    sequence_id: 1,
+                }),
+            }.into_sub_msg("callback_address".to_string(), 2).unwrap(),
+        ],
+        expected_error: None,
+    };
+    "Sudo Timeout With Callback - Send Ibc Coin And Timeout Callback")]
+#[test_case(
+    Params {
+        contract_balance: vec![Coin::new(100, "uosmo")],
+        channel_id: "channel_id".to_string(),
+        sequence_id: 1,
+        sudo_msg: TransferSudoMsg::Error {
+            request: RequestPacket {
+                sequence: Some(1),
+                source_port: None,
+                source_channel: Some("channel_id".to_string()),
+                destination_port: None,
+                destination_channel: None,
+                data: None,
+                timeout_height: None,
+                timeout_timestamp: None,
+            },
+            details: "".to_string(),
+        },
+        stored_in_progress_recover_address: Some("recover_address".to_string()),
+        stored_callback: Some(IbcTransferCallback {
+            callback_address: "callback_address".to_string(),
+            coin: Coin::new(90, "uosmo"),
+        }),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "recover_address".to_string(),
+                    amount: vec![Coin::new(100, "uosmo")],
+                }.into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SkipCallback {
+                status: CallbackStatus::Error,
+                amount_out: Coin::new(90, "uosmo"),
+                ack_id: Some(CallbackAckId {
+                    channel_id: "channel_id".to_string(),
+                    sequence_id: 1,
+                }),
+            }.into_sub_msg("callback_address".to_string(), 2).unwrap(),
+        ],
+        expected_error: None,
+    };
+    "Sudo Error With Callback - Send Ibc Coin And Error Callback")]
 #[test_case(
     Params {
         contract_balance: vec![Coin::new(100, "uosmo")],
@@ -223,6 +367,7 @@ struct Params {
             details: "".to_string(),
         },
         stored_in_progress_recover_address: None,
+        stored_callback: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Std(StdError::NotFound {
             kind: "alloc::string::String".to_string(),
@@ -248,6 +393,7 @@ struct Params {
             details: "".to_string(),
         },
         stored_in_progress_recover_address: None,
+        stored_callback: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::ChannelIDNotFound),
     };
@@ -271,6 +417,7 @@ struct Params {
             details: "".to_string(),
         },
         stored_in_progress_recover_address: None,
+        stored_callback: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::SequenceNotFound),
     };
@@ -294,6 +441,7 @@ struct Params {
             details: "".to_string(),
         },
         stored_in_progress_recover_address: Some("recover_address".to_string()),
+        stored_callback: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::NoFundsToRefund),
     };
@@ -318,6 +466,15 @@ fn test_sudo(params: Params) -> ContractResult<()> {
         )?;
     }
 
+    // Store the callback to state if it exists
+    if let Some(callback) = params.stored_callback.clone() {
+        ACK_ID_TO_CALLBACK.save(
+            deps.as_mut().storage,
+            (&params.channel_id, params.sequence_id),
+            &callback,
+        )?;
+    }
+
     // Call sudo with the given test parameters
     let res =
         skip_api_ibc_adapter_neutron_transfer::contract::sudo(deps.as_mut(), env, params.sudo_msg);
@@ -350,6 +507,11 @@ fn test_sudo(params: Params) -> ContractResult<()> {
                 ),
             };
 
+            // Verify the callback was removed from storage
+            assert!(ACK_ID_TO_CALLBACK
+                .may_load(&deps.storage, (&params.channel_id, params.sequence_id))?
+                .is_none());
+
             // Verify the messages in the response are correct
             assert_eq!(res.messages, params.expected_messages);
         }
@@ -3,22 +3,39 @@ use crate::{
     state::{ENTRY_POINT_CONTRACT_ADDRESS, ROUTER_CONTRACT_ADDRESS},
 };
 use astroport::{
-    asset::{Asset, AssetInfo},
-    pair::{QueryMsg as PairQueryMsg, ReverseSimulationResponse},
+    asset::{Asset, AssetInfo, PairInfo},
+    pair::{
+        Cw20HookMsg as PairCw20HookMsg, ExecuteMsg as PairExecuteMsg, PoolResponse,
+        QueryMsg as PairQueryMsg, ReverseSimulationResponse, SimulationResponse,
+    },
     router::{
-        ExecuteMsg as RouterExecuteMsg, QueryMsg as RouterQueryMsg, SimulateSwapOperationsResponse,
+        Cw20HookMsg as RouterCw20HookMsg, ExecuteMsg as RouterExecuteMsg,
+        QueryMsg as RouterQueryMsg, SimulateSwapOperationsResponse,
     },
 };
 use cosmwasm_std::{
-    entry_point, to_binary, Addr, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Response, Uint128,
-    WasmMsg,
+    entry_point, from_binary, to_binary, Addr, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut,
+    Env, MessageInfo, Response, StdResult, Uint128, WasmMsg,
 };
+use cw2::set_contract_version;
+use cw20::Cw20ReceiveMsg;
 use cw_utils::one_coin;
-use skip::swap::{
-    execute_transfer_funds_back, ExecuteMsg, NeutronInstantiateMsg as InstantiateMsg, QueryMsg,
-    SwapOperation,
+use skip::{
+    asset::{cw20_contract_address, cw20_denom, execute_msg_with_coin, transfer_msg},
+    error::SkipError,
+    migrate::{validate_migration, MigrateMsg},
+    swap::{
+        astroport_asset_info, execute_transfer_funds_back, provide_liquidity_min_amount,
+        provide_liquidity_reserves, provide_liquidity_swap_amount,
+        validate_withdraw_liquidity_denoms, ConfigResponse, Cw20HookMsg, ExecuteMsg,
+        NeutronInstantiateMsg as InstantiateMsg, QueryMsg, SimulateSwapWithMetadataResponse,
+        SwapFee, SwapOperation, SwapOperationMetadata,
+    },
 };
 
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 ///////////////////
 /// INSTANTIATE ///
 ///////////////////
@@ -30,6 +47,9 @@ pub fn instantiate(
     _info: MessageInfo,
     msg: InstantiateMsg,
 ) -> ContractResult<Response> {
+    // Store the contract name and version for migrations
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
     // Validate entry point contract address
     let checked_entry_point_contract_address =
         deps.api.addr_validate(&msg.entry_point_contract_address)?;
@@ -55,6 +75,30 @@ pub fn instantiate(
         ))
 }
 
+///////////////
+/// MIGRATE ///
+///////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> ContractResult<Response> {
+    // Validate the migration and get the version being migrated from. Contracts deployed
+    // before cw2 version tracking are recognized by their stored entry point contract address.
+    let from_version = validate_migration(
+        deps.storage,
+        CONTRACT_NAME,
+        CONTRACT_VERSION,
+        ENTRY_POINT_CONTRACT_ADDRESS.exists(deps.storage),
+    )?;
+
+    // Store the new contract version
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", from_version.to_string())
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
 ///////////////
 /// EXECUTE ///
 ///////////////
@@ -67,10 +111,86 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> ContractResult<Response> {
     match msg {
-        ExecuteMsg::Swap { operations } => execute_swap(deps, env, info, operations),
+        ExecuteMsg::Swap {
+            operations,
+            minimum_receive,
+            max_spread,
+        } => execute_swap(deps, env, info, operations, minimum_receive, max_spread),
+        // The astroport router has no exact out swaps, so the full coin sent is swapped
+        // with the coin out amount as the minimum receive
+        ExecuteMsg::SwapExactCoinOut {
+            operations,
+            coin_out,
+        } => execute_swap_exact_coin_out(deps, env, info, operations, coin_out),
+        ExecuteMsg::ProvideLiquidity {
+            pool,
+            recipient,
+            slippage_tolerance,
+        } => execute_provide_liquidity(deps, env, info, pool, recipient, slippage_tolerance),
+        ExecuteMsg::WithdrawLiquidity { pool, denoms_out } => {
+            execute_withdraw_liquidity(deps, env, info, pool, denoms_out)
+        }
+        ExecuteMsg::Receive(cw20_msg) => execute_receive(deps, env, info, cw20_msg),
         ExecuteMsg::TransferFundsBack { swapper } => {
             Ok(execute_transfer_funds_back(deps, env, info, swapper)?)
         }
+        ExecuteMsg::ProvideLiquidityWithBalances {
+            pool,
+            denoms,
+            swapper,
+            slippage_tolerance,
+            ..
+        } => execute_provide_liquidity_with_balances(
+            deps,
+            env,
+            info,
+            pool,
+            denoms,
+            swapper,
+            slippage_tolerance,
+        ),
+    }
+}
+
+// Receives a CW20 coin in and executes the swap (or liquidity withdrawal) in the
+// CW20 hook message as if the CW20 sender had called the contract with the coin as funds
+fn execute_receive(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> ContractResult<Response> {
+    // Create the message info of the CW20 sender, with the
+    // CW20 coin in (named by the CW20 contract address) as funds
+    let cw20_info = MessageInfo {
+        sender: deps.api.addr_validate(&cw20_msg.sender)?,
+        funds: vec![Coin {
+            denom: cw20_denom(info.sender.as_str()),
+            amount: cw20_msg.amount,
+        }],
+    };
+
+    // Execute the swap or liquidity withdrawal in the CW20 hook message
+    match from_binary(&cw20_msg.msg)? {
+        Cw20HookMsg::Swap {
+            operations,
+            minimum_receive,
+            max_spread,
+        } => execute_swap(
+            deps,
+            env,
+            cw20_info,
+            operations,
+            minimum_receive,
+            max_spread,
+        ),
+        Cw20HookMsg::SwapExactCoinOut {
+            operations,
+            coin_out,
+        } => execute_swap_exact_coin_out(deps, env, cw20_info, operations, coin_out),
+        Cw20HookMsg::WithdrawLiquidity { pool, denoms_out } => {
+            execute_withdraw_liquidity(deps, env, cw20_info, pool, denoms_out)
+        }
     }
 }
 
@@ -79,6 +199,8 @@ fn execute_swap(
     env: Env,
     info: MessageInfo,
     operations: Vec<SwapOperation>,
+    minimum_receive: Option<Uint128>,
+    max_spread: Option<Decimal>,
 ) -> ContractResult<Response> {
     // Get entry point contract address from storage
     let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
@@ -94,8 +216,11 @@ fn execute_swap(
     // Create the astroport swap message
     let swap_msg = create_astroport_swap_msg(
         ROUTER_CONTRACT_ADDRESS.load(deps.storage)?,
+        &info.sender,
         coin_in,
         operations,
+        minimum_receive,
+        max_spread,
     )?;
 
     // Create the transfer funds back message
@@ -113,33 +238,363 @@ fn execute_swap(
         .add_attribute("action", "dispatch_swap_and_transfer_back"))
 }
 
+// Swaps the simulated coin in needed to receive the coin out, at most the coin sent, with the
+// coin out amount as the minimum receive, as the astroport router has no native exact out swap.
+// The rest of the coin sent is returned to the caller along with the coin out.
+fn execute_swap_exact_coin_out(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    operations: Vec<SwapOperation>,
+    coin_out: Coin,
+) -> ContractResult<Response> {
+    // Get entry point contract address from storage
+    let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
+
+    // Enforce the caller is the entry point contract
+    if info.sender != entry_point_contract_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Get the max coin in from the message info, error if there is not exactly one coin sent
+    let max_coin_in = one_coin(&info)?;
+
+    // Simulate the coin in needed to receive the coin out
+    let coin_in_needed =
+        query_simulate_swap_exact_coin_out(deps.as_ref(), coin_out.clone(), operations.clone())?;
+
+    // Swap the coin in needed, capped at the max coin in so the swap fails at the
+    // router on its minimum receive if the max coin in is not enough
+    let coin_in = Coin {
+        denom: max_coin_in.denom.clone(),
+        amount: coin_in_needed.amount.min(max_coin_in.amount),
+    };
+
+    // Create a response object to return
+    let mut response: Response = Response::new();
+
+    // Return the unused coin in to the caller, sent directly as
+    // transferring funds back only returns the contract's bank balances
+    let unused_amount = max_coin_in.amount.checked_sub(coin_in.amount)?;
+    if !unused_amount.is_zero() {
+        response = response.add_message(transfer_msg(
+            info.sender.to_string(),
+            Coin {
+                denom: max_coin_in.denom,
+                amount: unused_amount,
+            },
+        )?);
+    }
+
+    // Create the astroport swap message
+    let swap_msg = create_astroport_swap_msg(
+        ROUTER_CONTRACT_ADDRESS.load(deps.storage)?,
+        &info.sender,
+        coin_in,
+        operations,
+        Some(coin_out.amount),
+        None,
+    )?;
+
+    // Create the transfer funds back message
+    let transfer_funds_back_msg = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_binary(&ExecuteMsg::TransferFundsBack {
+            swapper: info.sender,
+        })?,
+        funds: vec![],
+    };
+
+    Ok(response
+        .add_message(swap_msg)
+        .add_message(transfer_funds_back_msg)
+        .add_attribute("action", "dispatch_swap_exact_coin_out_and_transfer_back"))
+}
+
+// Swaps part of the coin in into the other asset of the astroport pair, using the swap
+// simulation to balance the two assets, and then provides liquidity with both assets,
+// minting the LP tokens to the recipient if one is given, or else to the caller
+fn execute_provide_liquidity(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pool: String,
+    recipient: Option<String>,
+    slippage_tolerance: Decimal,
+) -> ContractResult<Response> {
+    // Get entry point contract address from storage
+    let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
+
+    // Enforce the caller is the entry point contract
+    if info.sender != entry_point_contract_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Get coin in from the message info, error if there is not exactly one coin sent
+    let coin_in = one_coin(&info)?;
+
+    // Get the address the LP tokens are sent to, which is the validated
+    // recipient if one is given, or else the caller
+    let lp_recipient = match recipient {
+        Some(recipient) => deps.api.addr_validate(&recipient)?,
+        None => info.sender.clone(),
+    };
+
+    // Query the astroport pair contract for its reserves, and get the reserve
+    // of the coin in denom and the reserve of the pair's other asset
+    let res: PoolResponse = deps
+        .querier
+        .query_wasm_smart(&pool, &PairQueryMsg::Pool {})?;
+    let pool_reserves = res
+        .assets
+        .into_iter()
+        .map(|asset| Coin {
+            denom: asset_denom(asset.info),
+            amount: asset.amount,
+        })
+        .collect();
+    let (reserve_in, reserve_out) = provide_liquidity_reserves(pool_reserves, &coin_in.denom)?;
+
+    // Error if the pair's other asset is a CW20, which cannot be sent as funds
+    if cw20_contract_address(&reserve_out.denom).is_some() {
+        return Err(ContractError::ProvideLiquidityCw20NotSupported);
+    }
+
+    // Get the amount of the coin in to swap into the pair's other asset
+    let swap_operation = SwapOperation {
+        pool: pool.clone(),
+        denom_in: coin_in.denom.clone(),
+        denom_out: reserve_out.denom.clone(),
+    };
+    let swap_amount =
+        provide_liquidity_swap_amount(coin_in.amount, reserve_in, reserve_out.amount, |amount| {
+            query_simulate_swap_exact_coin_in(
+                deps.as_ref(),
+                Coin {
+                    denom: coin_in.denom.clone(),
+                    amount,
+                },
+                vec![swap_operation.clone()],
+            )
+            .map(|coin_out| coin_out.amount)
+        })?;
+
+    // Error if none of the coin in can be swapped
+    if swap_amount.is_zero() {
+        return Err(SkipError::ProvideLiquidityCoinInTooSmall.into());
+    }
+    let swap_coin_in = Coin {
+        denom: coin_in.denom.clone(),
+        amount: swap_amount,
+    };
+
+    // Get the minimum receive of the swap from its simulated coin out and the slippage tolerance
+    let swap_coin_out = query_simulate_swap_exact_coin_in(
+        deps.as_ref(),
+        swap_coin_in.clone(),
+        vec![swap_operation.clone()],
+    )?;
+    let minimum_receive = provide_liquidity_min_amount(swap_coin_out.amount, slippage_tolerance)?;
+
+    // Create the astroport swap message
+    let swap_msg = create_astroport_swap_msg(
+        ROUTER_CONTRACT_ADDRESS.load(deps.storage)?,
+        &info.sender,
+        swap_coin_in,
+        vec![swap_operation],
+        Some(minimum_receive),
+        None,
+    )?;
+
+    // Create the provide liquidity message, which provides
+    // the contract's balances of both assets after the swap
+    let provide_liquidity_msg = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_binary(&ExecuteMsg::ProvideLiquidityWithBalances {
+            pool,
+            denoms: vec![coin_in.denom, reserve_out.denom],
+            swapper: lp_recipient,
+            slippage_tolerance,
+            min_lp_out: None,
+        })?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_message(swap_msg)
+        .add_message(provide_liquidity_msg)
+        .add_attribute("action", "dispatch_swap_and_provide_liquidity"))
+}
+
+// Provides liquidity to the astroport pair with the contract's balances of the given denoms
+// and the slippage tolerance, minting the LP tokens to the swapper.
+// Can only be called by the contract itself
+fn execute_provide_liquidity_with_balances(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pool: String,
+    denoms: Vec<String>,
+    swapper: Addr,
+    slippage_tolerance: Decimal,
+) -> ContractResult<Response> {
+    // Ensure the caller is the contract itself
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Query the contract's balances of the given denoms, sorted by denom as funds must be
+    let mut balances = denoms
+        .into_iter()
+        .map(|denom| deps.querier.query_balance(&env.contract.address, denom))
+        .collect::<StdResult<Vec<Coin>>>()?;
+    balances.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+    // Create the astroport provide liquidity message
+    let provide_liquidity_msg = WasmMsg::Execute {
+        contract_addr: pool,
+        msg: to_binary(&PairExecuteMsg::ProvideLiquidity {
+            assets: balances
+                .iter()
+                .map(|coin| Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: coin.denom.clone(),
+                    },
+                    amount: coin.amount,
+                })
+                .collect(),
+            slippage_tolerance: Some(slippage_tolerance),
+            auto_stake: None,
+            receiver: Some(swapper.to_string()),
+        })?,
+        funds: balances,
+    };
+
+    Ok(Response::new()
+        .add_message(provide_liquidity_msg)
+        .add_attribute("action", "dispatch_provide_liquidity"))
+}
+
+// Withdraws liquidity from the astroport pair with the LP tokens sent, and
+// then transfers the withdrawn assets back to the caller
+fn execute_withdraw_liquidity(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pool: String,
+    denoms_out: Vec<String>,
+) -> ContractResult<Response> {
+    // Get entry point contract address from storage
+    let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
+
+    // Enforce the caller is the entry point contract
+    if info.sender != entry_point_contract_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Get coin in from the message info, error if there is not exactly one coin sent
+    let coin_in = one_coin(&info)?;
+
+    // Query the astroport pair contract for its LP token and assets
+    let pair_info: PairInfo = deps
+        .querier
+        .query_wasm_smart(&pool, &PairQueryMsg::Pair {})?;
+
+    // Error if the coin in is not the pair's LP token
+    if coin_in.denom != cw20_denom(pair_info.liquidity_token.as_str()) {
+        return Err(SkipError::WithdrawLiquidityCoinInNotPoolShare.into());
+    }
+
+    // Error if the pair has a CW20 asset, which transferring funds back cannot return
+    let pool_denoms: Vec<String> = pair_info.asset_infos.into_iter().map(asset_denom).collect();
+    if pool_denoms
+        .iter()
+        .any(|denom| cw20_contract_address(denom).is_some())
+    {
+        return Err(ContractError::WithdrawLiquidityCw20NotSupported);
+    }
+
+    // Error if the pair's assets are not the denoms out
+    validate_withdraw_liquidity_denoms(pool_denoms, denoms_out)?;
+
+    // Create the astroport withdraw liquidity message, sending the LP tokens to the pair
+    let withdraw_liquidity_msg = execute_msg_with_coin(
+        pool,
+        to_binary(&PairCw20HookMsg::WithdrawLiquidity { assets: vec![] })?,
+        coin_in,
+    )?;
+
+    // Create the transfer funds back message, which returns the withdrawn assets
+    let transfer_funds_back_msg = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_binary(&ExecuteMsg::TransferFundsBack {
+            swapper: info.sender,
+        })?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_message(withdraw_liquidity_msg)
+        .add_message(transfer_funds_back_msg)
+        .add_attribute("action", "dispatch_withdraw_liquidity_and_transfer_back"))
+}
+
 ////////////////////////
 /// HELPER FUNCTIONS ///
 ////////////////////////
 
+// Converts an astroport asset info to a denom, which is the CW20
+// denom for tokens and the native denom for native tokens
+fn asset_denom(asset_info: AssetInfo) -> String {
+    match asset_info {
+        AssetInfo::Token { contract_addr } => cw20_denom(contract_addr.as_str()),
+        AssetInfo::NativeToken { denom } => denom,
+    }
+}
+
 // Converts the swap operations to astroport AstroSwap operations
 fn create_astroport_swap_msg(
     router_contract_address: Addr,
+    swapper: &Addr,
     coin_in: Coin,
     swap_operations: Vec<SwapOperation>,
-) -> ContractResult<WasmMsg> {
+    minimum_receive: Option<Uint128>,
+    max_spread: Option<Decimal>,
+) -> ContractResult<CosmosMsg> {
+    // Send a CW20 coin out from the router directly to the swapper, since
+    // transferring funds back only returns the contract's bank balances
+    let to = swap_operations
+        .last()
+        .filter(|op| cw20_contract_address(&op.denom_out).is_some())
+        .map(|_| swapper.to_string());
+
     // Convert the swap operations to astroport swap operations
     let astroport_swap_operations = swap_operations.into_iter().map(From::from).collect();
 
-    // Create the astroport router execute message arguments
-    let astroport_router_msg_args = RouterExecuteMsg::ExecuteSwapOperations {
-        operations: astroport_swap_operations,
-        minimum_receive: None,
-        to: None,
-        max_spread: None,
+    // Create the astroport router message arguments, passing through the minimum receive
+    // and max spread so the swap fails at the router if not met. A CW20 coin in is sent
+    // to the router with the arguments as the CW20 hook message.
+    let astroport_router_msg_args = match cw20_contract_address(&coin_in.denom) {
+        Some(_) => to_binary(&RouterCw20HookMsg::ExecuteSwapOperations {
+            operations: astroport_swap_operations,
+            minimum_receive,
+            to,
+            max_spread,
+        })?,
+        None => to_binary(&RouterExecuteMsg::ExecuteSwapOperations {
+            operations: astroport_swap_operations,
+            minimum_receive,
+            to,
+            max_spread,
+        })?,
     };
 
     // Create the astroport router swap message
-    let swap_msg = WasmMsg::Execute {
-        contract_addr: router_contract_address.to_string(),
-        msg: to_binary(&astroport_router_msg_args)?,
-        funds: vec![coin_in],
-    };
+    let swap_msg = execute_msg_with_coin(
+        router_contract_address.to_string(),
+        astroport_router_msg_args,
+        coin_in,
+    )?;
 
     Ok(swap_msg)
 }
@@ -151,6 +606,10 @@ fn create_astroport_swap_msg(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
     match msg {
+        QueryMsg::Config {} => to_binary(&ConfigResponse {
+            entry_point_contract_address: ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?,
+            router_contract_address: Some(ROUTER_CONTRACT_ADDRESS.load(deps.storage)?),
+        }),
         QueryMsg::RouterContractAddress {} => {
             to_binary(&ROUTER_CONTRACT_ADDRESS.load(deps.storage)?)
         }
@@ -170,6 +629,25 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
             coin_out,
             swap_operations,
         )?),
+        QueryMsg::SimulateSwapExactCoinInWithMetadata {
+            coin_in,
+            swap_operations,
+        } => to_binary(&query_simulate_swap_exact_coin_in_with_metadata(
+            deps,
+            coin_in,
+            swap_operations,
+        )?),
+        QueryMsg::SimulateSwapExactCoinOutWithMetadata {
+            coin_out,
+            swap_operations,
+        } => to_binary(&query_simulate_swap_exact_coin_out_with_metadata(
+            deps,
+            coin_out,
+            swap_operations,
+        )?),
+        QueryMsg::SimulateWithdrawLiquidity { pool, coin_in } => {
+            to_binary(&query_simulate_withdraw_liquidity(deps, pool, coin_in)?)
+        }
     }
     .map_err(From::from)
 }
@@ -221,6 +699,108 @@ fn query_simulate_swap_exact_coin_out(
     coin_out: Coin,
     swap_operations: Vec<SwapOperation>,
 ) -> ContractResult<Coin> {
+    // Simulate the swap with metadata and return the coin in needed
+    let res = query_simulate_swap_exact_coin_out_with_metadata(deps, coin_out, swap_operations)?;
+
+    Ok(res.coin_in)
+}
+
+// Queries the astroport pair contract to simulate withdrawing liquidity with its LP token
+fn query_simulate_withdraw_liquidity(
+    deps: Deps,
+    pool: String,
+    coin_in: Coin,
+) -> ContractResult<Vec<Coin>> {
+    // Query the astroport pair contract for its LP token
+    let pair_info: PairInfo = deps
+        .querier
+        .query_wasm_smart(&pool, &PairQueryMsg::Pair {})?;
+
+    // Error if the coin in is not the pair's LP token
+    if coin_in.denom != cw20_denom(pair_info.liquidity_token.as_str()) {
+        return Err(SkipError::WithdrawLiquidityCoinInNotPoolShare.into());
+    }
+
+    // Query the astroport pair contract for the assets withdrawn by the LP token amount
+    let assets: Vec<Asset> = deps.querier.query_wasm_smart(
+        &pool,
+        &PairQueryMsg::Share {
+            amount: coin_in.amount,
+        },
+    )?;
+
+    // Return the withdrawn assets as coins
+    Ok(assets
+        .into_iter()
+        .map(|asset| Coin {
+            denom: asset_denom(asset.info),
+            amount: asset.amount,
+        })
+        .collect())
+}
+
+// Queries the astroport pool contracts to simulate a multi-hop swap exact amount in,
+// returning the coins, prices and fees of each swap operation
+fn query_simulate_swap_exact_coin_in_with_metadata(
+    deps: Deps,
+    coin_in: Coin,
+    swap_operations: Vec<SwapOperation>,
+) -> ContractResult<SimulateSwapWithMetadataResponse> {
+    // Error if swap operations is empty
+    let Some(first_op) = swap_operations.first() else {
+        return Err(ContractError::SwapOperationsEmpty);
+    };
+
+    // Ensure coin_in's denom is the same as the first swap operation's denom in
+    if coin_in.denom != first_op.denom_in {
+        return Err(ContractError::CoinInDenomMismatch);
+    }
+
+    // Iterate through the swap operations in order, querying the astroport pool contracts
+    // to simulate each swap operation with the coin out of the previous swap operation
+    let mut operations = Vec::with_capacity(swap_operations.len());
+    let mut coin_in = coin_in;
+    for operation in swap_operations {
+        let res: SimulationResponse = deps.querier.query_wasm_smart(
+            &operation.pool,
+            &PairQueryMsg::Simulation {
+                offer_asset: Asset {
+                    info: astroport_asset_info(operation.denom_in.clone()),
+                    amount: coin_in.amount,
+                },
+                ask_asset_info: Some(astroport_asset_info(operation.denom_out.clone())),
+            },
+        )?;
+
+        let coin_out = Coin {
+            denom: operation.denom_out,
+            amount: res.return_amount,
+        };
+
+        operations.push(create_swap_operation_metadata(
+            operation.pool,
+            coin_in,
+            coin_out.clone(),
+            res.return_amount,
+            res.spread_amount,
+            res.commission_amount,
+        )?);
+
+        coin_in = coin_out;
+    }
+
+    Ok(SimulateSwapWithMetadataResponse::from_operations(
+        operations,
+    )?)
+}
+
+// Queries the astroport pool contracts to simulate a multi-hop swap exact amount out,
+// returning the coins, prices and fees of each swap operation
+fn query_simulate_swap_exact_coin_out_with_metadata(
+    deps: Deps,
+    coin_out: Coin,
+    swap_operations: Vec<SwapOperation>,
+) -> ContractResult<SimulateSwapWithMetadataResponse> {
     // Error if swap operations is empty
     let Some(last_op) = swap_operations.last() else {
         return Err(ContractError::SwapOperationsEmpty);
@@ -234,29 +814,84 @@ fn query_simulate_swap_exact_coin_out(
     // Iterate through the swap operations in reverse order, querying the astroport pool contracts
     // contracts to get the coin in needed for each swap operation, and then updating the coin in
     // needed for the next swap operation until the coin in needed for the first swap operation is found.
-    let coin_in_needed = swap_operations.iter().rev().try_fold(
-        coin_out,
-        |coin_in_needed, operation| -> Result<_, ContractError> {
-            let res: ReverseSimulationResponse = deps.querier.query_wasm_smart(
-                &operation.pool,
-                &PairQueryMsg::ReverseSimulation {
-                    offer_asset_info: None,
-                    ask_asset: Asset {
-                        info: AssetInfo::NativeToken {
-                            denom: coin_in_needed.denom,
-                        },
-                        amount: coin_in_needed.amount,
-                    },
+    let mut operations = Vec::with_capacity(swap_operations.len());
+    let mut coin_out = coin_out;
+    for operation in swap_operations.into_iter().rev() {
+        let res: ReverseSimulationResponse = deps.querier.query_wasm_smart(
+            &operation.pool,
+            &PairQueryMsg::ReverseSimulation {
+                offer_asset_info: None,
+                ask_asset: Asset {
+                    info: astroport_asset_info(coin_out.denom.clone()),
+                    amount: coin_out.amount,
                 },
-            )?;
+            },
+        )?;
+
+        let coin_in_needed = Coin {
+            denom: operation.denom_in,
+            amount: res.offer_amount.checked_add(Uint128::one())?,
+        };
+
+        operations.push(create_swap_operation_metadata(
+            operation.pool,
+            coin_in_needed.clone(),
+            coin_out.clone(),
+            coin_out.amount,
+            res.spread_amount,
+            res.commission_amount,
+        )?);
+
+        coin_out = coin_in_needed;
+    }
+
+    // Put the swap operations back in swap order
+    operations.reverse();
 
-            Ok(Coin {
-                denom: operation.denom_in.clone(),
-                amount: res.offer_amount.checked_add(Uint128::one())?,
-            })
+    Ok(SimulateSwapWithMetadataResponse::from_operations(
+        operations,
+    )?)
+}
+
+// Creates the metadata of an astroport swap operation. The spot price is the return amount
+// before the spread and commission are deducted divided by the offer amount, and both the
+// spread and commission are reported as fees in the ask denom.
+fn create_swap_operation_metadata(
+    pool: String,
+    coin_in: Coin,
+    coin_out: Coin,
+    return_amount: Uint128,
+    spread_amount: Uint128,
+    commission_amount: Uint128,
+) -> ContractResult<SwapOperationMetadata> {
+    // Get the spot price from the return amount without spread and commission
+    let spot_price = Decimal::checked_from_ratio(
+        return_amount
+            .checked_add(spread_amount)?
+            .checked_add(commission_amount)?,
+        coin_in.amount,
+    )
+    .unwrap_or_default();
+
+    // Create the commission and spread fees
+    let fees = vec![
+        SwapFee {
+            name: "commission".to_string(),
+            coin: Coin {
+                denom: coin_out.denom.clone(),
+                amount: commission_amount,
+            },
         },
-    )?;
+        SwapFee {
+            name: "spread".to_string(),
+            coin: Coin {
+                denom: coin_out.denom.clone(),
+                amount: spread_amount,
+            },
+        },
+    ];
 
-    // Return the coin in needed
-    Ok(coin_in_needed)
+    Ok(SwapOperationMetadata::new(
+        pool, coin_in, coin_out, spot_price, fees,
+    ))
 }
@@ -29,4 +29,10 @@ pub enum ContractError {
 
     #[error("coin_out denom must match the last swap operation's denom out")]
     CoinOutDenomMismatch,
+
+    #[error("CW20 Pool Assets Are Not Supported When Providing Liquidity")]
+    ProvideLiquidityCw20NotSupported,
+
+    #[error("CW20 Pool Assets Are Not Supported When Withdrawing Liquidity")]
+    WithdrawLiquidityCw20NotSupported,
 }
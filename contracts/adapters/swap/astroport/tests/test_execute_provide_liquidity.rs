@@ -0,0 +1,385 @@
+use astroport::{
+    asset::{Asset, AssetInfo},
+    pair::{ExecuteMsg as PairExecuteMsg, PoolResponse, QueryMsg as PairQueryMsg},
+    router::{
+        ExecuteMsg as RouterExecuteMsg, QueryMsg as RouterQueryMsg, SimulateSwapOperationsResponse,
+        SwapOperation as AstroportSwapOperation,
+    },
+};
+use cosmwasm_std::{
+    from_binary,
+    testing::{mock_dependencies_with_balances, mock_env, mock_info},
+    to_binary, Addr, Coin, ContractResult as SystemContractResult, Decimal, QuerierResult,
+    ReplyOn::Never,
+    SubMsg, SystemResult, Uint128, WasmMsg, WasmQuery,
+};
+use skip::{error::SkipError, swap::ExecuteMsg};
+use skip_api_swap_adapter_astroport::{
+    error::{ContractError, ContractResult},
+    state::{ENTRY_POINT_CONTRACT_ADDRESS, ROUTER_CONTRACT_ADDRESS},
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - Provide Liquidity (Swaps part of the coin in with the simulated coin out less the slippage tolerance as the minimum receive, and provides liquidity with the balances)
+    - Provide Liquidity To Recipient (Ensure the LP tokens are minted to the recipient)
+    - Provide Liquidity With Balances (Provides the contract balances with the slippage tolerance, minting the LP tokens to the swapper)
+
+Expect Error
+    - Unauthorized Caller (Only the stored entry point contract can call this function)
+    - Coin In Denom Not In Pool
+    - CW20 Pool Asset
+    - Coin In Too Small To Swap
+    - Slippage Tolerance Greater Than One
+    - Provide Liquidity With Balances Unauthorized Caller (Only the contract itself can call this function)
+
+ */
+
+// Define test parameters
+struct Params {
+    caller: String,
+    info_funds: Vec<Coin>,
+    msg: ExecuteMsg,
+    expected_messages: Vec<SubMsg>,
+    expected_error: Option<ContractError>,
+}
+
+// Test execute_provide_liquidity and execute_provide_liquidity_with_balances
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(1_000, "uatom")],
+        msg: ExecuteMsg::ProvideLiquidity {
+            pool: "pool_1".to_string(),
+            recipient: None,
+            slippage_tolerance: Decimal::percent(1),
+        },
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "router_contract".to_string(),
+                    msg: to_binary(&RouterExecuteMsg::ExecuteSwapOperations {
+                        operations: vec![AstroportSwapOperation::AstroSwap {
+                            offer_asset_info: AssetInfo::NativeToken {
+                                denom: "uatom".to_string(),
+                            },
+                            ask_asset_info: AssetInfo::NativeToken {
+                                denom: "uosmo".to_string(),
+                            },
+                        }],
+                        minimum_receive: Some(Uint128::new(494)),
+                        to: None,
+                        max_spread: None,
+                    })?,
+                    funds: vec![Coin::new(500, "uatom")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_binary(&ExecuteMsg::ProvideLiquidityWithBalances {
+                        pool: "pool_1".to_string(),
+                        denoms: vec!["uatom".to_string(), "uosmo".to_string()],
+                        swapper: Addr::unchecked("entry_point"),
+                        slippage_tolerance: Decimal::percent(1),
+                        min_lp_out: None,
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "Provide Liquidity")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(1_000, "uatom")],
+        msg: ExecuteMsg::ProvideLiquidity {
+            pool: "pool_1".to_string(),
+            recipient: Some("recipient".to_string()),
+            slippage_tolerance: Decimal::percent(1),
+        },
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "router_contract".to_string(),
+                    msg: to_binary(&RouterExecuteMsg::ExecuteSwapOperations {
+                        operations: vec![AstroportSwapOperation::AstroSwap {
+                            offer_asset_info: AssetInfo::NativeToken {
+                                denom: "uatom".to_string(),
+                            },
+                            ask_asset_info: AssetInfo::NativeToken {
+                                denom: "uosmo".to_string(),
+                            },
+                        }],
+                        minimum_receive: Some(Uint128::new(494)),
+                        to: None,
+                        max_spread: None,
+                    })?,
+                    funds: vec![Coin::new(500, "uatom")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_binary(&ExecuteMsg::ProvideLiquidityWithBalances {
+                        pool: "pool_1".to_string(),
+                        denoms: vec!["uatom".to_string(), "uosmo".to_string()],
+                        swapper: Addr::unchecked("recipient"),
+                        slippage_tolerance: Decimal::percent(1),
+                        min_lp_out: None,
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "Provide Liquidity To Recipient")]
+#[test_case(
+    Params {
+        caller: "swap_contract_address".to_string(),
+        info_funds: vec![],
+        msg: ExecuteMsg::ProvideLiquidityWithBalances {
+            pool: "pool_1".to_string(),
+            denoms: vec!["uosmo".to_string(), "uatom".to_string()],
+            swapper: Addr::unchecked("entry_point"),
+            slippage_tolerance: Decimal::percent(1),
+            min_lp_out: None,
+        },
+        expected_messages: vec![SubMsg {
+            id: 0,
+            msg: WasmMsg::Execute {
+                contract_addr: "pool_1".to_string(),
+                msg: to_binary(&PairExecuteMsg::ProvideLiquidity {
+                    assets: vec![
+                        Asset {
+                            info: AssetInfo::NativeToken {
+                                denom: "uatom".to_string(),
+                            },
+                            amount: Uint128::new(500),
+                        },
+                        Asset {
+                            info: AssetInfo::NativeToken {
+                                denom: "uosmo".to_string(),
+                            },
+                            amount: Uint128::new(499),
+                        },
+                    ],
+                    slippage_tolerance: Some(Decimal::percent(1)),
+                    auto_stake: None,
+                    receiver: Some("entry_point".to_string()),
+                })?,
+                funds: vec![Coin::new(500, "uatom"), Coin::new(499, "uosmo")],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }],
+        expected_error: None,
+    };
+    "Provide Liquidity With Balances")]
+#[test_case(
+    Params {
+        caller: "random".to_string(),
+        info_funds: vec![Coin::new(1_000, "uatom")],
+        msg: ExecuteMsg::ProvideLiquidity {
+            pool: "pool_1".to_string(),
+            recipient: None,
+            slippage_tolerance: Decimal::percent(1),
+        },
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Unauthorized Caller - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(1_000, "untrn")],
+        msg: ExecuteMsg::ProvideLiquidity {
+            pool: "pool_1".to_string(),
+            recipient: None,
+            slippage_tolerance: Decimal::percent(1),
+        },
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Skip(SkipError::ProvideLiquidityPoolDenomMismatch)),
+    };
+    "Coin In Denom Not In Pool - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(1_000, "uatom")],
+        msg: ExecuteMsg::ProvideLiquidity {
+            pool: "pool_cw20".to_string(),
+            recipient: None,
+            slippage_tolerance: Decimal::percent(1),
+        },
+        expected_messages: vec![],
+        expected_error: Some(ContractError::ProvideLiquidityCw20NotSupported),
+    };
+    "CW20 Pool Asset - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(1, "uatom")],
+        msg: ExecuteMsg::ProvideLiquidity {
+            pool: "pool_1".to_string(),
+            recipient: None,
+            slippage_tolerance: Decimal::percent(1),
+        },
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Skip(SkipError::ProvideLiquidityCoinInTooSmall)),
+    };
+    "Coin In Too Small To Swap - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(1_000, "uatom")],
+        msg: ExecuteMsg::ProvideLiquidity {
+            pool: "pool_1".to_string(),
+            recipient: None,
+            slippage_tolerance: Decimal::percent(101),
+        },
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Skip(SkipError::ProvideLiquiditySlippageToleranceTooHigh)),
+    };
+    "Slippage Tolerance Greater Than One - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![],
+        msg: ExecuteMsg::ProvideLiquidityWithBalances {
+            pool: "pool_1".to_string(),
+            denoms: vec!["uosmo".to_string(), "uatom".to_string()],
+            swapper: Addr::unchecked("entry_point"),
+            slippage_tolerance: Decimal::percent(1),
+            min_lp_out: None,
+        },
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Provide Liquidity With Balances Unauthorized Caller - Expect Error")]
+fn test_execute_provide_liquidity(params: Params) -> ContractResult<()> {
+    // Create mock dependencies with the contract's balances after the swap
+    let mut deps = mock_dependencies_with_balances(&[(
+        "swap_contract_address",
+        &[Coin::new(500, "uatom"), Coin::new(499, "uosmo")],
+    )]);
+
+    // Create mock wasm handler to handle the pair pool and router simulation queries
+    // The pools have reserves of 1_000_000 of each asset and no fees
+    let wasm_handler = |query: &WasmQuery| -> QuerierResult {
+        match query {
+            WasmQuery::Smart { contract_addr, msg } => {
+                let res = match contract_addr.as_str() {
+                    "router_contract" => match from_binary(msg).unwrap() {
+                        RouterQueryMsg::SimulateSwapOperations { offer_amount, .. } => {
+                            to_binary(&SimulateSwapOperationsResponse {
+                                amount: Uint128::new(1_000_000).multiply_ratio(
+                                    offer_amount,
+                                    offer_amount + Uint128::new(1_000_000),
+                                ),
+                            })
+                        }
+                        query => panic!("Unsupported query: {:?}", query),
+                    },
+                    pool => match from_binary(msg).unwrap() {
+                        PairQueryMsg::Pool {} => to_binary(&PoolResponse {
+                            assets: vec![
+                                Asset {
+                                    info: AssetInfo::NativeToken {
+                                        denom: "uatom".to_string(),
+                                    },
+                                    amount: Uint128::new(1_000_000),
+                                },
+                                Asset {
+                                    info: match pool {
+                                        "pool_cw20" => AssetInfo::Token {
+                                            contract_addr: Addr::unchecked("cw20_contract"),
+                                        },
+                                        _ => AssetInfo::NativeToken {
+                                            denom: "uosmo".to_string(),
+                                        },
+                                    },
+                                    amount: Uint128::new(1_000_000),
+                                },
+                            ],
+                            total_share: Uint128::new(1_000_000),
+                        }),
+                        query => panic!("Unsupported query: {:?}", query),
+                    },
+                };
+                SystemResult::Ok(SystemContractResult::Ok(res.unwrap()))
+            }
+            _ => panic!("Unsupported query: {:?}", query),
+        }
+    };
+
+    // Update querier with mock wasm handler
+    deps.querier.update_wasm(wasm_handler);
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("swap_contract_address");
+
+    // Create mock info with the given caller and funds
+    let info = mock_info(&params.caller, &params.info_funds);
+
+    // Store the entry point contract address
+    ENTRY_POINT_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("entry_point"))?;
+
+    // Store the router contract address
+    ROUTER_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("router_contract"))?;
+
+    // Call execute with the given test parameters
+    let res =
+        skip_api_swap_adapter_astroport::contract::execute(deps.as_mut(), env, info, params.msg);
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the messages are correct
+            assert_eq!(res.messages, params.expected_messages);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,317 @@
+use astroport::{
+    asset::AssetInfo,
+    pair::{QueryMsg as PairQueryMsg, ReverseSimulationResponse},
+    router::{Cw20HookMsg as RouterCw20HookMsg, SwapOperation as AstroportSwapOperation},
+};
+use cosmwasm_std::{
+    from_binary,
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_binary, Addr, Binary, Coin, ContractResult as SystemContractResult, QuerierResult,
+    ReplyOn::Never,
+    SubMsg, SystemResult, Uint128, WasmMsg, WasmQuery,
+};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use skip::swap::{ExecuteMsg, SwapOperation};
+use skip_api_swap_adapter_astroport::{
+    error::{ContractError, ContractResult},
+    state::{ENTRY_POINT_CONTRACT_ADDRESS, ROUTER_CONTRACT_ADDRESS},
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - CW20 Coin In To Native Coin Out (Sends the CW20 coin in to the router with the swap as the hook message)
+    - CW20 Coin In To CW20 Coin Out (Router sends the CW20 coin out directly to the entry point)
+    - CW20 Coin In With Exact Coin Out (Swaps the simulated coin in needed, returning the rest with a CW20 transfer)
+
+Expect Error
+    - Unauthorized CW20 Sender (Only the stored entry point contract can send CW20 coins to swap)
+
+ */
+
+// Define test parameters
+struct Params {
+    cw20_sender: String,
+    amount: Uint128,
+    hook_msg: Binary,
+    expected_messages: Vec<SubMsg>,
+    expected_error: Option<ContractError>,
+}
+
+// Test execute_receive
+#[test_case(
+    Params {
+        cw20_sender: "entry_point".to_string(),
+        amount: Uint128::new(100),
+        hook_msg: to_binary(&ExecuteMsg::Swap {
+            operations: vec![
+                SwapOperation {
+                    pool: "pool_1".to_string(),
+                    denom_in: "cw20:cw20_contract".to_string(),
+                    denom_out: "uatom".to_string(),
+                }
+            ],
+            minimum_receive: Some(Uint128::new(50)),
+            max_spread: None,
+        })?,
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "cw20_contract".to_string(),
+                    msg: to_binary(&Cw20ExecuteMsg::Send {
+                        contract: "router_contract".to_string(),
+                        amount: Uint128::new(100),
+                        msg: to_binary(&RouterCw20HookMsg::ExecuteSwapOperations {
+                            operations: vec![
+                                AstroportSwapOperation::AstroSwap {
+                                    offer_asset_info: AssetInfo::Token {
+                                        contract_addr: Addr::unchecked("cw20_contract"),
+                                    },
+                                    ask_asset_info: AssetInfo::NativeToken {
+                                        denom: "uatom".to_string(),
+                                    },
+                                }
+                            ],
+                            minimum_receive: Some(Uint128::new(50)),
+                            to: None,
+                            max_spread: None,
+                        })?,
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            transfer_funds_back_msg()?,
+        ],
+        expected_error: None,
+    };
+    "CW20 Coin In To Native Coin Out")]
+#[test_case(
+    Params {
+        cw20_sender: "entry_point".to_string(),
+        amount: Uint128::new(100),
+        hook_msg: to_binary(&ExecuteMsg::Swap {
+            operations: vec![
+                SwapOperation {
+                    pool: "pool_1".to_string(),
+                    denom_in: "cw20:cw20_contract".to_string(),
+                    denom_out: "cw20:cw20_contract_2".to_string(),
+                }
+            ],
+            minimum_receive: None,
+            max_spread: None,
+        })?,
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "cw20_contract".to_string(),
+                    msg: to_binary(&Cw20ExecuteMsg::Send {
+                        contract: "router_contract".to_string(),
+                        amount: Uint128::new(100),
+                        msg: to_binary(&RouterCw20HookMsg::ExecuteSwapOperations {
+                            operations: vec![
+                                AstroportSwapOperation::AstroSwap {
+                                    offer_asset_info: AssetInfo::Token {
+                                        contract_addr: Addr::unchecked("cw20_contract"),
+                                    },
+                                    ask_asset_info: AssetInfo::Token {
+                                        contract_addr: Addr::unchecked("cw20_contract_2"),
+                                    },
+                                }
+                            ],
+                            minimum_receive: None,
+                            to: Some("entry_point".to_string()),
+                            max_spread: None,
+                        })?,
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            transfer_funds_back_msg()?,
+        ],
+        expected_error: None,
+    };
+    "CW20 Coin In To CW20 Coin Out")]
+#[test_case(
+    Params {
+        cw20_sender: "entry_point".to_string(),
+        amount: Uint128::new(100),
+        hook_msg: to_binary(&ExecuteMsg::SwapExactCoinOut {
+            operations: vec![
+                SwapOperation {
+                    pool: "pool_1".to_string(),
+                    denom_in: "cw20:cw20_contract".to_string(),
+                    denom_out: "uatom".to_string(),
+                }
+            ],
+            coin_out: Coin::new(50, "uatom"),
+        })?,
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "cw20_contract".to_string(),
+                    msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                        recipient: "entry_point".to_string(),
+                        amount: Uint128::new(40),
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "cw20_contract".to_string(),
+                    msg: to_binary(&Cw20ExecuteMsg::Send {
+                        contract: "router_contract".to_string(),
+                        amount: Uint128::new(60),
+                        msg: to_binary(&RouterCw20HookMsg::ExecuteSwapOperations {
+                            operations: vec![
+                                AstroportSwapOperation::AstroSwap {
+                                    offer_asset_info: AssetInfo::Token {
+                                        contract_addr: Addr::unchecked("cw20_contract"),
+                                    },
+                                    ask_asset_info: AssetInfo::NativeToken {
+                                        denom: "uatom".to_string(),
+                                    },
+                                }
+                            ],
+                            minimum_receive: Some(Uint128::new(50)),
+                            to: None,
+                            max_spread: None,
+                        })?,
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            transfer_funds_back_msg()?,
+        ],
+        expected_error: None,
+    };
+    "CW20 Coin In With Exact Coin Out")]
+#[test_case(
+    Params {
+        cw20_sender: "random".to_string(),
+        amount: Uint128::new(100),
+        hook_msg: to_binary(&ExecuteMsg::Swap {
+            operations: vec![],
+            minimum_receive: None,
+            max_spread: None,
+        })?,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Unauthorized CW20 Sender - Expect Error")]
+fn test_execute_receive(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock wasm handler to handle the pool reverse simulation query
+    // Will always need 60 of the coin in (the offer amount plus one)
+    let wasm_handler = |query: &WasmQuery| -> QuerierResult {
+        match query {
+            WasmQuery::Smart { msg, .. } => match from_binary(msg).unwrap() {
+                PairQueryMsg::ReverseSimulation { .. } => {
+                    SystemResult::Ok(SystemContractResult::Ok(
+                        to_binary(&ReverseSimulationResponse {
+                            offer_amount: Uint128::new(59),
+                            spread_amount: Uint128::zero(),
+                            commission_amount: Uint128::zero(),
+                        })
+                        .unwrap(),
+                    ))
+                }
+                query => panic!("Unsupported query: {:?}", query),
+            },
+            _ => panic!("Unsupported query: {:?}", query),
+        }
+    };
+
+    // Update querier with mock wasm handler
+    deps.querier.update_wasm(wasm_handler);
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("swap_contract_address");
+
+    // Create mock info with the cw20 contract address as the caller
+    let info = mock_info("cw20_contract", &[]);
+
+    // Store the entry point contract address
+    ENTRY_POINT_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("entry_point"))?;
+
+    // Store the router contract address
+    ROUTER_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("router_contract"))?;
+
+    // Call execute_receive with the given test parameters
+    let res = skip_api_swap_adapter_astroport::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: params.cw20_sender.clone(),
+            amount: params.amount,
+            msg: params.hook_msg.clone(),
+        }),
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the messages are correct
+            assert_eq!(res.messages, params.expected_messages);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+
+    Ok(())
+}
+
+// Creates the transfer funds back message to the entry point
+fn transfer_funds_back_msg() -> ContractResult<SubMsg> {
+    Ok(SubMsg {
+        id: 0,
+        msg: WasmMsg::Execute {
+            contract_addr: "swap_contract_address".to_string(),
+            msg: to_binary(&ExecuteMsg::TransferFundsBack {
+                swapper: Addr::unchecked("entry_point"),
+            })?,
+            funds: vec![],
+        }
+        .into(),
+        gas_limit: None,
+        reply_on: Never,
+    })
+}
@@ -4,9 +4,9 @@ use astroport::{
 };
 use cosmwasm_std::{
     testing::{mock_dependencies, mock_env, mock_info},
-    to_binary, Addr, Coin,
+    to_binary, Addr, Coin, Decimal,
     ReplyOn::Never,
-    SubMsg, WasmMsg,
+    SubMsg, Uint128, WasmMsg,
 };
 use skip::swap::{ExecuteMsg, SwapOperation};
 use skip_api_swap_adapter_astroport::{
@@ -20,6 +20,7 @@ Test Cases:
 
 Expect Success
     - One Swap Operation
+    - One Swap Operation With Minimum Receive And Max Spread
     - Multiple Swap Operations
     - No Swap Operations (This is prevented in the entry point contract; and will fail on Astroport router if attempted)
 
@@ -35,6 +36,8 @@ struct Params {
     caller: String,
     info_funds: Vec<Coin>,
     swap_operations: Vec<SwapOperation>,
+    minimum_receive: Option<Uint128>,
+    max_spread: Option<Decimal>,
     expected_messages: Vec<SubMsg>,
     expected_error: Option<ContractError>,
 }
@@ -51,6 +54,8 @@ struct Params {
                 denom_out: "uatom".to_string(),
             }
         ],
+        minimum_receive: None,
+        max_spread: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -94,6 +99,62 @@ struct Params {
         expected_error: None,
     };
     "One Swap Operation")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "uosmo")],
+        swap_operations: vec![
+            SwapOperation {
+                pool: "pool_1".to_string(),
+                denom_in: "uosmo".to_string(),
+                denom_out: "uatom".to_string(),
+            }
+        ],
+        minimum_receive: Some(Uint128::new(90)),
+        max_spread: Some(Decimal::percent(1)),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "router_contract".to_string(),
+                    msg: to_binary(&RouterExecuteMsg::ExecuteSwapOperations {
+                        operations: vec![
+                            AstroportSwapOperation::AstroSwap {
+                                offer_asset_info: AssetInfo::NativeToken {
+                                    denom: "uosmo".to_string(),
+                                },
+                                ask_asset_info: AssetInfo::NativeToken {
+                                    denom: "uatom".to_string(),
+                                },
+                            }
+                        ],
+                        minimum_receive: Some(Uint128::new(90)),
+                        to: None,
+                        max_spread: Some(Decimal::percent(1)),
+                    })?,
+                    funds: vec![Coin::new(100, "uosmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_binary(&ExecuteMsg::TransferFundsBack {
+                        swapper: Addr::unchecked("entry_point"),
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "One Swap Operation With Minimum Receive And Max Spread")]
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
@@ -110,6 +171,8 @@ struct Params {
                 denom_out: "untrn".to_string(),
             }
         ],
+        minimum_receive: None,
+        max_spread: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -166,6 +229,8 @@ struct Params {
         caller: "entry_point".to_string(),
         info_funds: vec![Coin::new(100, "uosmo")],
         swap_operations: vec![],
+        minimum_receive: None,
+        max_spread: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -205,6 +270,8 @@ struct Params {
         caller: "entry_point".to_string(),
         info_funds: vec![],
         swap_operations: vec![],
+        minimum_receive: None,
+        max_spread: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Payment(cw_utils::PaymentError::NoFunds{})),
     };
@@ -217,6 +284,8 @@ struct Params {
             Coin::new(100, "uosmo"),
         ],
         swap_operations: vec![],
+        minimum_receive: None,
+        max_spread: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Payment(cw_utils::PaymentError::MultipleDenoms{})),
     };
@@ -229,6 +298,8 @@ struct Params {
             Coin::new(100, "uosmo"),
         ],
         swap_operations: vec![],
+        minimum_receive: None,
+        max_spread: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Unauthorized),
     };
@@ -260,6 +331,8 @@ fn test_execute_swap(params: Params) -> ContractResult<()> {
         info,
         ExecuteMsg::Swap {
             operations: params.swap_operations.clone(),
+            minimum_receive: params.minimum_receive,
+            max_spread: params.max_spread,
         },
     );
 
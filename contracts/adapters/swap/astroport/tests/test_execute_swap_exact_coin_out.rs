@@ -0,0 +1,255 @@
+use astroport::{
+    asset::AssetInfo,
+    pair::{QueryMsg as PairQueryMsg, ReverseSimulationResponse},
+    router::{ExecuteMsg as RouterExecuteMsg, SwapOperation as AstroportSwapOperation},
+};
+use cosmwasm_std::{
+    from_binary,
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_binary, Addr, BankMsg, Coin, ContractResult as SystemContractResult, QuerierResult,
+    ReplyOn::Never,
+    SubMsg, SystemResult, Uint128, WasmMsg, WasmQuery,
+};
+use skip::swap::{ExecuteMsg, SwapOperation};
+use skip_api_swap_adapter_astroport::{
+    error::{ContractError, ContractResult},
+    state::{ENTRY_POINT_CONTRACT_ADDRESS, ROUTER_CONTRACT_ADDRESS},
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - One Swap Operation (Swaps the simulated coin in needed with the coin out amount as the minimum receive, returning the rest)
+    - Coin Sent Less Than Simulated Coin In (Swaps the full coin sent, failing at the router on its minimum receive)
+
+Expect Error
+    - Unauthorized Caller (Only the stored entry point contract can call this function)
+
+ */
+
+// Define test parameters
+struct Params {
+    caller: String,
+    info_funds: Vec<Coin>,
+    swap_operations: Vec<SwapOperation>,
+    coin_out: Coin,
+    expected_messages: Vec<SubMsg>,
+    expected_error: Option<ContractError>,
+}
+
+// Test execute_swap with an exact coin out
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "uosmo")],
+        swap_operations: vec![
+            SwapOperation {
+                pool: "pool_1".to_string(),
+                denom_in: "uosmo".to_string(),
+                denom_out: "uatom".to_string(),
+            }
+        ],
+        coin_out: Coin::new(50, "uatom"),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "entry_point".to_string(),
+                    amount: vec![Coin::new(40, "uosmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "router_contract".to_string(),
+                    msg: to_binary(&RouterExecuteMsg::ExecuteSwapOperations {
+                        operations: vec![
+                            AstroportSwapOperation::AstroSwap {
+                                offer_asset_info: AssetInfo::NativeToken {
+                                    denom: "uosmo".to_string(),
+                                },
+                                ask_asset_info: AssetInfo::NativeToken {
+                                    denom: "uatom".to_string(),
+                                },
+                            }
+                        ],
+                        minimum_receive: Some(Uint128::new(50)),
+                        to: None,
+                        max_spread: None,
+                    })?,
+                    funds: vec![Coin::new(60, "uosmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_binary(&ExecuteMsg::TransferFundsBack {
+                        swapper: Addr::unchecked("entry_point"),
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "One Swap Operation")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(50, "uosmo")],
+        swap_operations: vec![
+            SwapOperation {
+                pool: "pool_1".to_string(),
+                denom_in: "uosmo".to_string(),
+                denom_out: "uatom".to_string(),
+            }
+        ],
+        coin_out: Coin::new(50, "uatom"),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "router_contract".to_string(),
+                    msg: to_binary(&RouterExecuteMsg::ExecuteSwapOperations {
+                        operations: vec![
+                            AstroportSwapOperation::AstroSwap {
+                                offer_asset_info: AssetInfo::NativeToken {
+                                    denom: "uosmo".to_string(),
+                                },
+                                ask_asset_info: AssetInfo::NativeToken {
+                                    denom: "uatom".to_string(),
+                                },
+                            }
+                        ],
+                        minimum_receive: Some(Uint128::new(50)),
+                        to: None,
+                        max_spread: None,
+                    })?,
+                    funds: vec![Coin::new(50, "uosmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_binary(&ExecuteMsg::TransferFundsBack {
+                        swapper: Addr::unchecked("entry_point"),
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "Coin Sent Less Than Simulated Coin In")]
+#[test_case(
+    Params {
+        caller: "random".to_string(),
+        info_funds: vec![Coin::new(100, "uosmo")],
+        swap_operations: vec![],
+        coin_out: Coin::new(50, "uatom"),
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Unauthorized Caller - Expect Error")]
+fn test_execute_swap_exact_coin_out(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock wasm handler to handle the pool reverse simulation query
+    // Will always need 60 of the coin in (the offer amount plus one)
+    let wasm_handler = |query: &WasmQuery| -> QuerierResult {
+        match query {
+            WasmQuery::Smart { msg, .. } => match from_binary(msg).unwrap() {
+                PairQueryMsg::ReverseSimulation { .. } => {
+                    SystemResult::Ok(SystemContractResult::Ok(
+                        to_binary(&ReverseSimulationResponse {
+                            offer_amount: Uint128::new(59),
+                            spread_amount: Uint128::zero(),
+                            commission_amount: Uint128::zero(),
+                        })
+                        .unwrap(),
+                    ))
+                }
+                query => panic!("Unsupported query: {:?}", query),
+            },
+            _ => panic!("Unsupported query: {:?}", query),
+        }
+    };
+
+    // Update querier with mock wasm handler
+    deps.querier.update_wasm(wasm_handler);
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("swap_contract_address");
+
+    // Convert info funds vector into a slice of Coin objects
+    let info_funds: &[Coin] = &params.info_funds;
+
+    // Create mock info with entry point contract address
+    let info = mock_info(&params.caller, info_funds);
+
+    // Store the entry point contract address
+    ENTRY_POINT_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("entry_point"))?;
+
+    // Store the router contract address
+    ROUTER_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("router_contract"))?;
+
+    // Call execute_swap_exact_coin_out with the given test parameters
+    let res = skip_api_swap_adapter_astroport::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::SwapExactCoinOut {
+            operations: params.swap_operations.clone(),
+            coin_out: params.coin_out.clone(),
+        },
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the messages are correct
+            assert_eq!(res.messages, params.expected_messages);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+
+    Ok(())
+}
@@ -14,7 +14,7 @@ Test Cases:
 Expect Success
     - One Coin Balance
     - Multiple Coin Balance
-    - No Coin Balance (This will fail at the bank module if attempted)
+    - No Coin Balance (No bank send is dispatched, as it would fail at the bank module)
 
 Expect Error
     - Unauthorized Caller (Only contract itself can call this function)
@@ -75,17 +75,7 @@ struct Params {
     Params {
         caller: "swap_contract_address".to_string(),
         contract_balance: vec![],
-        expected_messages: vec![
-            SubMsg {
-                id: 0,
-                msg: BankMsg::Send {
-                    to_address: "swapper".to_string(),
-                    amount: vec![],
-                }.into(),
-                gas_limit: None,
-                reply_on: Never,
-            },
-        ],
+        expected_messages: vec![],
         expected_error: None,
     };
     "Transfers No Coin Balance")]
@@ -0,0 +1,219 @@
+use astroport::{
+    asset::{AssetInfo, PairInfo},
+    factory::PairType,
+    pair::{Cw20HookMsg as PairCw20HookMsg, QueryMsg as PairQueryMsg},
+};
+use cosmwasm_std::{
+    from_binary,
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_binary, Addr, ContractResult as SystemContractResult, QuerierResult,
+    ReplyOn::Never,
+    SubMsg, SystemResult, Uint128, WasmMsg, WasmQuery,
+};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use skip::{error::SkipError, swap::ExecuteMsg};
+use skip_api_swap_adapter_astroport::{
+    error::{ContractError, ContractResult},
+    state::{ENTRY_POINT_CONTRACT_ADDRESS, ROUTER_CONTRACT_ADDRESS},
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - Withdraw Liquidity (Sends the LP tokens to the pair with the withdraw as the hook message, then transfers the assets back)
+
+Expect Error
+    - Unauthorized CW20 Sender (Only the stored entry point contract can send LP tokens to withdraw)
+    - Coin In Is Not The Pair's LP Token
+    - CW20 Pool Asset
+    - Denoms Out Are Not The Pool Assets
+
+ */
+
+// Define test parameters
+struct Params {
+    cw20_sender: String,
+    lp_token: String,
+    pool: String,
+    denoms_out: Vec<String>,
+    expected_messages: Vec<SubMsg>,
+    expected_error: Option<ContractError>,
+}
+
+// Test execute_withdraw_liquidity
+#[test_case(
+    Params {
+        cw20_sender: "entry_point".to_string(),
+        lp_token: "lp_token".to_string(),
+        pool: "pool_1".to_string(),
+        denoms_out: vec!["uosmo".to_string(), "uatom".to_string()],
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "lp_token".to_string(),
+                    msg: to_binary(&Cw20ExecuteMsg::Send {
+                        contract: "pool_1".to_string(),
+                        amount: Uint128::new(1_000),
+                        msg: to_binary(&PairCw20HookMsg::WithdrawLiquidity { assets: vec![] })?,
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_binary(&ExecuteMsg::TransferFundsBack {
+                        swapper: Addr::unchecked("entry_point"),
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "Withdraw Liquidity")]
+#[test_case(
+    Params {
+        cw20_sender: "random".to_string(),
+        lp_token: "lp_token".to_string(),
+        pool: "pool_1".to_string(),
+        denoms_out: vec!["uatom".to_string(), "uosmo".to_string()],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Unauthorized CW20 Sender - Expect Error")]
+#[test_case(
+    Params {
+        cw20_sender: "entry_point".to_string(),
+        lp_token: "other_token".to_string(),
+        pool: "pool_1".to_string(),
+        denoms_out: vec!["uatom".to_string(), "uosmo".to_string()],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Skip(SkipError::WithdrawLiquidityCoinInNotPoolShare)),
+    };
+    "Coin In Is Not The Pair's LP Token - Expect Error")]
+#[test_case(
+    Params {
+        cw20_sender: "entry_point".to_string(),
+        lp_token: "lp_token".to_string(),
+        pool: "pool_cw20".to_string(),
+        denoms_out: vec!["uatom".to_string(), "cw20:cw20_contract".to_string()],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::WithdrawLiquidityCw20NotSupported),
+    };
+    "CW20 Pool Asset - Expect Error")]
+#[test_case(
+    Params {
+        cw20_sender: "entry_point".to_string(),
+        lp_token: "lp_token".to_string(),
+        pool: "pool_1".to_string(),
+        denoms_out: vec!["uatom".to_string(), "untrn".to_string()],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Skip(SkipError::WithdrawLiquidityPoolDenomMismatch)),
+    };
+    "Denoms Out Are Not The Pool Assets - Expect Error")]
+fn test_execute_withdraw_liquidity(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock wasm handler to handle the pair info query
+    // The pairs have an LP token of lp_token and uatom as their first asset
+    let wasm_handler = |query: &WasmQuery| -> QuerierResult {
+        match query {
+            WasmQuery::Smart { contract_addr, msg } => {
+                let res = match from_binary(msg).unwrap() {
+                    PairQueryMsg::Pair {} => to_binary(&PairInfo {
+                        asset_infos: vec![
+                            AssetInfo::NativeToken {
+                                denom: "uatom".to_string(),
+                            },
+                            match contract_addr.as_str() {
+                                "pool_cw20" => AssetInfo::Token {
+                                    contract_addr: Addr::unchecked("cw20_contract"),
+                                },
+                                _ => AssetInfo::NativeToken {
+                                    denom: "uosmo".to_string(),
+                                },
+                            },
+                        ],
+                        contract_addr: Addr::unchecked(contract_addr),
+                        liquidity_token: Addr::unchecked("lp_token"),
+                        pair_type: PairType::Xyk {},
+                    }),
+                    query => panic!("Unsupported query: {:?}", query),
+                };
+                SystemResult::Ok(SystemContractResult::Ok(res.unwrap()))
+            }
+            _ => panic!("Unsupported query: {:?}", query),
+        }
+    };
+
+    // Update querier with mock wasm handler
+    deps.querier.update_wasm(wasm_handler);
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("swap_contract_address");
+
+    // Create mock info with the LP token contract address as the caller
+    let info = mock_info(&params.lp_token, &[]);
+
+    // Store the entry point contract address
+    ENTRY_POINT_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("entry_point"))?;
+
+    // Store the router contract address
+    ROUTER_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("router_contract"))?;
+
+    // Call execute_receive with the withdraw liquidity hook message
+    let res = skip_api_swap_adapter_astroport::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: params.cw20_sender,
+            amount: Uint128::new(1_000),
+            msg: to_binary(&ExecuteMsg::WithdrawLiquidity {
+                pool: params.pool,
+                denoms_out: params.denoms_out,
+            })?,
+        }),
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the messages are correct
+            assert_eq!(res.messages, params.expected_messages);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,232 @@
+use astroport::pair::{QueryMsg as PairQueryMsg, ReverseSimulationResponse, SimulationResponse};
+use cosmwasm_std::{
+    from_binary,
+    testing::{mock_dependencies, mock_env},
+    to_binary, Coin, ContractResult as SystemContractResult, Decimal, QuerierResult, SystemResult,
+    Uint128, WasmQuery,
+};
+use skip::swap::{
+    QueryMsg, SimulateSwapWithMetadataResponse, SwapFee, SwapOperation, SwapOperationMetadata,
+};
+use skip_api_swap_adapter_astroport::error::{ContractError, ContractResult};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response
+    - Swap Exact Coin In With One Swap Operation
+    - Swap Exact Coin In With Multiple Swap Operations
+    - Swap Exact Coin Out With One Swap Operation
+
+Expect Error
+    - Empty Swap Operations
+    - Coin In Denom Does Not Match First Swap Operation Denom In
+    - Coin Out Denom Does Not Match Last Swap Operation Denom Out
+
+ */
+
+// Define test parameters
+struct Params {
+    query_msg: QueryMsg,
+    expected_response: Option<SimulateSwapWithMetadataResponse>,
+    expected_error: Option<ContractError>,
+}
+
+// Test query_simulate_swap_exact_coin_in_with_metadata and
+// query_simulate_swap_exact_coin_out_with_metadata
+#[test_case(
+    Params {
+        query_msg: QueryMsg::SimulateSwapExactCoinInWithMetadata {
+            coin_in: Coin::new(100, "uatom"),
+            swap_operations: vec![swap_operation("pool_1", "uatom", "uosmo")],
+        },
+        expected_response: Some(SimulateSwapWithMetadataResponse::from_operations(vec![
+            SwapOperationMetadata::new(
+                "pool_1".to_string(),
+                Coin::new(100, "uatom"),
+                Coin::new(194, "uosmo"),
+                Decimal::from_ratio(2u128, 1u128),
+                fees(2, 4, "uosmo"),
+            ),
+        ])?),
+        expected_error: None,
+    };
+    "Swap Exact Coin In With One Swap Operation")]
+#[test_case(
+    Params {
+        query_msg: QueryMsg::SimulateSwapExactCoinInWithMetadata {
+            coin_in: Coin::new(100, "uatom"),
+            swap_operations: vec![
+                swap_operation("pool_1", "uatom", "uosmo"),
+                swap_operation("pool_2", "uosmo", "untrn"),
+            ],
+        },
+        expected_response: Some(SimulateSwapWithMetadataResponse::from_operations(vec![
+            SwapOperationMetadata::new(
+                "pool_1".to_string(),
+                Coin::new(100, "uatom"),
+                Coin::new(194, "uosmo"),
+                Decimal::from_ratio(2u128, 1u128),
+                fees(2, 4, "uosmo"),
+            ),
+            SwapOperationMetadata::new(
+                "pool_2".to_string(),
+                Coin::new(194, "uosmo"),
+                Coin::new(378, "untrn"),
+                Decimal::from_ratio(2u128, 1u128),
+                fees(3, 7, "untrn"),
+            ),
+        ])?),
+        expected_error: None,
+    };
+    "Swap Exact Coin In With Multiple Swap Operations")]
+#[test_case(
+    Params {
+        query_msg: QueryMsg::SimulateSwapExactCoinOutWithMetadata {
+            coin_out: Coin::new(200, "uosmo"),
+            swap_operations: vec![swap_operation("pool_1", "uatom", "uosmo")],
+        },
+        expected_response: Some(SimulateSwapWithMetadataResponse::from_operations(vec![
+            SwapOperationMetadata::new(
+                "pool_1".to_string(),
+                Coin::new(103, "uatom"),
+                Coin::new(200, "uosmo"),
+                Decimal::from_ratio(2u128, 1u128),
+                fees(2, 4, "uosmo"),
+            ),
+        ])?),
+        expected_error: None,
+    };
+    "Swap Exact Coin Out With One Swap Operation")]
+#[test_case(
+    Params {
+        query_msg: QueryMsg::SimulateSwapExactCoinInWithMetadata {
+            coin_in: Coin::new(100, "uatom"),
+            swap_operations: vec![],
+        },
+        expected_response: None,
+        expected_error: Some(ContractError::SwapOperationsEmpty),
+    };
+    "Empty Swap Operations - Expect Error")]
+#[test_case(
+    Params {
+        query_msg: QueryMsg::SimulateSwapExactCoinInWithMetadata {
+            coin_in: Coin::new(100, "untrn"),
+            swap_operations: vec![swap_operation("pool_1", "uatom", "uosmo")],
+        },
+        expected_response: None,
+        expected_error: Some(ContractError::CoinInDenomMismatch),
+    };
+    "Coin In Denom Does Not Match First Swap Operation Denom In - Expect Error")]
+#[test_case(
+    Params {
+        query_msg: QueryMsg::SimulateSwapExactCoinOutWithMetadata {
+            coin_out: Coin::new(200, "untrn"),
+            swap_operations: vec![swap_operation("pool_1", "uatom", "uosmo")],
+        },
+        expected_response: None,
+        expected_error: Some(ContractError::CoinOutDenomMismatch),
+    };
+    "Coin Out Denom Does Not Match Last Swap Operation Denom Out - Expect Error")]
+fn test_query_simulate_swap_with_metadata(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock wasm handler to handle the astroport pool simulation queries
+    // Pools have a spot price of 2, a commission of 1% and a spread of 2%
+    let wasm_handler = |query: &WasmQuery| -> QuerierResult {
+        match query {
+            WasmQuery::Smart { msg, .. } => {
+                let res = match from_binary(msg).unwrap() {
+                    PairQueryMsg::Simulation { offer_asset, .. } => {
+                        let gross_amount = offer_asset.amount * Uint128::new(2);
+                        let commission_amount = gross_amount / Uint128::new(100);
+                        let spread_amount = gross_amount / Uint128::new(50);
+                        to_binary(&SimulationResponse {
+                            return_amount: gross_amount - commission_amount - spread_amount,
+                            spread_amount,
+                            commission_amount,
+                        })
+                    }
+                    PairQueryMsg::ReverseSimulation { ask_asset, .. } => {
+                        let commission_amount = ask_asset.amount / Uint128::new(100);
+                        let spread_amount = ask_asset.amount / Uint128::new(50);
+                        to_binary(&ReverseSimulationResponse {
+                            offer_amount: (ask_asset.amount + commission_amount + spread_amount)
+                                / Uint128::new(2)
+                                - Uint128::one(),
+                            spread_amount,
+                            commission_amount,
+                        })
+                    }
+                    query => panic!("Unsupported query: {:?}", query),
+                };
+                SystemResult::Ok(SystemContractResult::Ok(res.unwrap()))
+            }
+            _ => panic!("Unsupported query: {:?}", query),
+        }
+    };
+
+    // Update querier with mock wasm handler
+    deps.querier.update_wasm(wasm_handler);
+
+    // Call query with the given test parameters
+    let res = skip_api_swap_adapter_astroport::contract::query(
+        deps.as_ref(),
+        mock_env(),
+        params.query_msg,
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the response is correct
+            let res: SimulateSwapWithMetadataResponse = from_binary(&res)?;
+            assert_eq!(res, params.expected_response.unwrap());
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+
+    Ok(())
+}
+
+// Creates a swap operation through the pool from the denom in to the denom out
+fn swap_operation(pool: &str, denom_in: &str, denom_out: &str) -> SwapOperation {
+    SwapOperation {
+        pool: pool.to_string(),
+        denom_in: denom_in.to_string(),
+        denom_out: denom_out.to_string(),
+    }
+}
+
+// Creates the commission and spread fees in the denom out
+fn fees(commission_amount: u128, spread_amount: u128, denom_out: &str) -> Vec<SwapFee> {
+    vec![
+        SwapFee {
+            name: "commission".to_string(),
+            coin: Coin::new(commission_amount, denom_out),
+        },
+        SwapFee {
+            name: "spread".to_string(),
+            coin: Coin::new(spread_amount, denom_out),
+        },
+    ]
+}
@@ -0,0 +1,139 @@
+use astroport::{
+    asset::{Asset, AssetInfo, PairInfo},
+    factory::PairType,
+    pair::QueryMsg as PairQueryMsg,
+};
+use cosmwasm_std::{
+    from_binary,
+    testing::{mock_dependencies, mock_env},
+    to_binary, Addr, Coin, ContractResult as SystemContractResult, QuerierResult, SystemResult,
+    Uint128, WasmQuery,
+};
+use skip::{error::SkipError, swap::QueryMsg};
+use skip_api_swap_adapter_astroport::error::{ContractError, ContractResult};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response
+    - Simulate Withdraw Liquidity (Returns the pair's share of the LP token amount as coins)
+
+Expect Error
+    - Coin In Is Not The Pair's LP Token
+
+ */
+
+// Define test parameters
+struct Params {
+    coin_in: Coin,
+    expected_response: Option<Vec<Coin>>,
+    expected_error: Option<ContractError>,
+}
+
+// Test query_simulate_withdraw_liquidity
+#[test_case(
+    Params {
+        coin_in: Coin::new(1_000, "cw20:lp_token"),
+        expected_response: Some(vec![
+            Coin::new(500, "uatom"),
+            Coin::new(2_000, "uosmo"),
+        ]),
+        expected_error: None,
+    };
+    "Simulate Withdraw Liquidity")]
+#[test_case(
+    Params {
+        coin_in: Coin::new(1_000, "uatom"),
+        expected_response: None,
+        expected_error: Some(SkipError::WithdrawLiquidityCoinInNotPoolShare.into()),
+    };
+    "Coin In Is Not The Pair's LP Token - Expect Error")]
+fn test_query_simulate_withdraw_liquidity(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock wasm handler to handle the pair info and share queries
+    // The pair has an LP token of lp_token, and each LP token is a share
+    // of half a uatom and two uosmo
+    let wasm_handler = |query: &WasmQuery| -> QuerierResult {
+        match query {
+            WasmQuery::Smart { contract_addr, msg } => {
+                let res = match from_binary(msg).unwrap() {
+                    PairQueryMsg::Pair {} => to_binary(&PairInfo {
+                        asset_infos: vec![
+                            AssetInfo::NativeToken {
+                                denom: "uatom".to_string(),
+                            },
+                            AssetInfo::NativeToken {
+                                denom: "uosmo".to_string(),
+                            },
+                        ],
+                        contract_addr: Addr::unchecked(contract_addr),
+                        liquidity_token: Addr::unchecked("lp_token"),
+                        pair_type: PairType::Xyk {},
+                    }),
+                    PairQueryMsg::Share { amount } => to_binary(&vec![
+                        Asset {
+                            info: AssetInfo::NativeToken {
+                                denom: "uatom".to_string(),
+                            },
+                            amount: amount / Uint128::new(2),
+                        },
+                        Asset {
+                            info: AssetInfo::NativeToken {
+                                denom: "uosmo".to_string(),
+                            },
+                            amount: amount * Uint128::new(2),
+                        },
+                    ]),
+                    query => panic!("Unsupported query: {:?}", query),
+                };
+                SystemResult::Ok(SystemContractResult::Ok(res.unwrap()))
+            }
+            _ => panic!("Unsupported query: {:?}", query),
+        }
+    };
+
+    // Update querier with mock wasm handler
+    deps.querier.update_wasm(wasm_handler);
+
+    // Call query with the given test parameters
+    let res = skip_api_swap_adapter_astroport::contract::query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::SimulateWithdrawLiquidity {
+            pool: "pool_1".to_string(),
+            coin_in: params.coin_in,
+        },
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the response is correct
+            let res: Vec<Coin> = from_binary(&res)?;
+            assert_eq!(res, params.expected_response.unwrap());
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+
+    Ok(())
+}
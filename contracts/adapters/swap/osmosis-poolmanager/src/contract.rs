@@ -2,24 +2,42 @@ use crate::{
     error::{ContractError, ContractResult},
     state::ENTRY_POINT_CONTRACT_ADDRESS,
 };
+use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    entry_point, to_binary, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response,
-    Uint128, WasmMsg,
+    entry_point, to_binary, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo,
+    QueryRequest, Response, StdResult, Uint128, WasmMsg,
 };
+use cw2::set_contract_version;
 use cw_utils::one_coin;
-use osmosis_std::types::osmosis::poolmanager::v1beta1::{
-    EstimateSwapExactAmountInResponse, EstimateSwapExactAmountOutResponse, MsgSwapExactAmountIn,
-    PoolmanagerQuerier, SwapAmountInRoute, SwapAmountOutRoute,
+use osmosis_std::types::osmosis::{
+    gamm::{
+        poolmodels::stableswap::v1beta1::PoolParams as StableswapPoolParams,
+        v1beta1::{GammQuerier, MsgExitPool, MsgJoinPool, PoolParams as BalancerPoolParams},
+    },
+    poolmanager::v1beta1::{
+        EstimateSwapExactAmountInResponse, EstimateSwapExactAmountOutResponse,
+        MsgSwapExactAmountIn, MsgSwapExactAmountOut, PoolmanagerQuerier, SwapAmountInRoute,
+        SwapAmountOutRoute,
+    },
 };
+use prost::Message;
 use skip::{
+    error::SkipError,
+    migrate::{validate_migration, MigrateMsg},
     proto_coin::ProtoCoin,
     swap::{
-        convert_swap_operations, execute_transfer_funds_back, ExecuteMsg,
-        OsmosisInstantiateMsg as InstantiateMsg, QueryMsg, SwapOperation,
+        convert_swap_operations, execute_transfer_funds_back, provide_liquidity_min_amount,
+        provide_liquidity_reserves, provide_liquidity_swap_amount,
+        validate_withdraw_liquidity_denoms, ConfigResponse, ExecuteMsg,
+        OsmosisInstantiateMsg as InstantiateMsg, QueryMsg, SimulateSwapWithMetadataResponse,
+        SwapFee, SwapOperation, SwapOperationMetadata,
     },
 };
 use std::str::FromStr;
 
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 ///////////////////
 /// INSTANTIATE ///
 ///////////////////
@@ -31,6 +49,9 @@ pub fn instantiate(
     _info: MessageInfo,
     msg: InstantiateMsg,
 ) -> ContractResult<Response> {
+    // Store the contract name and version for migrations
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
     // Validate entry point contract address
     let checked_entry_point_contract_address =
         deps.api.addr_validate(&msg.entry_point_contract_address)?;
@@ -46,6 +67,30 @@ pub fn instantiate(
         ))
 }
 
+///////////////
+/// MIGRATE ///
+///////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> ContractResult<Response> {
+    // Validate the migration and get the version being migrated from. Contracts deployed
+    // before cw2 version tracking are recognized by their stored entry point contract address.
+    let from_version = validate_migration(
+        deps.storage,
+        CONTRACT_NAME,
+        CONTRACT_VERSION,
+        ENTRY_POINT_CONTRACT_ADDRESS.exists(deps.storage),
+    )?;
+
+    // Store the new contract version
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", from_version.to_string())
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
 ///////////////
 /// EXECUTE ///
 ///////////////
@@ -58,10 +103,34 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> ContractResult<Response> {
     match msg {
-        ExecuteMsg::Swap { operations } => execute_swap(deps, env, info, operations),
+        ExecuteMsg::Swap {
+            operations,
+            minimum_receive,
+            ..
+        } => execute_swap(deps, env, info, operations, minimum_receive),
+        ExecuteMsg::SwapExactCoinOut {
+            operations,
+            coin_out,
+        } => execute_swap_exact_coin_out(deps, env, info, operations, coin_out),
+        ExecuteMsg::ProvideLiquidity {
+            pool,
+            recipient,
+            slippage_tolerance,
+        } => execute_provide_liquidity(deps, env, info, pool, recipient, slippage_tolerance),
+        ExecuteMsg::WithdrawLiquidity { pool, denoms_out } => {
+            execute_withdraw_liquidity(deps, env, info, pool, denoms_out)
+        }
+        // CW20 tokens do not exist on osmosis, so they cannot be swapped by this adapter
+        ExecuteMsg::Receive(_) => Err(ContractError::Cw20NotSupported),
         ExecuteMsg::TransferFundsBack { swapper } => {
             Ok(execute_transfer_funds_back(deps, env, info, swapper)?)
         }
+        ExecuteMsg::ProvideLiquidityWithBalances {
+            pool,
+            denoms,
+            min_lp_out,
+            ..
+        } => execute_provide_liquidity_with_balances(deps, env, info, pool, denoms, min_lp_out),
     }
 }
 
@@ -71,6 +140,7 @@ fn execute_swap(
     env: Env,
     info: MessageInfo,
     operations: Vec<SwapOperation>,
+    minimum_receive: Option<Uint128>,
 ) -> ContractResult<Response> {
     // Get entry point contract address from storage
     let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
@@ -84,7 +154,7 @@ fn execute_swap(
     let coin_in = one_coin(&info)?;
 
     // Create the osmosis poolmanager swap exact amount in message
-    let swap_msg = create_osmosis_swap_msg(&env, coin_in, operations)?;
+    let swap_msg = create_osmosis_swap_msg(&env, coin_in, operations, minimum_receive)?;
 
     // Create the transfer funds back message
     let transfer_funds_back_msg = WasmMsg::Execute {
@@ -101,6 +171,285 @@ fn execute_swap(
         .add_attribute("action", "dispatch_swap_and_transfer_back"))
 }
 
+// Executes a swap for exactly the given coin out with the given swap operations, using at most
+// the coin sent, and then transfers the coin out and unused coin in back to the caller
+fn execute_swap_exact_coin_out(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    operations: Vec<SwapOperation>,
+    coin_out: Coin,
+) -> ContractResult<Response> {
+    // Get entry point contract address from storage
+    let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
+
+    // Enforce the caller is the entry point contract
+    if info.sender != entry_point_contract_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Get the max coin in from the message info, error if there is not exactly one coin sent
+    let max_coin_in = one_coin(&info)?;
+
+    // Create the osmosis poolmanager swap exact amount out message
+    let swap_msg =
+        create_osmosis_swap_exact_amount_out_msg(&env, max_coin_in, operations, coin_out)?;
+
+    // Create the transfer funds back message, which also returns the unused coin in
+    let transfer_funds_back_msg = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_binary(&ExecuteMsg::TransferFundsBack {
+            swapper: info.sender,
+        })?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_message(swap_msg)
+        .add_message(transfer_funds_back_msg)
+        .add_attribute("action", "dispatch_swap_exact_coin_out_and_transfer_back"))
+}
+
+// Swaps part of the coin in into the other asset of the gamm pool, using the swap simulation
+// to balance the two assets, joins the pool with both assets, and then transfers the
+// pool shares and any assets the join did not use to the recipient if one is given,
+// or else back to the caller
+fn execute_provide_liquidity(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pool: String,
+    recipient: Option<String>,
+    slippage_tolerance: Decimal,
+) -> ContractResult<Response> {
+    // Get entry point contract address from storage
+    let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
+
+    // Enforce the caller is the entry point contract
+    if info.sender != entry_point_contract_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Get coin in from the message info, error if there is not exactly one coin sent
+    let coin_in = one_coin(&info)?;
+
+    // Get the address the LP tokens are sent to, which is the validated
+    // recipient if one is given, or else the caller
+    let lp_recipient = match recipient {
+        Some(recipient) => deps.api.addr_validate(&recipient)?,
+        None => info.sender.clone(),
+    };
+
+    let pool_id = pool.parse::<u64>()?;
+
+    // Query the gamm module for the pool's reserves, and get the reserve
+    // of the coin in denom and the reserve of the pool's other asset
+    let pool_reserves = GammQuerier::new(&deps.querier)
+        .total_pool_liquidity(pool_id)?
+        .liquidity
+        .into_iter()
+        .map(Coin::try_from)
+        .collect::<StdResult<Vec<Coin>>>()?;
+    let (reserve_in, reserve_out) = provide_liquidity_reserves(pool_reserves, &coin_in.denom)?;
+
+    // Get the amount of the coin in to swap into the pool's other asset
+    let swap_operation = SwapOperation {
+        pool: pool.clone(),
+        denom_in: coin_in.denom.clone(),
+        denom_out: reserve_out.denom.clone(),
+    };
+    let swap_amount =
+        provide_liquidity_swap_amount(coin_in.amount, reserve_in, reserve_out.amount, |amount| {
+            query_simulate_swap_exact_coin_in(
+                deps.as_ref(),
+                Coin {
+                    denom: coin_in.denom.clone(),
+                    amount,
+                },
+                vec![swap_operation.clone()],
+            )
+            .map(|coin_out| coin_out.amount)
+        })?;
+
+    // Error if none of the coin in can be swapped
+    if swap_amount.is_zero() {
+        return Err(SkipError::ProvideLiquidityCoinInTooSmall.into());
+    }
+    let swap_coin_in = Coin {
+        denom: coin_in.denom.clone(),
+        amount: swap_amount,
+    };
+
+    // Get the minimum receive of the swap from its simulated coin out and the slippage tolerance
+    let swap_coin_out = query_simulate_swap_exact_coin_in(
+        deps.as_ref(),
+        swap_coin_in.clone(),
+        vec![swap_operation.clone()],
+    )?;
+    let minimum_receive = provide_liquidity_min_amount(swap_coin_out.amount, slippage_tolerance)?;
+
+    // Get the min LP out from the pool shares of joining the pool with the coin in
+    // as a single asset and the slippage tolerance
+    let res = GammQuerier::new(&deps.querier)
+        .calc_join_pool_shares(pool_id, vec![ProtoCoin(coin_in.clone()).into()])?;
+    let min_lp_out = provide_liquidity_min_amount(
+        Uint128::from_str(&res.share_out_amount)?,
+        slippage_tolerance,
+    )?;
+
+    // Create the osmosis poolmanager swap exact amount in message
+    let swap_msg = create_osmosis_swap_msg(
+        &env,
+        swap_coin_in,
+        vec![swap_operation],
+        Some(minimum_receive),
+    )?;
+
+    // Create the provide liquidity message, which joins the pool
+    // with the contract's balances of both assets after the swap
+    let provide_liquidity_msg = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_binary(&ExecuteMsg::ProvideLiquidityWithBalances {
+            pool,
+            denoms: vec![coin_in.denom, reserve_out.denom],
+            swapper: lp_recipient.clone(),
+            slippage_tolerance,
+            min_lp_out: Some(min_lp_out),
+        })?,
+        funds: vec![],
+    };
+
+    // Create the transfer funds back message, which returns the pool shares
+    // and any assets the join did not use
+    let transfer_funds_back_msg = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_binary(&ExecuteMsg::TransferFundsBack {
+            swapper: lp_recipient,
+        })?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_message(swap_msg)
+        .add_message(provide_liquidity_msg)
+        .add_message(transfer_funds_back_msg)
+        .add_attribute("action", "dispatch_swap_and_provide_liquidity"))
+}
+
+// Joins the gamm pool with the contract's balances of the given denoms, for the most pool
+// shares they can buy, erroring if those are less than the min LP out if one is given.
+// Can only be called by the contract itself
+fn execute_provide_liquidity_with_balances(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pool: String,
+    denoms: Vec<String>,
+    min_lp_out: Option<Uint128>,
+) -> ContractResult<Response> {
+    // Ensure the caller is the contract itself
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let pool_id = pool.parse::<u64>()?;
+
+    // Query the contract's balances of the given denoms, sorted by denom as sdk coins must be
+    let mut balances = denoms
+        .into_iter()
+        .map(|denom| deps.querier.query_balance(&env.contract.address, denom))
+        .collect::<StdResult<Vec<Coin>>>()?;
+    balances.sort_by(|a, b| a.denom.cmp(&b.denom));
+    let token_in_maxs: Vec<_> = balances
+        .into_iter()
+        .map(|coin| ProtoCoin(coin).into())
+        .collect();
+
+    // Query the gamm module for the pool shares received from joining with the balances
+    let res = GammQuerier::new(&deps.querier)
+        .calc_join_pool_no_swap_shares(pool_id, token_in_maxs.clone())?;
+
+    // Error if the pool shares received are less than the min LP out
+    if let Some(min_lp_out) = min_lp_out {
+        if Uint128::from_str(&res.shares_out)? < min_lp_out {
+            return Err(SkipError::ProvideLiquidityLpOutLessThanMin.into());
+        }
+    }
+
+    // Create the osmosis gamm join pool message
+    let join_pool_msg: CosmosMsg = MsgJoinPool {
+        sender: env.contract.address.to_string(),
+        pool_id,
+        share_out_amount: res.shares_out,
+        token_in_maxs,
+    }
+    .into();
+
+    Ok(Response::new()
+        .add_message(join_pool_msg)
+        .add_attribute("action", "dispatch_provide_liquidity"))
+}
+
+// Exits the gamm pool with the pool shares sent, and then
+// transfers the withdrawn assets back to the caller
+fn execute_withdraw_liquidity(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pool: String,
+    denoms_out: Vec<String>,
+) -> ContractResult<Response> {
+    // Get entry point contract address from storage
+    let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
+
+    // Enforce the caller is the entry point contract
+    if info.sender != entry_point_contract_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Get coin in from the message info, error if there is not exactly one coin sent
+    let coin_in = one_coin(&info)?;
+
+    let pool_id = pool.parse::<u64>()?;
+
+    // Error if the coin in is not the pool's shares
+    if coin_in.denom != format!("gamm/pool/{}", pool_id) {
+        return Err(SkipError::WithdrawLiquidityCoinInNotPoolShare.into());
+    }
+
+    // Query the gamm module for the pool's assets, erroring if they are not the denoms out
+    let pool_denoms = GammQuerier::new(&deps.querier)
+        .total_pool_liquidity(pool_id)?
+        .liquidity
+        .into_iter()
+        .map(|coin| coin.denom)
+        .collect();
+    validate_withdraw_liquidity_denoms(pool_denoms, denoms_out)?;
+
+    // Create the osmosis gamm exit pool message
+    let exit_pool_msg: CosmosMsg = MsgExitPool {
+        sender: env.contract.address.to_string(),
+        pool_id,
+        share_in_amount: coin_in.amount.to_string(),
+        token_out_mins: vec![],
+    }
+    .into();
+
+    // Create the transfer funds back message, which returns the withdrawn assets
+    let transfer_funds_back_msg = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_binary(&ExecuteMsg::TransferFundsBack {
+            swapper: info.sender,
+        })?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_message(exit_pool_msg)
+        .add_message(transfer_funds_back_msg)
+        .add_attribute("action", "dispatch_withdraw_liquidity_and_transfer_back"))
+}
+
 ////////////////////////
 /// HELPER FUNCTIONS ///
 ////////////////////////
@@ -110,6 +459,7 @@ fn create_osmosis_swap_msg(
     env: &Env,
     coin_in: Coin,
     swap_operations: Vec<SwapOperation>,
+    minimum_receive: Option<Uint128>,
 ) -> ContractResult<CosmosMsg> {
     // Convert the swap operations to osmosis swap amount in routes
     // Return an error if there was an error converting the swap
@@ -117,15 +467,44 @@ fn create_osmosis_swap_msg(
     let osmosis_swap_amount_in_routes: Vec<SwapAmountInRoute> =
         convert_swap_operations(swap_operations).map_err(ContractError::ParseIntPoolID)?;
 
+    // Get the token out min amount from the minimum receive, defaulting to 1
+    // since the poolmanager module requires a positive token out min amount
+    let token_out_min_amount = minimum_receive
+        .unwrap_or_else(Uint128::one)
+        .max(Uint128::one());
+
     // Create the osmosis poolmanager swap exact amount in message
-    // The token out min amount is set to 1 because we are not concerned
-    // with the minimum amount in this contract, that gets verified in the
-    // entry point contract.
     let swap_msg: CosmosMsg = MsgSwapExactAmountIn {
         sender: env.contract.address.to_string(),
         routes: osmosis_swap_amount_in_routes,
         token_in: Some(ProtoCoin(coin_in).into()),
-        token_out_min_amount: "1".to_string(),
+        token_out_min_amount: token_out_min_amount.to_string(),
+    }
+    .into();
+
+    Ok(swap_msg)
+}
+
+// Creates the osmosis poolmanager swap exact amount out message
+fn create_osmosis_swap_exact_amount_out_msg(
+    env: &Env,
+    max_coin_in: Coin,
+    swap_operations: Vec<SwapOperation>,
+    coin_out: Coin,
+) -> ContractResult<CosmosMsg> {
+    // Convert the swap operations to osmosis swap amount out routes
+    // Return an error if there was an error converting the swap
+    // operations to osmosis swap amount out routes.
+    let osmosis_swap_amount_out_routes: Vec<SwapAmountOutRoute> =
+        convert_swap_operations(swap_operations).map_err(ContractError::ParseIntPoolID)?;
+
+    // Create the osmosis poolmanager swap exact amount out message
+    // The token in max amount is the full coin sent to the contract
+    let swap_msg: CosmosMsg = MsgSwapExactAmountOut {
+        sender: env.contract.address.to_string(),
+        routes: osmosis_swap_amount_out_routes,
+        token_in_max_amount: max_coin_in.amount.to_string(),
+        token_out: Some(ProtoCoin(coin_out).into()),
     }
     .into();
 
@@ -139,6 +518,10 @@ fn create_osmosis_swap_msg(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
     match msg {
+        QueryMsg::Config {} => to_binary(&ConfigResponse {
+            entry_point_contract_address: ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?,
+            router_contract_address: None,
+        }),
         QueryMsg::SimulateSwapExactCoinIn {
             coin_in,
             swap_operations,
@@ -155,6 +538,25 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
             coin_out,
             swap_operations,
         )?),
+        QueryMsg::SimulateSwapExactCoinInWithMetadata {
+            coin_in,
+            swap_operations,
+        } => to_binary(&query_simulate_swap_exact_coin_in_with_metadata(
+            deps,
+            coin_in,
+            swap_operations,
+        )?),
+        QueryMsg::SimulateSwapExactCoinOutWithMetadata {
+            coin_out,
+            swap_operations,
+        } => to_binary(&query_simulate_swap_exact_coin_out_with_metadata(
+            deps,
+            coin_out,
+            swap_operations,
+        )?),
+        QueryMsg::SimulateWithdrawLiquidity { pool, coin_in } => {
+            to_binary(&query_simulate_withdraw_liquidity(deps, pool, coin_in)?)
+        }
         _ => {
             unimplemented!()
         }
@@ -241,3 +643,239 @@ fn query_simulate_swap_exact_coin_out(
         amount: Uint128::from_str(&res.token_in_amount)?,
     })
 }
+
+// Queries the osmosis gamm module to simulate withdrawing liquidity with the pool's shares
+fn query_simulate_withdraw_liquidity(
+    deps: Deps,
+    pool: String,
+    coin_in: Coin,
+) -> ContractResult<Vec<Coin>> {
+    let pool_id = pool.parse::<u64>()?;
+
+    // Error if the coin in is not the pool's shares
+    if coin_in.denom != format!("gamm/pool/{}", pool_id) {
+        return Err(SkipError::WithdrawLiquidityCoinInNotPoolShare.into());
+    }
+
+    // Query the osmosis gamm module for the assets withdrawn by the share amount
+    let res = GammQuerier::new(&deps.querier)
+        .calc_exit_pool_coins_from_shares(pool_id, coin_in.amount.to_string())?;
+
+    // Return the withdrawn assets as coins
+    res.tokens_out
+        .into_iter()
+        .map(|coin| {
+            Ok(Coin {
+                denom: coin.denom,
+                amount: Uint128::from_str(&coin.amount)?,
+            })
+        })
+        .collect()
+}
+
+// Queries the osmosis poolmanager module to simulate a swap exact amount in
+// one pool at a time, returning the coins, prices and fees of each swap operation
+fn query_simulate_swap_exact_coin_in_with_metadata(
+    deps: Deps,
+    coin_in: Coin,
+    swap_operations: Vec<SwapOperation>,
+) -> ContractResult<SimulateSwapWithMetadataResponse> {
+    // Error if swap operations is empty
+    let Some(first_op) = swap_operations.first() else {
+        return Err(ContractError::SwapOperationsEmpty);
+    };
+
+    // Ensure coin_in's denom is the same as the first swap operation's denom in
+    if coin_in.denom != first_op.denom_in {
+        return Err(ContractError::CoinInDenomMismatch);
+    }
+
+    // Iterate through the swap operations in order, simulating each swap operation
+    // with the coin out of the previous swap operation
+    let mut operations = Vec::with_capacity(swap_operations.len());
+    let mut coin_in = coin_in;
+    for operation in swap_operations {
+        let pool_id = operation.pool.parse::<u64>()?;
+
+        // Query the osmosis poolmanager module to simulate the single pool swap
+        let res: EstimateSwapExactAmountInResponse = PoolmanagerQuerier::new(&deps.querier)
+            .estimate_single_pool_swap_exact_amount_in(
+                pool_id,
+                coin_in.to_string(),
+                operation.denom_out.clone(),
+            )?;
+
+        let coin_out = Coin {
+            denom: operation.denom_out,
+            amount: Uint128::from_str(&res.token_out_amount)?,
+        };
+
+        operations.push(create_swap_operation_metadata(
+            deps,
+            operation.pool,
+            pool_id,
+            coin_in,
+            coin_out.clone(),
+        )?);
+
+        coin_in = coin_out;
+    }
+
+    Ok(SimulateSwapWithMetadataResponse::from_operations(
+        operations,
+    )?)
+}
+
+// Queries the osmosis poolmanager module to simulate a swap exact amount out
+// one pool at a time, returning the coins, prices and fees of each swap operation
+fn query_simulate_swap_exact_coin_out_with_metadata(
+    deps: Deps,
+    coin_out: Coin,
+    swap_operations: Vec<SwapOperation>,
+) -> ContractResult<SimulateSwapWithMetadataResponse> {
+    // Error if swap operations is empty
+    let Some(last_op) = swap_operations.last() else {
+        return Err(ContractError::SwapOperationsEmpty);
+    };
+
+    // Ensure coin_out's denom is the same as the last swap operation's denom out
+    if coin_out.denom != last_op.denom_out {
+        return Err(ContractError::CoinOutDenomMismatch);
+    }
+
+    // Iterate through the swap operations in reverse order, simulating the coin in
+    // needed for each swap operation to produce the coin in of the next one
+    let mut operations = Vec::with_capacity(swap_operations.len());
+    let mut coin_out = coin_out;
+    for operation in swap_operations.into_iter().rev() {
+        let pool_id = operation.pool.parse::<u64>()?;
+
+        // Query the osmosis poolmanager module to simulate the single pool swap
+        let res: EstimateSwapExactAmountOutResponse = PoolmanagerQuerier::new(&deps.querier)
+            .estimate_single_pool_swap_exact_amount_out(
+                pool_id,
+                operation.denom_in.clone(),
+                coin_out.to_string(),
+            )?;
+
+        let coin_in_needed = Coin {
+            denom: operation.denom_in,
+            amount: Uint128::from_str(&res.token_in_amount)?,
+        };
+
+        operations.push(create_swap_operation_metadata(
+            deps,
+            operation.pool,
+            pool_id,
+            coin_in_needed.clone(),
+            coin_out,
+        )?);
+
+        coin_out = coin_in_needed;
+    }
+
+    // Put the swap operations back in swap order
+    operations.reverse();
+
+    Ok(SimulateSwapWithMetadataResponse::from_operations(
+        operations,
+    )?)
+}
+
+// Creates the metadata of an osmosis swap operation, querying the pool's spot price
+// and reporting the pool's spread factor and the taker fee as fees in the offer denom
+fn create_swap_operation_metadata(
+    deps: Deps,
+    pool: String,
+    pool_id: u64,
+    coin_in: Coin,
+    coin_out: Coin,
+) -> ContractResult<SwapOperationMetadata> {
+    // Query the spot price of the denom in quoted in the denom out
+    let spot_price = PoolmanagerQuerier::new(&deps.querier).spot_price(
+        pool_id,
+        coin_in.denom.clone(),
+        coin_out.denom.clone(),
+    )?;
+    let spot_price = Decimal::from_str(&spot_price.spot_price)?;
+
+    // Create the spread factor fee if the pool exposes one, and the taker fee
+    // if the chain charges one on swaps between the denom in and the denom out
+    let fees = [
+        ("spread_factor", query_spread_factor(deps, pool_id)),
+        (
+            "taker_fee",
+            query_taker_fee(deps, &coin_in.denom, &coin_out.denom),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(name, fee)| {
+        fee.map(|fee| SwapFee {
+            name: name.to_string(),
+            coin: Coin {
+                denom: coin_in.denom.clone(),
+                amount: coin_in.amount.mul_floor(fee),
+            },
+        })
+    })
+    .collect();
+
+    Ok(SwapOperationMetadata::new(
+        pool, coin_in, coin_out, spot_price, fees,
+    ))
+}
+
+// Queries the spread factor (swap fee) of a gamm pool. Returns None for pools
+// without gamm pool params (e.g. concentrated liquidity pools).
+fn query_spread_factor(deps: Deps, pool_id: u64) -> Option<Decimal> {
+    let params = GammQuerier::new(&deps.querier)
+        .pool_params(pool_id)
+        .ok()?
+        .params?;
+
+    let swap_fee = match params.type_url.as_str() {
+        BalancerPoolParams::TYPE_URL => BalancerPoolParams::try_from(params).ok()?.swap_fee,
+        StableswapPoolParams::TYPE_URL => StableswapPoolParams::try_from(params).ok()?.swap_fee,
+        _ => return None,
+    };
+
+    Decimal::from_str(&swap_fee).ok()
+}
+
+// The path of the osmosis poolmanager query for the taker fee charged on swaps between two
+// denoms. Taker fees were added after the osmosis-std version used, so the query's request
+// and response types are defined here.
+const TRADING_PAIR_TAKER_FEE_QUERY_PATH: &str =
+    "/osmosis.poolmanager.v1beta1.Query/TradingPairTakerFee";
+
+#[derive(Clone, PartialEq, Message)]
+struct QueryTradingPairTakerFeeRequest {
+    #[prost(string, tag = "1")]
+    denom_0: String,
+    #[prost(string, tag = "2")]
+    denom_1: String,
+}
+
+#[cw_serde]
+struct QueryTradingPairTakerFeeResponse {
+    taker_fee: String,
+}
+
+// Queries the taker fee the poolmanager module charges on the coin in of swaps between the
+// two denoms. Returns None on chains whose osmosis version does not charge taker fees.
+fn query_taker_fee(deps: Deps, denom_in: &str, denom_out: &str) -> Option<Decimal> {
+    let request = QueryTradingPairTakerFeeRequest {
+        denom_0: denom_in.to_string(),
+        denom_1: denom_out.to_string(),
+    };
+
+    let res: QueryTradingPairTakerFeeResponse = deps
+        .querier
+        .query(&QueryRequest::Stargate {
+            path: TRADING_PAIR_TAKER_FEE_QUERY_PATH.to_string(),
+            data: request.encode_to_vec().into(),
+        })
+        .ok()?;
+
+    Decimal::from_str(&res.taker_fee).ok()
+}
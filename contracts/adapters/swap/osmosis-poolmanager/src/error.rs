@@ -29,4 +29,7 @@ pub enum ContractError {
 
     #[error("coin_out denom must match the last swap operation's denom out")]
     CoinOutDenomMismatch,
+
+    #[error("CW20 Tokens Are Not Supported By This Swap Adapter")]
+    Cw20NotSupported,
 }
@@ -0,0 +1,128 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env, mock_info},
+    Addr, Coin, Decimal,
+};
+use skip::swap::ExecuteMsg;
+use skip_api_swap_adapter_osmosis_poolmanager::{
+    error::ContractResult, state::ENTRY_POINT_CONTRACT_ADDRESS,
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Error
+    - Unauthorized Caller (Only the stored entry point contract can call this function)
+    - No Coin Sent
+    - Invalid Pool ID Conversion
+    - Provide Liquidity With Balances Unauthorized Caller (Only the contract itself can call this function)
+    - Provide Liquidity With Balances Invalid Pool ID Conversion
+
+ */
+
+// Define test parameters
+struct Params {
+    caller: String,
+    info_funds: Vec<Coin>,
+    msg: ExecuteMsg,
+    expected_error_string: String,
+}
+
+// Test execute_provide_liquidity and execute_provide_liquidity_with_balances
+#[test_case(
+    Params {
+        caller: "random".to_string(),
+        info_funds: vec![Coin::new(1_000, "uatom")],
+        msg: ExecuteMsg::ProvideLiquidity {
+            pool: "1".to_string(),
+            recipient: None,
+            slippage_tolerance: Decimal::percent(1),
+        },
+        expected_error_string: "Unauthorized".to_string(),
+    };
+    "Unauthorized Caller - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![],
+        msg: ExecuteMsg::ProvideLiquidity {
+            pool: "1".to_string(),
+            recipient: None,
+            slippage_tolerance: Decimal::percent(1),
+        },
+        expected_error_string: "No funds sent".to_string(),
+    };
+    "No Coin Sent - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(1_000, "uatom")],
+        msg: ExecuteMsg::ProvideLiquidity {
+            pool: "pool".to_string(),
+            recipient: None,
+            slippage_tolerance: Decimal::percent(1),
+        },
+        expected_error_string: "Parse Int error raised: invalid pool String to pool id u64 conversion".to_string(),
+    };
+    "Invalid Pool ID Conversion - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![],
+        msg: ExecuteMsg::ProvideLiquidityWithBalances {
+            pool: "1".to_string(),
+            denoms: vec!["uatom".to_string(), "uosmo".to_string()],
+            swapper: Addr::unchecked("entry_point"),
+            slippage_tolerance: Decimal::percent(1),
+            min_lp_out: None,
+        },
+        expected_error_string: "Unauthorized".to_string(),
+    };
+    "Provide Liquidity With Balances Unauthorized Caller - Expect Error")]
+#[test_case(
+    Params {
+        caller: "swap_contract_address".to_string(),
+        info_funds: vec![],
+        msg: ExecuteMsg::ProvideLiquidityWithBalances {
+            pool: "pool".to_string(),
+            denoms: vec!["uatom".to_string(), "uosmo".to_string()],
+            swapper: Addr::unchecked("entry_point"),
+            slippage_tolerance: Decimal::percent(1),
+            min_lp_out: None,
+        },
+        expected_error_string: "Parse Int error raised: invalid pool String to pool id u64 conversion".to_string(),
+    };
+    "Provide Liquidity With Balances Invalid Pool ID Conversion - Expect Error")]
+fn test_execute_provide_liquidity(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("swap_contract_address");
+
+    // Create mock info with the given caller and funds
+    let info = mock_info(&params.caller, &params.info_funds);
+
+    // Store the entry point contract address
+    ENTRY_POINT_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("entry_point"))?;
+
+    // Call execute with the given test parameters
+    let res = skip_api_swap_adapter_osmosis_poolmanager::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        params.msg,
+    );
+
+    // Assert the call errored with the expected error
+    match res {
+        Ok(_) => panic!(
+            "expected test to error with {:?}, but it succeeded",
+            params.expected_error_string
+        ),
+        Err(err) => assert_eq!(err.to_string(), params.expected_error_string),
+    }
+
+    Ok(())
+}
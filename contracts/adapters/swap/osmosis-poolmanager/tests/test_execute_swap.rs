@@ -2,7 +2,7 @@ use cosmwasm_std::{
     testing::{mock_dependencies, mock_env, mock_info},
     to_binary, Addr, Coin,
     ReplyOn::Never,
-    SubMsg, WasmMsg,
+    SubMsg, Uint128, WasmMsg,
 };
 use osmosis_std::types::cosmos::base::v1beta1::Coin as OsmosisStdCoin;
 use osmosis_std::types::osmosis::poolmanager::v1beta1::{MsgSwapExactAmountIn, SwapAmountInRoute};
@@ -17,6 +17,7 @@ Test Cases:
 
 Expect Success
     - One Swap Operation
+    - One Swap Operation With Minimum Receive
     - Multiple Swap Operations
     - No Swap Operations (This is prevented in the entry point contract; and will fail on Osmosis module if attempted)
 
@@ -33,6 +34,7 @@ struct Params {
     caller: String,
     info_funds: Vec<Coin>,
     swap_operations: Vec<SwapOperation>,
+    minimum_receive: Option<Uint128>,
     expected_messages: Vec<SubMsg>,
     expected_error_string: String,
 }
@@ -49,6 +51,7 @@ struct Params {
                 denom_out: "uatom".to_string(),
             }
         ],
+        minimum_receive: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -89,6 +92,58 @@ struct Params {
         expected_error_string: "".to_string(),
     };
 "One Swap Operation")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "uosmo")],
+        swap_operations: vec![
+            SwapOperation {
+                pool: "1".to_string(),
+                denom_in: "uosmo".to_string(),
+                denom_out: "uatom".to_string(),
+            }
+        ],
+        minimum_receive: Some(Uint128::new(90)),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: MsgSwapExactAmountIn {
+                    sender: "swap_contract_address".to_string(),
+                    routes: vec![
+                        SwapAmountInRoute {
+                            pool_id: 1,
+                            token_out_denom: "uatom".to_string(),
+                        }
+                    ],
+                    token_in: Some(
+                        OsmosisStdCoin {
+                            denom: "uosmo".to_string(),
+                            amount: "100".to_string(),
+                        }
+                    ),
+                    token_out_min_amount: "90".to_string(),
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_binary(&ExecuteMsg::TransferFundsBack {
+                        swapper: Addr::unchecked("entry_point"),
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error_string: "".to_string(),
+    };
+"One Swap Operation With Minimum Receive")]
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
@@ -105,6 +160,7 @@ struct Params {
                 denom_out: "untrn".to_string(),
             }
         ],
+        minimum_receive: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -154,6 +210,7 @@ struct Params {
         caller: "entry_point".to_string(),
         info_funds: vec![Coin::new(100, "uosmo")],
         swap_operations: vec![],
+        minimum_receive: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -200,6 +257,7 @@ struct Params {
                 denom_out: "uatom".to_string(),
             }
         ],
+        minimum_receive: None,
         expected_messages: vec![],
         expected_error_string: "No funds sent".to_string(),
     };
@@ -218,6 +276,7 @@ struct Params {
                 denom_out: "uatom".to_string(),
             }
         ],
+        minimum_receive: None,
         expected_messages: vec![],
         expected_error_string: "Sent more than one denomination".to_string(),
     };
@@ -233,6 +292,7 @@ struct Params {
                 denom_out: "uatom".to_string(),
             }
         ],
+        minimum_receive: None,
         expected_messages: vec![],
         expected_error_string: "Parse Int error raised: invalid pool String to pool id u64 conversion".to_string(),
     };
@@ -245,6 +305,7 @@ struct Params {
             Coin::new(100, "uosmo"),
         ],
         swap_operations: vec![],
+        minimum_receive: None,
         expected_messages: vec![],
         expected_error_string: "Unauthorized".to_string(),
     };
@@ -273,6 +334,8 @@ fn test_execute_swap(params: Params) -> ContractResult<()> {
         info,
         ExecuteMsg::Swap {
             operations: params.swap_operations.clone(),
+            minimum_receive: params.minimum_receive,
+            max_spread: None,
         },
     );
 
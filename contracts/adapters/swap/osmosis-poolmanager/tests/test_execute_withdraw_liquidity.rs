@@ -0,0 +1,98 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env, mock_info},
+    Addr, Coin,
+};
+use skip::swap::ExecuteMsg;
+use skip_api_swap_adapter_osmosis_poolmanager::{
+    error::ContractResult, state::ENTRY_POINT_CONTRACT_ADDRESS,
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Error
+    - Unauthorized Caller (Only the stored entry point contract can call this function)
+    - No Coin Sent
+    - Invalid Pool ID Conversion
+    - Coin In Is Not The Pool Shares
+
+ */
+
+// Define test parameters
+struct Params {
+    caller: String,
+    info_funds: Vec<Coin>,
+    pool: String,
+    expected_error_string: String,
+}
+
+// Test execute_withdraw_liquidity
+#[test_case(
+    Params {
+        caller: "random".to_string(),
+        info_funds: vec![Coin::new(1_000, "gamm/pool/1")],
+        pool: "1".to_string(),
+        expected_error_string: "Unauthorized".to_string(),
+    };
+    "Unauthorized Caller - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![],
+        pool: "1".to_string(),
+        expected_error_string: "No funds sent".to_string(),
+    };
+    "No Coin Sent - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(1_000, "gamm/pool/1")],
+        pool: "pool".to_string(),
+        expected_error_string: "Parse Int error raised: invalid pool String to pool id u64 conversion".to_string(),
+    };
+    "Invalid Pool ID Conversion - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(1_000, "gamm/pool/2")],
+        pool: "1".to_string(),
+        expected_error_string: "Withdraw Liquidity Coin In Must Be The Pool's LP Token".to_string(),
+    };
+    "Coin In Is Not The Pool Shares - Expect Error")]
+fn test_execute_withdraw_liquidity(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("swap_contract_address");
+
+    // Create mock info with the given caller and funds
+    let info = mock_info(&params.caller, &params.info_funds);
+
+    // Store the entry point contract address
+    ENTRY_POINT_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("entry_point"))?;
+
+    // Call execute with the given test parameters
+    let res = skip_api_swap_adapter_osmosis_poolmanager::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::WithdrawLiquidity {
+            pool: params.pool,
+            denoms_out: vec!["uatom".to_string(), "uosmo".to_string()],
+        },
+    );
+
+    // Assert the call errored with the expected error
+    match res {
+        Ok(_) => panic!(
+            "expected test to error with {:?}, but it succeeded",
+            params.expected_error_string
+        ),
+        Err(err) => assert_eq!(err.to_string(), params.expected_error_string),
+    }
+
+    Ok(())
+}
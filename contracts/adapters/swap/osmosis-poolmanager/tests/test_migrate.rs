@@ -0,0 +1,160 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env},
+    Addr,
+};
+use cw2::{get_contract_version, set_contract_version, ContractVersion};
+use skip::{error::SkipError, migrate::MigrateMsg};
+use skip_api_swap_adapter_osmosis_poolmanager::{
+    error::{ContractError, ContractResult},
+    state::ENTRY_POINT_CONTRACT_ADDRESS,
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - Migrate From Older Version
+    - Migrate From Same Version
+    - Migrate Untracked Contract (Contracts deployed before cw2 version tracking)
+
+Expect Error
+    - Untracked Contract Without Entry Point Contract Address Stored
+    - Different Contract Stored
+    - Downgrade To Older Version
+
+ */
+
+const CONTRACT_NAME: &str = "skip-api-swap-adapter-osmosis-poolmanager";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// Define test parameters
+struct Params {
+    stored_contract_version: Option<(&'static str, &'static str)>,
+    store_entry_point_contract_address: bool,
+    expected_from_version: &'static str,
+    expected_error: Option<ContractError>,
+}
+
+// Test migrate
+#[test_case(
+    Params {
+        stored_contract_version: Some((CONTRACT_NAME, "0.1.0")),
+        store_entry_point_contract_address: true,
+        expected_from_version: "0.1.0",
+        expected_error: None,
+    };
+    "Migrate From Older Version")]
+#[test_case(
+    Params {
+        stored_contract_version: Some((CONTRACT_NAME, CONTRACT_VERSION)),
+        store_entry_point_contract_address: true,
+        expected_from_version: CONTRACT_VERSION,
+        expected_error: None,
+    };
+    "Migrate From Same Version")]
+#[test_case(
+    Params {
+        stored_contract_version: None,
+        store_entry_point_contract_address: true,
+        expected_from_version: "0.2.1",
+        expected_error: None,
+    };
+    "Migrate Untracked Contract")]
+#[test_case(
+    Params {
+        stored_contract_version: None,
+        store_entry_point_contract_address: false,
+        expected_from_version: "",
+        expected_error: Some(ContractError::Skip(SkipError::MigrationContractMismatch {
+            stored: "unknown".to_string(),
+            new: CONTRACT_NAME.to_string(),
+        })),
+    };
+    "Untracked Contract Without Entry Point Contract Address Stored - Expect Error")]
+#[test_case(
+    Params {
+        stored_contract_version: Some(("skip-api-entry-point", "0.1.0")),
+        store_entry_point_contract_address: true,
+        expected_from_version: "",
+        expected_error: Some(ContractError::Skip(SkipError::MigrationContractMismatch {
+            stored: "skip-api-entry-point".to_string(),
+            new: CONTRACT_NAME.to_string(),
+        })),
+    };
+    "Different Contract Stored - Expect Error")]
+#[test_case(
+    Params {
+        stored_contract_version: Some((CONTRACT_NAME, "99.0.0")),
+        store_entry_point_contract_address: true,
+        expected_from_version: "",
+        expected_error: Some(ContractError::Skip(SkipError::MigrationDowngrade {
+            stored: "99.0.0".to_string(),
+            new: CONTRACT_VERSION.to_string(),
+        })),
+    };
+    "Downgrade To Older Version - Expect Error")]
+fn test_migrate(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env
+    let env = mock_env();
+
+    // Store the contract version if provided
+    if let Some((contract, version)) = params.stored_contract_version {
+        set_contract_version(deps.as_mut().storage, contract, version)?;
+    }
+
+    // Store the entry point contract address if required
+    if params.store_entry_point_contract_address {
+        ENTRY_POINT_CONTRACT_ADDRESS
+            .save(deps.as_mut().storage, &Addr::unchecked("entry_point"))?;
+    }
+
+    // Call migrate with the given test parameters
+    let res = skip_api_swap_adapter_osmosis_poolmanager::contract::migrate(
+        deps.as_mut(),
+        env,
+        MigrateMsg {},
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the from version attribute is correct
+            assert!(res.attributes.iter().any(
+                |attr| attr.key == "from_version" && attr.value == params.expected_from_version
+            ));
+
+            // Assert the stored contract version is updated
+            assert_eq!(
+                get_contract_version(deps.as_ref().storage)?,
+                ContractVersion {
+                    contract: CONTRACT_NAME.to_string(),
+                    version: CONTRACT_VERSION.to_string(),
+                }
+            );
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,697 @@
+use crate::{
+    error::{ContractError, ContractResult},
+    state::{
+        BLOCKED_CONTRACT_ADDRESSES, DEFAULT_IBC_TRANSFER_ADAPTER, DELEGATE_ENABLED, GUARDIANS,
+        IBC_TRANSFER_ADAPTER_MAP, MANUALLY_BLOCKED_CONTRACT_ADDRESSES, MAX_TOTAL_AFFILIATE_FEE,
+        OWNER, PAUSED, PAUSED_ACTIONS, PAUSED_SWAP_VENUES, PENDING_OWNER, PROTOCOL_FEE,
+        REGISTERED_AFFILIATES, SWAP_VENUE_MAP,
+    },
+};
+use cosmwasm_std::{Addr, Deps, DepsMut, MessageInfo, Response, StdResult, Storage, Uint128};
+use skip::{
+    entry_point::{
+        ActionType, PauseTarget, ProtocolFee, RegisteredAffiliate, MAX_PROTOCOL_FEE_BASIS_POINTS,
+        MAX_TOTAL_AFFILIATE_BASIS_POINTS_FEE,
+    },
+    ibc::{bech32_prefix, IbcTransferAdapter},
+    swap::SwapVenue,
+};
+
+/////////////////////////
+/// ADMIN ENTRYPOINTS ///
+/////////////////////////
+
+// Starts a two step ownership transfer by storing the new owner as the pending owner
+// Can only be called by the current owner
+pub fn execute_transfer_ownership(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_owner: String,
+) -> ContractResult<Response> {
+    // Enforce the caller is the owner
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    // Validate the new owner address
+    let checked_new_owner = deps.api.addr_validate(&new_owner)?;
+
+    // Store the new owner as the pending owner, overwriting any previous pending owner
+    PENDING_OWNER.save(deps.storage, &checked_new_owner)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "transfer_ownership")
+        .add_attribute("pending_owner", checked_new_owner))
+}
+
+// Completes the two step ownership transfer
+// Can only be called by the pending owner
+pub fn execute_accept_ownership(deps: DepsMut, info: MessageInfo) -> ContractResult<Response> {
+    // Get the pending owner, error if there is no ownership transfer in progress
+    let pending_owner = PENDING_OWNER
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingOwner)?;
+
+    // Enforce the caller is the pending owner
+    if info.sender != pending_owner {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Set the pending owner as the owner and remove the pending owner
+    OWNER.save(deps.storage, &pending_owner)?;
+    PENDING_OWNER.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_ownership")
+        .add_attribute("owner", pending_owner))
+}
+
+// Adds a new swap venue, erroring if the swap venue name is already stored
+pub fn execute_add_swap_venue(
+    deps: DepsMut,
+    info: MessageInfo,
+    swap_venue: SwapVenue,
+) -> ContractResult<Response> {
+    // Enforce the caller is the owner
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    // Store the swap venue and block its adapter contract address
+    let checked_swap_contract_address = save_new_swap_venue(deps, &swap_venue)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_swap_venue")
+        .add_attribute("name", swap_venue.name)
+        .add_attribute("contract_address", checked_swap_contract_address))
+}
+
+// Updates the adapter contract address of an existing swap venue. The previous adapter
+// contract address stays blocked, as the retired adapter still trusts the entry point.
+pub fn execute_update_swap_venue(
+    deps: DepsMut,
+    info: MessageInfo,
+    swap_venue: SwapVenue,
+) -> ContractResult<Response> {
+    // Enforce the caller is the owner
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    // Get the current swap adapter contract address, error if the swap venue does not exist
+    let previous_swap_contract_address =
+        SWAP_VENUE_MAP
+            .may_load(deps.storage, &swap_venue.name)?
+            .ok_or_else(|| ContractError::SwapVenueNotFound(swap_venue.name.clone()))?;
+
+    // Validate the new swap contract address
+    let checked_swap_contract_address = deps
+        .api
+        .addr_validate(&swap_venue.adapter_contract_address)?;
+
+    // Store the new swap contract address and block it
+    SWAP_VENUE_MAP.save(
+        deps.storage,
+        &swap_venue.name,
+        &checked_swap_contract_address,
+    )?;
+    block_adapter_contract_address(deps.storage, &checked_swap_contract_address)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_swap_venue")
+        .add_attribute("name", swap_venue.name)
+        .add_attribute("previous_contract_address", previous_swap_contract_address)
+        .add_attribute("contract_address", checked_swap_contract_address))
+}
+
+// Removes a swap venue. Its adapter contract address stays blocked,
+// as the retired adapter still trusts the entry point.
+pub fn execute_remove_swap_venue(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+) -> ContractResult<Response> {
+    // Enforce the caller is the owner
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    // Get the swap adapter contract address, error if the swap venue does not exist
+    let swap_contract_address = SWAP_VENUE_MAP
+        .may_load(deps.storage, &name)?
+        .ok_or_else(|| ContractError::SwapVenueNotFound(name.clone()))?;
+
+    // Remove the swap venue and its pause flag
+    SWAP_VENUE_MAP.remove(deps.storage, &name);
+    PAUSED_SWAP_VENUES.remove(deps.storage, &name);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_swap_venue")
+        .add_attribute("name", name)
+        .add_attribute("contract_address", swap_contract_address))
+}
+
+// Adds a new ibc transfer adapter, erroring if the ibc transfer adapter name is already stored
+pub fn execute_add_ibc_transfer_adapter(
+    deps: DepsMut,
+    info: MessageInfo,
+    ibc_transfer_adapter: IbcTransferAdapter,
+) -> ContractResult<Response> {
+    // Enforce the caller is the owner
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    // Store the ibc transfer adapter and block its contract address
+    let checked_ibc_transfer_contract_address =
+        save_new_ibc_transfer_adapter(deps, &ibc_transfer_adapter)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_ibc_transfer_adapter")
+        .add_attribute("name", ibc_transfer_adapter.name)
+        .add_attribute("contract_address", checked_ibc_transfer_contract_address))
+}
+
+// Updates the contract address of an existing ibc transfer adapter. The previous adapter
+// contract address stays blocked, as the retired adapter still trusts the entry point.
+pub fn execute_update_ibc_transfer_adapter(
+    deps: DepsMut,
+    info: MessageInfo,
+    ibc_transfer_adapter: IbcTransferAdapter,
+) -> ContractResult<Response> {
+    // Enforce the caller is the owner
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    // Get the current ibc transfer adapter contract address, error if the adapter does not exist
+    let previous_ibc_transfer_contract_address = IBC_TRANSFER_ADAPTER_MAP
+        .may_load(deps.storage, &ibc_transfer_adapter.name)?
+        .ok_or_else(|| {
+            ContractError::IbcTransferAdapterNotFound(ibc_transfer_adapter.name.clone())
+        })?;
+
+    // Validate the new ibc transfer adapter contract address
+    let checked_ibc_transfer_contract_address = deps
+        .api
+        .addr_validate(&ibc_transfer_adapter.adapter_contract_address)?;
+
+    // Store the new ibc transfer adapter contract address and block it
+    IBC_TRANSFER_ADAPTER_MAP.save(
+        deps.storage,
+        &ibc_transfer_adapter.name,
+        &checked_ibc_transfer_contract_address,
+    )?;
+    block_adapter_contract_address(deps.storage, &checked_ibc_transfer_contract_address)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_ibc_transfer_adapter")
+        .add_attribute("name", ibc_transfer_adapter.name)
+        .add_attribute(
+            "previous_contract_address",
+            previous_ibc_transfer_contract_address,
+        )
+        .add_attribute("contract_address", checked_ibc_transfer_contract_address))
+}
+
+// Removes an ibc transfer adapter, keeping its contract address blocked as the retired
+// adapter still trusts the entry point. The default ibc transfer adapter cannot be removed.
+pub fn execute_remove_ibc_transfer_adapter(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+) -> ContractResult<Response> {
+    // Enforce the caller is the owner
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    // Get the ibc transfer adapter contract address, error if the adapter does not exist
+    let ibc_transfer_contract_address = IBC_TRANSFER_ADAPTER_MAP
+        .may_load(deps.storage, &name)?
+        .ok_or_else(|| ContractError::IbcTransferAdapterNotFound(name.clone()))?;
+
+    // Error if the ibc transfer adapter is the default ibc transfer adapter
+    if DEFAULT_IBC_TRANSFER_ADAPTER.load(deps.storage)? == name {
+        return Err(ContractError::CannotRemoveDefaultIbcTransferAdapter);
+    }
+
+    // Remove the ibc transfer adapter
+    IBC_TRANSFER_ADAPTER_MAP.remove(deps.storage, &name);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_ibc_transfer_adapter")
+        .add_attribute("name", name)
+        .add_attribute("contract_address", ibc_transfer_contract_address))
+}
+
+// Sets the ibc transfer adapter used by ibc transfer actions that do not name one
+pub fn execute_set_default_ibc_transfer_adapter(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+) -> ContractResult<Response> {
+    // Enforce the caller is the owner
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    // Store the default ibc transfer adapter name, error if the adapter does not exist
+    save_default_ibc_transfer_adapter(deps.storage, &name)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_default_ibc_transfer_adapter")
+        .add_attribute("name", name))
+}
+
+// Adds an address to the blocked contract addresses that cannot be called by a contract call action
+pub fn execute_add_blocked_contract_address(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> ContractResult<Response> {
+    // Enforce the caller is the owner
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    // Validate the address
+    let checked_address = deps.api.addr_validate(&address)?;
+
+    // Block the address, tracking it as manually blocked unless it is
+    // already blocked as the entry point or an adapter contract
+    if !BLOCKED_CONTRACT_ADDRESSES.has(deps.storage, &checked_address) {
+        BLOCKED_CONTRACT_ADDRESSES.save(deps.storage, &checked_address, &())?;
+        MANUALLY_BLOCKED_CONTRACT_ADDRESSES.save(deps.storage, &checked_address, &())?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "add_blocked_contract_address")
+        .add_attribute("contract_address", checked_address))
+}
+
+// Removes a manually blocked address from the blocked contract addresses
+// The entry point and current or retired adapter contracts can never be unblocked
+pub fn execute_remove_blocked_contract_address(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> ContractResult<Response> {
+    // Enforce the caller is the owner
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    // Validate the address and error if it is not manually blocked
+    let checked_address = deps.api.addr_validate(&address)?;
+    if !MANUALLY_BLOCKED_CONTRACT_ADDRESSES.has(deps.storage, &checked_address) {
+        // Error if the address is the entry point or an adapter contract
+        if BLOCKED_CONTRACT_ADDRESSES.has(deps.storage, &checked_address) {
+            return Err(ContractError::CannotUnblockRegisteredContract);
+        }
+
+        return Err(ContractError::ContractAddressNotBlocked);
+    }
+
+    MANUALLY_BLOCKED_CONTRACT_ADDRESSES.remove(deps.storage, &checked_address);
+    BLOCKED_CONTRACT_ADDRESSES.remove(deps.storage, &checked_address);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_blocked_contract_address")
+        .add_attribute("contract_address", checked_address))
+}
+
+// Adds an address to the guardians that are allowed to pause the contract
+pub fn execute_add_guardian(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> ContractResult<Response> {
+    // Enforce the caller is the owner
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    // Validate and store the guardian address
+    let checked_address = deps.api.addr_validate(&address)?;
+    GUARDIANS.save(deps.storage, &checked_address, &())?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_guardian")
+        .add_attribute("address", checked_address))
+}
+
+// Removes an address from the guardians
+pub fn execute_remove_guardian(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> ContractResult<Response> {
+    // Enforce the caller is the owner
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    // Validate the address and error if it is not a guardian
+    let checked_address = deps.api.addr_validate(&address)?;
+    if !GUARDIANS.has(deps.storage, &checked_address) {
+        return Err(ContractError::GuardianNotFound);
+    }
+
+    GUARDIANS.remove(deps.storage, &checked_address);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_guardian")
+        .add_attribute("address", checked_address))
+}
+
+// Sets the protocol fee taken from every SwapAndAction call, or removes it if none is given
+pub fn execute_set_protocol_fee(
+    deps: DepsMut,
+    info: MessageInfo,
+    protocol_fee: Option<ProtocolFee>,
+) -> ContractResult<Response> {
+    // Enforce the caller is the owner
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    // Remove the protocol fee if none is given
+    let Some(protocol_fee) = protocol_fee else {
+        PROTOCOL_FEE.remove(deps.storage);
+
+        return Ok(Response::new().add_attribute("action", "remove_protocol_fee"));
+    };
+
+    // Error if the protocol fee is more than the maximum protocol fee
+    if protocol_fee.basis_points_fee > MAX_PROTOCOL_FEE_BASIS_POINTS {
+        return Err(ContractError::ProtocolFeeTooHigh(
+            MAX_PROTOCOL_FEE_BASIS_POINTS,
+        ));
+    }
+
+    // Validate the collector address and store the protocol fee
+    let checked_collector_address = deps.api.addr_validate(&protocol_fee.collector_address)?;
+    PROTOCOL_FEE.save(
+        deps.storage,
+        &ProtocolFee {
+            basis_points_fee: protocol_fee.basis_points_fee,
+            collector_address: checked_collector_address.to_string(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_protocol_fee")
+        .add_attribute("basis_points_fee", protocol_fee.basis_points_fee)
+        .add_attribute("collector_address", checked_collector_address))
+}
+
+// Registers an affiliate partner that callers can refer to by id, or updates it if the id is
+// already registered
+pub fn execute_register_affiliate(
+    deps: DepsMut,
+    info: MessageInfo,
+    affiliate: RegisteredAffiliate,
+) -> ContractResult<Response> {
+    // Enforce the caller is the owner
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    // Error if the id is empty or a bech32 address, so an id is never mistaken for an address
+    if affiliate.id.is_empty() || bech32_prefix(&affiliate.id).is_ok() {
+        return Err(ContractError::InvalidRegisteredAffiliateId);
+    }
+
+    // Error if the default fee is more than the max fee
+    if affiliate.default_basis_points_fee > affiliate.max_basis_points_fee {
+        return Err(ContractError::RegisteredAffiliateDefaultFeeAboveMax);
+    }
+
+    // Error if the max fee is more than the maximum total affiliate fee
+    if affiliate.max_basis_points_fee > MAX_TOTAL_AFFILIATE_BASIS_POINTS_FEE {
+        return Err(ContractError::AffiliateFeeTooHigh(
+            MAX_TOTAL_AFFILIATE_BASIS_POINTS_FEE,
+        ));
+    }
+
+    // Validate the affiliate address and store the affiliate
+    let checked_address = deps.api.addr_validate(&affiliate.address)?;
+    REGISTERED_AFFILIATES.save(
+        deps.storage,
+        &affiliate.id,
+        &RegisteredAffiliate {
+            address: checked_address.to_string(),
+            ..affiliate.clone()
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register_affiliate")
+        .add_attribute("id", affiliate.id)
+        .add_attribute("address", checked_address)
+        .add_attribute(
+            "default_basis_points_fee",
+            affiliate.default_basis_points_fee,
+        )
+        .add_attribute("max_basis_points_fee", affiliate.max_basis_points_fee))
+}
+
+// Removes a registered affiliate partner
+pub fn execute_deregister_affiliate(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: String,
+) -> ContractResult<Response> {
+    // Enforce the caller is the owner
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    // Error if the affiliate is not registered
+    if !REGISTERED_AFFILIATES.has(deps.storage, &id) {
+        return Err(ContractError::RegisteredAffiliateNotFound(id));
+    }
+
+    REGISTERED_AFFILIATES.remove(deps.storage, &id);
+
+    Ok(Response::new()
+        .add_attribute("action", "deregister_affiliate")
+        .add_attribute("id", id))
+}
+
+// Sets the maximum total affiliate fee of a single SwapAndAction call
+pub fn execute_set_max_total_affiliate_basis_points_fee(
+    deps: DepsMut,
+    info: MessageInfo,
+    max_total_affiliate_basis_points_fee: Uint128,
+) -> ContractResult<Response> {
+    // Enforce the caller is the owner
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    // Error if the maximum is more than the maximum total affiliate fee the owner can set
+    if max_total_affiliate_basis_points_fee > MAX_TOTAL_AFFILIATE_BASIS_POINTS_FEE {
+        return Err(ContractError::AffiliateFeeTooHigh(
+            MAX_TOTAL_AFFILIATE_BASIS_POINTS_FEE,
+        ));
+    }
+
+    MAX_TOTAL_AFFILIATE_FEE.save(deps.storage, &max_total_affiliate_basis_points_fee)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_max_total_affiliate_basis_points_fee")
+        .add_attribute(
+            "max_total_affiliate_basis_points_fee",
+            max_total_affiliate_basis_points_fee,
+        ))
+}
+
+// Enables or disables Delegate post swap actions, which must only be enabled on chains with
+// the liquid staking module. Can only be called by the owner
+pub fn execute_set_delegate_enabled(
+    deps: DepsMut,
+    info: MessageInfo,
+    enabled: bool,
+) -> ContractResult<Response> {
+    // Enforce the caller is the owner
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    DELEGATE_ENABLED.save(deps.storage, &enabled)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_delegate_enabled")
+        .add_attribute("enabled", enabled.to_string()))
+}
+
+// Pauses all SwapAndAction calls, a single swap venue, or a single post swap action type
+// Can be called by the owner or a guardian
+pub fn execute_pause(
+    deps: DepsMut,
+    info: MessageInfo,
+    target: PauseTarget,
+) -> ContractResult<Response> {
+    // Enforce the caller is the owner or a guardian
+    if !GUARDIANS.has(deps.storage, &info.sender) {
+        assert_owner(deps.as_ref(), &info.sender)?;
+    }
+
+    match &target {
+        PauseTarget::All {} => PAUSED.save(deps.storage, &true)?,
+        PauseTarget::SwapVenue { name } => {
+            // Error if the swap venue does not exist
+            if !SWAP_VENUE_MAP.has(deps.storage, name) {
+                return Err(ContractError::SwapVenueNotFound(name.clone()));
+            }
+
+            PAUSED_SWAP_VENUES.save(deps.storage, name, &())?;
+        }
+        PauseTarget::Action { action_type } => {
+            let mut paused_actions = PAUSED_ACTIONS.may_load(deps.storage)?.unwrap_or_default();
+
+            if !paused_actions.contains(action_type) {
+                paused_actions.push(action_type.clone());
+                PAUSED_ACTIONS.save(deps.storage, &paused_actions)?;
+            }
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "pause")
+        .add_attribute("target", target.to_string())
+        .add_attribute("sender", info.sender))
+}
+
+// Unpauses all SwapAndAction calls, a single swap venue, or a single post swap action type
+// Can only be called by the owner
+pub fn execute_unpause(
+    deps: DepsMut,
+    info: MessageInfo,
+    target: PauseTarget,
+) -> ContractResult<Response> {
+    // Enforce the caller is the owner
+    assert_owner(deps.as_ref(), &info.sender)?;
+
+    match &target {
+        PauseTarget::All {} => PAUSED.save(deps.storage, &false)?,
+        PauseTarget::SwapVenue { name } => PAUSED_SWAP_VENUES.remove(deps.storage, name),
+        PauseTarget::Action { action_type } => {
+            let mut paused_actions = PAUSED_ACTIONS.may_load(deps.storage)?.unwrap_or_default();
+
+            paused_actions.retain(|paused_action| paused_action != action_type);
+            PAUSED_ACTIONS.save(deps.storage, &paused_actions)?;
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "unpause")
+        .add_attribute("target", target.to_string()))
+}
+
+////////////////////////
+/// HELPER FUNCTIONS ///
+////////////////////////
+
+// Errors if the given address is not the owner of the contract
+pub fn assert_owner(deps: Deps, sender: &Addr) -> ContractResult<()> {
+    if OWNER.load(deps.storage)? != *sender {
+        return Err(ContractError::Unauthorized);
+    }
+
+    Ok(())
+}
+
+// Errors if all SwapAndAction calls are paused
+pub fn assert_not_paused(storage: &dyn Storage) -> ContractResult<()> {
+    if PAUSED.may_load(storage)?.unwrap_or_default() {
+        return Err(ContractError::RoutePaused(PauseTarget::All {}));
+    }
+
+    Ok(())
+}
+
+// Errors if the given swap venue is paused
+pub fn assert_swap_venue_not_paused(storage: &dyn Storage, name: &str) -> ContractResult<()> {
+    if PAUSED_SWAP_VENUES.has(storage, name) {
+        return Err(ContractError::RoutePaused(PauseTarget::SwapVenue {
+            name: name.to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
+// Errors if the given post swap action type is paused
+pub fn assert_action_not_paused(
+    storage: &dyn Storage,
+    action_type: ActionType,
+) -> ContractResult<()> {
+    if PAUSED_ACTIONS
+        .may_load(storage)?
+        .unwrap_or_default()
+        .contains(&action_type)
+    {
+        return Err(ContractError::RoutePaused(PauseTarget::Action {
+            action_type,
+        }));
+    }
+
+    Ok(())
+}
+
+// Errors if Delegate post swap actions are not enabled
+pub fn assert_delegate_enabled(storage: &dyn Storage) -> ContractResult<()> {
+    if !DELEGATE_ENABLED.may_load(storage)?.unwrap_or_default() {
+        return Err(ContractError::DelegateNotEnabled);
+    }
+
+    Ok(())
+}
+
+// Validates and stores a new swap venue, adding its adapter contract address
+// to the blocked contract addresses. Errors if the venue name is already stored.
+pub fn save_new_swap_venue(deps: DepsMut, swap_venue: &SwapVenue) -> ContractResult<Addr> {
+    // Validate the swap contract address
+    let checked_swap_contract_address = deps
+        .api
+        .addr_validate(&swap_venue.adapter_contract_address)?;
+
+    // Prevent duplicate swap venues by erroring if the venue name is already stored
+    if SWAP_VENUE_MAP.has(deps.storage, &swap_venue.name) {
+        return Err(ContractError::DuplicateSwapVenueName);
+    }
+
+    // Store the swap venue name and contract address inside the swap venue map
+    SWAP_VENUE_MAP.save(
+        deps.storage,
+        &swap_venue.name,
+        &checked_swap_contract_address,
+    )?;
+
+    // Insert the swap contract address into the blocked contract addresses map
+    block_adapter_contract_address(deps.storage, &checked_swap_contract_address)?;
+
+    Ok(checked_swap_contract_address)
+}
+
+// Validates and stores a new ibc transfer adapter, adding its contract address to
+// the blocked contract addresses. Errors if the adapter name is already stored.
+pub fn save_new_ibc_transfer_adapter(
+    deps: DepsMut,
+    ibc_transfer_adapter: &IbcTransferAdapter,
+) -> ContractResult<Addr> {
+    // Validate the ibc transfer adapter contract address
+    let checked_ibc_transfer_contract_address = deps
+        .api
+        .addr_validate(&ibc_transfer_adapter.adapter_contract_address)?;
+
+    // Prevent duplicate ibc transfer adapters by erroring if the adapter name is already stored
+    if IBC_TRANSFER_ADAPTER_MAP.has(deps.storage, &ibc_transfer_adapter.name) {
+        return Err(ContractError::DuplicateIbcTransferAdapterName);
+    }
+
+    // Store the ibc transfer adapter name and contract address inside the ibc transfer adapter map
+    IBC_TRANSFER_ADAPTER_MAP.save(
+        deps.storage,
+        &ibc_transfer_adapter.name,
+        &checked_ibc_transfer_contract_address,
+    )?;
+
+    // Insert the ibc transfer adapter contract address into the blocked contract addresses map
+    block_adapter_contract_address(deps.storage, &checked_ibc_transfer_contract_address)?;
+
+    Ok(checked_ibc_transfer_contract_address)
+}
+
+// Stores the default ibc transfer adapter name, erroring if no adapter is stored with that name
+pub fn save_default_ibc_transfer_adapter(
+    storage: &mut dyn Storage,
+    name: &str,
+) -> ContractResult<()> {
+    if !IBC_TRANSFER_ADAPTER_MAP.has(storage, name) {
+        return Err(ContractError::IbcTransferAdapterNotFound(name.to_string()));
+    }
+
+    DEFAULT_IBC_TRANSFER_ADAPTER.save(storage, &name.to_string())?;
+
+    Ok(())
+}
+
+// Blocks an adapter contract address for good, so it stays blocked after the adapter is
+// updated or removed. The address is no longer tracked as manually blocked, if it was,
+// so the owner cannot unblock it.
+fn block_adapter_contract_address(storage: &mut dyn Storage, address: &Addr) -> StdResult<()> {
+    BLOCKED_CONTRACT_ADDRESSES.save(storage, address, &())?;
+    MANUALLY_BLOCKED_CONTRACT_ADDRESSES.remove(storage, address);
+
+    Ok(())
+}
@@ -1,10 +1,14 @@
 use cosmwasm_schema::write_api;
-use skip::entry_point::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use skip::{
+    entry_point::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    migrate::MigrateMsg,
+};
 
 fn main() {
     write_api! {
         instantiate: InstantiateMsg,
         execute: ExecuteMsg,
-        query: QueryMsg
+        query: QueryMsg,
+        migrate: MigrateMsg
     }
 }
@@ -1,17 +1,51 @@
 use crate::{
+    admin::{
+        execute_accept_ownership, execute_add_blocked_contract_address, execute_add_guardian,
+        execute_add_ibc_transfer_adapter, execute_add_swap_venue, execute_deregister_affiliate,
+        execute_pause, execute_register_affiliate, execute_remove_blocked_contract_address,
+        execute_remove_guardian, execute_remove_ibc_transfer_adapter, execute_remove_swap_venue,
+        execute_set_default_ibc_transfer_adapter, execute_set_delegate_enabled,
+        execute_set_max_total_affiliate_basis_points_fee, execute_set_protocol_fee,
+        execute_transfer_ownership, execute_unpause, execute_update_ibc_transfer_adapter,
+        execute_update_swap_venue, save_default_ibc_transfer_adapter,
+        save_new_ibc_transfer_adapter, save_new_swap_venue,
+    },
     error::{ContractError, ContractResult},
     execute::{
-        execute_post_swap_action, execute_swap_and_action, execute_swap_and_action_with_recover,
-        execute_user_swap,
+        execute_claim_affiliate_fees, execute_post_swap_action, execute_post_swap_action_step,
+        execute_receive, execute_set_affiliate_fee_accrual, execute_swap_and_action,
+        execute_swap_and_action_with_recover, execute_transfer_delegation_shares,
+        execute_user_swap, execute_user_swap_refund, execute_user_swap_withdrawn_assets,
+    },
+    migrate::migrate_state,
+    query::{
+        query_accrued_affiliate_fees, query_affiliate_fee_accrual,
+        query_blocked_contract_addresses, query_config, query_default_ibc_transfer_adapter,
+        query_delegate_enabled, query_guardians, query_ibc_transfer_adapter_contract,
+        query_max_total_affiliate_basis_points_fee, query_pause_state, query_protocol_fee,
+        query_registered_affiliate, query_registered_affiliates, query_simulate_swap_and_action,
+        query_simulate_swap_exact_coin_in_with_metadata,
+        query_simulate_swap_exact_coin_out_with_metadata, query_swap_venue_adapter_contract,
+        query_swap_venues,
+    },
+    reply::{
+        reply_skip_callback, reply_swap_and_action_with_recover, CALLBACK_REPLY_ID,
+        RECOVER_REPLY_ID,
     },
-    query::{query_ibc_transfer_adapter_contract, query_swap_venue_adapter_contract},
-    reply::{reply_swap_and_action_with_recover, RECOVER_REPLY_ID},
-    state::{BLOCKED_CONTRACT_ADDRESSES, IBC_TRANSFER_CONTRACT_ADDRESS, SWAP_VENUE_MAP},
+    state::{BLOCKED_CONTRACT_ADDRESSES, IBC_TRANSFER_CONTRACT_ADDRESS, OWNER},
 };
 use cosmwasm_std::{
-    entry_point, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult,
+    entry_point, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError,
+    StdResult,
 };
-use skip::entry_point::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use cw2::set_contract_version;
+use skip::{
+    entry_point::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    migrate::{validate_migration, MigrateMsg},
+};
+
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 ///////////////////
 /// INSTANTIATE ///
@@ -19,38 +53,35 @@ use skip::entry_point::{ExecuteMsg, InstantiateMsg, QueryMsg};
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
-    _info: MessageInfo,
+    info: MessageInfo,
     msg: InstantiateMsg,
 ) -> ContractResult<Response> {
     // Create response object to return
     let mut response: Response = Response::new().add_attribute("action", "instantiate");
 
+    // Store the contract name and version for migrations
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    // Validate and store the owner, defaulting to the instantiator if no owner is provided
+    let checked_owner = match msg.owner {
+        Some(owner) => deps.api.addr_validate(&owner)?,
+        None => info.sender,
+    };
+    OWNER.save(deps.storage, &checked_owner)?;
+
+    // Add the owner to the response
+    response = response.add_attribute("owner", &checked_owner);
+
     // Insert the entry point contract address into the blocked contract addresses map
     BLOCKED_CONTRACT_ADDRESSES.save(deps.storage, &env.contract.address, &())?;
 
     // Iterate through the swap venues provided and create a map of venue names to swap adapter contract addresses
     for swap_venue in msg.swap_venues.iter() {
-        // Validate the swap contract address
-        let checked_swap_contract_address = deps
-            .api
-            .addr_validate(&swap_venue.adapter_contract_address)?;
-
-        // Prevent duplicate swap venues by erroring if the venue name is already stored
-        if SWAP_VENUE_MAP.has(deps.storage, &swap_venue.name) {
-            return Err(ContractError::DuplicateSwapVenueName);
-        }
-
-        // Store the swap venue name and contract address inside the swap venue map
-        SWAP_VENUE_MAP.save(
-            deps.storage,
-            &swap_venue.name,
-            &checked_swap_contract_address,
-        )?;
-
-        // Insert the swap contract address into the blocked contract addresses map
-        BLOCKED_CONTRACT_ADDRESSES.save(deps.storage, &checked_swap_contract_address, &())?;
+        // Validate and store the swap venue, erroring if the venue name is duplicated,
+        // and insert the swap contract address into the blocked contract addresses map
+        let checked_swap_contract_address = save_new_swap_venue(deps.branch(), swap_venue)?;
 
         // Add the swap venue and contract address to the response
         response = response
@@ -59,24 +90,59 @@ pub fn instantiate(
             .add_attribute("contract_address", &checked_swap_contract_address);
     }
 
-    // Validate ibc transfer adapter contract addresses
-    let checked_ibc_transfer_contract_address =
-        deps.api.addr_validate(&msg.ibc_transfer_contract_address)?;
+    // Iterate through the ibc transfer adapters provided and create a map of adapter names to contract addresses
+    for ibc_transfer_adapter in msg.ibc_transfer_adapters.iter() {
+        // Validate and store the ibc transfer adapter, erroring if the adapter name is duplicated,
+        // and insert the ibc transfer adapter contract address into the blocked contract addresses map
+        let checked_ibc_transfer_contract_address =
+            save_new_ibc_transfer_adapter(deps.branch(), ibc_transfer_adapter)?;
 
-    // Store the ibc transfer adapter contract address
-    IBC_TRANSFER_CONTRACT_ADDRESS.save(deps.storage, &checked_ibc_transfer_contract_address)?;
+        // Add the ibc transfer adapter and contract address to the response
+        response = response
+            .add_attribute("action", "add_ibc_transfer_adapter")
+            .add_attribute("name", &ibc_transfer_adapter.name)
+            .add_attribute("contract_address", &checked_ibc_transfer_contract_address);
+    }
 
-    // Insert the ibc transfer adapter contract address into the blocked contract addresses map
-    BLOCKED_CONTRACT_ADDRESSES.save(deps.storage, &checked_ibc_transfer_contract_address, &())?;
+    // Store the default ibc transfer adapter, erroring if it was not provided above
+    save_default_ibc_transfer_adapter(deps.storage, &msg.default_ibc_transfer_adapter)?;
 
-    // Add the ibc transfer adapter contract address to the response
-    response = response
-        .add_attribute("action", "add_ibc_transfer_adapter")
-        .add_attribute("contract_address", &checked_ibc_transfer_contract_address);
+    // Add the default ibc transfer adapter to the response
+    response = response.add_attribute(
+        "default_ibc_transfer_adapter",
+        &msg.default_ibc_transfer_adapter,
+    );
 
     Ok(response)
 }
 
+///////////////
+/// MIGRATE ///
+///////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(mut deps: DepsMut, env: Env, _msg: MigrateMsg) -> ContractResult<Response> {
+    // Validate the migration and get the version being migrated from. Contracts deployed
+    // before cw2 version tracking are recognized by their stored ibc transfer adapter.
+    let from_version = validate_migration(
+        deps.storage,
+        CONTRACT_NAME,
+        CONTRACT_VERSION,
+        IBC_TRANSFER_CONTRACT_ADDRESS.exists(deps.storage),
+    )?;
+
+    // Run the state migrations from the version being migrated from
+    let response = migrate_state(deps.branch(), &env, &from_version)?;
+
+    // Store the new contract version
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(response
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", from_version.to_string())
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
 ///////////////
 /// EXECUTE ///
 ///////////////
@@ -90,50 +156,85 @@ pub fn execute(
 ) -> ContractResult<Response> {
     match msg {
         ExecuteMsg::SwapAndActionWithRecover {
+            pre_swap_action,
             user_swap,
             min_coin,
             timeout_timestamp,
             post_swap_action,
             affiliates,
+            affiliate_fee_basis,
+            callback_address,
+            ibc_recovery,
             recovery_addr,
         } => execute_swap_and_action_with_recover(
             deps,
             env,
             info,
+            pre_swap_action,
             user_swap,
             min_coin,
             timeout_timestamp,
             post_swap_action,
             affiliates,
+            affiliate_fee_basis,
+            callback_address,
+            ibc_recovery,
             recovery_addr,
         ),
         ExecuteMsg::SwapAndAction {
+            pre_swap_action,
             user_swap,
             min_coin,
             timeout_timestamp,
             post_swap_action,
             affiliates,
+            affiliate_fee_basis,
+            callback_address,
         } => execute_swap_and_action(
             deps,
             env,
             info,
+            pre_swap_action,
             user_swap,
             min_coin,
             timeout_timestamp,
             post_swap_action,
             affiliates,
+            affiliate_fee_basis,
+            callback_address,
         ),
+        ExecuteMsg::Receive(cw20_msg) => execute_receive(deps, env, info, cw20_msg),
         ExecuteMsg::UserSwap {
             swap,
             min_coin,
             remaining_coin,
             affiliates,
         } => execute_user_swap(deps, env, info, swap, min_coin, remaining_coin, affiliates),
+        ExecuteMsg::UserSwapWithdrawnAssets {
+            swaps,
+            pre_balances,
+            min_coin,
+            affiliates,
+        } => execute_user_swap_withdrawn_assets(
+            deps,
+            env,
+            info,
+            swaps,
+            pre_balances,
+            min_coin,
+            affiliates,
+        ),
+        ExecuteMsg::UserSwapRefund {
+            pre_balance,
+            refund_address,
+        } => execute_user_swap_refund(deps, env, info, pre_balance, refund_address),
         ExecuteMsg::PostSwapAction {
             min_coin,
             timeout_timestamp,
             post_swap_action,
             exact_out,
+            affiliates,
+            callback_address,
         } => execute_post_swap_action(
             deps,
             env,
@@ -142,14 +243,101 @@ pub fn execute(
             timeout_timestamp,
             post_swap_action,
             exact_out,
+            affiliates,
+            callback_address,
+        ),
+        ExecuteMsg::PostSwapActionStep {
+            min_coin,
+            pre_balance,
+            timeout_timestamp,
+            steps,
+            callback_address,
+            send_callback,
+        } => execute_post_swap_action_step(
+            deps,
+            env,
+            info,
+            min_coin,
+            pre_balance,
+            timeout_timestamp,
+            steps,
+            callback_address,
+            send_callback,
+        ),
+        ExecuteMsg::TransferDelegationShares {
+            validator,
+            pre_balances,
+            delegator_recipient,
+        } => execute_transfer_delegation_shares(
+            deps,
+            env,
+            info,
+            validator,
+            pre_balances,
+            delegator_recipient,
+        ),
+        ExecuteMsg::TransferOwnership { new_owner } => {
+            execute_transfer_ownership(deps, info, new_owner)
+        }
+        ExecuteMsg::AcceptOwnership {} => execute_accept_ownership(deps, info),
+        ExecuteMsg::AddSwapVenue { swap_venue } => execute_add_swap_venue(deps, info, swap_venue),
+        ExecuteMsg::UpdateSwapVenue { swap_venue } => {
+            execute_update_swap_venue(deps, info, swap_venue)
+        }
+        ExecuteMsg::RemoveSwapVenue { name } => execute_remove_swap_venue(deps, info, name),
+        ExecuteMsg::AddIbcTransferAdapter {
+            ibc_transfer_adapter,
+        } => execute_add_ibc_transfer_adapter(deps, info, ibc_transfer_adapter),
+        ExecuteMsg::UpdateIbcTransferAdapter {
+            ibc_transfer_adapter,
+        } => execute_update_ibc_transfer_adapter(deps, info, ibc_transfer_adapter),
+        ExecuteMsg::RemoveIbcTransferAdapter { name } => {
+            execute_remove_ibc_transfer_adapter(deps, info, name)
+        }
+        ExecuteMsg::SetDefaultIbcTransferAdapter { name } => {
+            execute_set_default_ibc_transfer_adapter(deps, info, name)
+        }
+        ExecuteMsg::AddBlockedContractAddress { address } => {
+            execute_add_blocked_contract_address(deps, info, address)
+        }
+        ExecuteMsg::RemoveBlockedContractAddress { address } => {
+            execute_remove_blocked_contract_address(deps, info, address)
+        }
+        ExecuteMsg::AddGuardian { address } => execute_add_guardian(deps, info, address),
+        ExecuteMsg::RemoveGuardian { address } => execute_remove_guardian(deps, info, address),
+        ExecuteMsg::Pause { target } => execute_pause(deps, info, target),
+        ExecuteMsg::Unpause { target } => execute_unpause(deps, info, target),
+        ExecuteMsg::SetProtocolFee { protocol_fee } => {
+            execute_set_protocol_fee(deps, info, protocol_fee)
+        }
+        ExecuteMsg::RegisterAffiliate { affiliate } => {
+            execute_register_affiliate(deps, info, affiliate)
+        }
+        ExecuteMsg::DeregisterAffiliate { id } => execute_deregister_affiliate(deps, info, id),
+        ExecuteMsg::SetMaxTotalAffiliateBasisPointsFee {
+            max_total_affiliate_basis_points_fee,
+        } => execute_set_max_total_affiliate_basis_points_fee(
+            deps,
+            info,
+            max_total_affiliate_basis_points_fee,
         ),
+        ExecuteMsg::SetDelegateEnabled { enabled } => {
+            execute_set_delegate_enabled(deps, info, enabled)
+        }
+        ExecuteMsg::SetAffiliateFeeAccrual { accrue } => {
+            execute_set_affiliate_fee_accrual(deps, info, accrue)
+        }
+        ExecuteMsg::ClaimAffiliateFees { denom, destination } => {
+            execute_claim_affiliate_fees(deps, env, info, denom, destination)
+        }
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
     match msg.id {
-        RECOVER_REPLY_ID => reply_swap_and_action_with_recover(deps, msg),
+        RECOVER_REPLY_ID => reply_swap_and_action_with_recover(deps, env, msg),
+        CALLBACK_REPLY_ID => reply_skip_callback(msg),
         _ => Err(ContractError::ReplyIdError(msg.id)),
     }
 }
@@ -159,13 +347,91 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
 /////////////
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::SwapVenueAdapterContract { name } => {
             to_binary(&query_swap_venue_adapter_contract(deps, name)?)
         }
-        QueryMsg::IbcTransferAdapterContract {} => {
-            to_binary(&query_ibc_transfer_adapter_contract(deps)?)
+        QueryMsg::IbcTransferAdapterContract { name } => {
+            to_binary(&query_ibc_transfer_adapter_contract(deps, name)?)
+        }
+        QueryMsg::DefaultIbcTransferAdapter {} => {
+            to_binary(&query_default_ibc_transfer_adapter(deps)?)
+        }
+        QueryMsg::PauseState {} => to_binary(&query_pause_state(deps)?),
+        QueryMsg::Guardians {} => to_binary(&query_guardians(deps)?),
+        QueryMsg::ProtocolFee {} => to_binary(&query_protocol_fee(deps)?),
+        QueryMsg::RegisteredAffiliate { id } => to_binary(&query_registered_affiliate(deps, id)?),
+        QueryMsg::RegisteredAffiliates { start_after, limit } => {
+            to_binary(&query_registered_affiliates(deps, start_after, limit)?)
+        }
+        QueryMsg::MaxTotalAffiliateBasisPointsFee {} => {
+            to_binary(&query_max_total_affiliate_basis_points_fee(deps)?)
+        }
+        QueryMsg::DelegateEnabled {} => to_binary(&query_delegate_enabled(deps)?),
+        QueryMsg::AffiliateFeeAccrual { address } => {
+            to_binary(&query_affiliate_fee_accrual(deps, address)?)
+        }
+        QueryMsg::AccruedAffiliateFees {
+            address,
+            start_after,
+            limit,
+        } => to_binary(&query_accrued_affiliate_fees(
+            deps,
+            address,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::SwapVenues { start_after, limit } => {
+            to_binary(&query_swap_venues(deps, start_after, limit)?)
         }
+        QueryMsg::BlockedContractAddresses { start_after, limit } => {
+            to_binary(&query_blocked_contract_addresses(deps, start_after, limit)?)
+        }
+        QueryMsg::Config {} => to_binary(&query_config(deps)?),
+        QueryMsg::SimulateSwapAndAction {
+            coin_in,
+            pre_swap_action,
+            user_swap,
+            min_coin,
+            timeout_timestamp,
+            post_swap_action,
+            affiliates,
+            affiliate_fee_basis,
+        } => to_binary(
+            &query_simulate_swap_and_action(
+                deps,
+                env,
+                coin_in,
+                pre_swap_action,
+                user_swap,
+                min_coin,
+                timeout_timestamp,
+                post_swap_action,
+                affiliates,
+                affiliate_fee_basis,
+            )
+            .map_err(|err| StdError::generic_err(err.to_string()))?,
+        ),
+        QueryMsg::SimulateSwapExactCoinInWithMetadata {
+            swap_venue_name,
+            coin_in,
+            swap_operations,
+        } => to_binary(&query_simulate_swap_exact_coin_in_with_metadata(
+            deps,
+            swap_venue_name,
+            coin_in,
+            swap_operations,
+        )?),
+        QueryMsg::SimulateSwapExactCoinOutWithMetadata {
+            swap_venue_name,
+            coin_out,
+            swap_operations,
+        } => to_binary(&query_simulate_swap_exact_coin_out_with_metadata(
+            deps,
+            swap_venue_name,
+            coin_out,
+            swap_operations,
+        )?),
     }
 }
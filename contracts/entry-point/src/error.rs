@@ -1,5 +1,5 @@
-use cosmwasm_std::{OverflowError, StdError};
-use skip::error::SkipError;
+use cosmwasm_std::{OverflowError, StdError, Uint128};
+use skip::{entry_point::PauseTarget, error::SkipError};
 use thiserror::Error;
 
 pub type ContractResult<T> = core::result::Result<T, ContractError>;
@@ -28,12 +28,40 @@ pub enum ContractError {
     #[error("Timeout Timestamp Less Than Current Timestamp")]
     Timeout,
 
+    #[error("Route Paused: {0}")]
+    RoutePaused(PauseTarget),
+
     #[error("Duplicate Swap Venue Name Provided")]
     DuplicateSwapVenueName,
 
+    #[error("Duplicate IBC Transfer Adapter Name Provided")]
+    DuplicateIbcTransferAdapterName,
+
+    #[error("IBC Transfer Adapter Not Found: {0}")]
+    IbcTransferAdapterNotFound(String),
+
     #[error("IBC fee denom differs from coin received without a fee swap to convert")]
     IBCFeeDenomDiffersFromCoinReceived,
 
+    /////////////////
+    /// AFFILIATE ///
+    /////////////////
+
+    #[error("Basis Points Fee Required For Unregistered Affiliate: {0}")]
+    AffiliateBasisPointsFeeRequired(String),
+
+    #[error("Affiliate {0} Basis Points Fee Cannot Be More Than Its Max Of {1} Basis Points")]
+    RegisteredAffiliateFeeTooHigh(String, Uint128),
+
+    #[error("Total Affiliate Basis Points Fee Cannot Be More Than {0} Basis Points")]
+    TotalAffiliateFeeTooHigh(Uint128),
+
+    #[error("No Accrued Affiliate Fees To Claim: {0}")]
+    NoAccruedAffiliateFees(String),
+
+    #[error("Actual Out Affiliate Fee Basis Requires An Exact In User Swap")]
+    ActualOutAffiliateFeeBasisExactOut,
+
     ////////////////
     /// FEE SWAP ///
     ////////////////
@@ -54,6 +82,16 @@ pub enum ContractError {
     #[error("No Refund Address Provided For Swap Exact Coin Out User Swap")]
     NoRefundAddress,
 
+    ///////////////////////
+    /// PRE SWAP ACTION ///
+    ///////////////////////
+
+    #[error("Pre Swap Actions Require The User Swap To Be A Swap Exact Coin In")]
+    PreSwapActionUserSwapNotExactCoinIn,
+
+    #[error("User Swap And Other Asset Swap Must Swap Different Withdrawn Assets")]
+    WithdrawnAssetSwapsSameDenom,
+
     ////////////////////////
     /// POST SWAP ACTION ///
     ////////////////////////
@@ -64,6 +102,120 @@ pub enum ContractError {
     #[error("Contract Call Address Cannot Be The Entry Point Or Adapter Contracts")]
     ContractCallAddressBlocked,
 
+    #[error("Callback Address Cannot Be The Entry Point Or Adapter Contracts")]
+    CallbackAddressBlocked,
+
+    #[error(
+        "Sender Is Not The IBC Hooks Intermediate Sender Of The IBC Recovery's Original Sender"
+    )]
+    IbcRecoverySenderNotIbcHooksSender,
+
+    #[error("IBC Recovery Fee Must Be Less Than The Coin Sent")]
+    IbcRecoveryFeeNotLessThanCoinSent,
+
+    #[error("IBC Recovery Timeout Seconds Must Be Greater Than Zero")]
+    IbcRecoveryTimeoutZero,
+
+    #[error("CW20 Tokens Cannot Be IBC Transferred")]
+    Cw20IbcTransferNotSupported,
+
+    #[error("Multi Action Legs Empty")]
+    MultiActionLegsEmpty,
+
+    #[error("Multi Action Legs Cannot Be Multi Or Sequence Actions")]
+    MultiActionNested,
+
+    #[error("Multi Action Leg Amount Must Be Greater Than Zero")]
+    MultiActionLegAmountZero,
+
+    #[error("Multi Action Leg Shares Cannot Sum To More Than One")]
+    MultiActionSharesGreaterThanOne,
+
+    #[error("Multi Action Leg Fixed Amounts Exceed Coin Transferred Out")]
+    MultiActionFixedAmountsExceedCoin,
+
+    #[error("Sequence Steps Empty")]
+    SequenceStepsEmpty,
+
+    #[error("Sequence Steps Cannot Be Sequence Actions")]
+    SequenceNested,
+
+    #[error("Sequence Steps Before The Last Step Must Be Contract Calls Or Provide Liquidity Actions Without A Recipient, With A Min Coin Out")]
+    SequenceIntermediateStepInvalid,
+
+    #[error("Sequence Last Step Cannot Have A Min Coin Out")]
+    SequenceLastStepMinCoinOut,
+
+    #[error("Received Less Coin From Sequence Step Than Minimum Coin Required")]
+    ReceivedLessCoinFromSequenceStepThanMinCoin,
+
+    #[error(
+        "Provide Liquidity Actions Without A Recipient Must Be Sequence Steps Before The Last Step"
+    )]
+    ProvideLiquidityNoRecipient,
+
+    #[error("CW20 Tokens Cannot Be Provided As Liquidity")]
+    Cw20ProvideLiquidityNotSupported,
+
+    #[error("Delegate Actions Are Not Enabled, They Require The Liquid Staking Module")]
+    DelegateNotEnabled,
+
+    #[error("Delegate Coin Denom Must Be The Bond Denom")]
+    DelegateCoinNotBondDenom,
+
+    #[error("Delegate Validator Not Found: {0}")]
+    DelegateValidatorNotFound(String),
+
+    #[error("Delegate Tokenized No Share Tokens To Transfer To The Delegator Recipient")]
+    DelegateNoSharesTokenized,
+
     #[error("Reply id: {0} not valid")]
     ReplyIdError(u64),
+
+    /////////////
+    /// ADMIN ///
+    /////////////
+
+    #[error("No Pending Ownership Transfer To Accept")]
+    NoPendingOwner,
+
+    #[error("Swap Venue Not Found: {0}")]
+    SwapVenueNotFound(String),
+
+    #[error("Contract Address Is Not Blocked")]
+    ContractAddressNotBlocked,
+
+    #[error("Cannot Unblock The Entry Point Or A Current Or Retired Adapter Contract")]
+    CannotUnblockRegisteredContract,
+
+    #[error("Cannot Remove The Default IBC Transfer Adapter")]
+    CannotRemoveDefaultIbcTransferAdapter,
+
+    #[error("Address Is Not A Guardian")]
+    GuardianNotFound,
+
+    #[error("Protocol Fee Cannot Be More Than {0} Basis Points")]
+    ProtocolFeeTooHigh(Uint128),
+
+    #[error("Registered Affiliate Id Cannot Be Empty Or A Bech32 Address")]
+    InvalidRegisteredAffiliateId,
+
+    #[error("Registered Affiliate Default Basis Points Fee Cannot Be More Than Its Max")]
+    RegisteredAffiliateDefaultFeeAboveMax,
+
+    #[error("Affiliate Basis Points Fee Cannot Be More Than {0} Basis Points")]
+    AffiliateFeeTooHigh(Uint128),
+
+    #[error("Registered Affiliate Not Found: {0}")]
+    RegisteredAffiliateNotFound(String),
+
+    #[error("Affiliate Address Is Not The Address Of Registered Affiliate: {0}")]
+    RegisteredAffiliateAddressMismatch(String),
+
+    ///////////////
+    /// MIGRATE ///
+    ///////////////
+
+    #[error("Contract Has No Admin To Set As Owner During Migration")]
+    NoContractAdmin,
 }
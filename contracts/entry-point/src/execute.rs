@@ -1,23 +1,46 @@
 use crate::{
+    admin::{
+        assert_action_not_paused, assert_delegate_enabled, assert_not_paused,
+        assert_swap_venue_not_paused,
+    },
     error::{ContractError, ContractResult},
-    reply::{RecoverTempStorage, RECOVER_REPLY_ID},
+    reply::{RecoverTempStorage, CALLBACK_REPLY_ID, RECOVER_REPLY_ID},
     state::{
-        BLOCKED_CONTRACT_ADDRESSES, IBC_TRANSFER_CONTRACT_ADDRESS, RECOVER_TEMP_STORAGE,
-        SWAP_VENUE_MAP,
+        ACCRUED_AFFILIATE_FEES, AFFILIATE_FEE_ACCRUAL, BLOCKED_CONTRACT_ADDRESSES,
+        DEFAULT_IBC_TRANSFER_ADAPTER, IBC_TRANSFER_ADAPTER_MAP, MAX_TOTAL_AFFILIATE_FEE,
+        PROTOCOL_FEE, RECOVER_TEMP_STORAGE, REGISTERED_AFFILIATES, SWAP_VENUE_MAP,
+        TOTAL_ACCRUED_AFFILIATE_FEES,
     },
 };
 use cosmwasm_std::{
-    to_binary, Addr, BankMsg, Coin, CosmosMsg, DepsMut, Env, MessageInfo, Response, SubMsg,
-    Uint128, WasmMsg,
+    from_binary, to_binary, Addr, BankMsg, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env,
+    MessageInfo, Response, StakingMsg, StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
+use cw20::Cw20ReceiveMsg;
 use cw_utils::one_coin;
 use skip::{
-    entry_point::{Action, Affiliate, ExecuteMsg},
-    ibc::{ExecuteMsg as IbcTransferExecuteMsg, IbcTransfer},
+    asset::{
+        cw20_contract_address, cw20_denom, execute_msg_with_coin, query_balance, transfer_msg,
+    },
+    callback::{CallbackStatus, SkipCallback},
+    entry_point::{
+        Action, ActionType, Affiliate, AffiliateFee, AffiliateFeeBasis, ClaimDestination,
+        Cw20HookMsg, ExecuteMsg, IbcRecovery, MultiActionAmount, MultiActionLeg, PreSwapAction,
+        ProtocolFee, SequenceStep, MAX_TOTAL_AFFILIATE_BASIS_POINTS_FEE,
+    },
+    error::SkipError,
+    ibc::{
+        bech32_prefix, derive_ibc_hooks_sender, ExecuteMsg as IbcTransferExecuteMsg, IbcTransfer,
+    },
+    memo::validate_memo,
+    proto_coin::ProtoCoin,
+    staking::MsgTokenizeShares,
     swap::{
-        validate_swap_operations, ExecuteMsg as SwapExecuteMsg, QueryMsg as SwapQueryMsg, Swap,
-        SwapExactCoinOut,
+        validate_split_swap_legs, validate_swap_operations, ExecuteMsg as SwapExecuteMsg,
+        QueryMsg as SwapQueryMsg, SplitSwapLeg, Swap, SwapExactCoinIn, SwapExactCoinOut,
+        SwapOperation,
     },
+    template::{apply_msg_templates, validate_msg_templates},
 };
 
 ///////////////////////////
@@ -25,18 +48,136 @@ use skip::{
 ///////////////////////////
 
 // Main entry point for the contract
-// Dispatches the swap and post swap action
+// Dispatches the pre swap action, swap and post swap action
 #[allow(clippy::too_many_arguments)]
 pub fn execute_swap_and_action(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    pre_swap_action: Option<PreSwapAction>,
     user_swap: Swap,
     min_coin: Coin,
     timeout_timestamp: u64,
     post_swap_action: Action,
     affiliates: Vec<Affiliate>,
+    affiliate_fee_basis: Option<AffiliateFeeBasis>,
+    callback_address: Option<String>,
 ) -> ContractResult<Response> {
+    // Validate the call and create the ibc fee messages, getting the remaining coin to swap
+    let (mut response, remaining_coin, _) = prepare_swap_and_action(
+        deps.as_ref(),
+        &env,
+        &info,
+        timeout_timestamp,
+        pre_swap_action.as_ref(),
+        &post_swap_action,
+    )?;
+
+    // Validate the callback address if one is provided
+    let callback_address = callback_address
+        .map(|address| validate_callback_address(deps.as_ref(), &address))
+        .transpose()?;
+
+    // Set a boolean to determine if the user swap is exact out or not
+    let exact_out = is_exact_out(&user_swap);
+
+    // Split the affiliates into those charged on the min coin by the user swap,
+    // and those charged on the actual coin out by the post swap action
+    let (affiliates, post_swap_affiliates) =
+        split_affiliates_by_fee_basis(affiliates, affiliate_fee_basis, exact_out)?;
+
+    // Create the user swap message, which swaps the assets withdrawn
+    // by the pre swap action instead if there is a pre swap action
+    let user_swap_msg = match pre_swap_action {
+        Some(pre_swap_action) => {
+            // Create the pre swap action message, getting the swaps of the withdrawn assets
+            let (pre_swap_action_msg, swaps) = pre_swap_action_msgs(
+                deps.as_ref(),
+                pre_swap_action,
+                user_swap,
+                remaining_coin,
+                &min_coin,
+            )?;
+
+            // Get the contract's balance of each withdrawn asset before the pre swap action,
+            // used to find the amount withdrawn before swapping it
+            let pre_balances = swaps
+                .iter()
+                .map(|swap| {
+                    query_balance(
+                        &deps.querier,
+                        &env.contract.address,
+                        &swap.operations[0].denom_in,
+                    )
+                })
+                .collect::<StdResult<Vec<Coin>>>()?;
+
+            // Add the pre swap action message to the response
+            response = response
+                .add_message(pre_swap_action_msg)
+                .add_attribute("action", "dispatch_pre_swap_action");
+
+            // Create the withdrawn assets user swap message
+            WasmMsg::Execute {
+                contract_addr: env.contract.address.to_string(),
+                msg: to_binary(&ExecuteMsg::UserSwapWithdrawnAssets {
+                    swaps,
+                    pre_balances,
+                    min_coin: min_coin.clone(),
+                    affiliates,
+                })?,
+                funds: vec![],
+            }
+        }
+        None => WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_binary(&ExecuteMsg::UserSwap {
+                swap: user_swap,
+                min_coin: min_coin.clone(),
+                remaining_coin,
+                affiliates,
+            })?,
+            funds: vec![],
+        },
+    };
+
+    // Add the user swap message to the response
+    response = response
+        .add_message(user_swap_msg)
+        .add_attribute("action", "dispatch_user_swap");
+
+    // Create the post swap action message
+    let post_swap_action_msg = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_binary(&ExecuteMsg::PostSwapAction {
+            min_coin,
+            timeout_timestamp,
+            post_swap_action,
+            exact_out,
+            affiliates: post_swap_affiliates,
+            callback_address,
+        })?,
+        funds: vec![],
+    };
+
+    // Add the post swap action message to the response and return the response
+    Ok(response
+        .add_message(post_swap_action_msg)
+        .add_attribute("action", "dispatch_post_swap_action"))
+}
+
+// Validates a SwapAndAction call and creates the fee swap and ibc fee messages.
+// Returns the response with the messages, the remaining coin left to swap (or to
+// dispatch the pre swap action with) after the ibc fees are paid, and the total
+// fee swap coin in if there are fee swaps.
+pub(crate) fn prepare_swap_and_action(
+    deps: Deps,
+    env: &Env,
+    info: &MessageInfo,
+    timeout_timestamp: u64,
+    pre_swap_action: Option<&PreSwapAction>,
+    post_swap_action: &Action,
+) -> ContractResult<(Response, Coin, Option<Coin>)> {
     // Create a response object to return
     let mut response: Response = Response::new().add_attribute("action", "execute_swap_and_action");
 
@@ -45,14 +186,66 @@ pub fn execute_swap_and_action(
         return Err(ContractError::Timeout);
     }
 
+    // Error if all routes are paused
+    assert_not_paused(deps.storage)?;
+
     // Get coin sent to the contract from the MessageInfo
     // Error if there is not exactly one coin sent to the contract
-    let mut remaining_coin = one_coin(&info)?;
+    let mut remaining_coin = one_coin(info)?;
+    let mut fee_swap_coin_in: Option<Coin> = None;
+
+    // Error if the swap venue of the pre swap action does not exist or is paused
+    if let Some(PreSwapAction::WithdrawLiquidity {
+        swap_venue_name, ..
+    }) = pre_swap_action
+    {
+        SWAP_VENUE_MAP.load(deps.storage, swap_venue_name)?;
+        assert_swap_venue_not_paused(deps.storage, swap_venue_name)?;
+    }
+
+    // Validate the multi action legs or sequence steps, erroring before the swap if they are invalid
+    validate_post_swap_action(post_swap_action)?;
+
+    // Error before the swap if there is a delegate action but delegate actions are not enabled
+    if post_swap_actions(post_swap_action)
+        .into_iter()
+        .any(|action| matches!(action, Action::Delegate { .. }))
+    {
+        assert_delegate_enabled(deps.storage)?;
+    }
+
+    // Validate the msg templates of each contract call, erroring before the swap if they are invalid
+    for action in post_swap_actions(post_swap_action) {
+        if let Action::ContractCall {
+            msg,
+            msg_templates: Some(msg_templates),
+            ..
+        } = action
+        {
+            validate_msg_templates(msg, msg_templates)?;
+        }
+    }
 
-    // If the post swap action is an IBC transfer, then handle the ibc fees
+    // For each post swap action that is an IBC transfer, handle the ibc fees
     // by either creating a fee swap message or deducting the ibc fees from
     // the remaining coin received amount.
-    if let Action::IbcTransfer { ibc_info, fee_swap } = &post_swap_action {
+    for action in post_swap_actions(post_swap_action) {
+        let Action::IbcTransfer {
+            ibc_info,
+            fee_swap,
+            ibc_transfer_adapter,
+        } = action
+        else {
+            continue;
+        };
+
+        // Get the ibc transfer adapter contract address, erroring early if the adapter does not exist
+        let ibc_transfer_contract_address =
+            load_ibc_transfer_adapter(deps.storage, ibc_transfer_adapter.as_deref())?;
+
+        // Validate the memo, erroring before the swap if it is a malformed json memo
+        validate_memo(&ibc_info.memo)?;
+
         let ibc_fee_coin = ibc_info
             .fee
             .as_ref()
@@ -65,12 +258,17 @@ pub fn execute_swap_and_action(
                 .ok_or(ContractError::FeeSwapWithoutIbcFees)?;
 
             // NOTE: this call mutates remaining_coin_received by deducting ibc_fee_coin's amount from it
-            let fee_swap_msg = verify_and_create_fee_swap_msg(
-                &deps,
-                fee_swap,
-                &mut remaining_coin,
-                &ibc_fee_coin,
-            )?;
+            let (fee_swap_msg, coin_in) =
+                verify_and_create_fee_swap_msg(deps, fee_swap, &mut remaining_coin, &ibc_fee_coin)?;
+
+            // Add the coin in to the total fee swap coin in
+            fee_swap_coin_in = Some(match fee_swap_coin_in {
+                Some(total) => Coin {
+                    denom: total.denom,
+                    amount: total.amount.checked_add(coin_in.amount)?,
+                },
+                None => coin_in,
+            });
 
             // Add the fee swap message to the response
             response = response
@@ -87,9 +285,6 @@ pub fn execute_swap_and_action(
 
         // Dispatch the ibc fee bank send to the ibc transfer adapter contract if needed
         if let Some(ibc_fee_coin) = ibc_fee_coin {
-            // Get the ibc transfer adapter contract address
-            let ibc_transfer_contract_address = IBC_TRANSFER_CONTRACT_ADDRESS.load(deps.storage)?;
-
             // Create the ibc fee bank send message
             let ibc_fee_msg = BankMsg::Send {
                 to_address: ibc_transfer_contract_address.to_string(),
@@ -103,44 +298,53 @@ pub fn execute_swap_and_action(
         }
     }
 
-    // Set a boolean to determine if the user swap is exact out or not
-    let exact_out = match &user_swap {
-        Swap::SwapExactCoinIn(_) => false,
-        Swap::SwapExactCoinOut(_) => true,
-    };
+    Ok((response, remaining_coin, fee_swap_coin_in))
+}
 
-    let user_swap_msg = WasmMsg::Execute {
-        contract_addr: env.contract.address.to_string(),
-        msg: to_binary(&ExecuteMsg::UserSwap {
-            swap: user_swap,
-            min_coin: min_coin.clone(),
-            remaining_coin,
-            affiliates,
-        })?,
-        funds: vec![],
+// Entrypoint for CW20 sends to the contract, dispatching the CW20 hook message
+// with the CW20 coin sent as the coin to swap
+pub fn execute_receive(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> ContractResult<Response> {
+    // Get the CW20 coin sent, the caller is the CW20 contract
+    let sent_coin = Coin {
+        denom: cw20_denom(info.sender.as_str()),
+        amount: cw20_msg.amount,
     };
 
-    // Add the user swap message to the response
-    response = response
-        .add_message(user_swap_msg)
-        .add_attribute("action", "dispatch_user_swap");
+    // Create the message info of the CW20 sender, with the CW20 coin sent as the funds
+    let cw20_info = MessageInfo {
+        sender: deps.api.addr_validate(&cw20_msg.sender)?,
+        funds: vec![sent_coin],
+    };
 
-    // Create the post swap action message
-    let post_swap_action_msg = WasmMsg::Execute {
-        contract_addr: env.contract.address.to_string(),
-        msg: to_binary(&ExecuteMsg::PostSwapAction {
+    match from_binary(&cw20_msg.msg)? {
+        Cw20HookMsg::SwapAndAction {
+            pre_swap_action,
+            user_swap,
             min_coin,
             timeout_timestamp,
             post_swap_action,
-            exact_out,
-        })?,
-        funds: vec![],
-    };
-
-    // Add the post swap action message to the response and return the response
-    Ok(response
-        .add_message(post_swap_action_msg)
-        .add_attribute("action", "dispatch_post_swap_action"))
+            affiliates,
+            affiliate_fee_basis,
+            callback_address,
+        } => execute_swap_and_action(
+            deps,
+            env,
+            cw20_info,
+            pre_swap_action,
+            user_swap,
+            min_coin,
+            timeout_timestamp,
+            post_swap_action,
+            affiliates,
+            affiliate_fee_basis,
+            callback_address,
+        ),
+    }
 }
 
 // Entrypoint that catches all errors in SwapAndAction and recovers
@@ -150,19 +354,33 @@ pub fn execute_swap_and_action_with_recover(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    pre_swap_action: Option<PreSwapAction>,
     user_swap: Swap,
     min_coin: Coin,
     timeout_timestamp: u64,
     post_swap_action: Action,
     affiliates: Vec<Affiliate>,
+    affiliate_fee_basis: Option<AffiliateFeeBasis>,
+    callback_address: Option<String>,
+    ibc_recovery: Option<IbcRecovery>,
     recovery_addr: Addr,
 ) -> ContractResult<Response> {
+    // Error if all routes are paused, so the call errors instead of recovering the funds
+    assert_not_paused(deps.storage)?;
+
+    // Verify the IBC recovery, if given, so the call errors instead of recovering the funds
+    // to an origin chain address that did not send them
+    if let Some(ibc_recovery) = &ibc_recovery {
+        verify_ibc_recovery(deps.as_ref(), &env, &info, ibc_recovery)?;
+    }
+
     // Store all parameters into a temporary storage.
     RECOVER_TEMP_STORAGE.save(
         deps.storage,
         &RecoverTempStorage {
             funds: info.funds.clone(),
             recovery_addr,
+            ibc_recovery,
         },
     )?;
 
@@ -171,11 +389,14 @@ pub fn execute_swap_and_action_with_recover(
         CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: env.contract.address.to_string(),
             msg: to_binary(&ExecuteMsg::SwapAndAction {
+                pre_swap_action,
                 user_swap,
                 min_coin,
                 timeout_timestamp,
                 post_swap_action,
                 affiliates,
+                affiliate_fee_basis,
+                callback_address,
             })?,
             funds: info.funds,
         }),
@@ -200,43 +421,263 @@ pub fn execute_user_swap(
         return Err(ContractError::Unauthorized);
     }
 
+    // Get the refund address and coin in of an exact out user swap, which refunds its unused coin in
+    let exact_out_refund = match &swap {
+        Swap::SwapExactCoinOut(swap) => Some((swap.refund_address.clone(), remaining_coin.clone())),
+        _ => None,
+    };
+
+    // Create the user swap and refund/affiliate fee messages
+    let (mut response, outcome) =
+        user_swap_response(deps.as_ref(), swap, min_coin, remaining_coin, affiliates)?;
+
+    // Credit the affiliate fees accrued instead of sent to the affiliates' balances
+    accrue_affiliate_fees(deps.storage, &outcome.accrued_affiliate_fees)?;
+
+    // Dispatch the refund of the coin in the swap adapter returns unused after an exact out swap,
+    // found by the contract's balance change of the coin in denom since before the swap
+    if let Some((refund_address, coin_in)) = exact_out_refund {
+        // Get the contract balance of the coin in denom before the swap, excluding the coin in
+        let mut pre_balance = query_balance(&deps.querier, &env.contract.address, &coin_in.denom)?;
+        pre_balance.amount = pre_balance.amount.checked_sub(coin_in.amount)?;
+
+        let user_swap_refund_msg = WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_binary(&ExecuteMsg::UserSwapRefund {
+                pre_balance,
+                refund_address,
+            })?,
+            funds: vec![],
+        };
+
+        response = response
+            .add_message(user_swap_refund_msg)
+            .add_attribute("action", "dispatch_user_swap_refund");
+    }
+
+    Ok(response)
+}
+
+// Refunds the coin in left unused by an exact out user swap to the refund address, found by the
+// contract's balance change of the coin in denom since before the swap. Can only be called by the
+// contract itself
+pub fn execute_user_swap_refund(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pre_balance: Coin,
+    refund_address: Option<String>,
+) -> ContractResult<Response> {
+    // Enforce the caller is the contract itself
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized);
+    }
+
     // Create a response object to return
-    let mut response: Response = Response::new().add_attribute("action", "execute_user_swap");
+    let mut response: Response =
+        Response::new().add_attribute("action", "execute_user_swap_refund");
 
-    // Create affiliate response and total affiliate fee amount
-    let mut affiliate_response: Response = Response::new();
-    let mut total_affiliate_fee_amount: Uint128 = Uint128::zero();
+    // Get the unused coin in by the contract's balance change
+    let balance = query_balance(&deps.querier, &env.contract.address, &pre_balance.denom)?;
+    let refund_coin = Coin {
+        denom: pre_balance.denom,
+        amount: balance.amount.saturating_sub(pre_balance.amount),
+    };
 
-    // If affiliates exist, create the affiliate fee messages and attributes and
-    // add them to the affiliate response, updating the total affiliate fee amount
-    for affiliate in affiliates.iter() {
-        // Verify, calculate, and get the affiliate fee amount
-        let affiliate_fee_amount =
-            verify_and_calculate_affiliate_fee_amount(&deps, &min_coin, affiliate)?;
+    // If the refund amount is gt zero, then create the refund message and add it to the response
+    if !refund_coin.amount.is_zero() {
+        // Get the refund address, error if there is none
+        let to_address = refund_address.ok_or(ContractError::NoRefundAddress)?;
 
-        if affiliate_fee_amount > Uint128::zero() {
-            // Add the affiliate fee amount to the total affiliate fee amount
-            total_affiliate_fee_amount =
-                total_affiliate_fee_amount.checked_add(affiliate_fee_amount)?;
+        // Validate the refund address
+        deps.api.addr_validate(&to_address)?;
 
-            // Create the affiliate fee bank send message
-            let affiliate_fee_msg = BankMsg::Send {
-                to_address: affiliate.address.clone(),
-                amount: vec![Coin {
-                    denom: min_coin.denom.clone(),
-                    amount: affiliate_fee_amount,
-                }],
-            };
+        // Create the refund message
+        let refund_msg = transfer_msg(to_address.clone(), refund_coin.clone())?;
 
-            // Add the affiliate fee message and attributes to the response
-            affiliate_response = affiliate_response
-                .add_message(affiliate_fee_msg)
-                .add_attribute("action", "dispatch_affiliate_fee_bank_send")
-                .add_attribute("address", &affiliate.address)
-                .add_attribute("amount", affiliate_fee_amount);
+        // Add the refund message and attributes to the response
+        response = response
+            .add_message(refund_msg)
+            .add_attribute("action", "dispatch_refund")
+            .add_attribute("address", &to_address)
+            .add_attribute("amount", refund_coin.amount);
+    }
+
+    Ok(response)
+}
+
+// Dispatches the swaps of the assets withdrawn by a pre swap action, and the affiliate fee bank
+// sends if needed. Each withdrawn asset is found by the contract's balance change since the pre
+// swap action was dispatched. Can only be called by the contract itself
+pub fn execute_user_swap_withdrawn_assets(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    swaps: Vec<SwapExactCoinIn>,
+    pre_balances: Vec<Coin>,
+    min_coin: Coin,
+    affiliates: Vec<Affiliate>,
+) -> ContractResult<Response> {
+    // Enforce the caller is the contract itself
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Get each withdrawn asset by the contract's balance change
+    let coins_in = pre_balances
+        .into_iter()
+        .map(|pre_balance| {
+            let balance = query_balance(&deps.querier, &env.contract.address, &pre_balance.denom)?;
+            Ok(Coin {
+                denom: pre_balance.denom,
+                amount: balance.amount.saturating_sub(pre_balance.amount),
+            })
+        })
+        .collect::<StdResult<Vec<Coin>>>()?;
+
+    // Create the withdrawn asset swap and affiliate fee messages
+    let (response, outcome) = user_swap_withdrawn_assets_response(
+        deps.as_ref(),
+        swaps,
+        coins_in,
+        &min_coin,
+        &affiliates,
+    )?;
+
+    // Credit the affiliate fees accrued instead of sent to the affiliates' balances
+    accrue_affiliate_fees(deps.storage, &outcome.accrued_affiliate_fees)?;
+
+    Ok(response)
+}
+
+// Creates the swap messages of the assets withdrawn by a pre swap action, swapping each swap's
+// coin in, and the affiliate and protocol fee messages, returning the response with the messages
+// and the outcome of the swaps
+pub(crate) fn user_swap_withdrawn_assets_response(
+    deps: Deps,
+    swaps: Vec<SwapExactCoinIn>,
+    coins_in: Vec<Coin>,
+    min_coin: &Coin,
+    affiliates: &[Affiliate],
+) -> ContractResult<(Response, UserSwapOutcome)> {
+    // Create a response object and user swap outcome to return
+    let mut response: Response =
+        Response::new().add_attribute("action", "execute_user_swap_withdrawn_assets");
+    let mut outcome = UserSwapOutcome {
+        swaps: vec![],
+        exact_coin_out: None,
+        refund: None,
+        affiliate_fees: vec![],
+        accrued_affiliate_fees: vec![],
+        protocol_fee: None,
+    };
+
+    for (swap, coin_in) in swaps.into_iter().zip(coins_in) {
+        // Skip swaps of withdrawn assets the pre swap action did not return
+        if coin_in.amount.is_zero() {
+            continue;
         }
+
+        // Get swap adapter contract address from venue name
+        let swap_adapter_contract_address =
+            SWAP_VENUE_MAP.load(deps.storage, &swap.swap_venue_name)?;
+
+        // Error if the swap venue is paused
+        assert_swap_venue_not_paused(deps.storage, &swap.swap_venue_name)?;
+
+        // Get the swap operations for the user swap outcome
+        let operations = swap.operations.clone();
+
+        // Create the swap message, the combined coin out is checked against the
+        // min coin by the post swap action instead of at the swap venue
+        let swap_msg_args: SwapExecuteMsg = swap.into();
+        let swap_msg = execute_msg_with_coin(
+            swap_adapter_contract_address.to_string(),
+            to_binary(&swap_msg_args)?,
+            coin_in.clone(),
+        )?;
+
+        // Add the swap to the user swap outcome
+        outcome
+            .swaps
+            .push((swap_adapter_contract_address, operations, coin_in));
+
+        response = response
+            .add_message(swap_msg)
+            .add_attribute("action", "dispatch_user_swap_withdrawn_asset");
     }
 
+    // Add the affiliate and protocol fee messages and attributes after the swaps, so the
+    // contract has received the min coin denom to send to the affiliates and fee collector
+    let (affiliate_response, affiliate_fees, accrued_affiliate_fees, _) =
+        affiliate_fee_response(deps, min_coin, affiliates)?;
+    let (protocol_fee_response, protocol_fee) = protocol_fee_response(deps, min_coin)?;
+    outcome.affiliate_fees = affiliate_fees;
+    outcome.accrued_affiliate_fees = accrued_affiliate_fees;
+    outcome.protocol_fee = protocol_fee;
+
+    Ok((
+        response
+            .add_submessages(affiliate_response.messages)
+            .add_attributes(affiliate_response.attributes)
+            .add_submessages(protocol_fee_response.messages)
+            .add_attributes(protocol_fee_response.attributes),
+        outcome,
+    ))
+}
+
+// The UserSwapOutcome struct holds the swaps a user swap dispatches to the swap adapters, the
+// exact coin out of an exact out user swap and its simulated refund, the affiliate and protocol
+// fees paid, and the affiliate fees of them accrued in the contract instead of sent.
+pub(crate) struct UserSwapOutcome {
+    pub swaps: Vec<(Addr, Vec<SwapOperation>, Coin)>,
+    pub exact_coin_out: Option<Coin>,
+    pub refund: Option<Coin>,
+    pub affiliate_fees: Vec<AffiliateFee>,
+    pub accrued_affiliate_fees: Vec<AffiliateFee>,
+    pub protocol_fee: Option<Coin>,
+}
+
+// Creates the user swap and refund/affiliate fee messages, returning
+// the response with the messages and the outcome of the user swap
+pub(crate) fn user_swap_response(
+    deps: Deps,
+    swap: Swap,
+    min_coin: Coin,
+    remaining_coin: Coin,
+    affiliates: Vec<Affiliate>,
+) -> ContractResult<(Response, UserSwapOutcome)> {
+    // Create a response object and user swap outcome to return
+    let mut response: Response = Response::new().add_attribute("action", "execute_user_swap");
+    let mut outcome = UserSwapOutcome {
+        swaps: vec![],
+        exact_coin_out: None,
+        refund: None,
+        affiliate_fees: vec![],
+        accrued_affiliate_fees: vec![],
+        protocol_fee: None,
+    };
+
+    // Create the affiliate fee messages and attributes, getting the total affiliate fee amount
+    let (affiliate_response, affiliate_fees, accrued_affiliate_fees, total_affiliate_fee_amount) =
+        affiliate_fee_response(deps, &min_coin, &affiliates)?;
+    outcome.affiliate_fees = affiliate_fees;
+    outcome.accrued_affiliate_fees = accrued_affiliate_fees;
+
+    // Create the protocol fee message and attributes, getting the protocol fee
+    let (protocol_fee_response, protocol_fee) = protocol_fee_response(deps, &min_coin)?;
+    let protocol_fee_amount = protocol_fee
+        .as_ref()
+        .map_or(Uint128::zero(), |protocol_fee| protocol_fee.amount);
+    outcome.protocol_fee = protocol_fee;
+
+    // Calculate the minimum amount the user swap must receive, which is the min coin
+    // amount plus the total affiliate fee amount and protocol fee amount paid out of it
+    let min_swap_amount_out = min_coin
+        .amount
+        .checked_add(total_affiliate_fee_amount)?
+        .checked_add(protocol_fee_amount)?;
+
     // Create the user swap message
     match swap {
         Swap::SwapExactCoinIn(swap) => {
@@ -247,16 +688,30 @@ pub fn execute_user_swap(
             let user_swap_adapter_contract_address =
                 SWAP_VENUE_MAP.load(deps.storage, &swap.swap_venue_name)?;
 
-            // Create the user swap message args
-            let user_swap_msg_args: SwapExecuteMsg = swap.into();
+            // Error if the swap venue is paused
+            assert_swap_venue_not_paused(deps.storage, &swap.swap_venue_name)?;
 
-            // Create the user swap message
-            let user_swap_msg = WasmMsg::Execute {
-                contract_addr: user_swap_adapter_contract_address.to_string(),
-                msg: to_binary(&user_swap_msg_args)?,
-                funds: vec![remaining_coin],
+            // Create the user swap message args, enforcing the minimum amount out at the swap venue
+            let user_swap_msg_args = SwapExecuteMsg::Swap {
+                operations: swap.operations.clone(),
+                minimum_receive: Some(min_swap_amount_out),
+                max_spread: None,
             };
 
+            // Create the user swap message
+            let user_swap_msg = execute_msg_with_coin(
+                user_swap_adapter_contract_address.to_string(),
+                to_binary(&user_swap_msg_args)?,
+                remaining_coin.clone(),
+            )?;
+
+            // Add the swap to the user swap outcome
+            outcome.swaps.push((
+                user_swap_adapter_contract_address,
+                swap.operations,
+                remaining_coin,
+            ));
+
             response = response
                 .add_message(user_swap_msg)
                 .add_attribute("action", "dispatch_user_swap_exact_coin_in");
@@ -269,15 +724,18 @@ pub fn execute_user_swap(
             let user_swap_adapter_contract_address =
                 SWAP_VENUE_MAP.load(deps.storage, &swap.swap_venue_name)?;
 
-            // Calculate the swap coin out by adding the min coin amount to the total affiliate fee amount
+            // Error if the swap venue is paused
+            assert_swap_venue_not_paused(deps.storage, &swap.swap_venue_name)?;
+
+            // Calculate the swap coin out by adding the min coin amount to the total fee amount
             let swap_coin_out = Coin {
                 denom: min_coin.denom,
-                amount: min_coin.amount.checked_add(total_affiliate_fee_amount)?,
+                amount: min_swap_amount_out,
             };
 
-            // Query the swap adapter to get the coin in needed to obtain the min coin plus affiliates
+            // Query the swap adapter to get the coin in needed to obtain the min coin plus fees
             let user_swap_coin_in = query_swap_coin_in(
-                &deps,
+                deps,
                 &user_swap_adapter_contract_address,
                 &swap,
                 &swap_coin_out,
@@ -288,65 +746,198 @@ pub fn execute_user_swap(
                 return Err(ContractError::UserSwapCoinInDenomMismatch);
             }
 
-            // Calculate refund amount to send back to the user
+            // Calculate the refund amount expected to be left unused by the swap
             let refund_amount = remaining_coin
                 .amount
                 .checked_sub(user_swap_coin_in.amount)?;
 
-            // If refund amount gt zero, then create the refund message and add it to the response
+            // If a refund is expected, then error early if there is no valid refund address
+            // to send it to, and add the expected refund to the user swap outcome. The refund
+            // itself is dispatched by the UserSwapRefund message after the swap, as the coin
+            // in left unused is only known by the contract's balance change.
             if refund_amount > Uint128::zero() {
                 // Get the refund address from the swap
                 let to_address = swap
                     .refund_address
-                    .clone()
+                    .as_ref()
                     .ok_or(ContractError::NoRefundAddress)?;
 
                 // Validate the refund address
-                deps.api.addr_validate(&to_address)?;
-
-                // Create the refund message
-                let refund_msg = BankMsg::Send {
-                    to_address: to_address.clone(),
-                    amount: vec![Coin {
-                        denom: remaining_coin.denom,
-                        amount: refund_amount,
-                    }],
-                };
+                deps.api.addr_validate(to_address)?;
+
+                // Add the expected refund to the user swap outcome
+                outcome.refund = Some(Coin {
+                    denom: remaining_coin.denom.clone(),
+                    amount: refund_amount,
+                });
+            }
+
+            // Create the user swap message args, swapping at most the remaining coin for the swap coin out
+            let user_swap_msg_args = SwapExecuteMsg::SwapExactCoinOut {
+                operations: swap.operations.clone(),
+                coin_out: swap_coin_out.clone(),
+            };
+
+            // Create the user swap message, sending the full remaining coin as the max coin in
+            // so that a worse execution price than simulated can still be paid for
+            let user_swap_msg = execute_msg_with_coin(
+                user_swap_adapter_contract_address.to_string(),
+                to_binary(&user_swap_msg_args)?,
+                remaining_coin,
+            )?;
+
+            // Add the swap with its simulated coin in and its exact coin out to the user swap outcome
+            outcome.swaps.push((
+                user_swap_adapter_contract_address,
+                swap.operations,
+                user_swap_coin_in,
+            ));
+            outcome.exact_coin_out = Some(swap_coin_out);
+
+            response = response
+                .add_message(user_swap_msg)
+                .add_attribute("action", "dispatch_user_swap_exact_coin_out");
+        }
+        Swap::SplitSwapExactCoinIn(swap) => {
+            // Validate the split swap legs
+            validate_split_swap_legs(&swap.legs, &remaining_coin.denom, &min_coin.denom)?;
+
+            // Split the remaining coin received across the legs by their shares
+            let leg_coins_in = split_swap_coin_in(&swap.legs, &remaining_coin)?;
+
+            for (leg, leg_coin_in) in swap.legs.into_iter().zip(leg_coins_in) {
+                // Get swap adapter contract address from venue name
+                let leg_swap_adapter_contract_address =
+                    SWAP_VENUE_MAP.load(deps.storage, &leg.swap_venue_name)?;
+
+                // Error if the swap venue is paused
+                assert_swap_venue_not_paused(deps.storage, &leg.swap_venue_name)?;
+
+                // Skip legs whose share of the remaining coin rounds down to zero
+                if leg_coin_in.amount.is_zero() {
+                    continue;
+                }
+
+                // Get the leg swap operations for the user swap outcome
+                let leg_operations = leg.operations.clone();
+
+                // Create the leg swap message args
+                let leg_swap_msg_args: SwapExecuteMsg = leg.into();
+
+                // Create the leg swap message
+                let leg_swap_msg = execute_msg_with_coin(
+                    leg_swap_adapter_contract_address.to_string(),
+                    to_binary(&leg_swap_msg_args)?,
+                    leg_coin_in.clone(),
+                )?;
+
+                // Add the leg swap to the user swap outcome
+                outcome.swaps.push((
+                    leg_swap_adapter_contract_address,
+                    leg_operations,
+                    leg_coin_in,
+                ));
+
+                response = response
+                    .add_message(leg_swap_msg)
+                    .add_attribute("action", "dispatch_user_split_swap_leg");
+            }
+        }
+        Swap::BestSwapExactCoinIn(swap) => {
+            // Error if there are no candidate routes
+            if swap.routes.is_empty() {
+                return Err(SkipError::BestSwapRoutesEmpty.into());
+            }
 
-                // Add the refund message and attributes to the response
+            // Simulate each candidate route, keeping the route with the largest coin out
+            let mut best_route: Option<(usize, Uint128)> = None;
+            for (i, route) in swap.routes.iter().enumerate() {
+                // Validate swap operations
+                validate_swap_operations(
+                    &route.operations,
+                    &remaining_coin.denom,
+                    &min_coin.denom,
+                )?;
+
+                // Get swap adapter contract address from venue name
+                let route_swap_adapter_contract_address =
+                    SWAP_VENUE_MAP.load(deps.storage, &route.swap_venue_name)?;
+
+                // Error if the swap venue is paused
+                assert_swap_venue_not_paused(deps.storage, &route.swap_venue_name)?;
+
+                // Query the swap adapter to get the coin out received from the route
+                let route_coin_out = query_swap_coin_out(
+                    deps,
+                    &route_swap_adapter_contract_address,
+                    &route.operations,
+                    &remaining_coin,
+                )?;
+
+                // Add the simulated route amount out to the response attributes
                 response = response
-                    .add_message(refund_msg)
-                    .add_attribute("action", "dispatch_refund")
-                    .add_attribute("address", &to_address)
-                    .add_attribute("amount", refund_amount);
+                    .add_attribute("route_index", i.to_string())
+                    .add_attribute("simulated_amount_out", route_coin_out.amount);
+
+                // Keep the first route with the largest coin out
+                if best_route.map_or(true, |(_, best_amount_out)| {
+                    route_coin_out.amount > best_amount_out
+                }) {
+                    best_route = Some((i, route_coin_out.amount));
+                }
             }
 
-            // Create the user swap message args
-            let user_swap_msg_args: SwapExecuteMsg = swap.into();
+            // Get the best route, which always exists since the routes are not empty
+            let (best_route_index, _) = best_route.ok_or(SkipError::BestSwapRoutesEmpty)?;
+            let route = swap.routes[best_route_index].clone();
 
-            // Create the user swap message
-            let user_swap_msg = WasmMsg::Execute {
-                contract_addr: user_swap_adapter_contract_address.to_string(),
-                msg: to_binary(&user_swap_msg_args)?,
-                funds: vec![user_swap_coin_in],
+            // Get swap adapter contract address from venue name
+            let user_swap_adapter_contract_address =
+                SWAP_VENUE_MAP.load(deps.storage, &route.swap_venue_name)?;
+
+            // Create the user swap message args, enforcing the minimum amount out at the swap venue
+            let user_swap_msg_args = SwapExecuteMsg::Swap {
+                operations: route.operations.clone(),
+                minimum_receive: Some(min_swap_amount_out),
+                max_spread: None,
             };
 
+            // Create the user swap message
+            let user_swap_msg = execute_msg_with_coin(
+                user_swap_adapter_contract_address.to_string(),
+                to_binary(&user_swap_msg_args)?,
+                remaining_coin.clone(),
+            )?;
+
+            // Add the swap to the user swap outcome
+            outcome.swaps.push((
+                user_swap_adapter_contract_address,
+                route.operations,
+                remaining_coin,
+            ));
+
             response = response
                 .add_message(user_swap_msg)
-                .add_attribute("action", "dispatch_user_swap_exact_coin_out");
+                .add_attribute("action", "dispatch_user_best_swap_exact_coin_in")
+                .add_attribute("best_route_index", best_route_index.to_string());
         }
     }
 
-    // Add the affiliate messages and attributes to the response and return the response
-    // Having the affiliate messages after the swap is purposeful, so that the affiliate
-    // bank sends are valid and the contract has funds to send to the affiliates.
-    Ok(response
+    // Add the affiliate and protocol fee messages and attributes to the response and return the
+    // response. Having the fee messages after the swap is purposeful, so that the fee bank sends
+    // are valid and the contract has funds to send to the affiliates and fee collector.
+    let response = response
         .add_submessages(affiliate_response.messages)
-        .add_attributes(affiliate_response.attributes))
+        .add_attributes(affiliate_response.attributes)
+        .add_submessages(protocol_fee_response.messages)
+        .add_attributes(protocol_fee_response.attributes);
+
+    Ok((response, outcome))
 }
 
-// Dispatches the post swap action
+// Dispatches the post swap action, and the SkipCallback to the callback address if there is one
 // Can only be called by the contract itself
+#[allow(clippy::too_many_arguments)]
 pub fn execute_post_swap_action(
     deps: DepsMut,
     env: Env,
@@ -355,21 +946,96 @@ pub fn execute_post_swap_action(
     timeout_timestamp: u64,
     post_swap_action: Action,
     exact_out: bool,
+    affiliates: Vec<Affiliate>,
+    callback_address: Option<Addr>,
 ) -> ContractResult<Response> {
     // Enforce the caller is the contract itself
     if info.sender != env.contract.address {
         return Err(ContractError::Unauthorized);
     }
 
-    // Create a response object to return
-    let mut response: Response =
-        Response::new().add_attribute("action", "execute_post_swap_action");
+    // Get contract balance of min out coin immediately after swap, excluding the accrued
+    // affiliate fees held for claims, for fee deduction and transfer out amount enforcement
+    let mut transfer_out_coin =
+        query_balance(&deps.querier, &env.contract.address, &min_coin.denom)?;
+    transfer_out_coin.amount = transfer_out_coin.amount.checked_sub(
+        TOTAL_ACCRUED_AFFILIATE_FEES
+            .may_load(deps.storage, &min_coin.denom)?
+            .unwrap_or_default(),
+    )?;
 
-    // Get contract balance of min out coin immediately after swap
-    // for fee deduction and transfer out amount enforcement
-    let transfer_out_coin = deps
-        .querier
-        .query_balance(&env.contract.address, &min_coin.denom)?;
+    // Create the affiliate fee messages of the affiliates charged on the actual coin out,
+    // deducting the affiliate fees from the coin transferred out, which is then enforced
+    // to be at least the min coin by the post swap action response
+    let (affiliate_response, _, accrued_affiliate_fees, total_affiliate_fee_amount) =
+        affiliate_fee_response(deps.as_ref(), &transfer_out_coin, &affiliates)?;
+    transfer_out_coin.amount = transfer_out_coin
+        .amount
+        .checked_sub(total_affiliate_fee_amount)?;
+
+    // Credit the affiliate fees accrued instead of sent to the affiliates' balances
+    accrue_affiliate_fees(deps.storage, &accrued_affiliate_fees)?;
+
+    // Get the callback status, and whether the post swap action is a sequence with steps after
+    // the first, whose callback is sent by the step dispatching the last step instead
+    let callback_status = callback_status(&post_swap_action);
+    let callback_sent_by_last_step =
+        matches!(&post_swap_action, Action::Sequence { steps } if steps.len() > 1);
+
+    // Create the post swap action message
+    let (mut response, transfer_out_coin) = post_swap_action_response(
+        deps.as_ref(),
+        &env,
+        transfer_out_coin,
+        min_coin,
+        timeout_timestamp,
+        post_swap_action,
+        exact_out,
+        callback_address.as_ref(),
+    )?;
+
+    // Add the affiliate fee messages and attributes after the post swap action messages, so
+    // the balance changes the sequence steps are found by are not affected by the fee sends
+    response = response
+        .add_submessages(affiliate_response.messages)
+        .add_attributes(affiliate_response.attributes);
+
+    // Send the callback last, so it runs after the post swap action completes.
+    // The callback replies on error, so a failing callback is ignored.
+    if let Some(callback_address) = callback_address.filter(|_| !callback_sent_by_last_step) {
+        let callback_msg = SkipCallback {
+            status: callback_status,
+            amount_out: transfer_out_coin,
+            ack_id: None,
+        }
+        .into_sub_msg(callback_address.to_string(), CALLBACK_REPLY_ID)?;
+
+        response = response
+            .add_submessage(callback_msg)
+            .add_attribute("action", "dispatch_skip_callback");
+    }
+
+    Ok(response)
+}
+
+// Creates the post swap action message transferring out the coin received from the
+// swaps, returning the response with the message and the coin transferred out
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn post_swap_action_response(
+    deps: Deps,
+    env: &Env,
+    transfer_out_coin: Coin,
+    min_coin: Coin,
+    timeout_timestamp: u64,
+    post_swap_action: Action,
+    exact_out: bool,
+    callback_address: Option<&Addr>,
+) -> ContractResult<(Response, Coin)> {
+    // Error if the post swap action type is paused
+    assert_action_not_paused(deps.storage, (&post_swap_action).into())?;
+
+    // Create a response object to return
+    let response: Response = Response::new().add_attribute("action", "execute_post_swap_action");
 
     // Error if the contract balance is less than the min out coin amount
     if transfer_out_coin.amount < min_coin.amount {
@@ -383,42 +1049,206 @@ pub fn execute_post_swap_action(
         transfer_out_coin
     };
 
-    match post_swap_action {
-        Action::BankSend { to_address } => {
-            // Error if the destination address is not a valid address on the current chain
-            deps.api.addr_validate(&to_address)?;
-
-            // Create the bank send message
-            let bank_send_msg = BankMsg::Send {
-                to_address,
-                amount: vec![transfer_out_coin],
-            };
+    // Add the post swap action messages to the response, a sequence sending the
+    // callback from its last step if there is a callback address
+    let response = add_post_swap_action_msgs(
+        deps,
+        env,
+        response,
+        post_swap_action,
+        transfer_out_coin.clone(),
+        timeout_timestamp,
+        callback_address,
+        callback_address.is_some(),
+    )?;
 
-            // Add the bank send message to the response
-            response = response
-                .add_message(bank_send_msg)
-                .add_attribute("action", "dispatch_post_swap_bank_send");
-        }
-        Action::IbcTransfer { ibc_info, .. } => {
-            // Validates recover address, errors if invalid
-            deps.api.addr_validate(&ibc_info.recover_address)?;
+    Ok((response, transfer_out_coin))
+}
 
-            // Create the IBC transfer message
-            let ibc_transfer_msg: IbcTransferExecuteMsg = IbcTransfer {
+// Dispatches the next steps of a Sequence post swap action with the coin received from
+// the previous step, found by the balance change of the min coin denom since the previous
+// step was dispatched. If send callback is true, the step dispatching the last step sends
+// the SkipCallback with the coin the last step is performed with.
+// Can only be called by the contract itself
+#[allow(clippy::too_many_arguments)]
+pub fn execute_post_swap_action_step(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    min_coin: Coin,
+    pre_balance: Uint128,
+    timeout_timestamp: u64,
+    steps: Vec<SequenceStep>,
+    callback_address: Option<Addr>,
+    send_callback: bool,
+) -> ContractResult<Response> {
+    // Enforce the caller is the contract itself
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Error if sequence post swap actions are paused
+    assert_action_not_paused(deps.storage, ActionType::Sequence)?;
+
+    // Get the coin received from the previous step by the contract's balance change
+    let balance = query_balance(&deps.querier, &env.contract.address, &min_coin.denom)?;
+    let coin_in = Coin {
+        denom: min_coin.denom,
+        amount: balance.amount.saturating_sub(pre_balance),
+    };
+
+    // Error if the coin received from the previous step is less than the min coin amount
+    if coin_in.amount < min_coin.amount {
+        return Err(ContractError::ReceivedLessCoinFromSequenceStepThanMinCoin);
+    }
+
+    // Create a response object to return
+    let response: Response =
+        Response::new().add_attribute("action", "execute_post_swap_action_step");
+
+    // Get whether the last step is dispatched now, and the callback status of the steps
+    let is_last_step = steps.len() == 1;
+    let sequence = Action::Sequence { steps };
+    let callback_status = callback_status(&sequence);
+
+    // Add the next steps' messages to the response
+    let mut response = add_post_swap_action_msgs(
+        deps.as_ref(),
+        &env,
+        response,
+        sequence,
+        coin_in.clone(),
+        timeout_timestamp,
+        callback_address.as_ref(),
+        send_callback,
+    )?;
+
+    // Send the callback after the last step, so it runs after the last step completes.
+    // The callback replies on error, so a failing callback is ignored.
+    if let Some(callback_address) = callback_address.filter(|_| send_callback && is_last_step) {
+        let callback_msg = SkipCallback {
+            status: callback_status,
+            amount_out: coin_in,
+            ack_id: None,
+        }
+        .into_sub_msg(callback_address.to_string(), CALLBACK_REPLY_ID)?;
+
+        response = response
+            .add_submessage(callback_msg)
+            .add_attribute("action", "dispatch_skip_callback");
+    }
+
+    Ok(response)
+}
+
+// Sends the share tokens minted to the contract by tokenizing a Delegate post swap action's
+// delegation to the delegator recipient. The share tokens are found by the balance changes of
+// the validator's share token denoms since before the delegation was tokenized.
+// Can only be called by the contract itself
+pub fn execute_transfer_delegation_shares(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    validator: String,
+    pre_balances: Vec<Coin>,
+    delegator_recipient: String,
+) -> ContractResult<Response> {
+    // Enforce the caller is the contract itself
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Get the share tokens minted by the balance changes of the validator's share token denoms
+    let shares: Vec<Coin> = query_delegation_share_balances(deps.as_ref(), &env, &validator)?
+        .into_iter()
+        .filter_map(|balance| {
+            let pre_balance = pre_balances
+                .iter()
+                .find(|pre_balance| pre_balance.denom == balance.denom)
+                .map_or(Uint128::zero(), |pre_balance| pre_balance.amount);
+
+            let amount = balance.amount.saturating_sub(pre_balance);
+            (!amount.is_zero()).then_some(Coin {
+                denom: balance.denom,
+                amount,
+            })
+        })
+        .collect();
+
+    // Error if no share tokens were minted
+    if shares.is_empty() {
+        return Err(ContractError::DelegateNoSharesTokenized);
+    }
+
+    // Create the bank send message sending the share tokens to the delegator recipient
+    let bank_send_msg = BankMsg::Send {
+        to_address: delegator_recipient,
+        amount: shares,
+    };
+
+    Ok(Response::new()
+        .add_message(bank_send_msg)
+        .add_attribute("action", "execute_transfer_delegation_shares"))
+}
+
+// Adds the messages dispatching the post swap action with the given coin to the response.
+// The callback address is passed on to the ibc transfer adapters for ibc transfers, and
+// if send callback is true, a sequence sends the SkipCallback from its last step.
+#[allow(clippy::too_many_arguments)]
+fn add_post_swap_action_msgs(
+    deps: Deps,
+    env: &Env,
+    mut response: Response,
+    post_swap_action: Action,
+    coin: Coin,
+    timeout_timestamp: u64,
+    callback_address: Option<&Addr>,
+    send_callback: bool,
+) -> ContractResult<Response> {
+    match post_swap_action {
+        Action::BankSend { to_address } => {
+            // Error if the destination address is not a valid address on the current chain
+            deps.api.addr_validate(&to_address)?;
+
+            // Create the bank send message, which is a CW20 transfer if the transfer out coin is a CW20
+            let bank_send_msg = transfer_msg(to_address, coin.clone())?;
+
+            // Add the bank send message to the response
+            response = response
+                .add_message(bank_send_msg)
+                .add_attribute("action", "dispatch_post_swap_bank_send");
+        }
+        Action::IbcTransfer {
+            ibc_info,
+            ibc_transfer_adapter,
+            ..
+        } => {
+            // Error if the transfer out coin is a CW20, which cannot be ICS-20 transferred
+            if cw20_contract_address(&coin.denom).is_some() {
+                return Err(ContractError::Cw20IbcTransferNotSupported);
+            }
+
+            // Validates recover address, errors if invalid
+            deps.api.addr_validate(&ibc_info.recover_address)?;
+
+            // Create the IBC transfer message
+            let ibc_transfer_msg: IbcTransferExecuteMsg = IbcTransfer {
                 info: ibc_info,
-                coin: transfer_out_coin.clone(),
+                coin: coin.clone(),
                 timeout_timestamp,
+                callback_address: callback_address.map(Addr::to_string),
             }
             .into();
 
             // Get the IBC transfer adapter contract address
-            let ibc_transfer_contract_address = IBC_TRANSFER_CONTRACT_ADDRESS.load(deps.storage)?;
+            let ibc_transfer_contract_address =
+                load_ibc_transfer_adapter(deps.storage, ibc_transfer_adapter.as_deref())?;
 
             // Send the IBC transfer by calling the IBC transfer contract
             let ibc_transfer_msg = WasmMsg::Execute {
                 contract_addr: ibc_transfer_contract_address.to_string(),
                 msg: to_binary(&ibc_transfer_msg)?,
-                funds: vec![transfer_out_coin],
+                funds: vec![coin.clone()],
             };
 
             // Add the IBC transfer message to the response
@@ -429,6 +1259,7 @@ pub fn execute_post_swap_action(
         Action::ContractCall {
             contract_address,
             msg,
+            msg_templates,
         } => {
             // Verify the contract address is valid, error if invalid
             let checked_contract_address = deps.api.addr_validate(&contract_address)?;
@@ -438,37 +1269,382 @@ pub fn execute_post_swap_action(
                 return Err(ContractError::ContractCallAddressBlocked);
             }
 
-            // Create the contract call message
-            let contract_call_msg = WasmMsg::Execute {
-                contract_addr: contract_address,
-                msg,
-                funds: vec![transfer_out_coin],
+            // Substitute the coin's amount and denom into the msg's template paths, if any
+            let msg = match msg_templates {
+                Some(msg_templates) => apply_msg_templates(&msg, &msg_templates, &coin)?,
+                None => msg,
             };
 
+            // Create the contract call message, which is a CW20 send
+            // to the contract address if the transfer out coin is a CW20
+            let contract_call_msg = execute_msg_with_coin(contract_address, msg, coin.clone())?;
+
             // Add the contract call message to the response
             response = response
                 .add_message(contract_call_msg)
                 .add_attribute("action", "dispatch_post_swap_contract_call");
         }
+        Action::Multi {
+            legs,
+            remainder_address,
+        } => {
+            // Validate the multi action legs and the remainder address
+            validate_multi_action_legs(&legs)?;
+            deps.api.addr_validate(&remainder_address)?;
+
+            // Split the coin across the legs, getting the remainder left after the legs are paid
+            let (leg_coins, remainder_coin) = split_multi_action_coin(&legs, &coin)?;
+
+            // Add each leg's action messages to the response
+            for (leg, leg_coin) in legs.into_iter().zip(leg_coins) {
+                // Error if the leg's post swap action type is paused
+                assert_action_not_paused(deps.storage, (&leg.action).into())?;
+
+                // Skip the leg if its share rounds down to zero, as zero coins cannot be sent
+                if leg_coin.amount.is_zero() {
+                    continue;
+                }
+
+                response = add_post_swap_action_msgs(
+                    deps,
+                    env,
+                    response,
+                    leg.action,
+                    leg_coin,
+                    timeout_timestamp,
+                    callback_address,
+                    false,
+                )?;
+            }
+
+            // Send the remainder to the remainder address if there is any
+            if !remainder_coin.amount.is_zero() {
+                let remainder_msg = transfer_msg(remainder_address, remainder_coin)?;
+
+                response = response
+                    .add_message(remainder_msg)
+                    .add_attribute("action", "dispatch_post_swap_multi_remainder");
+            }
+        }
+        Action::ProvideLiquidity {
+            swap_venue_name,
+            pool,
+            recipient,
+            slippage_tolerance,
+        } => {
+            // Error if the coin is a CW20, which the swap adapters cannot provide as liquidity
+            if cw20_contract_address(&coin.denom).is_some() {
+                return Err(ContractError::Cw20ProvideLiquidityNotSupported);
+            }
+
+            // Error if the swap venue is paused
+            assert_swap_venue_not_paused(deps.storage, &swap_venue_name)?;
+
+            // Validate the recipient address if one is given
+            if let Some(recipient) = &recipient {
+                deps.api.addr_validate(recipient)?;
+            }
+
+            // Get the swap adapter contract address for the swap venue
+            let swap_adapter_contract_address =
+                SWAP_VENUE_MAP.load(deps.storage, &swap_venue_name)?;
+
+            // Create the provide liquidity message, which sends the LP tokens
+            // to the recipient, or back to the contract if there is none
+            let provide_liquidity_msg = WasmMsg::Execute {
+                contract_addr: swap_adapter_contract_address.to_string(),
+                msg: to_binary(&SwapExecuteMsg::ProvideLiquidity {
+                    pool,
+                    recipient,
+                    slippage_tolerance,
+                })?,
+                funds: vec![coin.clone()],
+            };
+
+            // Add the provide liquidity message to the response
+            response = response
+                .add_message(provide_liquidity_msg)
+                .add_attribute("action", "dispatch_post_swap_provide_liquidity");
+        }
+        Action::Delegate {
+            validator,
+            delegator_recipient,
+        } => {
+            // Error if delegate actions are not enabled
+            assert_delegate_enabled(deps.storage)?;
+
+            // Error if the coin is not the chain's bond denom
+            if coin.denom != deps.querier.query_bonded_denom()? {
+                return Err(ContractError::DelegateCoinNotBondDenom);
+            }
+
+            // Error if the validator does not exist
+            if deps.querier.query_validator(&validator)?.is_none() {
+                return Err(ContractError::DelegateValidatorNotFound(validator));
+            }
+
+            // Validate the delegator recipient address
+            deps.api.addr_validate(&delegator_recipient)?;
+
+            // Create the delegate message, delegating the coin as the contract
+            let delegate_msg = StakingMsg::Delegate {
+                validator: validator.clone(),
+                amount: coin.clone(),
+            };
+
+            // Create the tokenize shares message, which tokenizes the contract's delegation
+            // into liquid staking share tokens minted to the contract, with the delegator
+            // recipient as the owner of the tokenize share record
+            let tokenize_shares_msg = MsgTokenizeShares {
+                delegator_address: env.contract.address.to_string(),
+                validator_address: validator.clone(),
+                amount: Some(ProtoCoin(coin.clone()).into()),
+                tokenized_share_owner: delegator_recipient.clone(),
+            };
+
+            // Get the contract's balances of the validator's share token denoms before the
+            // delegation is tokenized, so the share tokens minted are found by balance change
+            let pre_balances = query_delegation_share_balances(deps, env, &validator)?;
+
+            // Create the transfer delegation shares message, which sends the share tokens
+            // minted to the contract on to the delegator recipient
+            let transfer_delegation_shares_msg = WasmMsg::Execute {
+                contract_addr: env.contract.address.to_string(),
+                msg: to_binary(&ExecuteMsg::TransferDelegationShares {
+                    validator,
+                    pre_balances,
+                    delegator_recipient,
+                })?,
+                funds: vec![],
+            };
+
+            // Add the delegate, tokenize shares and transfer delegation shares messages to the response
+            response = response
+                .add_message(delegate_msg)
+                .add_message(CosmosMsg::from(tokenize_shares_msg))
+                .add_message(transfer_delegation_shares_msg)
+                .add_attribute("action", "dispatch_post_swap_delegate");
+        }
+        Action::Sequence { steps } => {
+            // Validate the sequence steps
+            validate_sequence_steps(&steps)?;
+
+            // Get the step to run now and the steps after it
+            let mut steps = steps.into_iter();
+            let Some(step) = steps.next() else {
+                return Err(ContractError::SequenceStepsEmpty);
+            };
+
+            // Error if the step's post swap action type is paused
+            assert_action_not_paused(deps.storage, (&step.action).into())?;
+
+            // Add the step's action messages to the response
+            response = add_post_swap_action_msgs(
+                deps,
+                env,
+                response,
+                step.action,
+                coin.clone(),
+                timeout_timestamp,
+                callback_address,
+                false,
+            )?;
+
+            // Dispatch the next steps with the coin received from the step if there are any
+            if let Some(min_coin_out) = step.min_coin_out {
+                // Get the contract balance of the min coin out denom before the step
+                // is dispatched, excluding the coin the step sends out
+                let mut pre_balance =
+                    query_balance(&deps.querier, &env.contract.address, &min_coin_out.denom)?
+                        .amount;
+                if min_coin_out.denom == coin.denom {
+                    pre_balance = pre_balance.saturating_sub(coin.amount);
+                }
+
+                // Create the post swap action step message
+                let post_swap_action_step_msg = WasmMsg::Execute {
+                    contract_addr: env.contract.address.to_string(),
+                    msg: to_binary(&ExecuteMsg::PostSwapActionStep {
+                        min_coin: min_coin_out,
+                        pre_balance,
+                        timeout_timestamp,
+                        steps: steps.collect(),
+                        callback_address: callback_address.cloned(),
+                        send_callback,
+                    })?,
+                    funds: vec![],
+                };
+
+                // Add the post swap action step message to the response
+                response = response
+                    .add_message(post_swap_action_step_msg)
+                    .add_attribute("action", "dispatch_post_swap_action_step");
+            }
+        }
     };
 
     Ok(response)
 }
 
+// Opts the caller in to (or out of) accruing its affiliate fees in the contract instead of
+// having them sent on every swap
+pub fn execute_set_affiliate_fee_accrual(
+    deps: DepsMut,
+    info: MessageInfo,
+    accrue: bool,
+) -> ContractResult<Response> {
+    if accrue {
+        AFFILIATE_FEE_ACCRUAL.save(deps.storage, &info.sender, &())?;
+    } else {
+        AFFILIATE_FEE_ACCRUAL.remove(deps.storage, &info.sender);
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_affiliate_fee_accrual")
+        .add_attribute("address", info.sender)
+        .add_attribute("accrue", accrue.to_string()))
+}
+
+// Claims the affiliate fees of the denom accrued to the caller, sending them to an address on
+// the current chain or IBC transferring them through an ibc transfer adapter
+pub fn execute_claim_affiliate_fees(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    destination: ClaimDestination,
+) -> ContractResult<Response> {
+    // Get the affiliate fees accrued to the caller, error if there are none
+    let amount = ACCRUED_AFFILIATE_FEES
+        .may_load(deps.storage, (&info.sender, &denom))?
+        .unwrap_or_default();
+    if amount.is_zero() {
+        return Err(ContractError::NoAccruedAffiliateFees(denom));
+    }
+
+    // Remove the claimed fees from the caller's and the total accrued affiliate fees
+    ACCRUED_AFFILIATE_FEES.remove(deps.storage, (&info.sender, &denom));
+    let total_accrued_amount = TOTAL_ACCRUED_AFFILIATE_FEES
+        .load(deps.storage, &denom)?
+        .checked_sub(amount)?;
+    if total_accrued_amount.is_zero() {
+        TOTAL_ACCRUED_AFFILIATE_FEES.remove(deps.storage, &denom);
+    } else {
+        TOTAL_ACCRUED_AFFILIATE_FEES.save(deps.storage, &denom, &total_accrued_amount)?;
+    }
+
+    // Create the claimed coin and a response object to return
+    let mut coin = Coin { denom, amount };
+    let mut response: Response = Response::new()
+        .add_attribute("action", "claim_affiliate_fees")
+        .add_attribute("address", &info.sender)
+        .add_attribute("amount", coin.to_string());
+
+    match destination {
+        ClaimDestination::Local { to_address } => {
+            // Error if the destination address is not a valid address on the current chain
+            deps.api.addr_validate(&to_address)?;
+
+            // Create the bank send message, which is a CW20 transfer if the claimed coin is a CW20
+            let bank_send_msg = transfer_msg(to_address, coin)?;
+
+            response = response
+                .add_message(bank_send_msg)
+                .add_attribute("action", "dispatch_claim_bank_send");
+        }
+        ClaimDestination::Ibc {
+            ibc_info,
+            ibc_transfer_adapter,
+            timeout_timestamp,
+        } => {
+            // Error if the current block time is greater than the timeout timestamp
+            if env.block.time.nanos() > timeout_timestamp {
+                return Err(ContractError::Timeout);
+            }
+
+            // Error if the claimed coin is a CW20, which cannot be ICS-20 transferred
+            if cw20_contract_address(&coin.denom).is_some() {
+                return Err(ContractError::Cw20IbcTransferNotSupported);
+            }
+
+            // Validate the recover address and the memo
+            deps.api.addr_validate(&ibc_info.recover_address)?;
+            validate_memo(&ibc_info.memo)?;
+
+            // Get the IBC transfer adapter contract address
+            let ibc_transfer_contract_address =
+                load_ibc_transfer_adapter(deps.storage, ibc_transfer_adapter.as_deref())?;
+
+            // Deduct the ibc fee from the claimed coin and send it to the ibc transfer adapter
+            if let Some(ibc_fee_coin) = ibc_info
+                .fee
+                .as_ref()
+                .map(|fee| fee.one_coin())
+                .transpose()?
+            {
+                if coin.denom != ibc_fee_coin.denom {
+                    return Err(ContractError::IBCFeeDenomDiffersFromCoinReceived);
+                }
+
+                coin.amount = coin.amount.checked_sub(ibc_fee_coin.amount)?;
+
+                response = response
+                    .add_message(BankMsg::Send {
+                        to_address: ibc_transfer_contract_address.to_string(),
+                        amount: vec![ibc_fee_coin],
+                    })
+                    .add_attribute("action", "dispatch_ibc_fee_bank_send");
+            }
+
+            // Create the IBC transfer message
+            let ibc_transfer_msg: IbcTransferExecuteMsg = IbcTransfer {
+                info: ibc_info,
+                coin: coin.clone(),
+                timeout_timestamp,
+                callback_address: None,
+            }
+            .into();
+
+            // Send the IBC transfer by calling the IBC transfer contract
+            let ibc_transfer_msg = WasmMsg::Execute {
+                contract_addr: ibc_transfer_contract_address.to_string(),
+                msg: to_binary(&ibc_transfer_msg)?,
+                funds: vec![coin],
+            };
+
+            response = response
+                .add_message(ibc_transfer_msg)
+                .add_attribute("action", "dispatch_claim_ibc_transfer");
+        }
+    }
+
+    Ok(response)
+}
+
 ////////////////////////
 /// HELPER FUNCTIONS ///
 ////////////////////////
 
+// Returns whether the user swap is an exact out swap
+pub(crate) fn is_exact_out(swap: &Swap) -> bool {
+    match swap {
+        Swap::SwapExactCoinIn(_) | Swap::SplitSwapExactCoinIn(_) | Swap::BestSwapExactCoinIn(_) => {
+            false
+        }
+        Swap::SwapExactCoinOut(_) => true,
+    }
+}
+
 // SWAP MESSAGE HELPER FUNCTIONS
 
-// Creates the fee swap message and returns it
+// Creates the fee swap message and returns it with the fee swap coin in
 // Also deducts the fee swap in amount from the mutable user swap coin
 fn verify_and_create_fee_swap_msg(
-    deps: &DepsMut,
+    deps: Deps,
     fee_swap: &SwapExactCoinOut,
     remaining_coin: &mut Coin,
     ibc_fee_coin: &Coin,
-) -> ContractResult<WasmMsg> {
+) -> ContractResult<(CosmosMsg, Coin)> {
     // Validate swap operations
     validate_swap_operations(
         &fee_swap.operations,
@@ -480,6 +1656,9 @@ fn verify_and_create_fee_swap_msg(
     let fee_swap_adapter_contract_address =
         SWAP_VENUE_MAP.load(deps.storage, &fee_swap.swap_venue_name)?;
 
+    // Error if the swap venue is paused
+    assert_swap_venue_not_paused(deps.storage, &fee_swap.swap_venue_name)?;
+
     // Query the swap adapter to get the coin in needed for the fee swap
     let fee_swap_coin_in = query_swap_coin_in(
         deps,
@@ -497,38 +1676,611 @@ fn verify_and_create_fee_swap_msg(
     // Error if swap requires more than the swappable coin amount
     remaining_coin.amount = remaining_coin.amount.checked_sub(fee_swap_coin_in.amount)?;
 
-    // Create the fee swap message args
-    let fee_swap_msg_args: SwapExecuteMsg = fee_swap.clone().into();
+    // Create the fee swap message args, swapping at most the simulated coin in for the ibc fee coin
+    let fee_swap_msg_args = SwapExecuteMsg::SwapExactCoinOut {
+        operations: fee_swap.operations.clone(),
+        coin_out: ibc_fee_coin.clone(),
+    };
 
     // Create the fee swap message
-    let fee_swap_msg = WasmMsg::Execute {
-        contract_addr: fee_swap_adapter_contract_address.to_string(),
-        msg: to_binary(&fee_swap_msg_args)?,
-        funds: vec![fee_swap_coin_in],
+    let fee_swap_msg = execute_msg_with_coin(
+        fee_swap_adapter_contract_address.to_string(),
+        to_binary(&fee_swap_msg_args)?,
+        fee_swap_coin_in.clone(),
+    )?;
+
+    Ok((fee_swap_msg, fee_swap_coin_in))
+}
+
+// Splits the coin in across the split swap legs by their shares, rounding each
+// leg down and giving the rounding remainder to the last leg
+fn split_swap_coin_in(legs: &[SplitSwapLeg], coin_in: &Coin) -> ContractResult<Vec<Coin>> {
+    let mut remaining_amount = coin_in.amount;
+
+    let mut leg_coins_in = Vec::with_capacity(legs.len());
+    for (i, leg) in legs.iter().enumerate() {
+        let leg_amount = if i == legs.len() - 1 {
+            remaining_amount
+        } else {
+            coin_in.amount * leg.share
+        };
+
+        remaining_amount = remaining_amount.checked_sub(leg_amount)?;
+
+        leg_coins_in.push(Coin {
+            denom: coin_in.denom.clone(),
+            amount: leg_amount,
+        });
+    }
+
+    Ok(leg_coins_in)
+}
+
+// PRE SWAP ACTION HELPER FUNCTIONS
+
+// Creates the message dispatching the pre swap action with the remaining coin, validating the
+// user swap and the other asset swap, and returns the swaps of the assets it withdraws
+pub(crate) fn pre_swap_action_msgs(
+    deps: Deps,
+    pre_swap_action: PreSwapAction,
+    user_swap: Swap,
+    remaining_coin: Coin,
+    min_coin: &Coin,
+) -> ContractResult<(CosmosMsg, Vec<SwapExactCoinIn>)> {
+    let PreSwapAction::WithdrawLiquidity {
+        swap_venue_name,
+        pool,
+        other_asset_swap,
+    } = pre_swap_action;
+
+    // Error if the user swap is not a swap exact coin in, as the withdrawn amounts are not known
+    let Swap::SwapExactCoinIn(user_swap) = user_swap else {
+        return Err(ContractError::PreSwapActionUserSwapNotExactCoinIn);
     };
 
-    Ok(fee_swap_msg)
+    // Get the denom out of the pre swap action that each swap swaps, which is the
+    // min coin denom for a swap without swap operations, validating the swap operations
+    let mut denoms_out: Vec<String> = vec![];
+    let mut swaps: Vec<SwapExactCoinIn> = vec![];
+    for swap in [user_swap, other_asset_swap] {
+        let Some(first_op) = swap.operations.first() else {
+            denoms_out.push(min_coin.denom.clone());
+            continue;
+        };
+        let denom_in = first_op.denom_in.clone();
+        validate_swap_operations(&swap.operations, &denom_in, &min_coin.denom)?;
+
+        denoms_out.push(denom_in);
+        swaps.push(swap);
+    }
+
+    // Error if both swaps swap the same withdrawn asset
+    if denoms_out[0] == denoms_out[1] {
+        return Err(ContractError::WithdrawnAssetSwapsSameDenom);
+    }
+
+    // Get swap adapter contract address from venue name
+    let swap_adapter_contract_address = SWAP_VENUE_MAP.load(deps.storage, &swap_venue_name)?;
+
+    // Create the withdraw liquidity message, which sends both withdrawn assets back to the contract
+    let pre_swap_action_msg = execute_msg_with_coin(
+        swap_adapter_contract_address.to_string(),
+        to_binary(&SwapExecuteMsg::WithdrawLiquidity { pool, denoms_out })?,
+        remaining_coin,
+    )?;
+
+    Ok((pre_swap_action_msg, swaps))
+}
+
+// POST SWAP ACTION HELPER FUNCTIONS
+
+// Returns the contract's balances of the validator's liquid staking share token denoms,
+// which are named "{validator}/{tokenize share record id}"
+fn query_delegation_share_balances(
+    deps: Deps,
+    env: &Env,
+    validator: &str,
+) -> ContractResult<Vec<Coin>> {
+    let share_denom_prefix = format!("{}/", validator);
+
+    Ok(deps
+        .querier
+        .query_all_balances(&env.contract.address)?
+        .into_iter()
+        .filter(|balance| balance.denom.starts_with(&share_denom_prefix))
+        .collect())
+}
+
+// Returns the status of the SkipCallback sent for the post swap action, which is pending
+// if it includes ibc transfers, as they send their own callbacks once they complete
+fn callback_status(post_swap_action: &Action) -> CallbackStatus {
+    if post_swap_actions(post_swap_action)
+        .into_iter()
+        .any(|action| matches!(action, Action::IbcTransfer { .. }))
+    {
+        CallbackStatus::Pending
+    } else {
+        CallbackStatus::Success
+    }
+}
+
+// Returns the actions dispatched by the post swap action, which are the legs' actions
+// of a Multi action, the steps' actions of a Sequence action, or else the post swap action itself
+fn post_swap_actions(post_swap_action: &Action) -> Vec<&Action> {
+    match post_swap_action {
+        Action::Multi { legs, .. } => legs.iter().map(|leg| &leg.action).collect(),
+        Action::Sequence { steps } => steps
+            .iter()
+            .flat_map(|step| post_swap_actions(&step.action))
+            .collect(),
+        action => vec![action],
+    }
+}
+
+// Validates the multi action legs or sequence steps of the post swap action
+fn validate_post_swap_action(post_swap_action: &Action) -> ContractResult<()> {
+    match post_swap_action {
+        Action::Multi { legs, .. } => validate_multi_action_legs(legs),
+        Action::Sequence { steps } => validate_sequence_steps(steps),
+        Action::ProvideLiquidity {
+            recipient: None, ..
+        } => Err(ContractError::ProvideLiquidityNoRecipient),
+        _ => Ok(()),
+    }
+}
+
+// Validates the sequence steps are not empty or nested sequence actions, every step before
+// the last is a contract call or a provide liquidity action without a recipient with a min
+// coin out, and the last step has no min coin out
+fn validate_sequence_steps(steps: &[SequenceStep]) -> ContractResult<()> {
+    // Verify the sequence steps are not empty
+    let Some((last_step, intermediate_steps)) = steps.split_last() else {
+        return Err(ContractError::SequenceStepsEmpty);
+    };
+
+    // Verify the steps before the last are contract calls or provide liquidity actions without
+    // a recipient with a min coin out, as their coin out must be received by the contract to
+    // run the next step
+    for step in intermediate_steps {
+        let returns_coin_out = matches!(
+            step.action,
+            Action::ContractCall { .. }
+                | Action::ProvideLiquidity {
+                    recipient: None,
+                    ..
+                }
+        );
+        if !returns_coin_out || step.min_coin_out.is_none() {
+            return Err(ContractError::SequenceIntermediateStepInvalid);
+        }
+    }
+
+    // Verify the last step has no min coin out, as there is no next step to enforce it
+    if last_step.min_coin_out.is_some() {
+        return Err(ContractError::SequenceLastStepMinCoinOut);
+    }
+
+    // Verify the last step is not a sequence or provide liquidity action without
+    // a recipient, and validate it if it is a multi action
+    match &last_step.action {
+        Action::Sequence { .. } => Err(ContractError::SequenceNested),
+        Action::ProvideLiquidity {
+            recipient: None, ..
+        } => Err(ContractError::ProvideLiquidityNoRecipient),
+        Action::Multi { legs, .. } => validate_multi_action_legs(legs),
+        _ => Ok(()),
+    }
+}
+
+// Validates the multi action legs are not empty or nested multi actions,
+// their amounts are not zero, and their shares do not sum to more than one
+fn validate_multi_action_legs(legs: &[MultiActionLeg]) -> ContractResult<()> {
+    // Verify the multi action legs are not empty
+    if legs.is_empty() {
+        return Err(ContractError::MultiActionLegsEmpty);
+    }
+
+    let mut total_share = Decimal::zero();
+    for leg in legs {
+        // Verify the leg's action is not a multi or sequence action
+        if let Action::Multi { .. } | Action::Sequence { .. } = leg.action {
+            return Err(ContractError::MultiActionNested);
+        }
+
+        // Verify the leg's action is not a provide liquidity action without a
+        // recipient, whose LP tokens can only be sent out by a following sequence step
+        if let Action::ProvideLiquidity {
+            recipient: None, ..
+        } = leg.action
+        {
+            return Err(ContractError::ProvideLiquidityNoRecipient);
+        }
+
+        // Verify the leg's amount is not zero
+        let amount_is_zero = match leg.amount {
+            MultiActionAmount::Share(share) => {
+                total_share = total_share.checked_add(share)?;
+                share.is_zero()
+            }
+            MultiActionAmount::Fixed(amount) => amount.is_zero(),
+        };
+        if amount_is_zero {
+            return Err(ContractError::MultiActionLegAmountZero);
+        }
+    }
+
+    // Verify the leg shares do not sum to more than one
+    if total_share > Decimal::one() {
+        return Err(ContractError::MultiActionSharesGreaterThanOne);
+    }
+
+    Ok(())
+}
+
+// Splits the coin across the multi action legs, paying the fixed amount legs first and
+// each share leg its share of the amount left after the fixed amounts, rounded down.
+// Returns each leg's coin and the remainder left after all legs are paid.
+fn split_multi_action_coin(
+    legs: &[MultiActionLeg],
+    coin: &Coin,
+) -> ContractResult<(Vec<Coin>, Coin)> {
+    // Get the amount left to split by share after the fixed amounts are paid
+    // Error if the fixed amounts are more than the coin
+    let shared_amount = legs
+        .iter()
+        .try_fold(coin.amount, |amount, leg| match leg.amount {
+            MultiActionAmount::Fixed(fixed_amount) => amount.checked_sub(fixed_amount),
+            MultiActionAmount::Share(_) => Ok(amount),
+        })
+        .map_err(|_| ContractError::MultiActionFixedAmountsExceedCoin)?;
+
+    let mut remaining_amount = coin.amount;
+
+    let mut leg_coins = Vec::with_capacity(legs.len());
+    for leg in legs {
+        let leg_amount = match leg.amount {
+            MultiActionAmount::Fixed(fixed_amount) => fixed_amount,
+            MultiActionAmount::Share(share) => shared_amount.mul_floor(share),
+        };
+
+        remaining_amount = remaining_amount.checked_sub(leg_amount)?;
+
+        leg_coins.push(Coin {
+            denom: coin.denom.clone(),
+            amount: leg_amount,
+        });
+    }
+
+    Ok((
+        leg_coins,
+        Coin {
+            denom: coin.denom.clone(),
+            amount: remaining_amount,
+        },
+    ))
+}
+
+// CALLBACK HELPER FUNCTIONS
+
+// Validates the callback address, erroring if it is invalid or a blocked contract
+// address, so the callback can never call the entry point or adapter contracts
+fn validate_callback_address(deps: Deps, callback_address: &str) -> ContractResult<Addr> {
+    // Verify the callback address is valid, error if invalid
+    let checked_callback_address = deps.api.addr_validate(callback_address)?;
+
+    // Error if the callback address is in the blocked contract addresses map
+    if BLOCKED_CONTRACT_ADDRESSES.has(deps.storage, &checked_callback_address) {
+        return Err(ContractError::CallbackAddressBlocked);
+    }
+
+    Ok(checked_callback_address)
+}
+
+// IBC TRANSFER HELPER FUNCTIONS
+
+// Loads the contract address of the named ibc transfer adapter, or of the
+// default ibc transfer adapter if no name is given. Errors if the adapter does not exist.
+pub(crate) fn load_ibc_transfer_adapter(
+    storage: &dyn Storage,
+    name: Option<&str>,
+) -> ContractResult<Addr> {
+    let name = match name {
+        Some(name) => name.to_string(),
+        None => DEFAULT_IBC_TRANSFER_ADAPTER.load(storage)?,
+    };
+
+    IBC_TRANSFER_ADAPTER_MAP
+        .may_load(storage, &name)?
+        .ok_or(ContractError::IbcTransferAdapterNotFound(name))
+}
+
+// Verifies the caller is the ibc-hooks intermediate sender of the IBC recovery's original sender
+// on its channel, so the call was made through an ibc-hooks wasm memo sent by the original sender,
+// and the default IBC transfer adapter used to refund the funds exists
+fn verify_ibc_recovery(
+    deps: Deps,
+    env: &Env,
+    info: &MessageInfo,
+    ibc_recovery: &IbcRecovery,
+) -> ContractResult<()> {
+    // Derive the intermediate sender with the bech32 prefix of the chain, taken from the contract address
+    let bech32_prefix = bech32_prefix(env.contract.address.as_str())?;
+    let intermediate_sender = derive_ibc_hooks_sender(
+        &ibc_recovery.channel,
+        &ibc_recovery.original_sender,
+        &bech32_prefix,
+    )?;
+
+    // Error if the caller is not the intermediate sender
+    if info.sender != intermediate_sender {
+        return Err(ContractError::IbcRecoverySenderNotIbcHooksSender);
+    }
+
+    // Error if the default IBC transfer adapter does not exist
+    load_ibc_transfer_adapter(deps.storage, None)?;
+
+    // Error if the recovery transfer would time out immediately
+    if ibc_recovery.timeout_seconds == Some(0) {
+        return Err(ContractError::IbcRecoveryTimeoutZero);
+    }
+
+    // Error if the IBC fee of the recovery transfer cannot be paid out of the funds sent,
+    // which must be a single coin of the fee denom that is more than the fee
+    if let Some(fee) = &ibc_recovery.fee {
+        let ibc_fee_coin = fee.one_coin()?;
+        let coin = one_coin(info)?;
+
+        if coin.denom != ibc_fee_coin.denom {
+            return Err(ContractError::IBCFeeDenomDiffersFromCoinReceived);
+        }
+
+        if coin.amount <= ibc_fee_coin.amount {
+            return Err(ContractError::IbcRecoveryFeeNotLessThanCoinSent);
+        }
+    }
+
+    Ok(())
 }
 
 // AFFILIATE FEE HELPER FUNCTIONS
 
-// Verifies the affiliate address is valid, if so then
-// returns the calculated affiliate fee amount.
-fn verify_and_calculate_affiliate_fee_amount(
-    deps: &DepsMut,
-    min_coin: &Coin,
-    affiliate: &Affiliate,
-) -> ContractResult<Uint128> {
+// Creates the affiliate fee messages and attributes of the affiliate fees charged on the fee
+// basis coin, returning the response with the messages and attributes, the affiliate fees
+// paid, the affiliate fees of them accrued instead of sent, and the total affiliate fee amount.
+// Errors if the total affiliate basis points fee is more than the maximum total affiliate fee.
+pub(crate) fn affiliate_fee_response(
+    deps: Deps,
+    fee_basis_coin: &Coin,
+    affiliates: &[Affiliate],
+) -> ContractResult<(Response, Vec<AffiliateFee>, Vec<AffiliateFee>, Uint128)> {
+    // Create affiliate response, affiliate fees, accrued affiliate fees and total affiliate fee amount
+    let mut affiliate_response: Response = Response::new();
+    let mut affiliate_fees: Vec<AffiliateFee> = vec![];
+    let mut accrued_affiliate_fees: Vec<AffiliateFee> = vec![];
+    let mut total_affiliate_fee_amount: Uint128 = Uint128::zero();
+
+    // Resolve the affiliates' addresses and basis points fees
+    let affiliates = affiliates
+        .iter()
+        .map(|affiliate| resolve_affiliate(deps, affiliate))
+        .collect::<ContractResult<Vec<_>>>()?;
+
+    // Error if the total affiliate basis points fee is more than the maximum total affiliate fee
+    let max_total_affiliate_basis_points_fee = MAX_TOTAL_AFFILIATE_FEE
+        .may_load(deps.storage)?
+        .unwrap_or(MAX_TOTAL_AFFILIATE_BASIS_POINTS_FEE);
+    let total_affiliate_basis_points_fee = affiliates
+        .iter()
+        .try_fold(Uint128::zero(), |total, (_, basis_points_fee)| {
+            total.checked_add(*basis_points_fee)
+        })?;
+    if total_affiliate_basis_points_fee > max_total_affiliate_basis_points_fee {
+        return Err(ContractError::TotalAffiliateFeeTooHigh(
+            max_total_affiliate_basis_points_fee,
+        ));
+    }
+
+    // If affiliates exist, create the affiliate fee messages and attributes and
+    // add them to the affiliate response, updating the total affiliate fee amount
+    for (affiliate_address, basis_points_fee) in affiliates {
+        // Get the affiliate fee amount by multiplying the fee basis
+        // coin amount by the affiliate basis points fee divided by 10000
+        let affiliate_fee_amount = fee_basis_coin
+            .amount
+            .multiply_ratio(basis_points_fee, Uint128::new(10000));
+
+        if affiliate_fee_amount > Uint128::zero() {
+            // Add the affiliate fee amount to the total affiliate fee amount
+            total_affiliate_fee_amount =
+                total_affiliate_fee_amount.checked_add(affiliate_fee_amount)?;
+
+            // Create the affiliate fee coin
+            let affiliate_fee_coin = Coin {
+                denom: fee_basis_coin.denom.clone(),
+                amount: affiliate_fee_amount,
+            };
+
+            // Add the affiliate fee to the affiliate fees paid
+            let affiliate_fee = AffiliateFee {
+                address: affiliate_address.to_string(),
+                coin: affiliate_fee_coin.clone(),
+            };
+            affiliate_fees.push(affiliate_fee.clone());
+
+            // Accrue the affiliate fee if the affiliate opted in to accruing its fees
+            if AFFILIATE_FEE_ACCRUAL.has(deps.storage, &affiliate_address) {
+                accrued_affiliate_fees.push(affiliate_fee);
+
+                affiliate_response = affiliate_response
+                    .add_attribute("action", "accrue_affiliate_fee")
+                    .add_attribute("address", affiliate_address)
+                    .add_attribute("amount", affiliate_fee_amount);
+
+                continue;
+            }
+
+            // Create the affiliate fee bank send (or CW20 transfer) message
+            let affiliate_fee_msg =
+                transfer_msg(affiliate_address.to_string(), affiliate_fee_coin)?;
+
+            // Add the affiliate fee message and attributes to the response
+            affiliate_response = affiliate_response
+                .add_message(affiliate_fee_msg)
+                .add_attribute("action", "dispatch_affiliate_fee_bank_send")
+                .add_attribute("address", affiliate_address)
+                .add_attribute("amount", affiliate_fee_amount);
+        }
+    }
+
+    Ok((
+        affiliate_response,
+        affiliate_fees,
+        accrued_affiliate_fees,
+        total_affiliate_fee_amount,
+    ))
+}
+
+// Splits the affiliates into the ones charged on the min coin by the user swap, and the ones
+// charged on the actual coin out by the post swap action, by the affiliate fee basis given.
+// Errors if the affiliates are charged on the actual coin out of an exact out user swap.
+pub(crate) fn split_affiliates_by_fee_basis(
+    affiliates: Vec<Affiliate>,
+    affiliate_fee_basis: Option<AffiliateFeeBasis>,
+    exact_out: bool,
+) -> ContractResult<(Vec<Affiliate>, Vec<Affiliate>)> {
+    match affiliate_fee_basis {
+        Some(AffiliateFeeBasis::ActualOut) => {
+            // Error if the user swap is exact out, as its coin out is the min coin plus the fees
+            if exact_out {
+                return Err(ContractError::ActualOutAffiliateFeeBasisExactOut);
+            }
+
+            Ok((vec![], affiliates))
+        }
+        Some(AffiliateFeeBasis::MinCoin) | None => Ok((affiliates, vec![])),
+    }
+}
+
+// Credits the accrued affiliate fees to the affiliates' and the total accrued affiliate fees
+fn accrue_affiliate_fees(
+    storage: &mut dyn Storage,
+    accrued_affiliate_fees: &[AffiliateFee],
+) -> ContractResult<()> {
+    for affiliate_fee in accrued_affiliate_fees {
+        let address = Addr::unchecked(&affiliate_fee.address);
+        let coin = &affiliate_fee.coin;
+
+        ACCRUED_AFFILIATE_FEES.update(
+            storage,
+            (&address, &coin.denom),
+            |amount| -> ContractResult<_> {
+                Ok(amount.unwrap_or_default().checked_add(coin.amount)?)
+            },
+        )?;
+        TOTAL_ACCRUED_AFFILIATE_FEES.update(
+            storage,
+            &coin.denom,
+            |amount| -> ContractResult<_> {
+                Ok(amount.unwrap_or_default().checked_add(coin.amount)?)
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
+// Returns the address and basis points fee of the affiliate. A registered affiliate's fee
+// defaults to its default fee and cannot be more than its max fee, an unregistered
+// affiliate's address must be valid and its fee must be given.
+fn resolve_affiliate(deps: Deps, affiliate: &Affiliate) -> ContractResult<(Addr, Uint128)> {
+    // Resolve the affiliate by its registered id if one is given
+    if let Some(id) = &affiliate.id {
+        // Error if the affiliate is not registered
+        let registered_affiliate = REGISTERED_AFFILIATES
+            .may_load(deps.storage, id)?
+            .ok_or_else(|| ContractError::RegisteredAffiliateNotFound(id.clone()))?;
+
+        // Error if an address is given that is not the registered affiliate's address
+        if !affiliate.address.is_empty() && affiliate.address != registered_affiliate.address {
+            return Err(ContractError::RegisteredAffiliateAddressMismatch(
+                id.clone(),
+            ));
+        }
+
+        let basis_points_fee = affiliate
+            .basis_points_fee
+            .unwrap_or(registered_affiliate.default_basis_points_fee);
+
+        // Error if the fee is more than the registered affiliate's max fee
+        if basis_points_fee > registered_affiliate.max_basis_points_fee {
+            return Err(ContractError::RegisteredAffiliateFeeTooHigh(
+                registered_affiliate.id,
+                registered_affiliate.max_basis_points_fee,
+            ));
+        }
+
+        return Ok((
+            Addr::unchecked(registered_affiliate.address),
+            basis_points_fee,
+        ));
+    }
+
     // Verify the affiliate address is valid
-    deps.api.addr_validate(&affiliate.address)?;
+    let checked_address = deps.api.addr_validate(&affiliate.address)?;
 
-    // Get the affiliate fee amount by multiplying the min_coin
-    // amount by the affiliate basis points fee divided by 10000
-    let affiliate_fee_amount = min_coin
-        .amount
-        .multiply_ratio(affiliate.basis_points_fee, Uint128::new(10000));
+    // Error if no fee is given for the unregistered affiliate
+    let basis_points_fee = affiliate
+        .basis_points_fee
+        .ok_or_else(|| ContractError::AffiliateBasisPointsFeeRequired(affiliate.address.clone()))?;
 
-    Ok(affiliate_fee_amount)
+    Ok((checked_address, basis_points_fee))
+}
+
+// PROTOCOL FEE HELPER FUNCTIONS
+
+// Creates the protocol fee message and attributes if a protocol fee is set, returning the
+// response with the message and attributes, and the protocol fee paid
+fn protocol_fee_response(deps: Deps, min_coin: &Coin) -> ContractResult<(Response, Option<Coin>)> {
+    // Return an empty response if no protocol fee is set
+    let Some(protocol_fee) = PROTOCOL_FEE.may_load(deps.storage)? else {
+        return Ok((Response::new(), None));
+    };
+
+    // Calculate the protocol fee amount, returning an empty response if it rounds down to zero
+    let protocol_fee_amount = calculate_protocol_fee_amount(min_coin, &protocol_fee);
+    if protocol_fee_amount.is_zero() {
+        return Ok((Response::new(), None));
+    }
+
+    // Create the protocol fee coin
+    let protocol_fee_coin = Coin {
+        denom: min_coin.denom.clone(),
+        amount: protocol_fee_amount,
+    };
+
+    // Create the protocol fee bank send (or CW20 transfer) message
+    let protocol_fee_msg = transfer_msg(
+        protocol_fee.collector_address.clone(),
+        protocol_fee_coin.clone(),
+    )?;
+
+    // Add the protocol fee message and attributes to the response
+    let protocol_fee_response = Response::new()
+        .add_message(protocol_fee_msg)
+        .add_attribute("action", "dispatch_protocol_fee_bank_send")
+        .add_attribute("address", protocol_fee.collector_address)
+        .add_attribute("amount", protocol_fee_amount);
+
+    Ok((protocol_fee_response, Some(protocol_fee_coin)))
+}
+
+// Returns the calculated protocol fee amount, the same way as the affiliate fee amounts
+fn calculate_protocol_fee_amount(min_coin: &Coin, protocol_fee: &ProtocolFee) -> Uint128 {
+    // Get the protocol fee amount by multiplying the min_coin
+    // amount by the protocol basis points fee divided by 10000
+    min_coin
+        .amount
+        .multiply_ratio(protocol_fee.basis_points_fee, Uint128::new(10000))
 }
 
 // QUERY HELPER FUNCTIONS
@@ -537,7 +2289,7 @@ fn verify_and_calculate_affiliate_fee_amount(
 // coin in needed for the fee swap. Verifies the fee swap in denom is the same as the
 // swap coin denom from the message. Returns the fee swap coin in.
 fn query_swap_coin_in(
-    deps: &DepsMut,
+    deps: Deps,
     swap_adapter_contract_address: &Addr,
     swap: &SwapExactCoinOut,
     swap_coin_out: &Coin,
@@ -553,3 +2305,23 @@ fn query_swap_coin_in(
 
     Ok(fee_swap_coin_in)
 }
+
+// Unexposed query helper function that queries the swap adapter contract to get the
+// coin out received from swapping the coin in through the given swap operations.
+pub(crate) fn query_swap_coin_out(
+    deps: Deps,
+    swap_adapter_contract_address: &Addr,
+    swap_operations: &[SwapOperation],
+    swap_coin_in: &Coin,
+) -> ContractResult<Coin> {
+    // Query the swap adapter to get the coin out received from the swap
+    let swap_coin_out: Coin = deps.querier.query_wasm_smart(
+        swap_adapter_contract_address,
+        &SwapQueryMsg::SimulateSwapExactCoinIn {
+            coin_in: swap_coin_in.clone(),
+            swap_operations: swap_operations.to_vec(),
+        },
+    )?;
+
+    Ok(swap_coin_out)
+}
@@ -1,6 +1,8 @@
+pub mod admin;
 pub mod contract;
 pub mod error;
 pub mod execute;
+pub mod migrate;
 pub mod query;
 pub mod reply;
 pub mod state;
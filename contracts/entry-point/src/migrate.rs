@@ -0,0 +1,64 @@
+use crate::{
+    error::{ContractError, ContractResult},
+    state::{
+        BLOCKED_CONTRACT_ADDRESSES, DEFAULT_IBC_TRANSFER_ADAPTER, IBC_TRANSFER_ADAPTER_MAP,
+        IBC_TRANSFER_CONTRACT_ADDRESS, OWNER,
+    },
+};
+use cosmwasm_std::{DepsMut, Env, Response};
+use semver::Version;
+
+// Name given to the single ibc transfer adapter of contracts deployed
+// before named ibc transfer adapters were supported.
+pub const MIGRATED_IBC_TRANSFER_ADAPTER_NAME: &str = "default";
+
+// Runs the state migrations needed to migrate from the given version to the current version
+pub fn migrate_state(deps: DepsMut, env: &Env, from_version: &Version) -> ContractResult<Response> {
+    // Create a response object to return
+    let mut response: Response = Response::new();
+
+    // v0.2.1 -> v0.3.0: Contract ownership was introduced, so the wasm
+    // admin that is migrating the contract becomes the contract owner.
+    if *from_version < Version::new(0, 3, 0) && !OWNER.exists(deps.storage) {
+        let owner = deps
+            .querier
+            .query_wasm_contract_info(&env.contract.address)?
+            .admin
+            .ok_or(ContractError::NoContractAdmin)?;
+
+        let checked_owner = deps.api.addr_validate(&owner)?;
+        OWNER.save(deps.storage, &checked_owner)?;
+
+        response = response
+            .add_attribute("action", "migrate_set_owner")
+            .add_attribute("owner", checked_owner);
+    }
+
+    // v0.2.1 -> v0.3.0: Named ibc transfer adapters were introduced, so the single
+    // ibc transfer adapter is moved into the ibc transfer adapter map and becomes
+    // the default adapter.
+    if *from_version < Version::new(0, 3, 0) {
+        if let Some(ibc_transfer_contract_address) =
+            IBC_TRANSFER_CONTRACT_ADDRESS.may_load(deps.storage)?
+        {
+            IBC_TRANSFER_ADAPTER_MAP.save(
+                deps.storage,
+                MIGRATED_IBC_TRANSFER_ADAPTER_NAME,
+                &ibc_transfer_contract_address,
+            )?;
+            DEFAULT_IBC_TRANSFER_ADAPTER.save(
+                deps.storage,
+                &MIGRATED_IBC_TRANSFER_ADAPTER_NAME.to_string(),
+            )?;
+            BLOCKED_CONTRACT_ADDRESSES.save(deps.storage, &ibc_transfer_contract_address, &())?;
+            IBC_TRANSFER_CONTRACT_ADDRESS.remove(deps.storage);
+
+            response = response
+                .add_attribute("action", "migrate_ibc_transfer_adapter")
+                .add_attribute("name", MIGRATED_IBC_TRANSFER_ADAPTER_NAME)
+                .add_attribute("contract_address", ibc_transfer_contract_address);
+        }
+    }
+
+    Ok(response)
+}
@@ -1,12 +1,499 @@
-use crate::state::{IBC_TRANSFER_CONTRACT_ADDRESS, SWAP_VENUE_MAP};
-use cosmwasm_std::{Addr, Deps, StdResult};
+use crate::{
+    error::ContractResult,
+    execute::{
+        affiliate_fee_response, is_exact_out, post_swap_action_response, pre_swap_action_msgs,
+        prepare_swap_and_action, query_swap_coin_out, split_affiliates_by_fee_basis,
+        user_swap_response, user_swap_withdrawn_assets_response, UserSwapOutcome,
+    },
+    state::{
+        ACCRUED_AFFILIATE_FEES, AFFILIATE_FEE_ACCRUAL, BLOCKED_CONTRACT_ADDRESSES,
+        DEFAULT_IBC_TRANSFER_ADAPTER, DELEGATE_ENABLED, GUARDIANS, IBC_TRANSFER_ADAPTER_MAP,
+        MAX_TOTAL_AFFILIATE_FEE, OWNER, PAUSED, PAUSED_ACTIONS, PAUSED_SWAP_VENUES, PENDING_OWNER,
+        PROTOCOL_FEE, REGISTERED_AFFILIATES, SWAP_VENUE_MAP,
+    },
+};
+use cosmwasm_std::{Addr, Coin, Deps, Env, MessageInfo, Order, Response, StdResult, Uint128};
+use cw_storage_plus::Bound;
+use skip::{
+    entry_point::{
+        Action, Affiliate, AffiliateFeeBasis, ConfigResponse, PauseStateResponse, PreSwapAction,
+        ProtocolFee, RegisteredAffiliate, SimulateSwapAndActionResponse,
+        MAX_TOTAL_AFFILIATE_BASIS_POINTS_FEE,
+    },
+    ibc::IbcTransferAdapter,
+    swap::{
+        QueryMsg as SwapQueryMsg, SimulateSwapWithMetadataResponse, Swap, SwapOperation, SwapVenue,
+    },
+};
+
+// Pagination limits for the list queries
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
 
 // Queries the swap venue map by name and returns the swap adapter contract address if it exists
 pub fn query_swap_venue_adapter_contract(deps: Deps, name: String) -> StdResult<Addr> {
     SWAP_VENUE_MAP.load(deps.storage, &name)
 }
 
-// Queries the IBC transfer adapter contract address and returns it if it exists
-pub fn query_ibc_transfer_adapter_contract(deps: Deps) -> StdResult<Addr> {
-    IBC_TRANSFER_CONTRACT_ADDRESS.load(deps.storage)
+// Queries the swap venue map, returning the swap venues ordered by name
+// starting after the given swap venue name
+pub fn query_swap_venues(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<SwapVenue>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+
+    SWAP_VENUE_MAP
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (name, adapter_contract_address) = item?;
+            Ok(SwapVenue {
+                name,
+                adapter_contract_address: adapter_contract_address.to_string(),
+            })
+        })
+        .collect()
+}
+
+// Queries the ibc transfer adapter map by name, or by the default ibc transfer adapter
+// name if no name is given, and returns the adapter contract address if it exists
+pub fn query_ibc_transfer_adapter_contract(deps: Deps, name: Option<String>) -> StdResult<Addr> {
+    let name = match name {
+        Some(name) => name,
+        None => DEFAULT_IBC_TRANSFER_ADAPTER.load(deps.storage)?,
+    };
+
+    IBC_TRANSFER_ADAPTER_MAP.load(deps.storage, &name)
+}
+
+// Queries the default ibc transfer adapter name
+pub fn query_default_ibc_transfer_adapter(deps: Deps) -> StdResult<String> {
+    DEFAULT_IBC_TRANSFER_ADAPTER.load(deps.storage)
+}
+
+// Queries whether all routes are paused, and the paused swap venues and post swap action types
+pub fn query_pause_state(deps: Deps) -> StdResult<PauseStateResponse> {
+    Ok(PauseStateResponse {
+        all: PAUSED.may_load(deps.storage)?.unwrap_or_default(),
+        swap_venues: PAUSED_SWAP_VENUES
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?,
+        actions: PAUSED_ACTIONS.may_load(deps.storage)?.unwrap_or_default(),
+    })
+}
+
+// Queries the addresses allowed to pause the contract
+pub fn query_guardians(deps: Deps) -> StdResult<Vec<Addr>> {
+    GUARDIANS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect()
+}
+
+// Queries the protocol fee, if one is set
+pub fn query_protocol_fee(deps: Deps) -> StdResult<Option<ProtocolFee>> {
+    PROTOCOL_FEE.may_load(deps.storage)
+}
+
+// Queries the registered affiliate by id
+pub fn query_registered_affiliate(deps: Deps, id: String) -> StdResult<RegisteredAffiliate> {
+    REGISTERED_AFFILIATES.load(deps.storage, &id)
+}
+
+// Queries the registered affiliates, returning the affiliates ordered by id
+// starting after the given id
+pub fn query_registered_affiliates(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<RegisteredAffiliate>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+
+    REGISTERED_AFFILIATES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| Ok(item?.1))
+        .collect()
+}
+
+// Queries the maximum total affiliate fee of a single SwapAndAction call
+pub fn query_max_total_affiliate_basis_points_fee(deps: Deps) -> StdResult<Uint128> {
+    Ok(MAX_TOTAL_AFFILIATE_FEE
+        .may_load(deps.storage)?
+        .unwrap_or(MAX_TOTAL_AFFILIATE_BASIS_POINTS_FEE))
+}
+
+// Queries whether Delegate post swap actions are enabled
+pub fn query_delegate_enabled(deps: Deps) -> StdResult<bool> {
+    Ok(DELEGATE_ENABLED.may_load(deps.storage)?.unwrap_or_default())
+}
+
+// Queries whether the affiliate fees of the address are accrued
+pub fn query_affiliate_fee_accrual(deps: Deps, address: String) -> StdResult<bool> {
+    let checked_address = deps.api.addr_validate(&address)?;
+
+    Ok(AFFILIATE_FEE_ACCRUAL.has(deps.storage, &checked_address))
+}
+
+// Queries the affiliate fees accrued to the address, returning the
+// accrued fees ordered by denom starting after the given denom
+pub fn query_accrued_affiliate_fees(
+    deps: Deps,
+    address: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Coin>> {
+    let checked_address = deps.api.addr_validate(&address)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+
+    ACCRUED_AFFILIATE_FEES
+        .prefix(&checked_address)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (denom, amount) = item?;
+            Ok(Coin { denom, amount })
+        })
+        .collect()
+}
+
+// Queries the blocked contract addresses, returning the addresses
+// in order starting after the given address
+pub fn query_blocked_contract_addresses(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Addr>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    let start = start_after.as_ref().map(Bound::exclusive);
+
+    BLOCKED_CONTRACT_ADDRESSES
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
+
+// Forwards the swap exact coin in simulation with metadata
+// to the swap adapter contract of the given swap venue
+pub fn query_simulate_swap_exact_coin_in_with_metadata(
+    deps: Deps,
+    swap_venue_name: String,
+    coin_in: Coin,
+    swap_operations: Vec<SwapOperation>,
+) -> StdResult<SimulateSwapWithMetadataResponse> {
+    // Get swap adapter contract address from swap venue name
+    let swap_adapter_contract_address = SWAP_VENUE_MAP.load(deps.storage, &swap_venue_name)?;
+
+    // Query the swap adapter contract for the simulation
+    deps.querier.query_wasm_smart(
+        swap_adapter_contract_address,
+        &SwapQueryMsg::SimulateSwapExactCoinInWithMetadata {
+            coin_in,
+            swap_operations,
+        },
+    )
+}
+
+// Forwards the swap exact coin out simulation with metadata
+// to the swap adapter contract of the given swap venue
+pub fn query_simulate_swap_exact_coin_out_with_metadata(
+    deps: Deps,
+    swap_venue_name: String,
+    coin_out: Coin,
+    swap_operations: Vec<SwapOperation>,
+) -> StdResult<SimulateSwapWithMetadataResponse> {
+    // Get swap adapter contract address from swap venue name
+    let swap_adapter_contract_address = SWAP_VENUE_MAP.load(deps.storage, &swap_venue_name)?;
+
+    // Query the swap adapter contract for the simulation
+    deps.querier.query_wasm_smart(
+        swap_adapter_contract_address,
+        &SwapQueryMsg::SimulateSwapExactCoinOutWithMetadata {
+            coin_out,
+            swap_operations,
+        },
+    )
+}
+
+// Queries all entry point settings
+pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    // Get all swap venues
+    let swap_venues = SWAP_VENUE_MAP
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (name, adapter_contract_address) = item?;
+            Ok(SwapVenue {
+                name,
+                adapter_contract_address: adapter_contract_address.to_string(),
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    // Get all ibc transfer adapters
+    let ibc_transfer_adapters = IBC_TRANSFER_ADAPTER_MAP
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (name, adapter_contract_address) = item?;
+            Ok(IbcTransferAdapter {
+                name,
+                adapter_contract_address: adapter_contract_address.to_string(),
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    Ok(ConfigResponse {
+        owner: OWNER.load(deps.storage)?,
+        pending_owner: PENDING_OWNER.may_load(deps.storage)?,
+        guardians: query_guardians(deps)?,
+        swap_venues,
+        ibc_transfer_adapters,
+        default_ibc_transfer_adapter: DEFAULT_IBC_TRANSFER_ADAPTER.load(deps.storage)?,
+        pause_state: query_pause_state(deps)?,
+        protocol_fee: query_protocol_fee(deps)?,
+        max_total_affiliate_basis_points_fee: query_max_total_affiliate_basis_points_fee(deps)?,
+        delegate_enabled: query_delegate_enabled(deps)?,
+    })
+}
+
+// Simulates a SwapAndAction call sending the coin in, reusing the execute logic of the
+// SwapAndAction, UserSwap and PostSwapAction calls with the swap amounts simulated by the
+// swap adapters. Errors where the SwapAndAction call would error.
+#[allow(clippy::too_many_arguments)]
+pub fn query_simulate_swap_and_action(
+    deps: Deps,
+    env: Env,
+    coin_in: Coin,
+    pre_swap_action: Option<PreSwapAction>,
+    user_swap: Swap,
+    min_coin: Coin,
+    timeout_timestamp: u64,
+    post_swap_action: Action,
+    affiliates: Vec<Affiliate>,
+    affiliate_fee_basis: Option<AffiliateFeeBasis>,
+) -> ContractResult<SimulateSwapAndActionResponse> {
+    // Create the message info of a SwapAndAction call sending the coin in
+    let info = MessageInfo {
+        sender: env.contract.address.clone(),
+        funds: vec![coin_in],
+    };
+
+    // Validate the call and create the ibc fee messages, getting the remaining coin to swap
+    let (fee_response, remaining_coin, fee_swap_coin_in) = prepare_swap_and_action(
+        deps,
+        &env,
+        &info,
+        timeout_timestamp,
+        pre_swap_action.as_ref(),
+        &post_swap_action,
+    )?;
+
+    // Set a boolean to determine if the user swap is exact out or not
+    let exact_out = is_exact_out(&user_swap);
+
+    // Split the affiliates into those charged on the min coin by the user swap,
+    // and those charged on the actual coin out by the post swap action
+    let (affiliates, post_swap_affiliates) =
+        split_affiliates_by_fee_basis(affiliates, affiliate_fee_basis, exact_out)?;
+
+    // Create the user swap and refund/affiliate fee messages, getting the user swap coin in and
+    // the coin out of the user swap not swapped by the swap adapters. With a pre swap action,
+    // the user swap coin in is the coin the pre swap action is dispatched with, otherwise it is
+    // the sum of the coin in of each swap dispatched.
+    let (user_swap_response, user_swap_outcome, user_swap_coin_in, unswapped_coin_out_amount) =
+        match pre_swap_action {
+            Some(pre_swap_action) => {
+                let (response, outcome, withdrawn_min_coin_amount) = simulate_pre_swap_action(
+                    deps,
+                    pre_swap_action,
+                    user_swap,
+                    remaining_coin.clone(),
+                    &min_coin,
+                    &affiliates,
+                )?;
+
+                (response, outcome, remaining_coin, withdrawn_min_coin_amount)
+            }
+            None => {
+                let (response, outcome) = user_swap_response(
+                    deps,
+                    user_swap,
+                    min_coin.clone(),
+                    remaining_coin.clone(),
+                    affiliates,
+                )?;
+
+                let user_swap_coin_in = Coin {
+                    denom: remaining_coin.denom,
+                    amount: outcome
+                        .swaps
+                        .iter()
+                        .try_fold(Uint128::zero(), |total, (_, _, coin_in)| {
+                            total.checked_add(coin_in.amount)
+                        })?,
+                };
+
+                (response, outcome, user_swap_coin_in, Uint128::zero())
+            }
+        };
+
+    // Get the user swap coin out, which is the exact coin out of an exact out user swap, or the
+    // sum of the coin out of each swap dispatched simulated by the swap adapters and the coin out
+    // not swapped
+    let user_swap_coin_out = match user_swap_outcome.exact_coin_out {
+        Some(exact_coin_out) => exact_coin_out,
+        None => {
+            let mut amount = unswapped_coin_out_amount;
+            for (swap_adapter_contract_address, operations, coin_in) in &user_swap_outcome.swaps {
+                let swap_coin_out =
+                    query_swap_coin_out(deps, swap_adapter_contract_address, operations, coin_in)?;
+                amount = amount.checked_add(swap_coin_out.amount)?;
+            }
+
+            Coin {
+                denom: min_coin.denom.clone(),
+                amount,
+            }
+        }
+    };
+
+    // Get the coin the contract holds after the user swap by deducting the affiliate and protocol fees
+    let mut transfer_out_coin = Coin {
+        denom: min_coin.denom.clone(),
+        amount: user_swap_outcome
+            .affiliate_fees
+            .iter()
+            .map(|affiliate_fee| &affiliate_fee.coin)
+            .chain(&user_swap_outcome.protocol_fee)
+            .try_fold(user_swap_coin_out.amount, |amount, fee_coin| {
+                amount.checked_sub(fee_coin.amount)
+            })?,
+    };
+
+    // Create the affiliate fee messages of the affiliates charged on the actual coin out,
+    // deducting the affiliate fees from the coin transferred out
+    let (post_swap_affiliate_response, post_swap_affiliate_fees, _, total_affiliate_fee_amount) =
+        affiliate_fee_response(deps, &transfer_out_coin, &post_swap_affiliates)?;
+    transfer_out_coin.amount = transfer_out_coin
+        .amount
+        .checked_sub(total_affiliate_fee_amount)?;
+
+    // Create the post swap action message
+    let (post_swap_action_response, coin_out) = post_swap_action_response(
+        deps,
+        &env,
+        transfer_out_coin,
+        min_coin,
+        timeout_timestamp,
+        post_swap_action,
+        exact_out,
+        None,
+    )?;
+
+    // Get the messages in the order they would be dispatched
+    let messages = fee_response
+        .messages
+        .into_iter()
+        .chain(user_swap_response.messages)
+        .chain(post_swap_action_response.messages)
+        .chain(post_swap_affiliate_response.messages)
+        .map(|sub_msg| sub_msg.msg)
+        .collect();
+
+    Ok(SimulateSwapAndActionResponse {
+        fee_swap_coin_in,
+        user_swap_coin_in,
+        user_swap_coin_out,
+        affiliate_fees: user_swap_outcome
+            .affiliate_fees
+            .into_iter()
+            .chain(post_swap_affiliate_fees)
+            .collect(),
+        protocol_fee: user_swap_outcome.protocol_fee,
+        refund: user_swap_outcome.refund,
+        coin_out,
+        messages,
+    })
+}
+
+// Simulates a pre swap action withdrawing the remaining coin, returning the response with the pre
+// swap action message and the messages swapping the withdrawn assets, the outcome of the swaps,
+// and the withdrawn amount of the min coin denom that is not swapped
+fn simulate_pre_swap_action(
+    deps: Deps,
+    pre_swap_action: PreSwapAction,
+    user_swap: Swap,
+    remaining_coin: Coin,
+    min_coin: &Coin,
+    affiliates: &[Affiliate],
+) -> ContractResult<(Response, UserSwapOutcome, Uint128)> {
+    // Get the swap venue and pool of the pre swap action
+    let PreSwapAction::WithdrawLiquidity {
+        swap_venue_name,
+        pool,
+        ..
+    } = pre_swap_action.clone();
+
+    // Create the pre swap action message, getting the swaps of the withdrawn assets
+    let (pre_swap_action_msg, swaps) = pre_swap_action_msgs(
+        deps,
+        pre_swap_action,
+        user_swap,
+        remaining_coin.clone(),
+        min_coin,
+    )?;
+
+    // Query the swap adapter to simulate the assets withdrawn by the pre swap action
+    let swap_adapter_contract_address = SWAP_VENUE_MAP.load(deps.storage, &swap_venue_name)?;
+    let withdrawn_coins: Vec<Coin> = deps.querier.query_wasm_smart(
+        swap_adapter_contract_address,
+        &SwapQueryMsg::SimulateWithdrawLiquidity {
+            pool,
+            coin_in: remaining_coin,
+        },
+    )?;
+
+    // Get the coin in of each swap, which is the withdrawn asset of its first swap operation's denom in
+    let withdrawn_amount = |denom: &str| {
+        withdrawn_coins
+            .iter()
+            .find(|coin| coin.denom == denom)
+            .map(|coin| coin.amount)
+            .unwrap_or_default()
+    };
+    let coins_in: Vec<Coin> = swaps
+        .iter()
+        .map(|swap| {
+            let denom_in = &swap.operations[0].denom_in;
+            Coin {
+                denom: denom_in.clone(),
+                amount: withdrawn_amount(denom_in),
+            }
+        })
+        .collect();
+
+    // Get the withdrawn amount of the min coin denom if no swap swaps it
+    let withdrawn_min_coin_amount = if coins_in.iter().any(|coin| coin.denom == min_coin.denom) {
+        Uint128::zero()
+    } else {
+        withdrawn_amount(&min_coin.denom)
+    };
+
+    // Create the withdrawn asset swap and affiliate fee messages
+    let (user_swap_response, outcome) =
+        user_swap_withdrawn_assets_response(deps, swaps, coins_in, min_coin, affiliates)?;
+
+    Ok((
+        Response::new()
+            .add_message(pre_swap_action_msg)
+            .add_submessages(user_swap_response.messages),
+        outcome,
+        withdrawn_min_coin_amount,
+    ))
 }
@@ -1,18 +1,32 @@
 use crate::error::ContractError;
+use crate::execute::load_ibc_transfer_adapter;
 use crate::state::RECOVER_TEMP_STORAGE;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, BankMsg, Coin, DepsMut, Reply, Response, SubMsgResult};
+use cosmwasm_std::{
+    to_binary, Addr, BankMsg, Coin, CosmosMsg, DepsMut, Env, Reply, Response, SubMsgResult, WasmMsg,
+};
+use skip::{
+    entry_point::IbcRecovery,
+    ibc::{ExecuteMsg as IbcTransferExecuteMsg, IbcInfo},
+};
 
 pub const RECOVER_REPLY_ID: u64 = 1;
+pub const CALLBACK_REPLY_ID: u64 = 2;
+
+// The default time after which an IBC recovery transfer times out, refunding
+// the funds to the recovery address through the IBC transfer adapter
+pub const DEFAULT_IBC_RECOVERY_TIMEOUT_SECONDS: u64 = 3600;
 
 #[cw_serde]
 pub struct RecoverTempStorage {
     pub funds: Vec<Coin>,
     pub recovery_addr: Addr,
+    pub ibc_recovery: Option<IbcRecovery>,
 }
 
 pub fn reply_swap_and_action_with_recover(
     deps: DepsMut,
+    env: Env,
     msg: Reply,
 ) -> Result<Response, ContractError> {
     match msg.result {
@@ -25,18 +39,116 @@ pub fn reply_swap_and_action_with_recover(
             let storage = RECOVER_TEMP_STORAGE.load(deps.storage)?;
             let funds = storage.funds;
 
-            // send original funds back to user
-            let return_funds_msg = BankMsg::Send {
-                to_address: storage.recovery_addr.to_string(),
-                amount: funds,
+            // send original funds back to user, over IBC to the original sender
+            // if the call was made through ibc-hooks, otherwise to the recovery address
+            let return_funds_msgs = match storage.ibc_recovery {
+                Some(ibc_recovery) => {
+                    ibc_recovery_msgs(&deps, &env, ibc_recovery, &storage.recovery_addr, funds)?
+                }
+                None => vec![BankMsg::Send {
+                    to_address: storage.recovery_addr.to_string(),
+                    amount: funds,
+                }
+                .into()],
             };
 
             RECOVER_TEMP_STORAGE.remove(deps.storage);
 
             Ok(Response::new()
-                .add_message(return_funds_msg)
+                .add_messages(return_funds_msgs)
                 .add_attribute("status", "swap_and_action_failed")
                 .add_attribute("error", e))
         }
     }
 }
+
+// Creates the messages IBC transferring each of the funds back to the IBC recovery's original
+// sender over its channel through the default IBC transfer adapter, which refunds them to the
+// recovery address if the transfer fails or times out. The IBC fee of the IBC recovery, if any,
+// is deducted from the coin of its denom and sent to the IBC transfer adapter.
+fn ibc_recovery_msgs(
+    deps: &DepsMut,
+    env: &Env,
+    ibc_recovery: IbcRecovery,
+    recovery_addr: &Addr,
+    funds: Vec<Coin>,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    // Get the default ibc transfer adapter contract address
+    let ibc_transfer_contract_address = load_ibc_transfer_adapter(deps.storage, None)?;
+
+    // Get the timeout timestamp of the IBC recovery transfers
+    let timeout_timestamp = env
+        .block
+        .time
+        .plus_seconds(
+            ibc_recovery
+                .timeout_seconds
+                .unwrap_or(DEFAULT_IBC_RECOVERY_TIMEOUT_SECONDS),
+        )
+        .nanos();
+
+    // Get the IBC fee coin of the IBC recovery if there is one
+    let ibc_fee_coin = ibc_recovery
+        .fee
+        .as_ref()
+        .map(|fee| fee.one_coin())
+        .transpose()?;
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    for mut coin in funds {
+        // Deduct the IBC fee from the coin of its denom and send it to the IBC transfer adapter
+        let fee = match &ibc_fee_coin {
+            Some(ibc_fee_coin) if ibc_fee_coin.denom == coin.denom => {
+                coin.amount = coin.amount.checked_sub(ibc_fee_coin.amount)?;
+
+                msgs.push(
+                    BankMsg::Send {
+                        to_address: ibc_transfer_contract_address.to_string(),
+                        amount: vec![ibc_fee_coin.clone()],
+                    }
+                    .into(),
+                );
+
+                ibc_recovery.fee.clone()
+            }
+            _ => None,
+        };
+
+        let ibc_transfer_msg = IbcTransferExecuteMsg::IbcTransfer {
+            info: IbcInfo {
+                source_channel: ibc_recovery.channel.clone(),
+                receiver: ibc_recovery.original_sender.clone(),
+                memo: "".to_string(),
+                fee,
+                recover_address: recovery_addr.to_string(),
+            },
+            coin: coin.clone(),
+            timeout_timestamp,
+            callback_address: None,
+        };
+
+        msgs.push(
+            WasmMsg::Execute {
+                contract_addr: ibc_transfer_contract_address.to_string(),
+                msg: to_binary(&ibc_transfer_msg)?,
+                funds: vec![coin],
+            }
+            .into(),
+        );
+    }
+
+    Ok(msgs)
+}
+
+// Handles the reply from a failed SkipCallback sub message, ignoring the error
+// so a failing callback contract never reverts the post swap action
+pub fn reply_skip_callback(msg: Reply) -> Result<Response, ContractError> {
+    match msg.result {
+        SubMsgResult::Ok(_response) => {
+            Ok(Response::new().add_attribute("status", "skip_callback_successful"))
+        }
+        SubMsgResult::Err(e) => Ok(Response::new()
+            .add_attribute("status", "skip_callback_failed")
+            .add_attribute("error", e)),
+    }
+}
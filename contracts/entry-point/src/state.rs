@@ -1,11 +1,56 @@
 use crate::reply::RecoverTempStorage;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Uint128};
 use cw_storage_plus::{Item, Map};
+use skip::entry_point::{ActionType, ProtocolFee, RegisteredAffiliate};
 
 pub const BLOCKED_CONTRACT_ADDRESSES: Map<&Addr, ()> = Map::new("blocked_contract_addresses");
 pub const SWAP_VENUE_MAP: Map<&str, Addr> = Map::new("swap_venue_map");
+pub const IBC_TRANSFER_ADAPTER_MAP: Map<&str, Addr> = Map::new("ibc_transfer_adapter_map");
+pub const DEFAULT_IBC_TRANSFER_ADAPTER: Item<String> = Item::new("default_ibc_transfer_adapter");
+
+// Single ibc transfer adapter stored by contracts deployed before named ibc transfer
+// adapters were supported, moved into the ibc transfer adapter map on migration.
 pub const IBC_TRANSFER_CONTRACT_ADDRESS: Item<Addr> = Item::new("ibc_transfer_contract_address");
 
+// Blocked contract addresses that were blocked by the owner, and so can be unblocked by the
+// owner. The other blocked contract addresses are the entry point and every adapter contract
+// ever registered, which stay blocked after the adapter is updated or removed.
+pub const MANUALLY_BLOCKED_CONTRACT_ADDRESSES: Map<&Addr, ()> =
+    Map::new("manually_blocked_contract_addresses");
+
+// Owner of the contract, allowed to update the adapters and blocked contract addresses.
+// Ownership is transferred in two steps, the pending owner must accept the transfer.
+pub const OWNER: Item<Addr> = Item::new("owner");
+pub const PENDING_OWNER: Item<Addr> = Item::new("pending_owner");
+
+// Guardians are allowed to pause the contract, only the owner can unpause it.
+// PAUSED pauses all SwapAndAction calls, the other maps pause single swap venues
+// and post swap action types.
+pub const GUARDIANS: Map<&Addr, ()> = Map::new("guardians");
+pub const PAUSED: Item<bool> = Item::new("paused");
+pub const PAUSED_SWAP_VENUES: Map<&str, ()> = Map::new("paused_swap_venues");
+pub const PAUSED_ACTIONS: Item<Vec<ActionType>> = Item::new("paused_actions");
+
+// Protocol fee taken from every SwapAndAction call and sent to its collector address, if set.
+pub const PROTOCOL_FEE: Item<ProtocolFee> = Item::new("protocol_fee");
+
+// Affiliate partners registered by the owner by id, and the maximum total affiliate fee of a
+// single SwapAndAction call set by the owner, MAX_TOTAL_AFFILIATE_BASIS_POINTS_FEE if not set.
+pub const REGISTERED_AFFILIATES: Map<&str, RegisteredAffiliate> = Map::new("registered_affiliates");
+pub const MAX_TOTAL_AFFILIATE_FEE: Item<Uint128> = Item::new("max_total_affiliate_fee");
+
+// Whether Delegate post swap actions are enabled by the owner, which must only be done on chains
+// with the liquid staking module, as the delegations are tokenized with MsgTokenizeShares.
+pub const DELEGATE_ENABLED: Item<bool> = Item::new("delegate_enabled");
+
+// Affiliates that opted in to accruing their affiliate fees in the contract, the affiliate fees
+// accrued to each affiliate by denom, and the total affiliate fees accrued by denom, which the
+// post swap action excludes from the contract balance it transfers out.
+pub const AFFILIATE_FEE_ACCRUAL: Map<&Addr, ()> = Map::new("affiliate_fee_accrual");
+pub const ACCRUED_AFFILIATE_FEES: Map<(&Addr, &str), Uint128> = Map::new("accrued_affiliate_fees");
+pub const TOTAL_ACCRUED_AFFILIATE_FEES: Map<&str, Uint128> =
+    Map::new("total_accrued_affiliate_fees");
+
 // Temporary state to save variables to be used in
 // reply handling in case of recovering from an error
 pub const RECOVER_TEMP_STORAGE: Item<RecoverTempStorage> = Item::new("recover_temp_storage");
@@ -0,0 +1,1343 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env, mock_info},
+    Addr, Order, Uint128,
+};
+use skip::{
+    entry_point::{ExecuteMsg, InstantiateMsg, ProtocolFee, RegisteredAffiliate},
+    ibc::IbcTransferAdapter,
+    swap::SwapVenue,
+};
+use skip_api_entry_point::{
+    error::ContractError,
+    state::{
+        BLOCKED_CONTRACT_ADDRESSES, DEFAULT_IBC_TRANSFER_ADAPTER, DELEGATE_ENABLED,
+        IBC_TRANSFER_ADAPTER_MAP, MAX_TOTAL_AFFILIATE_FEE, OWNER, PENDING_OWNER, PROTOCOL_FEE,
+        REGISTERED_AFFILIATES, SWAP_VENUE_MAP,
+    },
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response
+    // Ownership
+    - Transfer Ownership
+    - Transfer And Accept Ownership
+
+    // Swap Venues
+    - Add Swap Venue
+    - Update Swap Venue
+    - Update Swap Venue To Adapter Shared With Another Venue
+    - Remove Swap Venue
+    - Remove Swap Venue With Adapter Shared With Another Venue
+
+    // IBC Transfer Adapters
+    - Update IBC Transfer Adapter
+    - Add IBC Transfer Adapter
+    - Set Default And Remove Previous Default IBC Transfer Adapter
+
+    // Blocked Contract Addresses
+    - Add Blocked Contract Address
+    - Remove Blocked Contract Address
+
+    // Protocol Fee
+    - Set Protocol Fee
+    - Set Protocol Fee At The Maximum
+    - Set And Remove Protocol Fee
+
+    // Affiliate Registry
+    - Register Affiliate
+    - Register And Update Affiliate
+    - Register And Deregister Affiliate
+    - Set Max Total Affiliate Basis Points Fee
+
+    // Delegate
+    - Set Delegate Enabled
+    - Set Delegate Enabled And Disabled
+
+Expect Error
+    - Unauthorized Caller
+    - Accept Ownership Without Pending Owner
+    - Accept Ownership From Non Pending Owner
+    - Add Duplicate Swap Venue
+    - Update Swap Venue That Does Not Exist
+    - Remove Swap Venue That Does Not Exist
+    - Remove Blocked Contract Address That Is Not Blocked
+    - Add Duplicate IBC Transfer Adapter
+    - Update IBC Transfer Adapter That Does Not Exist
+    - Remove Default IBC Transfer Adapter
+    - Set Default IBC Transfer Adapter That Does Not Exist
+    - Remove Blocked Contract Address Of Registered Adapter
+    - Remove Blocked Contract Address Of Entry Point
+    - Remove Blocked Contract Address Of Retired Adapter
+    - Remove Manually Blocked Contract Address Later Registered As Adapter
+    - Set Protocol Fee Above The Maximum
+    - Set Protocol Fee Unauthorized Caller
+    - Register Affiliate With Empty Id
+    - Register Affiliate With Bech32 Address Id
+    - Register Affiliate With Default Fee Above Max Fee
+    - Register Affiliate With Max Fee Above The Maximum
+    - Register Affiliate Unauthorized Caller
+    - Deregister Affiliate That Is Not Registered
+    - Set Max Total Affiliate Basis Points Fee Above The Maximum
+    - Set Delegate Enabled Unauthorized Caller
+ */
+
+// Define test parameters
+struct Params {
+    msgs: Vec<(&'static str, ExecuteMsg)>,
+    expected_owner: &'static str,
+    expected_pending_owner: Option<&'static str>,
+    expected_swap_venues: Vec<(&'static str, &'static str)>,
+    expected_ibc_transfer_adapters: Vec<(&'static str, &'static str)>,
+    expected_default_ibc_transfer_adapter: &'static str,
+    expected_blocked_contract_addresses: Vec<&'static str>,
+    expected_error: Option<ContractError>,
+}
+
+// Test the admin execute messages
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::TransferOwnership {
+                new_owner: "new_owner".to_string(),
+            },
+        )],
+        expected_owner: "owner",
+        expected_pending_owner: Some("new_owner"),
+        expected_swap_venues: vec![("astroport", "astroport_adapter")],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![
+            "entry_point",
+            "astroport_adapter",
+            "ibc_transfer_adapter",
+        ],
+        expected_error: None,
+    };
+    "Transfer Ownership")]
+#[test_case(
+    Params {
+        msgs: vec![
+            (
+                "owner",
+                ExecuteMsg::TransferOwnership {
+                    new_owner: "new_owner".to_string(),
+                },
+            ),
+            ("new_owner", ExecuteMsg::AcceptOwnership {}),
+        ],
+        expected_owner: "new_owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![("astroport", "astroport_adapter")],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![
+            "entry_point",
+            "astroport_adapter",
+            "ibc_transfer_adapter",
+        ],
+        expected_error: None,
+    };
+    "Transfer And Accept Ownership")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::AddSwapVenue {
+                swap_venue: SwapVenue {
+                    name: "osmosis".to_string(),
+                    adapter_contract_address: "osmosis_adapter".to_string(),
+                },
+            },
+        )],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![
+            ("astroport", "astroport_adapter"),
+            ("osmosis", "osmosis_adapter"),
+        ],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![
+            "entry_point",
+            "astroport_adapter",
+            "osmosis_adapter",
+            "ibc_transfer_adapter",
+        ],
+        expected_error: None,
+    };
+    "Add Swap Venue")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::UpdateSwapVenue {
+                swap_venue: SwapVenue {
+                    name: "astroport".to_string(),
+                    adapter_contract_address: "new_astroport_adapter".to_string(),
+                },
+            },
+        )],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![("astroport", "new_astroport_adapter")],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![
+            "entry_point",
+            "astroport_adapter",
+            "new_astroport_adapter",
+            "ibc_transfer_adapter",
+        ],
+        expected_error: None,
+    };
+    "Update Swap Venue")]
+#[test_case(
+    Params {
+        msgs: vec![
+            (
+                "owner",
+                ExecuteMsg::AddSwapVenue {
+                    swap_venue: SwapVenue {
+                        name: "astroport-2".to_string(),
+                        adapter_contract_address: "astroport_adapter".to_string(),
+                    },
+                },
+            ),
+            (
+                "owner",
+                ExecuteMsg::UpdateSwapVenue {
+                    swap_venue: SwapVenue {
+                        name: "astroport".to_string(),
+                        adapter_contract_address: "new_astroport_adapter".to_string(),
+                    },
+                },
+            ),
+        ],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![
+            ("astroport", "new_astroport_adapter"),
+            ("astroport-2", "astroport_adapter"),
+        ],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![
+            "entry_point",
+            "astroport_adapter",
+            "new_astroport_adapter",
+            "ibc_transfer_adapter",
+        ],
+        expected_error: None,
+    };
+    "Update Swap Venue To Adapter Shared With Another Venue")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::RemoveSwapVenue {
+                name: "astroport".to_string(),
+            },
+        )],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![
+            "entry_point",
+            "astroport_adapter",
+            "ibc_transfer_adapter",
+        ],
+        expected_error: None,
+    };
+    "Remove Swap Venue")]
+#[test_case(
+    Params {
+        msgs: vec![
+            (
+                "owner",
+                ExecuteMsg::AddSwapVenue {
+                    swap_venue: SwapVenue {
+                        name: "astroport-2".to_string(),
+                        adapter_contract_address: "astroport_adapter".to_string(),
+                    },
+                },
+            ),
+            (
+                "owner",
+                ExecuteMsg::RemoveSwapVenue {
+                    name: "astroport".to_string(),
+                },
+            ),
+        ],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![("astroport-2", "astroport_adapter")],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![
+            "entry_point",
+            "astroport_adapter",
+            "ibc_transfer_adapter",
+        ],
+        expected_error: None,
+    };
+    "Remove Swap Venue With Adapter Shared With Another Venue")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::UpdateIbcTransferAdapter {
+                ibc_transfer_adapter: IbcTransferAdapter {
+                    name: "ibc_transfer".to_string(),
+                    adapter_contract_address: "new_ibc_transfer_adapter".to_string(),
+                },
+            },
+        )],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![("astroport", "astroport_adapter")],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "new_ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![
+            "entry_point",
+            "astroport_adapter",
+            "ibc_transfer_adapter",
+            "new_ibc_transfer_adapter",
+        ],
+        expected_error: None,
+    };
+    "Update IBC Transfer Adapter")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::AddIbcTransferAdapter {
+                ibc_transfer_adapter: IbcTransferAdapter {
+                    name: "ibc_transfer_v2".to_string(),
+                    adapter_contract_address: "ibc_transfer_adapter_v2".to_string(),
+                },
+            },
+        )],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![("astroport", "astroport_adapter")],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter"), ("ibc_transfer_v2", "ibc_transfer_adapter_v2")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![
+            "entry_point",
+            "astroport_adapter",
+            "ibc_transfer_adapter",
+            "ibc_transfer_adapter_v2",
+        ],
+        expected_error: None,
+    };
+    "Add IBC Transfer Adapter")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::AddIbcTransferAdapter {
+                ibc_transfer_adapter: IbcTransferAdapter {
+                    name: "ibc_transfer_v2".to_string(),
+                    adapter_contract_address: "ibc_transfer_adapter_v2".to_string(),
+                },
+            },
+        ),
+            (
+                "owner",
+                ExecuteMsg::SetDefaultIbcTransferAdapter {
+                    name: "ibc_transfer_v2".to_string(),
+                },
+            ),
+            (
+                "owner",
+                ExecuteMsg::RemoveIbcTransferAdapter {
+                    name: "ibc_transfer".to_string(),
+                },
+            ),
+        ],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![("astroport", "astroport_adapter")],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer_v2", "ibc_transfer_adapter_v2")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer_v2",
+        expected_blocked_contract_addresses: vec![
+            "entry_point",
+            "astroport_adapter",
+            "ibc_transfer_adapter",
+            "ibc_transfer_adapter_v2",
+        ],
+        expected_error: None,
+    };
+    "Set Default And Remove Previous Default IBC Transfer Adapter")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::AddBlockedContractAddress {
+                address: "vault".to_string(),
+            },
+        )],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![("astroport", "astroport_adapter")],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![
+            "entry_point",
+            "astroport_adapter",
+            "ibc_transfer_adapter",
+            "vault",
+        ],
+        expected_error: None,
+    };
+    "Add Blocked Contract Address")]
+#[test_case(
+    Params {
+        msgs: vec![
+            (
+                "owner",
+                ExecuteMsg::AddBlockedContractAddress {
+                    address: "vault".to_string(),
+                },
+            ),
+            (
+                "owner",
+                ExecuteMsg::RemoveBlockedContractAddress {
+                    address: "vault".to_string(),
+                },
+            ),
+        ],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![("astroport", "astroport_adapter")],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![
+            "entry_point",
+            "astroport_adapter",
+            "ibc_transfer_adapter",
+        ],
+        expected_error: None,
+    };
+    "Remove Blocked Contract Address")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "unauthorized",
+            ExecuteMsg::AddSwapVenue {
+                swap_venue: SwapVenue {
+                    name: "osmosis".to_string(),
+                    adapter_contract_address: "osmosis_adapter".to_string(),
+                },
+            },
+        )],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![],
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Unauthorized Caller - Expect Error")]
+#[test_case(
+    Params {
+        msgs: vec![("new_owner", ExecuteMsg::AcceptOwnership {})],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![],
+        expected_error: Some(ContractError::NoPendingOwner),
+    };
+    "Accept Ownership Without Pending Owner - Expect Error")]
+#[test_case(
+    Params {
+        msgs: vec![
+            (
+                "owner",
+                ExecuteMsg::TransferOwnership {
+                    new_owner: "new_owner".to_string(),
+                },
+            ),
+            ("unauthorized", ExecuteMsg::AcceptOwnership {}),
+        ],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![],
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Accept Ownership From Non Pending Owner - Expect Error")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::AddSwapVenue {
+                swap_venue: SwapVenue {
+                    name: "astroport".to_string(),
+                    adapter_contract_address: "new_astroport_adapter".to_string(),
+                },
+            },
+        )],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![],
+        expected_error: Some(ContractError::DuplicateSwapVenueName),
+    };
+    "Add Duplicate Swap Venue - Expect Error")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::UpdateSwapVenue {
+                swap_venue: SwapVenue {
+                    name: "osmosis".to_string(),
+                    adapter_contract_address: "osmosis_adapter".to_string(),
+                },
+            },
+        )],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![],
+        expected_error: Some(ContractError::SwapVenueNotFound("osmosis".to_string())),
+    };
+    "Update Swap Venue That Does Not Exist - Expect Error")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::RemoveSwapVenue {
+                name: "osmosis".to_string(),
+            },
+        )],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![],
+        expected_error: Some(ContractError::SwapVenueNotFound("osmosis".to_string())),
+    };
+    "Remove Swap Venue That Does Not Exist - Expect Error")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::RemoveBlockedContractAddress {
+                address: "vault".to_string(),
+            },
+        )],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![],
+        expected_error: Some(ContractError::ContractAddressNotBlocked),
+    };
+    "Remove Blocked Contract Address That Is Not Blocked - Expect Error")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::AddIbcTransferAdapter {
+                ibc_transfer_adapter: IbcTransferAdapter {
+                    name: "ibc_transfer".to_string(),
+                    adapter_contract_address: "ibc_transfer_adapter_v2".to_string(),
+                },
+            },
+        )],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![],
+        expected_ibc_transfer_adapters: vec![],
+        expected_default_ibc_transfer_adapter: "",
+        expected_blocked_contract_addresses: vec![],
+        expected_error: Some(ContractError::DuplicateIbcTransferAdapterName),
+    };
+    "Add Duplicate IBC Transfer Adapter - Expect Error")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::UpdateIbcTransferAdapter {
+                ibc_transfer_adapter: IbcTransferAdapter {
+                    name: "ibc_transfer_v2".to_string(),
+                    adapter_contract_address: "ibc_transfer_adapter_v2".to_string(),
+                },
+            },
+        )],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![],
+        expected_ibc_transfer_adapters: vec![],
+        expected_default_ibc_transfer_adapter: "",
+        expected_blocked_contract_addresses: vec![],
+        expected_error: Some(ContractError::IbcTransferAdapterNotFound(
+            "ibc_transfer_v2".to_string(),
+        )),
+    };
+    "Update IBC Transfer Adapter That Does Not Exist - Expect Error")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::RemoveIbcTransferAdapter {
+                name: "ibc_transfer".to_string(),
+            },
+        )],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![],
+        expected_ibc_transfer_adapters: vec![],
+        expected_default_ibc_transfer_adapter: "",
+        expected_blocked_contract_addresses: vec![],
+        expected_error: Some(ContractError::CannotRemoveDefaultIbcTransferAdapter),
+    };
+    "Remove Default IBC Transfer Adapter - Expect Error")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::SetDefaultIbcTransferAdapter {
+                name: "ibc_transfer_v2".to_string(),
+            },
+        )],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![],
+        expected_ibc_transfer_adapters: vec![],
+        expected_default_ibc_transfer_adapter: "",
+        expected_blocked_contract_addresses: vec![],
+        expected_error: Some(ContractError::IbcTransferAdapterNotFound(
+            "ibc_transfer_v2".to_string(),
+        )),
+    };
+    "Set Default IBC Transfer Adapter That Does Not Exist - Expect Error")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::RemoveBlockedContractAddress {
+                address: "astroport_adapter".to_string(),
+            },
+        )],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![],
+        expected_error: Some(ContractError::CannotUnblockRegisteredContract),
+    };
+    "Remove Blocked Contract Address Of Registered Adapter - Expect Error")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::RemoveBlockedContractAddress {
+                address: "entry_point".to_string(),
+            },
+        )],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![],
+        expected_error: Some(ContractError::CannotUnblockRegisteredContract),
+    };
+    "Remove Blocked Contract Address Of Entry Point - Expect Error")]
+#[test_case(
+    Params {
+        msgs: vec![
+            (
+                "owner",
+                ExecuteMsg::RemoveSwapVenue {
+                    name: "astroport".to_string(),
+                },
+            ),
+            (
+                "owner",
+                ExecuteMsg::RemoveBlockedContractAddress {
+                    address: "astroport_adapter".to_string(),
+                },
+            ),
+        ],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![],
+        expected_error: Some(ContractError::CannotUnblockRegisteredContract),
+    };
+    "Remove Blocked Contract Address Of Retired Adapter - Expect Error")]
+#[test_case(
+    Params {
+        msgs: vec![
+            (
+                "owner",
+                ExecuteMsg::AddBlockedContractAddress {
+                    address: "osmosis_adapter".to_string(),
+                },
+            ),
+            (
+                "owner",
+                ExecuteMsg::AddSwapVenue {
+                    swap_venue: SwapVenue {
+                        name: "osmosis".to_string(),
+                        adapter_contract_address: "osmosis_adapter".to_string(),
+                    },
+                },
+            ),
+            (
+                "owner",
+                ExecuteMsg::RemoveBlockedContractAddress {
+                    address: "osmosis_adapter".to_string(),
+                },
+            ),
+        ],
+        expected_owner: "owner",
+        expected_pending_owner: None,
+        expected_swap_venues: vec![],
+        expected_ibc_transfer_adapters: vec![("ibc_transfer", "ibc_transfer_adapter")],
+        expected_default_ibc_transfer_adapter: "ibc_transfer",
+        expected_blocked_contract_addresses: vec![],
+        expected_error: Some(ContractError::CannotUnblockRegisteredContract),
+    };
+    "Remove Manually Blocked Contract Address Later Registered As Adapter - Expect Error")]
+fn test_execute_admin(params: Params) {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env with the entry point contract address
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+
+    // Instantiate the contract with a single swap venue and the owner
+    skip_api_entry_point::contract::instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        InstantiateMsg {
+            owner: Some("owner".to_string()),
+            swap_venues: vec![SwapVenue {
+                name: "astroport".to_string(),
+                adapter_contract_address: "astroport_adapter".to_string(),
+            }],
+            ibc_transfer_adapters: vec![IbcTransferAdapter {
+                name: "ibc_transfer".to_string(),
+                adapter_contract_address: "ibc_transfer_adapter".to_string(),
+            }],
+            default_ibc_transfer_adapter: "ibc_transfer".to_string(),
+        },
+    )
+    .unwrap();
+
+    // Call execute with the given test parameters, stopping at the first error
+    let res = params.msgs.into_iter().try_for_each(|(caller, msg)| {
+        skip_api_entry_point::contract::execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(caller, &[]),
+            msg,
+        )
+        .map(|_| ())
+    });
+
+    match res {
+        Ok(()) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the owner and pending owner are correct
+            assert_eq!(
+                OWNER.load(deps.as_ref().storage).unwrap(),
+                Addr::unchecked(params.expected_owner)
+            );
+            assert_eq!(
+                PENDING_OWNER.may_load(deps.as_ref().storage).unwrap(),
+                params.expected_pending_owner.map(Addr::unchecked)
+            );
+
+            // Assert the swap venues stored are correct
+            let stored_swap_venues: Vec<(String, Addr)> = SWAP_VENUE_MAP
+                .range(deps.as_ref().storage, None, None, Order::Ascending)
+                .collect::<Result<_, _>>()
+                .unwrap();
+            assert_eq!(
+                stored_swap_venues,
+                params
+                    .expected_swap_venues
+                    .into_iter()
+                    .map(|(name, address)| (name.to_string(), Addr::unchecked(address)))
+                    .collect::<Vec<_>>()
+            );
+
+            // Assert the ibc transfer adapters stored are correct
+            let stored_ibc_transfer_adapters: Vec<(String, Addr)> = IBC_TRANSFER_ADAPTER_MAP
+                .range(deps.as_ref().storage, None, None, Order::Ascending)
+                .collect::<Result<_, _>>()
+                .unwrap();
+            assert_eq!(
+                stored_ibc_transfer_adapters,
+                params
+                    .expected_ibc_transfer_adapters
+                    .into_iter()
+                    .map(|(name, address)| (name.to_string(), Addr::unchecked(address)))
+                    .collect::<Vec<_>>()
+            );
+
+            // Assert the default ibc transfer adapter stored is correct
+            assert_eq!(
+                DEFAULT_IBC_TRANSFER_ADAPTER
+                    .load(deps.as_ref().storage)
+                    .unwrap(),
+                params.expected_default_ibc_transfer_adapter
+            );
+
+            // Assert the blocked contract addresses are correct
+            let mut stored_blocked_contract_addresses: Vec<Addr> = BLOCKED_CONTRACT_ADDRESSES
+                .keys(deps.as_ref().storage, None, None, Order::Ascending)
+                .collect::<Result<_, _>>()
+                .unwrap();
+            stored_blocked_contract_addresses.sort();
+            let mut expected_blocked_contract_addresses: Vec<Addr> = params
+                .expected_blocked_contract_addresses
+                .into_iter()
+                .map(Addr::unchecked)
+                .collect();
+            expected_blocked_contract_addresses.sort();
+            assert_eq!(
+                stored_blocked_contract_addresses,
+                expected_blocked_contract_addresses
+            );
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
+
+// Define test parameters
+struct ProtocolFeeParams {
+    msgs: Vec<(&'static str, ExecuteMsg)>,
+    expected_protocol_fee: Option<ProtocolFee>,
+    expected_error: Option<ContractError>,
+}
+
+// Test the set protocol fee execute message
+#[test_case(
+    ProtocolFeeParams {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::SetProtocolFee {
+                protocol_fee: Some(ProtocolFee {
+                    basis_points_fee: Uint128::new(10),
+                    collector_address: "collector".to_string(),
+                }),
+            },
+        )],
+        expected_protocol_fee: Some(ProtocolFee {
+            basis_points_fee: Uint128::new(10),
+            collector_address: "collector".to_string(),
+        }),
+        expected_error: None,
+    };
+    "Set Protocol Fee")]
+#[test_case(
+    ProtocolFeeParams {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::SetProtocolFee {
+                protocol_fee: Some(ProtocolFee {
+                    basis_points_fee: Uint128::new(100),
+                    collector_address: "collector".to_string(),
+                }),
+            },
+        )],
+        expected_protocol_fee: Some(ProtocolFee {
+            basis_points_fee: Uint128::new(100),
+            collector_address: "collector".to_string(),
+        }),
+        expected_error: None,
+    };
+    "Set Protocol Fee At The Maximum")]
+#[test_case(
+    ProtocolFeeParams {
+        msgs: vec![
+            (
+                "owner",
+                ExecuteMsg::SetProtocolFee {
+                    protocol_fee: Some(ProtocolFee {
+                        basis_points_fee: Uint128::new(10),
+                        collector_address: "collector".to_string(),
+                    }),
+                },
+            ),
+            ("owner", ExecuteMsg::SetProtocolFee { protocol_fee: None }),
+        ],
+        expected_protocol_fee: None,
+        expected_error: None,
+    };
+    "Set And Remove Protocol Fee")]
+#[test_case(
+    ProtocolFeeParams {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::SetProtocolFee {
+                protocol_fee: Some(ProtocolFee {
+                    basis_points_fee: Uint128::new(101),
+                    collector_address: "collector".to_string(),
+                }),
+            },
+        )],
+        expected_protocol_fee: None,
+        expected_error: Some(ContractError::ProtocolFeeTooHigh(Uint128::new(100))),
+    };
+    "Set Protocol Fee Above The Maximum - Expect Error")]
+#[test_case(
+    ProtocolFeeParams {
+        msgs: vec![(
+            "random",
+            ExecuteMsg::SetProtocolFee {
+                protocol_fee: Some(ProtocolFee {
+                    basis_points_fee: Uint128::new(10),
+                    collector_address: "collector".to_string(),
+                }),
+            },
+        )],
+        expected_protocol_fee: None,
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Set Protocol Fee Unauthorized Caller - Expect Error")]
+fn test_execute_set_protocol_fee(params: ProtocolFeeParams) {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env with the entry point contract address
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+
+    // Store the owner
+    OWNER
+        .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+        .unwrap();
+
+    // Call execute with the given test parameters, stopping at the first error
+    let res = params.msgs.into_iter().try_for_each(|(caller, msg)| {
+        skip_api_entry_point::contract::execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(caller, &[]),
+            msg,
+        )
+        .map(|_| ())
+    });
+
+    match res {
+        Ok(()) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the protocol fee stored is correct
+            assert_eq!(
+                PROTOCOL_FEE.may_load(deps.as_ref().storage).unwrap(),
+                params.expected_protocol_fee
+            );
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
+
+// Define test parameters
+struct AffiliateRegistryParams {
+    msgs: Vec<(&'static str, ExecuteMsg)>,
+    expected_registered_affiliates: Vec<RegisteredAffiliate>,
+    expected_max_total_affiliate_fee: Option<Uint128>,
+    expected_error: Option<ContractError>,
+}
+
+// Test the affiliate registry execute messages
+#[test_case(
+    AffiliateRegistryParams {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::RegisterAffiliate {
+                affiliate: registered_affiliate("partner", "partner_address", 50, 100),
+            },
+        )],
+        expected_registered_affiliates: vec![
+            registered_affiliate("partner", "partner_address", 50, 100),
+        ],
+        expected_max_total_affiliate_fee: None,
+        expected_error: None,
+    };
+    "Register Affiliate")]
+#[test_case(
+    AffiliateRegistryParams {
+        msgs: vec![
+            (
+                "owner",
+                ExecuteMsg::RegisterAffiliate {
+                    affiliate: registered_affiliate("partner", "partner_address", 50, 100),
+                },
+            ),
+            (
+                "owner",
+                ExecuteMsg::RegisterAffiliate {
+                    affiliate: registered_affiliate("partner", "new_partner_address", 25, 75),
+                },
+            ),
+        ],
+        expected_registered_affiliates: vec![
+            registered_affiliate("partner", "new_partner_address", 25, 75),
+        ],
+        expected_max_total_affiliate_fee: None,
+        expected_error: None,
+    };
+    "Register And Update Affiliate")]
+#[test_case(
+    AffiliateRegistryParams {
+        msgs: vec![
+            (
+                "owner",
+                ExecuteMsg::RegisterAffiliate {
+                    affiliate: registered_affiliate("partner", "partner_address", 50, 100),
+                },
+            ),
+            (
+                "owner",
+                ExecuteMsg::RegisterAffiliate {
+                    affiliate: registered_affiliate("other", "other_address", 50, 100),
+                },
+            ),
+            (
+                "owner",
+                ExecuteMsg::DeregisterAffiliate {
+                    id: "partner".to_string(),
+                },
+            ),
+        ],
+        expected_registered_affiliates: vec![
+            registered_affiliate("other", "other_address", 50, 100),
+        ],
+        expected_max_total_affiliate_fee: None,
+        expected_error: None,
+    };
+    "Register And Deregister Affiliate")]
+#[test_case(
+    AffiliateRegistryParams {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::SetMaxTotalAffiliateBasisPointsFee {
+                max_total_affiliate_basis_points_fee: Uint128::new(300),
+            },
+        )],
+        expected_registered_affiliates: vec![],
+        expected_max_total_affiliate_fee: Some(Uint128::new(300)),
+        expected_error: None,
+    };
+    "Set Max Total Affiliate Basis Points Fee")]
+#[test_case(
+    AffiliateRegistryParams {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::RegisterAffiliate {
+                affiliate: registered_affiliate("", "partner_address", 50, 100),
+            },
+        )],
+        expected_registered_affiliates: vec![],
+        expected_max_total_affiliate_fee: None,
+        expected_error: Some(ContractError::InvalidRegisteredAffiliateId),
+    };
+    "Register Affiliate With Empty Id - Expect Error")]
+#[test_case(
+    AffiliateRegistryParams {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::RegisterAffiliate {
+                affiliate: registered_affiliate(
+                    "cosmos1qnufjmd8vwm6j6d3q28wxqr4d8408f34f99rlj",
+                    "partner_address",
+                    50,
+                    100,
+                ),
+            },
+        )],
+        expected_registered_affiliates: vec![],
+        expected_max_total_affiliate_fee: None,
+        expected_error: Some(ContractError::InvalidRegisteredAffiliateId),
+    };
+    "Register Affiliate With Bech32 Address Id - Expect Error")]
+#[test_case(
+    AffiliateRegistryParams {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::RegisterAffiliate {
+                affiliate: registered_affiliate("partner", "partner_address", 101, 100),
+            },
+        )],
+        expected_registered_affiliates: vec![],
+        expected_max_total_affiliate_fee: None,
+        expected_error: Some(ContractError::RegisteredAffiliateDefaultFeeAboveMax),
+    };
+    "Register Affiliate With Default Fee Above Max Fee - Expect Error")]
+#[test_case(
+    AffiliateRegistryParams {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::RegisterAffiliate {
+                affiliate: registered_affiliate("partner", "partner_address", 50, 5001),
+            },
+        )],
+        expected_registered_affiliates: vec![],
+        expected_max_total_affiliate_fee: None,
+        expected_error: Some(ContractError::AffiliateFeeTooHigh(Uint128::new(5000))),
+    };
+    "Register Affiliate With Max Fee Above The Maximum - Expect Error")]
+#[test_case(
+    AffiliateRegistryParams {
+        msgs: vec![(
+            "random",
+            ExecuteMsg::RegisterAffiliate {
+                affiliate: registered_affiliate("partner", "partner_address", 50, 100),
+            },
+        )],
+        expected_registered_affiliates: vec![],
+        expected_max_total_affiliate_fee: None,
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Register Affiliate Unauthorized Caller - Expect Error")]
+#[test_case(
+    AffiliateRegistryParams {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::DeregisterAffiliate {
+                id: "partner".to_string(),
+            },
+        )],
+        expected_registered_affiliates: vec![],
+        expected_max_total_affiliate_fee: None,
+        expected_error: Some(ContractError::RegisteredAffiliateNotFound(
+            "partner".to_string(),
+        )),
+    };
+    "Deregister Affiliate That Is Not Registered - Expect Error")]
+#[test_case(
+    AffiliateRegistryParams {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::SetMaxTotalAffiliateBasisPointsFee {
+                max_total_affiliate_basis_points_fee: Uint128::new(5001),
+            },
+        )],
+        expected_registered_affiliates: vec![],
+        expected_max_total_affiliate_fee: None,
+        expected_error: Some(ContractError::AffiliateFeeTooHigh(Uint128::new(5000))),
+    };
+    "Set Max Total Affiliate Basis Points Fee Above The Maximum - Expect Error")]
+fn test_execute_affiliate_registry(params: AffiliateRegistryParams) {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env with the entry point contract address
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+
+    // Store the owner
+    OWNER
+        .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+        .unwrap();
+
+    // Call execute with the given test parameters, stopping at the first error
+    let res = params.msgs.into_iter().try_for_each(|(caller, msg)| {
+        skip_api_entry_point::contract::execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(caller, &[]),
+            msg,
+        )
+        .map(|_| ())
+    });
+
+    match res {
+        Ok(()) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the registered affiliates stored are correct
+            let registered_affiliates: Vec<RegisteredAffiliate> = REGISTERED_AFFILIATES
+                .range(deps.as_ref().storage, None, None, Order::Ascending)
+                .map(|item| item.unwrap().1)
+                .collect();
+            assert_eq!(registered_affiliates, params.expected_registered_affiliates);
+
+            // Assert the max total affiliate fee stored is correct
+            assert_eq!(
+                MAX_TOTAL_AFFILIATE_FEE
+                    .may_load(deps.as_ref().storage)
+                    .unwrap(),
+                params.expected_max_total_affiliate_fee
+            );
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
+
+// Define test parameters
+struct DelegateEnabledParams {
+    msgs: Vec<(&'static str, ExecuteMsg)>,
+    expected_delegate_enabled: Option<bool>,
+    expected_error: Option<ContractError>,
+}
+
+// Test the set delegate enabled execute message
+#[test_case(
+    DelegateEnabledParams {
+        msgs: vec![("owner", ExecuteMsg::SetDelegateEnabled { enabled: true })],
+        expected_delegate_enabled: Some(true),
+        expected_error: None,
+    };
+    "Set Delegate Enabled")]
+#[test_case(
+    DelegateEnabledParams {
+        msgs: vec![
+            ("owner", ExecuteMsg::SetDelegateEnabled { enabled: true }),
+            ("owner", ExecuteMsg::SetDelegateEnabled { enabled: false }),
+        ],
+        expected_delegate_enabled: Some(false),
+        expected_error: None,
+    };
+    "Set Delegate Enabled And Disabled")]
+#[test_case(
+    DelegateEnabledParams {
+        msgs: vec![("random", ExecuteMsg::SetDelegateEnabled { enabled: true })],
+        expected_delegate_enabled: None,
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Set Delegate Enabled Unauthorized Caller - Expect Error")]
+fn test_execute_set_delegate_enabled(params: DelegateEnabledParams) {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env with the entry point contract address
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+
+    // Store the owner
+    OWNER
+        .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+        .unwrap();
+
+    // Call execute with the given test parameters, stopping at the first error
+    let res = params.msgs.into_iter().try_for_each(|(caller, msg)| {
+        skip_api_entry_point::contract::execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(caller, &[]),
+            msg,
+        )
+        .map(|_| ())
+    });
+
+    match res {
+        Ok(()) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the delegate enabled flag stored is correct
+            assert_eq!(
+                DELEGATE_ENABLED.may_load(deps.as_ref().storage).unwrap(),
+                params.expected_delegate_enabled
+            );
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
+
+// Creates an affiliate registered by the owner
+fn registered_affiliate(
+    id: &str,
+    address: &str,
+    default_basis_points_fee: u128,
+    max_basis_points_fee: u128,
+) -> RegisteredAffiliate {
+    RegisteredAffiliate {
+        id: id.to_string(),
+        address: address.to_string(),
+        default_basis_points_fee: Uint128::new(default_basis_points_fee),
+        max_basis_points_fee: Uint128::new(max_basis_points_fee),
+    }
+}
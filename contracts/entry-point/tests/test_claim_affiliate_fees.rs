@@ -0,0 +1,581 @@
+use cosmwasm_std::{
+    from_binary,
+    testing::{mock_dependencies, mock_dependencies_with_balances, mock_env, mock_info},
+    to_binary, Addr, BankMsg, Coin, Order,
+    ReplyOn::Never,
+    SubMsg, Timestamp, Uint128, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+use skip::{
+    entry_point::{Action, Affiliate, ClaimDestination, ExecuteMsg, QueryMsg},
+    ibc::{ExecuteMsg as IbcTransferExecuteMsg, IbcFee, IbcInfo},
+    swap::{ExecuteMsg as SwapExecuteMsg, Swap, SwapExactCoinIn, SwapOperation},
+};
+use skip_api_entry_point::{
+    error::ContractError,
+    state::{
+        ACCRUED_AFFILIATE_FEES, DEFAULT_IBC_TRANSFER_ADAPTER, IBC_TRANSFER_ADAPTER_MAP,
+        SWAP_VENUE_MAP, TOTAL_ACCRUED_AFFILIATE_FEES,
+    },
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response
+    - Claim Locally
+    - Claim CW20 Locally (Transfers the CW20 fees from the CW20 contract)
+    - Claim Over IBC
+    - Claim Over IBC With IBC Fee (Deducts the IBC fee from the claimed fees and sends it to the adapter)
+    - Accrue, Transfer Out And Claim Affiliate Fees (The post swap action excludes the accrued fees)
+
+Expect Error
+    - Claim With No Accrued Fees
+    - Claim Over IBC With IBC Fee Of Different Denom
+    - Claim CW20 Over IBC
+    - Claim Over IBC After Timeout
+    - Claim Over IBC With IBC Transfer Adapter Not Found
+ */
+
+// Define test parameters
+struct Params {
+    caller: String,
+    denom: String,
+    destination: ClaimDestination,
+    expected_messages: Vec<SubMsg>,
+    expected_accrued_fees: Vec<Coin>,
+    expected_total_accrued_fees: Vec<Coin>,
+    expected_error: Option<ContractError>,
+}
+
+// Test execute_claim_affiliate_fees
+#[test_case(
+    Params {
+        caller: "affiliate".to_string(),
+        denom: "untrn".to_string(),
+        destination: ClaimDestination::Local {
+            to_address: "affiliate_wallet".to_string(),
+        },
+        expected_messages: vec![SubMsg {
+            id: 0,
+            msg: BankMsg::Send {
+                to_address: "affiliate_wallet".to_string(),
+                amount: vec![Coin::new(100_000, "untrn")],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }],
+        expected_accrued_fees: vec![Coin::new(50_000, "cw20:cw20_contract")],
+        expected_total_accrued_fees: vec![
+            Coin::new(50_000, "cw20:cw20_contract"),
+            Coin::new(50_000, "untrn"),
+        ],
+        expected_error: None,
+    };
+    "Claim Locally")]
+#[test_case(
+    Params {
+        caller: "affiliate".to_string(),
+        denom: "cw20:cw20_contract".to_string(),
+        destination: ClaimDestination::Local {
+            to_address: "affiliate_wallet".to_string(),
+        },
+        expected_messages: vec![SubMsg {
+            id: 0,
+            msg: WasmMsg::Execute {
+                contract_addr: "cw20_contract".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "affiliate_wallet".to_string(),
+                    amount: Uint128::new(50_000),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }],
+        expected_accrued_fees: vec![Coin::new(100_000, "untrn")],
+        expected_total_accrued_fees: vec![Coin::new(150_000, "untrn")],
+        expected_error: None,
+    };
+    "Claim CW20 Locally")]
+#[test_case(
+    Params {
+        caller: "affiliate".to_string(),
+        denom: "untrn".to_string(),
+        destination: ClaimDestination::Ibc {
+            ibc_info: ibc_info(None),
+            ibc_transfer_adapter: None,
+            timeout_timestamp: 101,
+        },
+        expected_messages: vec![SubMsg {
+            id: 0,
+            msg: WasmMsg::Execute {
+                contract_addr: "ibc_transfer_adapter".to_string(),
+                msg: to_binary(&IbcTransferExecuteMsg::IbcTransfer {
+                    info: ibc_info(None),
+                    coin: Coin::new(100_000, "untrn"),
+                    timeout_timestamp: 101,
+                    callback_address: None,
+                })
+                .unwrap(),
+                funds: vec![Coin::new(100_000, "untrn")],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }],
+        expected_accrued_fees: vec![Coin::new(50_000, "cw20:cw20_contract")],
+        expected_total_accrued_fees: vec![
+            Coin::new(50_000, "cw20:cw20_contract"),
+            Coin::new(50_000, "untrn"),
+        ],
+        expected_error: None,
+    };
+    "Claim Over IBC")]
+#[test_case(
+    Params {
+        caller: "affiliate".to_string(),
+        denom: "untrn".to_string(),
+        destination: ClaimDestination::Ibc {
+            ibc_info: ibc_info(Some(ibc_fee("untrn"))),
+            ibc_transfer_adapter: Some("hooks".to_string()),
+            timeout_timestamp: 101,
+        },
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "hooks_ibc_transfer_adapter".to_string(),
+                    amount: vec![Coin::new(20_000, "untrn")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "hooks_ibc_transfer_adapter".to_string(),
+                    msg: to_binary(&IbcTransferExecuteMsg::IbcTransfer {
+                        info: ibc_info(Some(ibc_fee("untrn"))),
+                        coin: Coin::new(80_000, "untrn"),
+                        timeout_timestamp: 101,
+                        callback_address: None,
+                    })
+                    .unwrap(),
+                    funds: vec![Coin::new(80_000, "untrn")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_accrued_fees: vec![Coin::new(50_000, "cw20:cw20_contract")],
+        expected_total_accrued_fees: vec![
+            Coin::new(50_000, "cw20:cw20_contract"),
+            Coin::new(50_000, "untrn"),
+        ],
+        expected_error: None,
+    };
+    "Claim Over IBC With IBC Fee")]
+#[test_case(
+    Params {
+        caller: "other_affiliate".to_string(),
+        denom: "cw20:cw20_contract".to_string(),
+        destination: ClaimDestination::Local {
+            to_address: "affiliate_wallet".to_string(),
+        },
+        expected_messages: vec![],
+        expected_accrued_fees: vec![],
+        expected_total_accrued_fees: vec![],
+        expected_error: Some(ContractError::NoAccruedAffiliateFees(
+            "cw20:cw20_contract".to_string(),
+        )),
+    };
+    "Claim With No Accrued Fees - Expect Error")]
+#[test_case(
+    Params {
+        caller: "affiliate".to_string(),
+        denom: "untrn".to_string(),
+        destination: ClaimDestination::Ibc {
+            ibc_info: ibc_info(Some(ibc_fee("uosmo"))),
+            ibc_transfer_adapter: None,
+            timeout_timestamp: 101,
+        },
+        expected_messages: vec![],
+        expected_accrued_fees: vec![],
+        expected_total_accrued_fees: vec![],
+        expected_error: Some(ContractError::IBCFeeDenomDiffersFromCoinReceived),
+    };
+    "Claim Over IBC With IBC Fee Of Different Denom - Expect Error")]
+#[test_case(
+    Params {
+        caller: "affiliate".to_string(),
+        denom: "cw20:cw20_contract".to_string(),
+        destination: ClaimDestination::Ibc {
+            ibc_info: ibc_info(None),
+            ibc_transfer_adapter: None,
+            timeout_timestamp: 101,
+        },
+        expected_messages: vec![],
+        expected_accrued_fees: vec![],
+        expected_total_accrued_fees: vec![],
+        expected_error: Some(ContractError::Cw20IbcTransferNotSupported),
+    };
+    "Claim CW20 Over IBC - Expect Error")]
+#[test_case(
+    Params {
+        caller: "affiliate".to_string(),
+        denom: "untrn".to_string(),
+        destination: ClaimDestination::Ibc {
+            ibc_info: ibc_info(None),
+            ibc_transfer_adapter: None,
+            timeout_timestamp: 99,
+        },
+        expected_messages: vec![],
+        expected_accrued_fees: vec![],
+        expected_total_accrued_fees: vec![],
+        expected_error: Some(ContractError::Timeout),
+    };
+    "Claim Over IBC After Timeout - Expect Error")]
+#[test_case(
+    Params {
+        caller: "affiliate".to_string(),
+        denom: "untrn".to_string(),
+        destination: ClaimDestination::Ibc {
+            ibc_info: ibc_info(None),
+            ibc_transfer_adapter: Some("unknown".to_string()),
+            timeout_timestamp: 101,
+        },
+        expected_messages: vec![],
+        expected_accrued_fees: vec![],
+        expected_total_accrued_fees: vec![],
+        expected_error: Some(ContractError::IbcTransferAdapterNotFound(
+            "unknown".to_string(),
+        )),
+    };
+    "Claim Over IBC With IBC Transfer Adapter Not Found - Expect Error")]
+fn test_execute_claim_affiliate_fees(params: Params) {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env with parameters that make testing easier
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+    env.block.time = Timestamp::from_nanos(100);
+
+    // Store the default and a second named ibc transfer adapter contract address
+    IBC_TRANSFER_ADAPTER_MAP
+        .save(
+            deps.as_mut().storage,
+            "ibc_transfer_adapter",
+            &Addr::unchecked("ibc_transfer_adapter"),
+        )
+        .unwrap();
+    DEFAULT_IBC_TRANSFER_ADAPTER
+        .save(deps.as_mut().storage, &"ibc_transfer_adapter".to_string())
+        .unwrap();
+    IBC_TRANSFER_ADAPTER_MAP
+        .save(
+            deps.as_mut().storage,
+            "hooks",
+            &Addr::unchecked("hooks_ibc_transfer_adapter"),
+        )
+        .unwrap();
+
+    // Store the affiliate fees accrued to two affiliates and their totals
+    for (address, coin) in [
+        ("affiliate", Coin::new(100_000, "untrn")),
+        ("affiliate", Coin::new(50_000, "cw20:cw20_contract")),
+        ("other_affiliate", Coin::new(50_000, "untrn")),
+    ] {
+        ACCRUED_AFFILIATE_FEES
+            .save(
+                deps.as_mut().storage,
+                (&Addr::unchecked(address), &coin.denom),
+                &coin.amount,
+            )
+            .unwrap();
+    }
+    for coin in [
+        Coin::new(150_000, "untrn"),
+        Coin::new(50_000, "cw20:cw20_contract"),
+    ] {
+        TOTAL_ACCRUED_AFFILIATE_FEES
+            .save(deps.as_mut().storage, &coin.denom, &coin.amount)
+            .unwrap();
+    }
+
+    // Call execute_claim_affiliate_fees with the given test parameters
+    let res = skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(&params.caller, &[]),
+        ExecuteMsg::ClaimAffiliateFees {
+            denom: params.denom,
+            destination: params.destination,
+        },
+    );
+
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the messages in the response are correct
+            assert_eq!(res.messages, params.expected_messages);
+
+            // Assert the caller's remaining accrued fees are correct
+            let accrued_fees: Vec<Coin> = from_binary(
+                &skip_api_entry_point::contract::query(
+                    deps.as_ref(),
+                    env,
+                    QueryMsg::AccruedAffiliateFees {
+                        address: params.caller,
+                        start_after: None,
+                        limit: None,
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(accrued_fees, params.expected_accrued_fees);
+
+            // Assert the total accrued fees are correct
+            let total_accrued_fees: Vec<Coin> = TOTAL_ACCRUED_AFFILIATE_FEES
+                .range(deps.as_ref().storage, None, None, Order::Ascending)
+                .map(|item| {
+                    let (denom, amount) = item.unwrap();
+                    Coin { denom, amount }
+                })
+                .collect();
+            assert_eq!(total_accrued_fees, params.expected_total_accrued_fees);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
+
+// Test an opted in affiliate's fees are accrued instead of sent by the user swap, excluded
+// from the coin transferred out by the post swap action, and claimed by the affiliate
+#[test]
+fn test_accrue_transfer_out_and_claim_affiliate_fees() {
+    // Create mock dependencies with the coin received from the user swap
+    let mut deps =
+        mock_dependencies_with_balances(&[("entry_point", &[Coin::new(1_000_000, "osmo")])]);
+
+    // Create mock env with parameters that make testing easier
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+    env.block.time = Timestamp::from_nanos(100);
+
+    // Store the swap venue adapter contract address
+    SWAP_VENUE_MAP
+        .save(
+            deps.as_mut().storage,
+            "swap_venue_name",
+            &Addr::unchecked("swap_venue_adapter"),
+        )
+        .unwrap();
+
+    // Opt the affiliate in to accruing its affiliate fees
+    skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("affiliate", &[]),
+        ExecuteMsg::SetAffiliateFeeAccrual { accrue: true },
+    )
+    .unwrap();
+
+    // Dispatch the user swap, accruing the affiliate's fee and sending the other affiliate's fee
+    let res = skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("entry_point", &[]),
+        ExecuteMsg::UserSwap {
+            swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![SwapOperation {
+                    pool: "pool".to_string(),
+                    denom_in: "untrn".to_string(),
+                    denom_out: "osmo".to_string(),
+                }],
+            }),
+            min_coin: Coin::new(800_000, "osmo"),
+            remaining_coin: Coin::new(1_000_000, "untrn"),
+            affiliates: vec![
+                Affiliate {
+                    address: "affiliate".to_string(),
+                    basis_points_fee: Some(Uint128::new(1000)),
+                    id: None,
+                },
+                Affiliate {
+                    address: "other_affiliate".to_string(),
+                    basis_points_fee: Some(Uint128::new(500)),
+                    id: None,
+                },
+            ],
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter".to_string(),
+                    msg: to_binary(&SwapExecuteMsg::Swap {
+                        operations: vec![SwapOperation {
+                            pool: "pool".to_string(),
+                            denom_in: "untrn".to_string(),
+                            denom_out: "osmo".to_string(),
+                        }],
+                        minimum_receive: Some(Uint128::new(920_000)),
+                        max_spread: None,
+                    })
+                    .unwrap(),
+                    funds: vec![Coin::new(1_000_000, "untrn")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "other_affiliate".to_string(),
+                    amount: vec![Coin::new(40_000, "osmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ]
+    );
+
+    // Assert the affiliate's fee is accrued
+    let accrued_fees: Vec<Coin> = from_binary(
+        &skip_api_entry_point::contract::query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::AccruedAffiliateFees {
+                address: "affiliate".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(accrued_fees, vec![Coin::new(80_000, "osmo")]);
+
+    // Dispatch the post swap action, which transfers out the contract balance
+    // minus the accrued affiliate fees
+    let res = skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("entry_point", &[]),
+        ExecuteMsg::PostSwapAction {
+            min_coin: Coin::new(800_000, "osmo"),
+            timeout_timestamp: 101,
+            post_swap_action: Action::BankSend {
+                to_address: "recipient".to_string(),
+            },
+            exact_out: false,
+            affiliates: vec![],
+            callback_address: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg {
+            id: 0,
+            msg: BankMsg::Send {
+                to_address: "recipient".to_string(),
+                amount: vec![Coin::new(920_000, "osmo")],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }]
+    );
+
+    // Claim the accrued affiliate fees
+    let res = skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env,
+        mock_info("affiliate", &[]),
+        ExecuteMsg::ClaimAffiliateFees {
+            denom: "osmo".to_string(),
+            destination: ClaimDestination::Local {
+                to_address: "affiliate".to_string(),
+            },
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg {
+            id: 0,
+            msg: BankMsg::Send {
+                to_address: "affiliate".to_string(),
+                amount: vec![Coin::new(80_000, "osmo")],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }]
+    );
+
+    // Assert no affiliate fees are accrued after the claim
+    assert!(ACCRUED_AFFILIATE_FEES
+        .may_load(
+            deps.as_ref().storage,
+            (&Addr::unchecked("affiliate"), "osmo")
+        )
+        .unwrap()
+        .is_none());
+    assert!(TOTAL_ACCRUED_AFFILIATE_FEES
+        .may_load(deps.as_ref().storage, "osmo")
+        .unwrap()
+        .is_none());
+}
+
+// Creates the IBC info of the claim IBC transfers with the given IBC fee
+fn ibc_info(fee: Option<IbcFee>) -> IbcInfo {
+    IbcInfo {
+        source_channel: "channel-0".to_string(),
+        receiver: "receiver".to_string(),
+        fee,
+        memo: "".to_string(),
+        recover_address: "recover_address".to_string(),
+    }
+}
+
+// Creates an IBC fee of 20_000 of the given denom
+fn ibc_fee(denom: &str) -> IbcFee {
+    IbcFee {
+        recv_fee: vec![],
+        ack_fee: vec![Coin::new(10_000, denom)],
+        timeout_fee: vec![Coin::new(10_000, denom)],
+    }
+}
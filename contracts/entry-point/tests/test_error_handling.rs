@@ -3,18 +3,24 @@ use cosmwasm_std::{
     to_binary, Addr, Coin, ContractResult, CosmosMsg, QuerierResult, ReplyOn, SubMsg, SystemResult,
     Timestamp, WasmMsg, WasmQuery,
 };
-use skip::entry_point::{Action, Affiliate, ExecuteMsg};
+use skip::entry_point::{Action, Affiliate, ExecuteMsg, IbcRecovery};
+use skip::ibc::IbcFee;
 use skip::swap::{Swap, SwapExactCoinIn, SwapOperation};
 use skip_api_entry_point::error::ContractError;
-use skip_api_entry_point::state::{IBC_TRANSFER_CONTRACT_ADDRESS, SWAP_VENUE_MAP};
+use skip_api_entry_point::state::{
+    DEFAULT_IBC_TRANSFER_ADAPTER, IBC_TRANSFER_ADAPTER_MAP, RECOVER_TEMP_STORAGE, SWAP_VENUE_MAP,
+};
 
 pub struct Params {
+    caller: String,
+    contract_address: String,
     info_funds: Vec<Coin>,
     user_swap: Swap,
     min_coin: Coin,
     timeout_timestamp: u64,
     post_swap_action: Action,
     affiliates: Vec<Affiliate>,
+    ibc_recovery: Option<IbcRecovery>,
     expected_messages: Vec<SubMsg>,
     expected_error: Option<ContractError>,
 }
@@ -43,14 +49,14 @@ pub fn test_execute_swap_and_action_with_recover(params: Params) {
 
     // Create mock env with parameters that make testing easier
     let mut env = mock_env();
-    env.contract.address = Addr::unchecked("entry_point");
+    env.contract.address = Addr::unchecked(params.contract_address);
     env.block.time = Timestamp::from_nanos(100);
 
     // Convert info funds vector into a slice of Coin objects
     let info_funds: &[Coin] = &params.info_funds;
 
-    // Create mock info with entry point contract address
-    let info = mock_info("swapper", info_funds);
+    // Create mock info with the caller and info funds
+    let info = mock_info(&params.caller, info_funds);
 
     // Store the swap venue adapter contract address
     let swap_venue_adapter = Addr::unchecked("swap_venue_adapter");
@@ -62,10 +68,17 @@ pub fn test_execute_swap_and_action_with_recover(params: Params) {
         )
         .unwrap();
 
-    // Store the ibc transfer adapter contract address
+    // Store the ibc transfer adapter contract address as the default ibc transfer adapter
     let ibc_transfer_adapter = Addr::unchecked("ibc_transfer_adapter");
-    IBC_TRANSFER_CONTRACT_ADDRESS
-        .save(deps.as_mut().storage, &ibc_transfer_adapter)
+    IBC_TRANSFER_ADAPTER_MAP
+        .save(
+            deps.as_mut().storage,
+            "ibc_transfer_adapter",
+            &ibc_transfer_adapter,
+        )
+        .unwrap();
+    DEFAULT_IBC_TRANSFER_ADAPTER
+        .save(deps.as_mut().storage, &"ibc_transfer_adapter".to_string())
         .unwrap();
 
     let recovery_addr = Addr::unchecked("recovery_address");
@@ -75,12 +88,16 @@ pub fn test_execute_swap_and_action_with_recover(params: Params) {
         env,
         info,
         ExecuteMsg::SwapAndActionWithRecover {
+            pre_swap_action: None,
             user_swap: params.user_swap,
             min_coin: params.min_coin,
             timeout_timestamp: params.timeout_timestamp,
             post_swap_action: params.post_swap_action,
             affiliates: params.affiliates,
+            affiliate_fee_basis: None,
             recovery_addr,
+            callback_address: None,
+            ibc_recovery: params.ibc_recovery.clone(),
         },
     );
 
@@ -104,6 +121,15 @@ pub fn test_execute_swap_and_action_with_recover(params: Params) {
 
             // Assert the messages in the response are correct
             assert_eq!(res.messages, params.expected_messages,);
+
+            // Assert the IBC recovery is stored to recover the funds with
+            assert_eq!(
+                RECOVER_TEMP_STORAGE
+                    .load(deps.as_ref().storage)
+                    .unwrap()
+                    .ibc_recovery,
+                params.ibc_recovery
+            );
         }
         Err(err) => {
             // Assert the test expected an error
@@ -123,6 +149,8 @@ pub fn test_execute_swap_and_action_with_recover(params: Params) {
 #[test]
 pub fn successful_swap_and_action_with_recover() {
     let params = Params {
+        caller: "swapper".to_string(),
+        contract_address: "entry_point".to_string(),
         info_funds: vec![Coin::new(1_000_000, "untrn")],
         user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
             swap_venue_name: "swap_venue_name".to_string(),
@@ -138,11 +166,13 @@ pub fn successful_swap_and_action_with_recover() {
             to_address: "to_address".to_string(),
         },
         affiliates: vec![],
+        ibc_recovery: None,
         expected_messages: vec![SubMsg {
             id: 1,
             msg: CosmosMsg::from(WasmMsg::Execute {
                 contract_addr: "entry_point".to_string(),
                 msg: to_binary(&ExecuteMsg::SwapAndAction {
+                    pre_swap_action: None,
                     user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
                         swap_venue_name: "swap_venue_name".to_string(),
                         operations: vec![SwapOperation {
@@ -157,6 +187,8 @@ pub fn successful_swap_and_action_with_recover() {
                         to_address: "to_address".to_string(),
                     },
                     affiliates: vec![],
+                    affiliate_fee_basis: None,
+                    callback_address: None,
                 })
                 .unwrap(),
                 funds: vec![Coin::new(1000000, "untrn")],
@@ -174,6 +206,8 @@ pub fn successful_swap_and_action_with_recover() {
 #[test]
 pub fn timeout_error_passes() {
     let params = Params {
+        caller: "swapper".to_string(),
+        contract_address: "entry_point".to_string(),
         info_funds: vec![Coin::new(1_000_000, "untrn")],
         user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
             swap_venue_name: "swap_venue_name".to_string(),
@@ -189,11 +223,13 @@ pub fn timeout_error_passes() {
             to_address: "to_address".to_string(),
         },
         affiliates: vec![],
+        ibc_recovery: None,
         expected_messages: vec![SubMsg {
             id: 1,
             msg: CosmosMsg::from(WasmMsg::Execute {
                 contract_addr: "entry_point".to_string(),
                 msg: to_binary(&ExecuteMsg::SwapAndAction {
+                    pre_swap_action: None,
                     user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
                         swap_venue_name: "swap_venue_name".to_string(),
                         operations: vec![SwapOperation {
@@ -208,6 +244,8 @@ pub fn timeout_error_passes() {
                         to_address: "to_address".to_string(),
                     },
                     affiliates: vec![],
+                    affiliate_fee_basis: None,
+                    callback_address: None,
                 })
                 .unwrap(),
                 funds: vec![Coin::new(1000000, "untrn")],
@@ -220,3 +258,199 @@ pub fn timeout_error_passes() {
 
     test_execute_swap_and_action_with_recover(params);
 }
+
+// Test SwapAndActionWithRecover called through ibc-hooks stores the IBC recovery to recover the funds with
+#[test]
+pub fn successful_swap_and_action_with_ibc_recovery() {
+    let params = Params {
+        caller: "osmo10ey3rve5ftljqadewalu3umgl73uetcf29jkqcjw05d3amarpyfs3s2d7z".to_string(),
+        contract_address: "osmo1x69lw8f95tjqclknpqnvhsg0nzz8trq4gzx709uq9qx878zq0kwsfx0wqv"
+            .to_string(),
+        info_funds: vec![Coin::new(1_000_000, "untrn")],
+        user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+            swap_venue_name: "swap_venue_name".to_string(),
+            operations: vec![],
+        }),
+        min_coin: Coin::new(1_000_000, "untrn"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::BankSend {
+            to_address: "to_address".to_string(),
+        },
+        affiliates: vec![],
+        ibc_recovery: Some(IbcRecovery {
+            channel: "channel-0".to_string(),
+            original_sender: "cosmos1qnufjmd8vwm6j6d3q28wxqr4d8408f34f99rlj".to_string(),
+            fee: Some(IbcFee {
+                recv_fee: vec![],
+                ack_fee: vec![Coin::new(1_000, "untrn")],
+                timeout_fee: vec![Coin::new(1_000, "untrn")],
+            }),
+            timeout_seconds: Some(600),
+        }),
+        expected_messages: vec![SubMsg {
+            id: 1,
+            msg: CosmosMsg::from(WasmMsg::Execute {
+                contract_addr: "osmo1x69lw8f95tjqclknpqnvhsg0nzz8trq4gzx709uq9qx878zq0kwsfx0wqv"
+                    .to_string(),
+                msg: to_binary(&ExecuteMsg::SwapAndAction {
+                    pre_swap_action: None,
+                    user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+                        swap_venue_name: "swap_venue_name".to_string(),
+                        operations: vec![],
+                    }),
+                    min_coin: Coin::new(1_000_000, "untrn"),
+                    timeout_timestamp: 101,
+                    post_swap_action: Action::BankSend {
+                        to_address: "to_address".to_string(),
+                    },
+                    affiliates: vec![],
+                    affiliate_fee_basis: None,
+                    callback_address: None,
+                })
+                .unwrap(),
+                funds: vec![Coin::new(1_000_000, "untrn")],
+            }),
+            gas_limit: None,
+            reply_on: ReplyOn::Always,
+        }],
+        expected_error: None,
+    };
+
+    test_execute_swap_and_action_with_recover(params);
+}
+
+// IBC recovery errors instead of recovering the funds if the caller is not the
+// ibc-hooks intermediate sender of the original sender on the channel
+#[test]
+pub fn ibc_recovery_sender_not_ibc_hooks_sender_error() {
+    let params = Params {
+        caller: "osmo10ey3rve5ftljqadewalu3umgl73uetcf29jkqcjw05d3amarpyfs3s2d7z".to_string(),
+        contract_address: "osmo1x69lw8f95tjqclknpqnvhsg0nzz8trq4gzx709uq9qx878zq0kwsfx0wqv"
+            .to_string(),
+        info_funds: vec![Coin::new(1_000_000, "untrn")],
+        user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+            swap_venue_name: "swap_venue_name".to_string(),
+            operations: vec![],
+        }),
+        min_coin: Coin::new(1_000_000, "untrn"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::BankSend {
+            to_address: "to_address".to_string(),
+        },
+        affiliates: vec![],
+        ibc_recovery: Some(IbcRecovery {
+            channel: "channel-1".to_string(),
+            original_sender: "cosmos1qnufjmd8vwm6j6d3q28wxqr4d8408f34f99rlj".to_string(),
+            fee: None,
+            timeout_seconds: None,
+        }),
+        expected_messages: vec![],
+        expected_error: Some(ContractError::IbcRecoverySenderNotIbcHooksSender),
+    };
+
+    test_execute_swap_and_action_with_recover(params);
+}
+
+// IBC recovery errors instead of recovering the funds if its IBC fee
+// is not in the denom of the coin sent
+#[test]
+pub fn ibc_recovery_fee_denom_differs_from_coin_sent_error() {
+    let params = Params {
+        caller: "osmo10ey3rve5ftljqadewalu3umgl73uetcf29jkqcjw05d3amarpyfs3s2d7z".to_string(),
+        contract_address: "osmo1x69lw8f95tjqclknpqnvhsg0nzz8trq4gzx709uq9qx878zq0kwsfx0wqv"
+            .to_string(),
+        info_funds: vec![Coin::new(1_000_000, "untrn")],
+        user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+            swap_venue_name: "swap_venue_name".to_string(),
+            operations: vec![],
+        }),
+        min_coin: Coin::new(1_000_000, "untrn"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::BankSend {
+            to_address: "to_address".to_string(),
+        },
+        affiliates: vec![],
+        ibc_recovery: Some(IbcRecovery {
+            channel: "channel-0".to_string(),
+            original_sender: "cosmos1qnufjmd8vwm6j6d3q28wxqr4d8408f34f99rlj".to_string(),
+            fee: Some(IbcFee {
+                recv_fee: vec![],
+                ack_fee: vec![Coin::new(1_000, "osmo")],
+                timeout_fee: vec![],
+            }),
+            timeout_seconds: None,
+        }),
+        expected_messages: vec![],
+        expected_error: Some(ContractError::IBCFeeDenomDiffersFromCoinReceived),
+    };
+
+    test_execute_swap_and_action_with_recover(params);
+}
+
+// IBC recovery errors instead of recovering the funds if its IBC fee
+// cannot be paid out of the coin sent
+#[test]
+pub fn ibc_recovery_fee_not_less_than_coin_sent_error() {
+    let params = Params {
+        caller: "osmo10ey3rve5ftljqadewalu3umgl73uetcf29jkqcjw05d3amarpyfs3s2d7z".to_string(),
+        contract_address: "osmo1x69lw8f95tjqclknpqnvhsg0nzz8trq4gzx709uq9qx878zq0kwsfx0wqv"
+            .to_string(),
+        info_funds: vec![Coin::new(1_000_000, "untrn")],
+        user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+            swap_venue_name: "swap_venue_name".to_string(),
+            operations: vec![],
+        }),
+        min_coin: Coin::new(1_000_000, "untrn"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::BankSend {
+            to_address: "to_address".to_string(),
+        },
+        affiliates: vec![],
+        ibc_recovery: Some(IbcRecovery {
+            channel: "channel-0".to_string(),
+            original_sender: "cosmos1qnufjmd8vwm6j6d3q28wxqr4d8408f34f99rlj".to_string(),
+            fee: Some(IbcFee {
+                recv_fee: vec![],
+                ack_fee: vec![Coin::new(1_000_000, "untrn")],
+                timeout_fee: vec![],
+            }),
+            timeout_seconds: None,
+        }),
+        expected_messages: vec![],
+        expected_error: Some(ContractError::IbcRecoveryFeeNotLessThanCoinSent),
+    };
+
+    test_execute_swap_and_action_with_recover(params);
+}
+
+// IBC recovery errors instead of recovering the funds if its recovery
+// transfer would time out immediately
+#[test]
+pub fn ibc_recovery_timeout_zero_error() {
+    let params = Params {
+        caller: "osmo10ey3rve5ftljqadewalu3umgl73uetcf29jkqcjw05d3amarpyfs3s2d7z".to_string(),
+        contract_address: "osmo1x69lw8f95tjqclknpqnvhsg0nzz8trq4gzx709uq9qx878zq0kwsfx0wqv"
+            .to_string(),
+        info_funds: vec![Coin::new(1_000_000, "untrn")],
+        user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+            swap_venue_name: "swap_venue_name".to_string(),
+            operations: vec![],
+        }),
+        min_coin: Coin::new(1_000_000, "untrn"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::BankSend {
+            to_address: "to_address".to_string(),
+        },
+        affiliates: vec![],
+        ibc_recovery: Some(IbcRecovery {
+            channel: "channel-0".to_string(),
+            original_sender: "cosmos1qnufjmd8vwm6j6d3q28wxqr4d8408f34f99rlj".to_string(),
+            fee: None,
+            timeout_seconds: Some(0),
+        }),
+        expected_messages: vec![],
+        expected_error: Some(ContractError::IbcRecoveryTimeoutZero),
+    };
+
+    test_execute_swap_and_action_with_recover(params);
+}
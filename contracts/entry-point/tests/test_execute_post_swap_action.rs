@@ -1,16 +1,25 @@
 use cosmwasm_std::{
     testing::{mock_dependencies_with_balances, mock_env, mock_info},
-    to_binary, Addr, BankMsg, Coin,
+    to_binary, Addr, BankMsg, Binary, Coin, ContractResult, CosmosMsg, Decimal, QuerierResult,
     ReplyOn::Never,
-    SubMsg, Timestamp, WasmMsg,
+    StakingMsg, SubMsg, SystemResult, Timestamp, Uint128, Validator, WasmMsg, WasmQuery,
 };
+use cw20::{BalanceResponse, Cw20ExecuteMsg};
 use skip::{
-    entry_point::{Action, ExecuteMsg},
+    entry_point::{Action, Affiliate, ExecuteMsg, MultiActionAmount, MultiActionLeg, SequenceStep},
+    error::SkipError,
     ibc::{ExecuteMsg as IbcTransferExecuteMsg, IbcFee, IbcInfo},
+    proto_coin::ProtoCoin,
+    staking::MsgTokenizeShares,
+    swap::ExecuteMsg as SwapExecuteMsg,
+    template::{MsgTemplate, TemplateValue},
 };
 use skip_api_entry_point::{
     error::ContractError,
-    state::{BLOCKED_CONTRACT_ADDRESSES, IBC_TRANSFER_CONTRACT_ADDRESS},
+    state::{
+        BLOCKED_CONTRACT_ADDRESSES, DEFAULT_IBC_TRANSFER_ADAPTER, DELEGATE_ENABLED,
+        IBC_TRANSFER_ADAPTER_MAP, SWAP_VENUE_MAP,
+    },
 };
 use test_case::test_case;
 
@@ -21,6 +30,7 @@ Expect Response
     // General
     - Bank Send
     - Ibc Transfer
+    - Ibc Transfer With Named IBC Transfer Adapter
     - Contract Call
 
     // With IBC Fees
@@ -34,11 +44,49 @@ Expect Response
     - Ibc Transfer w/ IBC Fees of same denom as min coin With Exact Out Set To True
     - Contract Call With Exact Out Set To True
 
+    // Msg Templates
+    - Contract Call With Msg Templates (Substitutes the coin's amount and denom into the templated paths)
+
+    // CW20
+    - Bank Send With CW20 Min Coin (Transfers the CW20 balance queried from the CW20 contract)
+    - Contract Call With CW20 Min Coin (Sends the CW20 balance to the contract with the message as the hook)
+
+    // Multi
+    - Multi With Fixed And Share Legs (Fixed amounts are paid first, shares split the rest)
+    - Multi With Rounding Remainder (Remainder is sent to the remainder address)
+
+    // Sequence
+    - Sequence With Contract Call Then Bank Send (Dispatches the next steps with the balance before the first step)
+    - Sequence With Provide Liquidity Then Bank Send (Sends the coin to the swap venue adapter to provide liquidity)
+
+    // Provide Liquidity
+    - Provide Liquidity To Recipient (Sends the coin to the swap venue adapter with the LP token recipient)
+
+    // Delegate
+    - Delegate (Delegates the coin, tokenizes the delegation and transfers the share tokens to the delegator recipient)
+
+    // Actual Out Affiliate Fees
+    - Bank Send With Actual Out Affiliate Fees (Fees are charged on the balance and sent after the post swap action)
+
 Expect Error
     - Bank Send Timeout
     - Received Less From Swap Than Min Coin
+    - Received Less From Swap Than Min Coin After Actual Out Affiliate Fees
     - Unauthorized Caller
     - Contract Call Address Blocked
+    - Contract Call With Msg Template At An Undeclared Path
+    - Ibc Transfer With Unknown IBC Transfer Adapter
+    - Ibc Transfer With CW20 Min Coin
+    - Multi With Fixed Amounts Exceeding Coin
+    - Multi With Nested Multi Leg
+    - Multi With Shares Summing To More Than One
+    - Sequence With Bank Send Before The Last Step
+    - Sequence With Min Coin Out On The Last Step
+    - Sequence With Provide Liquidity Without A Recipient As The Last Step
+    - Sequence With Provide Liquidity With A Recipient Before The Last Step
+    - Sequence With Provide Liquidity Of CW20 Min Coin
+    - Delegate Coin Not The Bond Denom
+    - Delegate To Unknown Validator
  */
 
 // Define test parameters
@@ -47,6 +95,7 @@ struct Params {
     min_coin: Coin,
     post_swap_action: Action,
     exact_out: bool,
+    affiliates: Vec<Affiliate>,
     expected_messages: Vec<SubMsg>,
     expected_error: Option<ContractError>,
 }
@@ -60,6 +109,7 @@ struct Params {
             to_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string(),
         },
         exact_out: true,
+        affiliates: vec![],
         expected_messages: vec![SubMsg {
             id: 0,
             msg: BankMsg::Send {
@@ -81,6 +131,7 @@ struct Params {
             to_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string(),
         },
         exact_out: false,
+        affiliates: vec![],
         expected_messages: vec![SubMsg {
             id: 0,
             msg: BankMsg::Send {
@@ -108,8 +159,10 @@ struct Params {
                     .to_string(),
             },
             fee_swap: None,
+            ibc_transfer_adapter: None,
         },
         exact_out: false,
+        affiliates: vec![],
         expected_messages: vec![SubMsg {
             id: 0,
             msg: WasmMsg::Execute {
@@ -125,6 +178,7 @@ struct Params {
                     },
                     coin: Coin::new(1_000_000, "osmo"),
                     timeout_timestamp: 101,
+                    callback_address: None,
                 })
                 .unwrap(),
                 funds: vec![Coin::new(1_000_000, "osmo")],
@@ -136,6 +190,51 @@ struct Params {
         expected_error: None,
     };
     "Ibc Transfer")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(1_000_000, "osmo"),
+        post_swap_action: Action::IbcTransfer {
+            ibc_info: IbcInfo {
+                source_channel: "channel-0".to_string(),
+                receiver: "receiver".to_string(),
+                memo: "".to_string(),
+                fee: None,
+                recover_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5"
+                    .to_string(),
+            },
+            fee_swap: None,
+            ibc_transfer_adapter: Some("hooks".to_string()),
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![SubMsg {
+            id: 0,
+            msg: WasmMsg::Execute {
+                contract_addr: "hooks_ibc_transfer_adapter".to_string(),
+                msg: to_binary(&IbcTransferExecuteMsg::IbcTransfer {
+                    info: IbcInfo {
+                        source_channel: "channel-0".to_string(),
+                        receiver: "receiver".to_string(),
+                        memo: "".to_string(),
+                        fee: None,
+                        recover_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5"
+                            .to_string(),
+                    },
+                    coin: Coin::new(1_000_000, "osmo"),
+                    timeout_timestamp: 101,
+                    callback_address: None,
+                })
+                .unwrap(),
+                funds: vec![Coin::new(1_000_000, "osmo")],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }],
+        expected_error: None,
+    };
+    "Ibc Transfer With Named IBC Transfer Adapter")]
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
@@ -150,8 +249,10 @@ struct Params {
                     .to_string(),
             },
             fee_swap: None,
+            ibc_transfer_adapter: None,
         },
         exact_out: true,
+        affiliates: vec![],
         expected_messages: vec![SubMsg {
             id: 0,
             msg: WasmMsg::Execute {
@@ -167,6 +268,7 @@ struct Params {
                     },
                     coin: Coin::new(100_000, "osmo"),
                     timeout_timestamp: 101,
+                    callback_address: None,
                 })
                 .unwrap(),
                 funds: vec![Coin::new(100_000, "osmo")],
@@ -196,8 +298,10 @@ struct Params {
                     .to_string(),
             },
             fee_swap: None,
+            ibc_transfer_adapter: None,
         },
         exact_out: true,
+        affiliates: vec![],
         expected_messages: vec![SubMsg {
             id: 0,
             msg: WasmMsg::Execute {
@@ -217,6 +321,7 @@ struct Params {
                     },
                     coin: Coin::new(100_000, "osmo"),
                     timeout_timestamp: 101,
+                    callback_address: None,
                 })
                 .unwrap(),
                 funds: vec![
@@ -248,8 +353,10 @@ struct Params {
                     .to_string(),
             },
             fee_swap: None,
+            ibc_transfer_adapter: None,
         },
         exact_out: true,
+        affiliates: vec![],
         expected_messages: vec![SubMsg {
             id: 0,
             msg: WasmMsg::Execute {
@@ -269,6 +376,7 @@ struct Params {
                     },
                     coin: Coin::new(100_000, "untrn"),
                     timeout_timestamp: 101,
+                    callback_address: None,
                 })
                 .unwrap(),
                 funds: vec![Coin::new(100_000, "untrn")],
@@ -287,8 +395,10 @@ struct Params {
         post_swap_action: Action::ContractCall {
             contract_address: "contract_call".to_string(),
             msg: to_binary(&"contract_call_msg").unwrap(),
+            msg_templates: None,
         },
         exact_out: false,
+        affiliates: vec![],
         expected_messages: vec![SubMsg {
             id: 0,
             msg: WasmMsg::Execute {
@@ -311,8 +421,10 @@ struct Params {
         post_swap_action: Action::ContractCall {
             contract_address: "contract_call".to_string(),
             msg: to_binary(&"contract_call_msg").unwrap(),
+            msg_templates: None,
         },
         exact_out: true,
+        affiliates: vec![],
         expected_messages: vec![SubMsg {
             id: 0,
             msg: WasmMsg::Execute {
@@ -327,6 +439,40 @@ struct Params {
         expected_error: None,
     };
     "Contract Call With Exact Out Set To True")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(900_000, "osmo"),
+        post_swap_action: Action::ContractCall {
+            contract_address: "contract_call".to_string(),
+            msg: Binary::from(br#"{"deposit":{"amount":"{{amount}}","denom":"{{denom}}"}}"#),
+            msg_templates: Some(vec![
+                MsgTemplate {
+                    path: vec!["deposit".to_string(), "amount".to_string()],
+                    value: TemplateValue::Amount,
+                },
+                MsgTemplate {
+                    path: vec!["deposit".to_string(), "denom".to_string()],
+                    value: TemplateValue::Denom,
+                },
+            ]),
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![SubMsg {
+            id: 0,
+            msg: WasmMsg::Execute {
+                contract_addr: "contract_call".to_string(),
+                msg: Binary::from(br#"{"deposit":{"amount":"1000000","denom":"osmo"}}"#),
+                funds: vec![Coin::new(1_000_000, "osmo")],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }],
+        expected_error: None,
+    };
+    "Contract Call With Msg Templates")]
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
@@ -345,8 +491,10 @@ struct Params {
                     .to_string(),
             },
             fee_swap: None,
+            ibc_transfer_adapter: None,
         },
         exact_out: false,
+        affiliates: vec![],
         expected_messages: vec![SubMsg {
             id: 0,
             msg: WasmMsg::Execute {
@@ -366,6 +514,7 @@ struct Params {
                     },
                     coin: Coin::new(1_000_000, "osmo"),
                     timeout_timestamp: 101,
+                    callback_address: None,
                 })
                 .unwrap(),
                 funds: vec![
@@ -397,8 +546,10 @@ struct Params {
                     .to_string(),
             },
             fee_swap: None,
+            ibc_transfer_adapter: None,
         },
         exact_out: false,
+        affiliates: vec![],
         expected_messages: vec![SubMsg {
             id: 0,
             msg: WasmMsg::Execute {
@@ -418,6 +569,7 @@ struct Params {
                     },
                     coin: Coin::new(1_000_000, "untrn"),
                     timeout_timestamp: 101,
+                    callback_address: None,
                 })
                 .unwrap(),
                 funds: vec![Coin::new(1_000_000, "untrn")],
@@ -429,6 +581,118 @@ struct Params {
         expected_error: None,
     };
     "Ibc Transfer w/ IBC Fees of same denom as min coin")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(1_000_000, "cw20:cw20_contract"),
+        post_swap_action: Action::BankSend {
+            to_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string(),
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![SubMsg {
+            id: 0,
+            msg: WasmMsg::Execute {
+                contract_addr: "cw20_contract".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string(),
+                    amount: Uint128::new(1_000_000),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }],
+        expected_error: None,
+    };
+    "Bank Send With CW20 Min Coin")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(1_000_000, "cw20:cw20_contract"),
+        post_swap_action: Action::ContractCall {
+            contract_address: "contract_call".to_string(),
+            msg: to_binary(&"contract_call_msg").unwrap(),
+            msg_templates: None,
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![SubMsg {
+            id: 0,
+            msg: WasmMsg::Execute {
+                contract_addr: "cw20_contract".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Send {
+                    contract: "contract_call".to_string(),
+                    amount: Uint128::new(1_000_000),
+                    msg: to_binary(&"contract_call_msg").unwrap(),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }],
+        expected_error: None,
+    };
+    "Contract Call With CW20 Min Coin")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(900_000, "osmo"),
+        post_swap_action: Action::BankSend {
+            to_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string(),
+        },
+        exact_out: false,
+        affiliates: vec![
+            Affiliate {
+                basis_points_fee: Some(Uint128::new(100)),
+                address: "affiliate_1".to_string(),
+                id: None,
+            },
+            Affiliate {
+                basis_points_fee: Some(Uint128::new(50)),
+                address: "affiliate_2".to_string(),
+                id: None,
+            },
+        ],
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string(),
+                    amount: vec![Coin::new(985_000, "osmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "affiliate_1".to_string(),
+                    amount: vec![Coin::new(10_000, "osmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "affiliate_2".to_string(),
+                    amount: vec![Coin::new(5_000, "osmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "Bank Send With Actual Out Affiliate Fees")]
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
@@ -437,10 +701,28 @@ struct Params {
             to_address: "swapper".to_string(),
         },
         exact_out: false,
+        affiliates: vec![],
         expected_messages: vec![],
         expected_error: Some(ContractError::ReceivedLessCoinFromSwapsThanMinCoin),
     };
     "Received Less From Swap Than Min Coin - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(995_000, "untrn"),
+        post_swap_action: Action::BankSend {
+            to_address: "swapper".to_string(),
+        },
+        exact_out: false,
+        affiliates: vec![Affiliate {
+            basis_points_fee: Some(Uint128::new(100)),
+            address: "affiliate".to_string(),
+            id: None,
+        }],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::ReceivedLessCoinFromSwapsThanMinCoin),
+    };
+    "Received Less From Swap Than Min Coin After Actual Out Affiliate Fees - Expect Error")]
 #[test_case(
     Params {
         caller: "unauthorized".to_string(),
@@ -449,6 +731,7 @@ struct Params {
             to_address: "swapper".to_string(),
         },
         exact_out: false,
+        affiliates: vec![],
         expected_messages: vec![],
         expected_error: Some(ContractError::Unauthorized),
     };
@@ -460,12 +743,677 @@ struct Params {
         post_swap_action: Action::ContractCall {
             contract_address: "entry_point".to_string(),
             msg: to_binary(&"contract_call_msg").unwrap(),
+            msg_templates: None,
         },
         exact_out: false,
+        affiliates: vec![],
         expected_messages: vec![],
         expected_error: Some(ContractError::ContractCallAddressBlocked),
     };
     "Contract Call Address Blocked - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(900_000, "osmo"),
+        post_swap_action: Action::ContractCall {
+            contract_address: "contract_call".to_string(),
+            msg: Binary::from(br#"{"deposit":{"amount":"{{amount}}","denom":"{{denom}}"}}"#),
+            msg_templates: Some(vec![MsgTemplate {
+                path: vec!["deposit".to_string(), "amount".to_string()],
+                value: TemplateValue::Amount,
+            }]),
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Skip(SkipError::MsgTemplatePlaceholderNotDeclared)),
+    };
+    "Contract Call With Msg Template At An Undeclared Path - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(1_000_000, "osmo"),
+        post_swap_action: Action::IbcTransfer {
+            ibc_info: IbcInfo {
+                source_channel: "channel-0".to_string(),
+                receiver: "receiver".to_string(),
+                memo: "".to_string(),
+                fee: None,
+                recover_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5"
+                    .to_string(),
+            },
+            fee_swap: None,
+            ibc_transfer_adapter: Some("unknown".to_string()),
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::IbcTransferAdapterNotFound("unknown".to_string())),
+    };
+    "Ibc Transfer With Unknown IBC Transfer Adapter - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(1_000_000, "cw20:cw20_contract"),
+        post_swap_action: Action::IbcTransfer {
+            ibc_info: IbcInfo {
+                source_channel: "channel-0".to_string(),
+                receiver: "receiver".to_string(),
+                memo: "".to_string(),
+                fee: None,
+                recover_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5"
+                    .to_string(),
+            },
+            fee_swap: None,
+            ibc_transfer_adapter: None,
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Cw20IbcTransferNotSupported),
+    };
+    "Ibc Transfer With CW20 Min Coin - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(900_000, "osmo"),
+        post_swap_action: Action::Multi {
+            legs: vec![
+                MultiActionLeg {
+                    amount: MultiActionAmount::Share(Decimal::percent(70)),
+                    action: Action::BankSend {
+                        to_address: "payroll".to_string(),
+                    },
+                },
+                MultiActionLeg {
+                    amount: MultiActionAmount::Fixed(Uint128::new(100_000)),
+                    action: Action::BankSend {
+                        to_address: "treasury".to_string(),
+                    },
+                },
+                MultiActionLeg {
+                    amount: MultiActionAmount::Share(Decimal::percent(30)),
+                    action: Action::IbcTransfer {
+                        ibc_info: IbcInfo {
+                            source_channel: "channel-0".to_string(),
+                            receiver: "receiver".to_string(),
+                            memo: "".to_string(),
+                            fee: None,
+                            recover_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5"
+                                .to_string(),
+                        },
+                        fee_swap: None,
+                        ibc_transfer_adapter: None,
+                    },
+                },
+            ],
+            remainder_address: "remainder".to_string(),
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "payroll".to_string(),
+                    amount: vec![Coin::new(630_000, "osmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "treasury".to_string(),
+                    amount: vec![Coin::new(100_000, "osmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "ibc_transfer_adapter".to_string(),
+                    msg: to_binary(&IbcTransferExecuteMsg::IbcTransfer {
+                        info: IbcInfo {
+                            source_channel: "channel-0".to_string(),
+                            receiver: "receiver".to_string(),
+                            memo: "".to_string(),
+                            fee: None,
+                            recover_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5"
+                                .to_string(),
+                        },
+                        coin: Coin::new(270_000, "osmo"),
+                        timeout_timestamp: 101,
+                        callback_address: None,
+                    })
+                    .unwrap(),
+                    funds: vec![Coin::new(270_000, "osmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "Multi With Fixed And Share Legs")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(900_000, "osmo"),
+        post_swap_action: Action::Multi {
+            legs: vec![
+                MultiActionLeg {
+                    amount: MultiActionAmount::Share(Decimal::from_ratio(1u128, 3u128)),
+                    action: Action::BankSend {
+                        to_address: "payroll".to_string(),
+                    },
+                },
+                MultiActionLeg {
+                    amount: MultiActionAmount::Share(Decimal::from_ratio(2u128, 3u128)),
+                    action: Action::BankSend {
+                        to_address: "treasury".to_string(),
+                    },
+                },
+            ],
+            remainder_address: "remainder".to_string(),
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "payroll".to_string(),
+                    amount: vec![Coin::new(333_333, "osmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "treasury".to_string(),
+                    amount: vec![Coin::new(666_666, "osmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "remainder".to_string(),
+                    amount: vec![Coin::new(1, "osmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "Multi With Rounding Remainder")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(900_000, "osmo"),
+        post_swap_action: Action::Multi {
+            legs: vec![
+                MultiActionLeg {
+                    amount: MultiActionAmount::Fixed(Uint128::new(600_000)),
+                    action: Action::BankSend {
+                        to_address: "payroll".to_string(),
+                    },
+                },
+                MultiActionLeg {
+                    amount: MultiActionAmount::Fixed(Uint128::new(600_000)),
+                    action: Action::BankSend {
+                        to_address: "treasury".to_string(),
+                    },
+                },
+            ],
+            remainder_address: "remainder".to_string(),
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::MultiActionFixedAmountsExceedCoin),
+    };
+    "Multi With Fixed Amounts Exceeding Coin - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(900_000, "osmo"),
+        post_swap_action: Action::Multi {
+            legs: vec![
+                MultiActionLeg {
+                    amount: MultiActionAmount::Share(Decimal::one()),
+                    action: Action::Multi {
+                        legs: vec![],
+                        remainder_address: "remainder".to_string(),
+                    },
+                },
+            ],
+            remainder_address: "remainder".to_string(),
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::MultiActionNested),
+    };
+    "Multi With Nested Multi Leg - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(900_000, "osmo"),
+        post_swap_action: Action::Multi {
+            legs: vec![
+                MultiActionLeg {
+                    amount: MultiActionAmount::Share(Decimal::percent(70)),
+                    action: Action::BankSend {
+                        to_address: "payroll".to_string(),
+                    },
+                },
+                MultiActionLeg {
+                    amount: MultiActionAmount::Share(Decimal::percent(40)),
+                    action: Action::BankSend {
+                        to_address: "treasury".to_string(),
+                    },
+                },
+            ],
+            remainder_address: "remainder".to_string(),
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::MultiActionSharesGreaterThanOne),
+    };
+    "Multi With Shares Summing To More Than One - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(900_000, "osmo"),
+        post_swap_action: Action::Sequence {
+            steps: vec![
+                SequenceStep {
+                    action: Action::ContractCall {
+                        contract_address: "vault".to_string(),
+                        msg: to_binary(&"deposit").unwrap(),
+                        msg_templates: None,
+                    },
+                    min_coin_out: Some(Coin::new(500_000, "untrn")),
+                },
+                SequenceStep {
+                    action: Action::BankSend {
+                        to_address: "to_address".to_string(),
+                    },
+                    min_coin_out: None,
+                },
+            ],
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "vault".to_string(),
+                    msg: to_binary(&"deposit").unwrap(),
+                    funds: vec![Coin::new(1_000_000, "osmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "entry_point".to_string(),
+                    msg: to_binary(&ExecuteMsg::PostSwapActionStep {
+                        min_coin: Coin::new(500_000, "untrn"),
+                        pre_balance: Uint128::new(1_000_000),
+                        timeout_timestamp: 101,
+                        steps: vec![SequenceStep {
+                            action: Action::BankSend {
+                                to_address: "to_address".to_string(),
+                            },
+                            min_coin_out: None,
+                        }],
+                        callback_address: None,
+                        send_callback: false,
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "Sequence With Contract Call Then Bank Send")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(900_000, "osmo"),
+        post_swap_action: Action::Sequence {
+            steps: vec![
+                SequenceStep {
+                    action: Action::ProvideLiquidity {
+                        swap_venue_name: "swap_venue_name".to_string(),
+                        pool: "pool".to_string(),
+                        recipient: None,
+                        slippage_tolerance: Decimal::percent(1),
+                    },
+                    min_coin_out: Some(Coin::new(500_000, "untrn")),
+                },
+                SequenceStep {
+                    action: Action::BankSend {
+                        to_address: "to_address".to_string(),
+                    },
+                    min_coin_out: None,
+                },
+            ],
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter".to_string(),
+                    msg: to_binary(&SwapExecuteMsg::ProvideLiquidity {
+                        pool: "pool".to_string(),
+                        recipient: None,
+                        slippage_tolerance: Decimal::percent(1),
+                    })
+                    .unwrap(),
+                    funds: vec![Coin::new(1_000_000, "osmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "entry_point".to_string(),
+                    msg: to_binary(&ExecuteMsg::PostSwapActionStep {
+                        min_coin: Coin::new(500_000, "untrn"),
+                        pre_balance: Uint128::new(1_000_000),
+                        timeout_timestamp: 101,
+                        steps: vec![SequenceStep {
+                            action: Action::BankSend {
+                                to_address: "to_address".to_string(),
+                            },
+                            min_coin_out: None,
+                        }],
+                        callback_address: None,
+                        send_callback: false,
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "Sequence With Provide Liquidity Then Bank Send")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(900_000, "osmo"),
+        post_swap_action: Action::ProvideLiquidity {
+            swap_venue_name: "swap_venue_name".to_string(),
+            pool: "pool".to_string(),
+            recipient: Some("recipient".to_string()),
+            slippage_tolerance: Decimal::percent(1),
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![SubMsg {
+            id: 0,
+            msg: WasmMsg::Execute {
+                contract_addr: "swap_venue_adapter".to_string(),
+                msg: to_binary(&SwapExecuteMsg::ProvideLiquidity {
+                    pool: "pool".to_string(),
+                    recipient: Some("recipient".to_string()),
+                    slippage_tolerance: Decimal::percent(1),
+                })
+                .unwrap(),
+                funds: vec![Coin::new(1_000_000, "osmo")],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }],
+        expected_error: None,
+    };
+    "Provide Liquidity To Recipient")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(900_000, "osmo"),
+        post_swap_action: Action::Delegate {
+            validator: "validator".to_string(),
+            delegator_recipient: "delegator_recipient".to_string(),
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: StakingMsg::Delegate {
+                    validator: "validator".to_string(),
+                    amount: Coin::new(1_000_000, "osmo"),
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: CosmosMsg::from(MsgTokenizeShares {
+                    delegator_address: "entry_point".to_string(),
+                    validator_address: "validator".to_string(),
+                    amount: Some(ProtoCoin(Coin::new(1_000_000, "osmo")).into()),
+                    tokenized_share_owner: "delegator_recipient".to_string(),
+                }),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "entry_point".to_string(),
+                    msg: to_binary(&ExecuteMsg::TransferDelegationShares {
+                        validator: "validator".to_string(),
+                        pre_balances: vec![],
+                        delegator_recipient: "delegator_recipient".to_string(),
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "Delegate")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(900_000, "osmo"),
+        post_swap_action: Action::Sequence {
+            steps: vec![
+                SequenceStep {
+                    action: Action::BankSend {
+                        to_address: "to_address".to_string(),
+                    },
+                    min_coin_out: Some(Coin::new(500_000, "untrn")),
+                },
+                SequenceStep {
+                    action: Action::BankSend {
+                        to_address: "to_address".to_string(),
+                    },
+                    min_coin_out: None,
+                },
+            ],
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::SequenceIntermediateStepInvalid),
+    };
+    "Sequence With Bank Send Before The Last Step - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(900_000, "osmo"),
+        post_swap_action: Action::Sequence {
+            steps: vec![
+                SequenceStep {
+                    action: Action::BankSend {
+                        to_address: "to_address".to_string(),
+                    },
+                    min_coin_out: Some(Coin::new(500_000, "untrn")),
+                },
+            ],
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::SequenceLastStepMinCoinOut),
+    };
+    "Sequence With Min Coin Out On The Last Step - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(900_000, "osmo"),
+        post_swap_action: Action::Sequence {
+            steps: vec![
+                SequenceStep {
+                    action: Action::ContractCall {
+                        contract_address: "vault".to_string(),
+                        msg: to_binary(&"deposit").unwrap(),
+                        msg_templates: None,
+                    },
+                    min_coin_out: Some(Coin::new(500_000, "untrn")),
+                },
+                SequenceStep {
+                    action: Action::ProvideLiquidity {
+                        swap_venue_name: "swap_venue_name".to_string(),
+                        pool: "pool".to_string(),
+                        recipient: None,
+                        slippage_tolerance: Decimal::percent(1),
+                    },
+                    min_coin_out: None,
+                },
+            ],
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::ProvideLiquidityNoRecipient),
+    };
+    "Sequence With Provide Liquidity Without A Recipient As The Last Step - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(900_000, "osmo"),
+        post_swap_action: Action::Sequence {
+            steps: vec![
+                SequenceStep {
+                    action: Action::ProvideLiquidity {
+                        swap_venue_name: "swap_venue_name".to_string(),
+                        pool: "pool".to_string(),
+                        recipient: Some("recipient".to_string()),
+                        slippage_tolerance: Decimal::percent(1),
+                    },
+                    min_coin_out: Some(Coin::new(500_000, "untrn")),
+                },
+                SequenceStep {
+                    action: Action::BankSend {
+                        to_address: "to_address".to_string(),
+                    },
+                    min_coin_out: None,
+                },
+            ],
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::SequenceIntermediateStepInvalid),
+    };
+    "Sequence With Provide Liquidity With A Recipient Before The Last Step - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(900_000, "cw20:neutron123"),
+        post_swap_action: Action::Sequence {
+            steps: vec![
+                SequenceStep {
+                    action: Action::ProvideLiquidity {
+                        swap_venue_name: "swap_venue_name".to_string(),
+                        pool: "pool".to_string(),
+                        recipient: None,
+                        slippage_tolerance: Decimal::percent(1),
+                    },
+                    min_coin_out: Some(Coin::new(500_000, "untrn")),
+                },
+                SequenceStep {
+                    action: Action::BankSend {
+                        to_address: "to_address".to_string(),
+                    },
+                    min_coin_out: None,
+                },
+            ],
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Cw20ProvideLiquidityNotSupported),
+    };
+    "Sequence With Provide Liquidity Of CW20 Min Coin - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(900_000, "untrn"),
+        post_swap_action: Action::Delegate {
+            validator: "validator".to_string(),
+            delegator_recipient: "delegator_recipient".to_string(),
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::DelegateCoinNotBondDenom),
+    };
+    "Delegate Coin Not The Bond Denom - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(900_000, "osmo"),
+        post_swap_action: Action::Delegate {
+            validator: "unknown_validator".to_string(),
+            delegator_recipient: "delegator_recipient".to_string(),
+        },
+        exact_out: false,
+        affiliates: vec![],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::DelegateValidatorNotFound("unknown_validator".to_string())),
+    };
+    "Delegate To Unknown Validator - Expect Error")]
 fn test_execute_post_swap_action(params: Params) {
     // Create mock dependencies
     let mut deps = mock_dependencies_with_balances(&[(
@@ -473,6 +1421,35 @@ fn test_execute_post_swap_action(params: Params) {
         &[Coin::new(1_000_000, "osmo"), Coin::new(1_000_000, "untrn")],
     )]);
 
+    // Create mock wasm handler to handle the cw20 contract balance query
+    // Will always return 1_000_000
+    let wasm_handler = |query: &WasmQuery| -> QuerierResult {
+        match query {
+            WasmQuery::Smart { .. } => SystemResult::Ok(ContractResult::Ok(
+                to_binary(&BalanceResponse {
+                    balance: Uint128::new(1_000_000),
+                })
+                .unwrap(),
+            )),
+            _ => panic!("Unsupported query: {:?}", query),
+        }
+    };
+
+    // Update querier with mock wasm handler
+    deps.querier.update_wasm(wasm_handler);
+
+    // Update querier with osmo as the bond denom and a single validator
+    deps.querier.update_staking(
+        "osmo",
+        &[Validator {
+            address: "validator".to_string(),
+            commission: Decimal::percent(5),
+            max_commission: Decimal::percent(10),
+            max_change_rate: Decimal::percent(1),
+        }],
+        &[],
+    );
+
     // Create mock env with parameters that make testing easier
     let mut env = mock_env();
     env.contract.address = Addr::unchecked("entry_point");
@@ -481,10 +1458,35 @@ fn test_execute_post_swap_action(params: Params) {
     // Create mock info with entry point contract address
     let info = mock_info(&params.caller, &[]);
 
-    // Store the ibc transfer adapter contract address
+    // Store the ibc transfer adapter contract address as the default ibc transfer adapter
     let ibc_transfer_adapter = Addr::unchecked("ibc_transfer_adapter");
-    IBC_TRANSFER_CONTRACT_ADDRESS
-        .save(deps.as_mut().storage, &ibc_transfer_adapter)
+    IBC_TRANSFER_ADAPTER_MAP
+        .save(
+            deps.as_mut().storage,
+            "ibc_transfer_adapter",
+            &ibc_transfer_adapter,
+        )
+        .unwrap();
+    DEFAULT_IBC_TRANSFER_ADAPTER
+        .save(deps.as_mut().storage, &"ibc_transfer_adapter".to_string())
+        .unwrap();
+
+    // Store a second named ibc transfer adapter contract address
+    IBC_TRANSFER_ADAPTER_MAP
+        .save(
+            deps.as_mut().storage,
+            "hooks",
+            &Addr::unchecked("hooks_ibc_transfer_adapter"),
+        )
+        .unwrap();
+
+    // Store the swap venue adapter contract address
+    SWAP_VENUE_MAP
+        .save(
+            deps.as_mut().storage,
+            "swap_venue_name",
+            &Addr::unchecked("swap_venue_adapter"),
+        )
         .unwrap();
 
     // Store the entry point contract address in the blocked contract addresses map
@@ -492,6 +1494,9 @@ fn test_execute_post_swap_action(params: Params) {
         .save(deps.as_mut().storage, &Addr::unchecked("entry_point"), &())
         .unwrap();
 
+    // Enable delegate actions
+    DELEGATE_ENABLED.save(deps.as_mut().storage, &true).unwrap();
+
     // Call execute_post_swap_action with the given test parameters
     let res = skip_api_entry_point::contract::execute(
         deps.as_mut(),
@@ -502,6 +1507,8 @@ fn test_execute_post_swap_action(params: Params) {
             timeout_timestamp: 101,
             post_swap_action: params.post_swap_action,
             exact_out: params.exact_out,
+            affiliates: params.affiliates,
+            callback_address: None,
         },
     );
 
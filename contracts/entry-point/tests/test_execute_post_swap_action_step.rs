@@ -0,0 +1,203 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies_with_balances, mock_env, mock_info},
+    to_binary, Addr, BankMsg, Coin,
+    ReplyOn::Never,
+    SubMsg, Timestamp, Uint128, WasmMsg,
+};
+use skip::entry_point::{Action, ExecuteMsg, SequenceStep};
+use skip_api_entry_point::error::ContractError;
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response
+    - Last Step Bank Send (Sends the balance change since the previous step)
+    - Contract Call Step Then Bank Send (Dispatches the next steps with the balance before the step)
+
+Expect Error
+    - Received Less From Previous Step Than Min Coin
+    - Unauthorized Caller
+ */
+
+// Define test parameters
+struct Params {
+    caller: String,
+    min_coin: Coin,
+    pre_balance: Uint128,
+    steps: Vec<SequenceStep>,
+    expected_messages: Vec<SubMsg>,
+    expected_error: Option<ContractError>,
+}
+
+// Test execute_post_swap_action_step
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(500_000, "untrn"),
+        pre_balance: Uint128::new(400_000),
+        steps: vec![SequenceStep {
+            action: Action::BankSend {
+                to_address: "to_address".to_string(),
+            },
+            min_coin_out: None,
+        }],
+        expected_messages: vec![SubMsg {
+            id: 0,
+            msg: BankMsg::Send {
+                to_address: "to_address".to_string(),
+                amount: vec![Coin::new(600_000, "untrn")],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }],
+        expected_error: None,
+    };
+    "Last Step Bank Send")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(500_000, "untrn"),
+        pre_balance: Uint128::new(400_000),
+        steps: vec![
+            SequenceStep {
+                action: Action::ContractCall {
+                    contract_address: "vault".to_string(),
+                    msg: to_binary(&"deposit").unwrap(),
+                    msg_templates: None,
+                },
+                min_coin_out: Some(Coin::new(100_000, "osmo")),
+            },
+            SequenceStep {
+                action: Action::BankSend {
+                    to_address: "to_address".to_string(),
+                },
+                min_coin_out: None,
+            },
+        ],
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "vault".to_string(),
+                    msg: to_binary(&"deposit").unwrap(),
+                    funds: vec![Coin::new(600_000, "untrn")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "entry_point".to_string(),
+                    msg: to_binary(&ExecuteMsg::PostSwapActionStep {
+                        min_coin: Coin::new(100_000, "osmo"),
+                        pre_balance: Uint128::new(1_000_000),
+                        timeout_timestamp: 101,
+                        steps: vec![SequenceStep {
+                            action: Action::BankSend {
+                                to_address: "to_address".to_string(),
+                            },
+                            min_coin_out: None,
+                        }],
+                        callback_address: None,
+                        send_callback: false,
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "Contract Call Step Then Bank Send")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        min_coin: Coin::new(500_000, "untrn"),
+        pre_balance: Uint128::new(600_000),
+        steps: vec![SequenceStep {
+            action: Action::BankSend {
+                to_address: "to_address".to_string(),
+            },
+            min_coin_out: None,
+        }],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::ReceivedLessCoinFromSequenceStepThanMinCoin),
+    };
+    "Received Less From Previous Step Than Min Coin - Expect Error")]
+#[test_case(
+    Params {
+        caller: "unauthorized".to_string(),
+        min_coin: Coin::new(500_000, "untrn"),
+        pre_balance: Uint128::new(400_000),
+        steps: vec![SequenceStep {
+            action: Action::BankSend {
+                to_address: "to_address".to_string(),
+            },
+            min_coin_out: None,
+        }],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Unauthorized Caller - Expect Error")]
+fn test_execute_post_swap_action_step(params: Params) {
+    // Create mock dependencies
+    let mut deps = mock_dependencies_with_balances(&[(
+        "entry_point",
+        &[Coin::new(1_000_000, "osmo"), Coin::new(1_000_000, "untrn")],
+    )]);
+
+    // Create mock env with parameters that make testing easier
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+    env.block.time = Timestamp::from_nanos(100);
+
+    // Create mock info with the given caller
+    let info = mock_info(&params.caller, &[]);
+
+    // Call execute_post_swap_action_step with the given test parameters
+    let res = skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::PostSwapActionStep {
+            min_coin: params.min_coin,
+            pre_balance: params.pre_balance,
+            timeout_timestamp: 101,
+            steps: params.steps,
+            callback_address: None,
+            send_callback: false,
+        },
+    );
+
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the messages in the response are correct
+            assert_eq!(res.messages, params.expected_messages);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_binary, Addr, Coin,
+    ReplyOn::Never,
+    SubMsg, Timestamp, Uint128, WasmMsg,
+};
+use cw20::Cw20ReceiveMsg;
+use skip::{
+    entry_point::{Action, Cw20HookMsg, ExecuteMsg},
+    swap::{Swap, SwapExactCoinIn, SwapOperation},
+};
+use skip_api_entry_point::{error::ContractError, state::SWAP_VENUE_MAP};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response
+    - CW20 Coin In With Bank Send (Swaps the CW20 coin sent as the remaining coin)
+
+Expect Error
+    - Current Block Time Greater Than Timeout Timestamp
+ */
+
+// Define test parameters
+struct Params {
+    amount: Uint128,
+    timeout_timestamp: u64,
+    expected_messages: Vec<SubMsg>,
+    expected_error: Option<ContractError>,
+}
+
+// Test execute_receive
+#[test_case(
+    Params {
+        amount: Uint128::new(1_000_000),
+        timeout_timestamp: 101,
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "entry_point".to_string(),
+                    msg: to_binary(&ExecuteMsg::UserSwap {
+                        swap: user_swap(),
+                        remaining_coin: Coin::new(1_000_000, "cw20:cw20_contract"),
+                        min_coin: Coin::new(1_000_000, "osmo"),
+                        affiliates: vec![],
+                    }).unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "entry_point".to_string(),
+                    msg: to_binary(&ExecuteMsg::PostSwapAction {
+                        min_coin: Coin::new(1_000_000, "osmo"),
+                        timeout_timestamp: 101,
+                        post_swap_action: Action::BankSend {
+                            to_address: "to_address".to_string(),
+                        },
+                        exact_out: false,
+                        affiliates: vec![],
+                        callback_address: None,
+                    }).unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "CW20 Coin In With Bank Send")]
+#[test_case(
+    Params {
+        amount: Uint128::new(1_000_000),
+        timeout_timestamp: 99,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Timeout),
+    };
+    "Current Block Time Greater Than Timeout Timestamp - Expect Error")]
+fn test_execute_receive(params: Params) {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env with parameters that make testing easier
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+    env.block.time = Timestamp::from_nanos(100);
+
+    // Create mock info with the cw20 contract address as the caller
+    let info = mock_info("cw20_contract", &[]);
+
+    // Store the swap venue adapter contract address
+    SWAP_VENUE_MAP
+        .save(
+            deps.as_mut().storage,
+            "swap_venue_name",
+            &Addr::unchecked("swap_venue_adapter"),
+        )
+        .unwrap();
+
+    // Call execute_receive with the given test case params
+    let res = skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: "swapper".to_string(),
+            amount: params.amount,
+            msg: to_binary(&Cw20HookMsg::SwapAndAction {
+                pre_swap_action: None,
+                user_swap: user_swap(),
+                min_coin: Coin::new(1_000_000, "osmo"),
+                timeout_timestamp: params.timeout_timestamp,
+                post_swap_action: Action::BankSend {
+                    to_address: "to_address".to_string(),
+                },
+                affiliates: vec![],
+                affiliate_fee_basis: None,
+                callback_address: None,
+            })
+            .unwrap(),
+        }),
+    );
+
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the messages in the response are correct
+            assert_eq!(res.messages, params.expected_messages);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
+
+// Creates the user swap of the CW20 coin sent to osmo
+fn user_swap() -> Swap {
+    Swap::SwapExactCoinIn(SwapExactCoinIn {
+        swap_venue_name: "swap_venue_name".to_string(),
+        operations: vec![SwapOperation {
+            pool: "pool".to_string(),
+            denom_in: "cw20:cw20_contract".to_string(),
+            denom_out: "osmo".to_string(),
+        }],
+    })
+}
@@ -1,23 +1,26 @@
 use cosmwasm_std::{
     testing::{mock_dependencies_with_balances, mock_env, mock_info},
-    to_binary, Addr, BankMsg, Coin, ContractResult, OverflowError, OverflowOperation,
-    QuerierResult,
+    to_binary, Addr, BankMsg, Binary, Coin, ContractResult, Decimal, OverflowError,
+    OverflowOperation, QuerierResult,
     ReplyOn::Never,
-    SubMsg, SystemResult, Timestamp, WasmMsg, WasmQuery,
+    SubMsg, SystemResult, Timestamp, Uint128, WasmMsg, WasmQuery,
 };
 use cw_utils::PaymentError::{MultipleDenoms, NoFunds};
 use skip::{
-    entry_point::{Action, Affiliate, ExecuteMsg},
+    entry_point::{
+        Action, Affiliate, AffiliateFeeBasis, ExecuteMsg, MultiActionAmount, MultiActionLeg,
+    },
     error::SkipError::{
-        IbcFeesNotOneCoin, SwapOperationsCoinInDenomMismatch, SwapOperationsCoinOutDenomMismatch,
-        SwapOperationsEmpty,
+        IbcFeesNotOneCoin, MemoInvalidTimeout, MsgTemplatePathNotFound,
+        SwapOperationsCoinInDenomMismatch, SwapOperationsCoinOutDenomMismatch, SwapOperationsEmpty,
     },
     ibc::{IbcFee, IbcInfo},
     swap::{ExecuteMsg as SwapExecuteMsg, Swap, SwapExactCoinIn, SwapExactCoinOut, SwapOperation},
+    template::{MsgTemplate, TemplateValue},
 };
 use skip_api_entry_point::{
     error::ContractError,
-    state::{IBC_TRANSFER_CONTRACT_ADDRESS, SWAP_VENUE_MAP},
+    state::{DEFAULT_IBC_TRANSFER_ADAPTER, IBC_TRANSFER_ADAPTER_MAP, SWAP_VENUE_MAP},
 };
 use test_case::test_case;
 
@@ -26,10 +29,12 @@ Test Cases:
 
 Expect Response
     - User Swap Exact Coin In With Bank Send
+    - User Swap Exact Coin In With Actual Out Affiliate Fees (Affiliates are passed to the post swap action instead of the user swap)
     - User Swap Exact Coin Out With Bank Send
     - User Swap Exact Coin In With IBC Transfer With IBC Fees
     - User Swap Exact Coin In With IBC Transfer Without IBC Fees
     - Fee Swap And User Swap Exact Coin In With IBC Fees
+    - User Swap Exact Coin In With Multi With IBC Fees (IBC fees of each IBC transfer leg are paid)
 
 Expect Error
     // Fee Swap
@@ -56,6 +61,22 @@ Expect Error
     - IBC Transfer With IBC Fees But More Than One IBC Fee Denom Specified
     - IBC Transfer With IBC Fees But No IBC Fee Coins Specified
     - IBC Transfer With IBC Fee Coin Amount Zero
+    - IBC Transfer With Invalid Json Memo
+
+    // Multi
+    - Multi With Empty Legs
+
+    // Provide Liquidity
+    - Provide Liquidity Without A Recipient Outside Of A Sequence
+
+    // Delegate
+    - Delegate Not Enabled (Errors before the swap)
+
+    // Contract Call
+    - Contract Call With Msg Template Path Not Found (Errors before the swap)
+
+    // Affiliates
+    - Actual Out Affiliate Fee Basis With User Swap Exact Coin Out
  */
 
 // Define test parameters
@@ -66,6 +87,7 @@ struct Params {
     timeout_timestamp: u64,
     post_swap_action: Action,
     affiliates: Vec<Affiliate>,
+    affiliate_fee_basis: Option<AffiliateFeeBasis>,
     expected_messages: Vec<SubMsg>,
     expected_error: Option<ContractError>,
 }
@@ -94,6 +116,7 @@ struct Params {
             to_address: "to_address".to_string(),
         },
         affiliates: vec![],
+        affiliate_fee_basis: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -133,6 +156,8 @@ struct Params {
                             to_address: "to_address".to_string(),
                         },
                         exact_out: false,
+                        affiliates: vec![],
+                        callback_address: None,
                     }).unwrap(),
                     funds: vec![],
                 }
@@ -144,6 +169,90 @@ struct Params {
         expected_error: None,
     };
     "User Swap Exact Coin In With Bank Send")]
+#[test_case(
+    Params {
+        info_funds: vec![
+            Coin::new(1_000_000, "untrn"),
+        ],
+        user_swap: Swap::SwapExactCoinIn (
+            SwapExactCoinIn{
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![
+                    SwapOperation {
+                        pool: "pool".to_string(),
+                        denom_in: "untrn".to_string(),
+                        denom_out: "osmo".to_string(),
+                    }
+                ],
+            }
+        ),
+        min_coin: Coin::new(1_000_000, "osmo"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::BankSend {
+            to_address: "to_address".to_string(),
+        },
+        affiliates: vec![Affiliate {
+            basis_points_fee: Some(Uint128::new(100)),
+            address: "affiliate".to_string(),
+            id: None,
+        }],
+        affiliate_fee_basis: Some(AffiliateFeeBasis::ActualOut),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "entry_point".to_string(), 
+                    msg: to_binary(&ExecuteMsg::UserSwap {
+                        swap: Swap::SwapExactCoinIn (
+                            SwapExactCoinIn{
+                                swap_venue_name: "swap_venue_name".to_string(),
+                                operations: vec![
+                                    SwapOperation {
+                                        pool: "pool".to_string(),
+                                        denom_in: "untrn".to_string(),
+                                        denom_out: "osmo".to_string(),
+                                    }
+                                ],
+                            }
+                        ),
+                        remaining_coin: Coin::new(1_000_000, "untrn"),
+                        min_coin: Coin::new(1_000_000, "osmo"),
+                        affiliates: vec![],
+                    }).unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "entry_point".to_string(), 
+                    msg: to_binary(&ExecuteMsg::PostSwapAction {
+                        min_coin: Coin::new(1_000_000, "osmo"),
+                        timeout_timestamp: 101,
+                        post_swap_action: Action::BankSend {
+                            to_address: "to_address".to_string(),
+                        },
+                        exact_out: false,
+                        affiliates: vec![Affiliate {
+                            basis_points_fee: Some(Uint128::new(100)),
+                            address: "affiliate".to_string(),
+                            id: None,
+                        }],
+                        callback_address: None,
+                    }).unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "User Swap Exact Coin In With Actual Out Affiliate Fees")]
 #[test_case(
     Params {
         info_funds: vec![
@@ -168,6 +277,7 @@ struct Params {
             to_address: "to_address".to_string(),
         },
         affiliates: vec![],
+        affiliate_fee_basis: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -208,6 +318,8 @@ struct Params {
                             to_address: "to_address".to_string(),
                         },
                         exact_out: true,
+                        affiliates: vec![],
+                        callback_address: None,
                     }).unwrap(),
                     funds: vec![],
                 }
@@ -252,8 +364,10 @@ struct Params {
                     .to_string(),
             },
             fee_swap: None,
+            ibc_transfer_adapter: None,
         },
         affiliates: vec![],
+        affiliate_fee_basis: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -313,8 +427,11 @@ struct Params {
                                     .to_string(),
                             },
                             fee_swap: None,
+                            ibc_transfer_adapter: None,
                         },
                         exact_out: false,
+                        affiliates: vec![],
+                        callback_address: None,
                     }).unwrap(),
                     funds: vec![],
                 }
@@ -355,8 +472,10 @@ struct Params {
                     .to_string(),
             },
             fee_swap: None,
+            ibc_transfer_adapter: None,
         },
         affiliates: vec![],
+        affiliate_fee_basis: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -402,8 +521,11 @@ struct Params {
                                     .to_string(),
                             },
                             fee_swap: None,
+                            ibc_transfer_adapter: None,
                         },
                         exact_out: false,
+                        affiliates: vec![],
+                        callback_address: None,
                     }).unwrap(),
                     funds: vec![],
                 }
@@ -460,14 +582,16 @@ struct Params {
                     refund_address: None,
                 }
             ),
+            ibc_transfer_adapter: None,
         },
         affiliates: vec![],
+        affiliate_fee_basis: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
                 msg: WasmMsg::Execute {
                     contract_addr: "swap_venue_adapter".to_string(), 
-                    msg: to_binary(&SwapExecuteMsg::Swap {
+                    msg: to_binary(&SwapExecuteMsg::SwapExactCoinOut {
                         operations: vec![
                             SwapOperation {
                                 pool: "pool".to_string(),
@@ -475,6 +599,7 @@ struct Params {
                                 denom_out: "untrn".to_string(),
                             }
                         ],
+                        coin_out: Coin::new(200_000, "untrn"),
                     }).unwrap(),
                     funds: vec![Coin::new(200_000, "osmo")], 
                 }
@@ -552,8 +677,11 @@ struct Params {
                                     refund_address: None,
                                 }
                             ),
+                            ibc_transfer_adapter: None,
                         },
                         exact_out: false,
+                        affiliates: vec![],
+                        callback_address: None,
                     }).unwrap(),
                     funds: vec![],
                 }
@@ -565,6 +693,194 @@ struct Params {
         expected_error: None,
     };
     "Fee Swap And User Swap Exact Coin In With IBC Fees")]
+#[test_case(
+    Params {
+        info_funds: vec![
+            Coin::new(1_000_000, "untrn"),
+        ],
+        user_swap: Swap::SwapExactCoinIn (
+            SwapExactCoinIn{
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![
+                    SwapOperation {
+                        pool: "pool".to_string(),
+                        denom_in: "untrn".to_string(),
+                        denom_out: "osmo".to_string(),
+                    }
+                ],
+            },
+        ),
+        min_coin: Coin::new(800_000, "osmo"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::Multi {
+            legs: vec![
+                MultiActionLeg {
+                    amount: MultiActionAmount::Share(Decimal::percent(50)),
+                    action: Action::BankSend {
+                        to_address: "to_address".to_string(),
+                    },
+                },
+                MultiActionLeg {
+                    amount: MultiActionAmount::Share(Decimal::percent(25)),
+                    action: Action::IbcTransfer {
+                        ibc_info: IbcInfo {
+                            source_channel: "channel-0".to_string(),
+                            receiver: "receiver".to_string(),
+                            memo: "".to_string(),
+                            fee: Some(IbcFee {
+                                recv_fee: vec![],
+                                ack_fee: vec![Coin::new(50_000, "untrn")],
+                                timeout_fee: vec![Coin::new(50_000, "untrn")],
+                            }),
+                            recover_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5"
+                                .to_string(),
+                        },
+                        fee_swap: None,
+                        ibc_transfer_adapter: None,
+                    },
+                },
+                MultiActionLeg {
+                    amount: MultiActionAmount::Share(Decimal::percent(25)),
+                    action: Action::IbcTransfer {
+                        ibc_info: IbcInfo {
+                            source_channel: "channel-1".to_string(),
+                            receiver: "receiver".to_string(),
+                            memo: "".to_string(),
+                            fee: Some(IbcFee {
+                                recv_fee: vec![],
+                                ack_fee: vec![Coin::new(50_000, "untrn")],
+                                timeout_fee: vec![Coin::new(50_000, "untrn")],
+                            }),
+                            recover_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5"
+                                .to_string(),
+                        },
+                        fee_swap: None,
+                        ibc_transfer_adapter: None,
+                    },
+                },
+            ],
+            remainder_address: "to_address".to_string(),
+        },
+        affiliates: vec![],
+        affiliate_fee_basis: None,
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "ibc_transfer_adapter".to_string(),
+                    amount: vec![Coin::new(100_000, "untrn")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "ibc_transfer_adapter".to_string(),
+                    amount: vec![Coin::new(100_000, "untrn")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "entry_point".to_string(),
+                    msg: to_binary(&ExecuteMsg::UserSwap {
+                        swap: Swap::SwapExactCoinIn (
+                            SwapExactCoinIn{
+                                swap_venue_name: "swap_venue_name".to_string(),
+                                operations: vec![
+                                    SwapOperation {
+                                        pool: "pool".to_string(),
+                                        denom_in: "untrn".to_string(),
+                                        denom_out: "osmo".to_string(),
+                                    }
+                                ],
+                            }
+                        ),
+                        remaining_coin: Coin::new(800_000, "untrn"),
+                        min_coin: Coin::new(800_000, "osmo"),
+                        affiliates: vec![],
+                    }).unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "entry_point".to_string(),
+                    msg: to_binary(&ExecuteMsg::PostSwapAction {
+                        min_coin: Coin::new(800_000, "osmo"),
+                        timeout_timestamp: 101,
+                        post_swap_action: Action::Multi {
+                            legs: vec![
+                                MultiActionLeg {
+                                    amount: MultiActionAmount::Share(Decimal::percent(50)),
+                                    action: Action::BankSend {
+                                        to_address: "to_address".to_string(),
+                                    },
+                                },
+                                MultiActionLeg {
+                                    amount: MultiActionAmount::Share(Decimal::percent(25)),
+                                    action: Action::IbcTransfer {
+                                        ibc_info: IbcInfo {
+                                            source_channel: "channel-0".to_string(),
+                                            receiver: "receiver".to_string(),
+                                            memo: "".to_string(),
+                                            fee: Some(IbcFee {
+                                                recv_fee: vec![],
+                                                ack_fee: vec![Coin::new(50_000, "untrn")],
+                                                timeout_fee: vec![Coin::new(50_000, "untrn")],
+                                            }),
+                                            recover_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5"
+                                                .to_string(),
+                                        },
+                                        fee_swap: None,
+                                        ibc_transfer_adapter: None,
+                                    },
+                                },
+                                MultiActionLeg {
+                                    amount: MultiActionAmount::Share(Decimal::percent(25)),
+                                    action: Action::IbcTransfer {
+                                        ibc_info: IbcInfo {
+                                            source_channel: "channel-1".to_string(),
+                                            receiver: "receiver".to_string(),
+                                            memo: "".to_string(),
+                                            fee: Some(IbcFee {
+                                                recv_fee: vec![],
+                                                ack_fee: vec![Coin::new(50_000, "untrn")],
+                                                timeout_fee: vec![Coin::new(50_000, "untrn")],
+                                            }),
+                                            recover_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5"
+                                                .to_string(),
+                                        },
+                                        fee_swap: None,
+                                        ibc_transfer_adapter: None,
+                                    },
+                                },
+                            ],
+                            remainder_address: "to_address".to_string(),
+                        },
+                        exact_out: false,
+                        affiliates: vec![],
+                        callback_address: None,
+                    }).unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "User Swap Exact Coin In With Multi With IBC Fees")]
 #[test_case(
     Params {
         info_funds: vec![
@@ -610,8 +926,10 @@ struct Params {
                     refund_address: None,
                 }
             ),
+            ibc_transfer_adapter: None,
         },
         affiliates: vec![],
+        affiliate_fee_basis: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Overflow(OverflowError {
             operation: OverflowOperation::Sub,
@@ -665,8 +983,10 @@ struct Params {
                     refund_address: None,
                 }
             ),
+            ibc_transfer_adapter: None,
         },
         affiliates: vec![],
+        affiliate_fee_basis: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::FeeSwapCoinInDenomMismatch),
     };
@@ -716,8 +1036,10 @@ struct Params {
                     refund_address: None,
                 }
             ),
+            ibc_transfer_adapter: None,
         },
         affiliates: vec![],
+        affiliate_fee_basis: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Skip(SwapOperationsCoinInDenomMismatch)),
     };
@@ -767,8 +1089,10 @@ struct Params {
                     refund_address: None,
                 }
             ),
+            ibc_transfer_adapter: None,
         },
         affiliates: vec![],
+        affiliate_fee_basis: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Skip(SwapOperationsCoinOutDenomMismatch)),
     };
@@ -806,8 +1130,10 @@ struct Params {
                     .to_string(),
             },
             fee_swap: None,
+            ibc_transfer_adapter: None,
         },
         affiliates: vec![],
+        affiliate_fee_basis: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::IBCFeeDenomDiffersFromCoinReceived),
     };
@@ -853,8 +1179,10 @@ struct Params {
                     refund_address: None,
                 }
             ),
+            ibc_transfer_adapter: None,
         },
         affiliates: vec![],
+        affiliate_fee_basis: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::FeeSwapWithoutIbcFees),
     };
@@ -904,8 +1232,10 @@ struct Params {
                     refund_address: None,
                 }
             ),
+            ibc_transfer_adapter: None,
         },
         affiliates: vec![],
+        affiliate_fee_basis: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Skip(IbcFeesNotOneCoin)),
     };
@@ -955,8 +1285,10 @@ struct Params {
                     refund_address: None,
                 }
             ),
+            ibc_transfer_adapter: None,
         },
         affiliates: vec![],
+        affiliate_fee_basis: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Skip(IbcFeesNotOneCoin)),
     };
@@ -1006,12 +1338,51 @@ struct Params {
                     refund_address: None,
                 }
             ),
+            ibc_transfer_adapter: None,
         },
         affiliates: vec![],
+        affiliate_fee_basis: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Skip(IbcFeesNotOneCoin)),
     };
     "IBC Transfer With IBC Fee Coin Amount Zero - Expect Error")]
+#[test_case(
+    Params {
+        info_funds: vec![
+            Coin::new(1_000_000, "osmo"),
+        ],
+        user_swap: Swap::SwapExactCoinIn (
+            SwapExactCoinIn{
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![
+                    SwapOperation {
+                        pool: "pool_2".to_string(),
+                        denom_in: "osmo".to_string(),
+                        denom_out: "atom".to_string(),
+                    }
+                ],
+            },
+        ),
+        min_coin: Coin::new(100_000, "atom"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::IbcTransfer {
+            ibc_info: IbcInfo {
+                source_channel: "channel-0".to_string(),
+                receiver: "receiver".to_string(),
+                memo: r#"{"forward":{"channel":"channel-1","port":"transfer","receiver":"receiver","timeout":"ten minutes"}}"#.to_string(),
+                fee: None,
+                recover_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5"
+                    .to_string(),
+            },
+            fee_swap: None,
+            ibc_transfer_adapter: None,
+        },
+        affiliates: vec![],
+        affiliate_fee_basis: None,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Skip(MemoInvalidTimeout("ten minutes".to_string()))),
+    };
+    "IBC Transfer With Invalid Json Memo - Expect Error")]
 #[test_case(
     Params {
         info_funds: vec![],
@@ -1033,6 +1404,7 @@ struct Params {
             to_address: "to_address".to_string(),
         },
         affiliates: vec![],
+        affiliate_fee_basis: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Payment(NoFunds{})),
     };
@@ -1061,6 +1433,7 @@ struct Params {
             to_address: "to_address".to_string(),
         },
         affiliates: vec![],
+        affiliate_fee_basis: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Payment(MultipleDenoms{})),
     };
@@ -1104,8 +1477,10 @@ struct Params {
                     refund_address: None,
                 }
             ),
+            ibc_transfer_adapter: None,
         },
         affiliates: vec![],
+        affiliate_fee_basis: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Skip(SwapOperationsEmpty)),
     };
@@ -1127,10 +1502,142 @@ struct Params {
             to_address: "to_address".to_string(),
         },
         affiliates: vec![],
+        affiliate_fee_basis: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Timeout),
     };
     "Current Block Time Greater Than Timeout Timestamp - Expect Error")]
+#[test_case(
+    Params {
+        info_funds: vec![
+            Coin::new(1_000_000, "untrn"),
+        ],
+        user_swap: Swap::SwapExactCoinIn (
+            SwapExactCoinIn{
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![],
+            },
+        ),
+        min_coin: Coin::new(800_000, "osmo"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::Multi {
+            legs: vec![],
+            remainder_address: "to_address".to_string(),
+        },
+        affiliates: vec![],
+        affiliate_fee_basis: None,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::MultiActionLegsEmpty),
+    };
+    "Multi With Empty Legs - Expect Error")]
+#[test_case(
+    Params {
+        info_funds: vec![
+            Coin::new(1_000_000, "untrn"),
+        ],
+        user_swap: Swap::SwapExactCoinIn (
+            SwapExactCoinIn{
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![],
+            },
+        ),
+        min_coin: Coin::new(800_000, "osmo"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::ProvideLiquidity {
+            swap_venue_name: "swap_venue_name".to_string(),
+            pool: "pool".to_string(),
+            recipient: None,
+            slippage_tolerance: Decimal::percent(1),
+        },
+        affiliates: vec![],
+        affiliate_fee_basis: None,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::ProvideLiquidityNoRecipient),
+    };
+    "Provide Liquidity Without A Recipient Outside Of A Sequence - Expect Error")]
+#[test_case(
+    Params {
+        info_funds: vec![
+            Coin::new(1_000_000, "untrn"),
+        ],
+        user_swap: Swap::SwapExactCoinIn (
+            SwapExactCoinIn{
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![],
+            },
+        ),
+        min_coin: Coin::new(800_000, "osmo"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::Delegate {
+            validator: "validator".to_string(),
+            delegator_recipient: "delegator_recipient".to_string(),
+        },
+        affiliates: vec![],
+        affiliate_fee_basis: None,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::DelegateNotEnabled),
+    };
+    "Delegate Not Enabled - Expect Error")]
+#[test_case(
+    Params {
+        info_funds: vec![
+            Coin::new(1_000_000, "untrn"),
+        ],
+        user_swap: Swap::SwapExactCoinOut (
+            SwapExactCoinOut{
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![
+                    SwapOperation {
+                        pool: "pool".to_string(),
+                        denom_in: "untrn".to_string(),
+                        denom_out: "osmo".to_string(),
+                    }
+                ],
+                refund_address: Some("refund_address".to_string()),
+            }
+        ),
+        min_coin: Coin::new(1_000_000, "osmo"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::BankSend {
+            to_address: "to_address".to_string(),
+        },
+        affiliates: vec![Affiliate {
+            basis_points_fee: Some(Uint128::new(100)),
+            address: "affiliate".to_string(),
+            id: None,
+        }],
+        affiliate_fee_basis: Some(AffiliateFeeBasis::ActualOut),
+        expected_messages: vec![],
+        expected_error: Some(ContractError::ActualOutAffiliateFeeBasisExactOut),
+    };
+    "Actual Out Affiliate Fee Basis With User Swap Exact Coin Out - Expect Error")]
+#[test_case(
+    Params {
+        info_funds: vec![
+            Coin::new(1_000_000, "untrn"),
+        ],
+        user_swap: Swap::SwapExactCoinIn (
+            SwapExactCoinIn{
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![],
+            },
+        ),
+        min_coin: Coin::new(800_000, "osmo"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::ContractCall {
+            contract_address: "contract_call".to_string(),
+            msg: Binary::from(br#"{"deposit":{"amount":"{{amount}}"}}"#),
+            msg_templates: Some(vec![MsgTemplate {
+                path: vec!["deposit".to_string(), "coin".to_string()],
+                value: TemplateValue::Amount,
+            }]),
+        },
+        affiliates: vec![],
+        affiliate_fee_basis: None,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Skip(MsgTemplatePathNotFound("deposit.coin".to_string()))),
+    };
+    "Contract Call With Msg Template Path Not Found - Expect Error")]
 fn test_execute_swap_and_action(params: Params) {
     // Create mock dependencies
     let mut deps = mock_dependencies_with_balances(&[(
@@ -1173,10 +1680,17 @@ fn test_execute_swap_and_action(params: Params) {
         )
         .unwrap();
 
-    // Store the ibc transfer adapter contract address
+    // Store the ibc transfer adapter contract address as the default ibc transfer adapter
     let ibc_transfer_adapter = Addr::unchecked("ibc_transfer_adapter");
-    IBC_TRANSFER_CONTRACT_ADDRESS
-        .save(deps.as_mut().storage, &ibc_transfer_adapter)
+    IBC_TRANSFER_ADAPTER_MAP
+        .save(
+            deps.as_mut().storage,
+            "ibc_transfer_adapter",
+            &ibc_transfer_adapter,
+        )
+        .unwrap();
+    DEFAULT_IBC_TRANSFER_ADAPTER
+        .save(deps.as_mut().storage, &"ibc_transfer_adapter".to_string())
         .unwrap();
 
     // Call execute_swap_and_action with the given test case params
@@ -1185,11 +1699,14 @@ fn test_execute_swap_and_action(params: Params) {
         env,
         info,
         ExecuteMsg::SwapAndAction {
+            pre_swap_action: None,
             user_swap: params.user_swap,
             min_coin: params.min_coin,
             timeout_timestamp: params.timeout_timestamp,
             post_swap_action: params.post_swap_action,
             affiliates: params.affiliates,
+            affiliate_fee_basis: params.affiliate_fee_basis,
+            callback_address: None,
         },
     );
 
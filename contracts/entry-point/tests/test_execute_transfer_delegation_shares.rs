@@ -0,0 +1,145 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies_with_balances, mock_env, mock_info},
+    Addr, BankMsg, Coin,
+    ReplyOn::Never,
+    SubMsg, Timestamp,
+};
+use skip::entry_point::ExecuteMsg;
+use skip_api_entry_point::error::ContractError;
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response
+    - Transfer Shares To Delegator Recipient (Sends the share tokens minted to the contract to the delegator recipient)
+    - Transfer Only Shares Minted (Share tokens held before the delegation was tokenized are not sent)
+
+Expect Error
+    - No Shares Tokenized
+    - Unauthorized Caller
+ */
+
+// Define test parameters
+struct Params {
+    caller: String,
+    pre_balances: Vec<Coin>,
+    expected_messages: Vec<SubMsg>,
+    expected_error: Option<ContractError>,
+}
+
+// Test execute_transfer_delegation_shares
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        pre_balances: vec![],
+        expected_messages: vec![SubMsg {
+            id: 0,
+            msg: BankMsg::Send {
+                to_address: "delegator_recipient".to_string(),
+                amount: vec![
+                    Coin::new(200_000, "validator/1"),
+                    Coin::new(1_000_000, "validator/2"),
+                ],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }],
+        expected_error: None,
+    };
+    "Transfer Shares To Delegator Recipient")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        pre_balances: vec![Coin::new(200_000, "validator/1")],
+        expected_messages: vec![SubMsg {
+            id: 0,
+            msg: BankMsg::Send {
+                to_address: "delegator_recipient".to_string(),
+                amount: vec![Coin::new(1_000_000, "validator/2")],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }],
+        expected_error: None,
+    };
+    "Transfer Only Shares Minted")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        pre_balances: vec![
+            Coin::new(200_000, "validator/1"),
+            Coin::new(1_000_000, "validator/2"),
+        ],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::DelegateNoSharesTokenized),
+    };
+    "No Shares Tokenized - Expect Error")]
+#[test_case(
+    Params {
+        caller: "unauthorized".to_string(),
+        pre_balances: vec![],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Unauthorized Caller - Expect Error")]
+fn test_execute_transfer_delegation_shares(params: Params) {
+    // Create mock dependencies with the contract holding share tokens of the validator
+    // and of another validator, along with the bond denom
+    let mut deps = mock_dependencies_with_balances(&[(
+        "entry_point",
+        &[
+            Coin::new(1_000_000, "osmo"),
+            Coin::new(200_000, "validator/1"),
+            Coin::new(1_000_000, "validator/2"),
+            Coin::new(300_000, "other_validator/1"),
+        ],
+    )]);
+
+    // Create mock env with parameters that make testing easier
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+    env.block.time = Timestamp::from_nanos(100);
+
+    // Create mock info with the given caller
+    let info = mock_info(&params.caller, &[]);
+
+    // Call execute_transfer_delegation_shares with the given test parameters
+    let res = skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::TransferDelegationShares {
+            validator: "validator".to_string(),
+            pre_balances: params.pre_balances,
+            delegator_recipient: "delegator_recipient".to_string(),
+        },
+    );
+
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the share tokens are sent to the delegator recipient, not kept by the contract
+            assert_eq!(res.messages, params.expected_messages);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
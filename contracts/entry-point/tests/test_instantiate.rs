@@ -2,10 +2,13 @@ use cosmwasm_std::{
     testing::{mock_dependencies, mock_env, mock_info},
     Addr,
 };
-use skip::{entry_point::InstantiateMsg, swap::SwapVenue};
+use skip::{entry_point::InstantiateMsg, ibc::IbcTransferAdapter, swap::SwapVenue};
 use skip_api_entry_point::{
     error::ContractError,
-    state::{BLOCKED_CONTRACT_ADDRESSES, IBC_TRANSFER_CONTRACT_ADDRESS, SWAP_VENUE_MAP},
+    state::{
+        BLOCKED_CONTRACT_ADDRESSES, DEFAULT_IBC_TRANSFER_ADAPTER, IBC_TRANSFER_ADAPTER_MAP, OWNER,
+        SWAP_VENUE_MAP,
+    },
 };
 use test_case::test_case;
 
@@ -14,21 +17,29 @@ Test Cases:
 
 Expect Response
     - Happy Path (tests the adapter and blocked contract addresses are stored correctly)
+    - Happy Path With Owner Provided
+    - Happy Path With Multiple IBC Transfer Adapters
 
 Expect Error
     - Duplicate Swap Venue Names
+    - Duplicate IBC Transfer Adapter Names
+    - Default IBC Transfer Adapter Not Provided
  */
 
 // Define test parameters
 struct Params {
+    owner: Option<String>,
     swap_venues: Vec<SwapVenue>,
-    ibc_transfer_contract_address: String,
+    ibc_transfer_adapters: Vec<IbcTransferAdapter>,
+    default_ibc_transfer_adapter: String,
+    expected_owner: String,
     expected_error: Option<ContractError>,
 }
 
 // Test instantiate
 #[test_case(
     Params {
+        owner: None,
         swap_venues: vec![
             SwapVenue {
                 name: "neutron-astroport".to_string(),
@@ -39,12 +50,64 @@ struct Params {
                 adapter_contract_address: "osmosis123".to_string(),
             },
         ],
-        ibc_transfer_contract_address: "ibc_transfer_adapter".to_string(),
+        ibc_transfer_adapters: vec![
+            IbcTransferAdapter {
+                name: "ibc_transfer".to_string(),
+                adapter_contract_address: "ibc_transfer_adapter".to_string(),
+            },
+        ],
+        default_ibc_transfer_adapter: "ibc_transfer".to_string(),
+        expected_owner: "creator".to_string(),
         expected_error: None,
     };
     "Happy Path")]
 #[test_case(
     Params {
+        owner: Some("owner".to_string()),
+        swap_venues: vec![
+            SwapVenue {
+                name: "neutron-astroport".to_string(),
+                adapter_contract_address: "neutron123".to_string(),
+            },
+        ],
+        ibc_transfer_adapters: vec![
+            IbcTransferAdapter {
+                name: "ibc_transfer".to_string(),
+                adapter_contract_address: "ibc_transfer_adapter".to_string(),
+            },
+        ],
+        default_ibc_transfer_adapter: "ibc_transfer".to_string(),
+        expected_owner: "owner".to_string(),
+        expected_error: None,
+    };
+    "Happy Path With Owner Provided")]
+#[test_case(
+    Params {
+        owner: None,
+        swap_venues: vec![
+            SwapVenue {
+                name: "neutron-astroport".to_string(),
+                adapter_contract_address: "neutron123".to_string(),
+            },
+        ],
+        ibc_transfer_adapters: vec![
+            IbcTransferAdapter {
+                name: "neutron-transfer".to_string(),
+                adapter_contract_address: "neutron_transfer_adapter".to_string(),
+            },
+            IbcTransferAdapter {
+                name: "neutron-transfer-v2".to_string(),
+                adapter_contract_address: "neutron_transfer_adapter_v2".to_string(),
+            },
+        ],
+        default_ibc_transfer_adapter: "neutron-transfer-v2".to_string(),
+        expected_owner: "creator".to_string(),
+        expected_error: None,
+    };
+    "Happy Path With Multiple IBC Transfer Adapters")]
+#[test_case(
+    Params {
+        owner: None,
         swap_venues: vec![
             SwapVenue {
                 name: "neutron-astroport".to_string(),
@@ -55,10 +118,61 @@ struct Params {
                 adapter_contract_address: "neutron456".to_string(),
             },
         ],
-        ibc_transfer_contract_address: "ibc_transfer_adapter".to_string(),
+        ibc_transfer_adapters: vec![
+            IbcTransferAdapter {
+                name: "ibc_transfer".to_string(),
+                adapter_contract_address: "ibc_transfer_adapter".to_string(),
+            },
+        ],
+        default_ibc_transfer_adapter: "ibc_transfer".to_string(),
+        expected_owner: "creator".to_string(),
         expected_error: Some(ContractError::DuplicateSwapVenueName),
     };
     "Duplicate Swap Venue Names")]
+#[test_case(
+    Params {
+        owner: None,
+        swap_venues: vec![
+            SwapVenue {
+                name: "neutron-astroport".to_string(),
+                adapter_contract_address: "neutron123".to_string(),
+            },
+        ],
+        ibc_transfer_adapters: vec![
+            IbcTransferAdapter {
+                name: "ibc_transfer".to_string(),
+                adapter_contract_address: "ibc_transfer_adapter".to_string(),
+            },
+            IbcTransferAdapter {
+                name: "ibc_transfer".to_string(),
+                adapter_contract_address: "ibc_transfer_adapter_2".to_string(),
+            },
+        ],
+        default_ibc_transfer_adapter: "ibc_transfer".to_string(),
+        expected_owner: "creator".to_string(),
+        expected_error: Some(ContractError::DuplicateIbcTransferAdapterName),
+    };
+    "Duplicate IBC Transfer Adapter Names")]
+#[test_case(
+    Params {
+        owner: None,
+        swap_venues: vec![
+            SwapVenue {
+                name: "neutron-astroport".to_string(),
+                adapter_contract_address: "neutron123".to_string(),
+            },
+        ],
+        ibc_transfer_adapters: vec![
+            IbcTransferAdapter {
+                name: "ibc_transfer".to_string(),
+                adapter_contract_address: "ibc_transfer_adapter".to_string(),
+            },
+        ],
+        default_ibc_transfer_adapter: "unknown".to_string(),
+        expected_owner: "creator".to_string(),
+        expected_error: Some(ContractError::IbcTransferAdapterNotFound("unknown".to_string())),
+    };
+    "Default IBC Transfer Adapter Not Provided")]
 fn test_instantiate(params: Params) {
     // Create mock dependencies
     let mut deps = mock_dependencies();
@@ -76,8 +190,10 @@ fn test_instantiate(params: Params) {
         env,
         info,
         InstantiateMsg {
+            owner: params.owner,
             swap_venues: params.swap_venues.clone(),
-            ibc_transfer_contract_address: params.ibc_transfer_contract_address,
+            ibc_transfer_adapters: params.ibc_transfer_adapters.clone(),
+            default_ibc_transfer_adapter: params.default_ibc_transfer_adapter.clone(),
         },
     );
 
@@ -90,18 +206,43 @@ fn test_instantiate(params: Params) {
                 params.expected_error
             );
 
+            // Assert the owner is stored correctly
+            assert_eq!(
+                OWNER.load(deps.as_ref().storage).unwrap(),
+                Addr::unchecked(params.expected_owner)
+            );
+
             // Assert the entry point contract address exists in the blocked contract addresses map
             assert!(BLOCKED_CONTRACT_ADDRESSES
                 .has(deps.as_ref().storage, &Addr::unchecked("entry_point")));
 
-            // Get stored ibc transfer adapter contract address
-            let stored_ibc_transfer_contract_address = IBC_TRANSFER_CONTRACT_ADDRESS
-                .load(deps.as_ref().storage)
-                .unwrap();
+            // Assert the default ibc transfer adapter is stored correctly
+            assert_eq!(
+                DEFAULT_IBC_TRANSFER_ADAPTER
+                    .load(deps.as_ref().storage)
+                    .unwrap(),
+                params.default_ibc_transfer_adapter
+            );
 
-            // Assert the ibc transfer adapter contract address exists in the blocked contract addresses map
-            assert!(BLOCKED_CONTRACT_ADDRESSES
-                .has(deps.as_ref().storage, &stored_ibc_transfer_contract_address));
+            params
+                .ibc_transfer_adapters
+                .into_iter()
+                .for_each(|ibc_transfer_adapter| {
+                    // Get stored ibc transfer adapter contract address
+                    let stored_ibc_transfer_contract_address = IBC_TRANSFER_ADAPTER_MAP
+                        .load(deps.as_ref().storage, &ibc_transfer_adapter.name)
+                        .unwrap();
+
+                    // Assert the adapter contract address stored is correct
+                    assert_eq!(
+                        stored_ibc_transfer_contract_address,
+                        Addr::unchecked(&ibc_transfer_adapter.adapter_contract_address)
+                    );
+
+                    // Assert the ibc transfer adapter contract address exists in the blocked contract addresses map
+                    assert!(BLOCKED_CONTRACT_ADDRESSES
+                        .has(deps.as_ref().storage, &stored_ibc_transfer_contract_address));
+                });
 
             params.swap_venues.into_iter().for_each(|swap_venue| {
                 // Get stored swap venue adapter contract address
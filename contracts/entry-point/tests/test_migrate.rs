@@ -0,0 +1,356 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env},
+    to_binary, Addr, ContractInfoResponse, ContractResult as SystemContractResult, QuerierResult,
+    SystemResult, WasmQuery,
+};
+use cw2::{get_contract_version, set_contract_version, ContractVersion};
+use skip::{error::SkipError, migrate::MigrateMsg};
+use skip_api_entry_point::{
+    error::{ContractError, ContractResult},
+    state::{
+        BLOCKED_CONTRACT_ADDRESSES, DEFAULT_IBC_TRANSFER_ADAPTER, IBC_TRANSFER_ADAPTER_MAP,
+        IBC_TRANSFER_CONTRACT_ADDRESS, OWNER,
+    },
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - Migrate Untracked Contract Sets Owner To Contract Admin
+    - Migrate Untracked Contract With Owner Already Stored
+    - Migrate From 0.2.1 Moves IBC Transfer Adapter Into Adapter Map
+    - Migrate From Same Version Leaves State Unchanged
+    - Migrate Twice At Same Version (Ensure Second Migration Is A No-Op)
+
+Expect Error
+    - Migrate Untracked Contract Without Contract Admin
+    - Untracked Contract Without IBC Transfer Adapter Stored
+    - Different Contract Stored
+    - Downgrade To Older Version
+
+ */
+
+const CONTRACT_NAME: &str = "skip-api-entry-point";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// Define test parameters
+struct Params {
+    stored_contract_version: Option<(&'static str, &'static str)>,
+    store_ibc_transfer_adapter: bool,
+    stored_owner: Option<&'static str>,
+    contract_admin: Option<&'static str>,
+    expected_from_version: &'static str,
+    expected_owner: Option<Addr>,
+    expected_ibc_transfer_adapter_migrated: bool,
+    expected_error: Option<ContractError>,
+}
+
+// Test migrate
+#[test_case(
+    Params {
+        stored_contract_version: None,
+        store_ibc_transfer_adapter: true,
+        stored_owner: None,
+        contract_admin: Some("admin"),
+        expected_from_version: "0.2.1",
+        expected_owner: Some(Addr::unchecked("admin")),
+        expected_ibc_transfer_adapter_migrated: true,
+        expected_error: None,
+    };
+    "Migrate Untracked Contract Sets Owner To Contract Admin")]
+#[test_case(
+    Params {
+        stored_contract_version: None,
+        store_ibc_transfer_adapter: true,
+        stored_owner: Some("owner"),
+        contract_admin: Some("admin"),
+        expected_from_version: "0.2.1",
+        expected_owner: Some(Addr::unchecked("owner")),
+        expected_ibc_transfer_adapter_migrated: true,
+        expected_error: None,
+    };
+    "Migrate Untracked Contract With Owner Already Stored")]
+#[test_case(
+    Params {
+        stored_contract_version: Some((CONTRACT_NAME, "0.2.1")),
+        store_ibc_transfer_adapter: true,
+        stored_owner: Some("owner"),
+        contract_admin: None,
+        expected_from_version: "0.2.1",
+        expected_owner: Some(Addr::unchecked("owner")),
+        expected_ibc_transfer_adapter_migrated: true,
+        expected_error: None,
+    };
+    "Migrate From 0.2.1 Moves IBC Transfer Adapter Into Adapter Map")]
+#[test_case(
+    Params {
+        stored_contract_version: Some((CONTRACT_NAME, CONTRACT_VERSION)),
+        store_ibc_transfer_adapter: true,
+        stored_owner: Some("owner"),
+        contract_admin: None,
+        expected_from_version: CONTRACT_VERSION,
+        expected_owner: Some(Addr::unchecked("owner")),
+        expected_ibc_transfer_adapter_migrated: false,
+        expected_error: None,
+    };
+    "Migrate From Same Version Leaves State Unchanged")]
+#[test_case(
+    Params {
+        stored_contract_version: None,
+        store_ibc_transfer_adapter: true,
+        stored_owner: None,
+        contract_admin: None,
+        expected_from_version: "",
+        expected_owner: None,
+        expected_ibc_transfer_adapter_migrated: false,
+        expected_error: Some(ContractError::NoContractAdmin),
+    };
+    "Migrate Untracked Contract Without Contract Admin - Expect Error")]
+#[test_case(
+    Params {
+        stored_contract_version: None,
+        store_ibc_transfer_adapter: false,
+        stored_owner: None,
+        contract_admin: Some("admin"),
+        expected_from_version: "",
+        expected_owner: None,
+        expected_ibc_transfer_adapter_migrated: false,
+        expected_error: Some(ContractError::Skip(SkipError::MigrationContractMismatch {
+            stored: "unknown".to_string(),
+            new: CONTRACT_NAME.to_string(),
+        })),
+    };
+    "Untracked Contract Without IBC Transfer Adapter Stored - Expect Error")]
+#[test_case(
+    Params {
+        stored_contract_version: Some(("skip-api-swap-adapter-astroport", "0.1.0")),
+        store_ibc_transfer_adapter: true,
+        stored_owner: Some("owner"),
+        contract_admin: Some("admin"),
+        expected_from_version: "",
+        expected_owner: None,
+        expected_ibc_transfer_adapter_migrated: false,
+        expected_error: Some(ContractError::Skip(SkipError::MigrationContractMismatch {
+            stored: "skip-api-swap-adapter-astroport".to_string(),
+            new: CONTRACT_NAME.to_string(),
+        })),
+    };
+    "Different Contract Stored - Expect Error")]
+#[test_case(
+    Params {
+        stored_contract_version: Some((CONTRACT_NAME, "99.0.0")),
+        store_ibc_transfer_adapter: true,
+        stored_owner: Some("owner"),
+        contract_admin: Some("admin"),
+        expected_from_version: "",
+        expected_owner: None,
+        expected_ibc_transfer_adapter_migrated: false,
+        expected_error: Some(ContractError::Skip(SkipError::MigrationDowngrade {
+            stored: "99.0.0".to_string(),
+            new: CONTRACT_VERSION.to_string(),
+        })),
+    };
+    "Downgrade To Older Version - Expect Error")]
+fn test_migrate(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+
+    // Mock the wasm contract info query to return the contract admin
+    let contract_admin = params.contract_admin;
+    deps.querier.update_wasm(move |query| -> QuerierResult {
+        match query {
+            WasmQuery::ContractInfo { .. } => {
+                let mut contract_info = ContractInfoResponse::default();
+                contract_info.code_id = 1;
+                contract_info.creator = "creator".to_string();
+                contract_info.admin = contract_admin.map(|admin| admin.to_string());
+
+                SystemResult::Ok(SystemContractResult::Ok(to_binary(&contract_info).unwrap()))
+            }
+            _ => panic!("Unsupported query: {:?}", query),
+        }
+    });
+
+    // Store the contract version if provided
+    if let Some((contract, version)) = params.stored_contract_version {
+        set_contract_version(deps.as_mut().storage, contract, version)?;
+    }
+
+    // Store the ibc transfer adapter contract address if required
+    if params.store_ibc_transfer_adapter {
+        IBC_TRANSFER_CONTRACT_ADDRESS.save(
+            deps.as_mut().storage,
+            &Addr::unchecked("ibc_transfer_adapter"),
+        )?;
+    }
+
+    // Store the owner if provided
+    if let Some(owner) = params.stored_owner {
+        OWNER.save(deps.as_mut().storage, &Addr::unchecked(owner))?;
+    }
+
+    // Call migrate with the given test parameters
+    let res = skip_api_entry_point::contract::migrate(deps.as_mut(), env, MigrateMsg {});
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the from version attribute is correct
+            assert!(res.attributes.iter().any(
+                |attr| attr.key == "from_version" && attr.value == params.expected_from_version
+            ));
+
+            // Assert the owner is correct
+            assert_eq!(
+                OWNER.may_load(deps.as_ref().storage)?,
+                params.expected_owner
+            );
+
+            // Assert the single ibc transfer adapter is only moved into the
+            // ibc transfer adapter map when migrating from before v0.3.0
+            if params.expected_ibc_transfer_adapter_migrated {
+                assert!(!IBC_TRANSFER_CONTRACT_ADDRESS.exists(deps.as_ref().storage));
+                assert_eq!(
+                    IBC_TRANSFER_ADAPTER_MAP.load(deps.as_ref().storage, "default")?,
+                    Addr::unchecked("ibc_transfer_adapter")
+                );
+                assert_eq!(
+                    DEFAULT_IBC_TRANSFER_ADAPTER.load(deps.as_ref().storage)?,
+                    "default"
+                );
+                assert!(BLOCKED_CONTRACT_ADDRESSES.has(
+                    deps.as_ref().storage,
+                    &Addr::unchecked("ibc_transfer_adapter")
+                ));
+            } else {
+                assert!(!IBC_TRANSFER_ADAPTER_MAP.has(deps.as_ref().storage, "default"));
+                assert!(!DEFAULT_IBC_TRANSFER_ADAPTER.exists(deps.as_ref().storage));
+                assert!(!res
+                    .attributes
+                    .iter()
+                    .any(|attr| attr.value == "migrate_ibc_transfer_adapter"));
+            }
+
+            // Assert the stored contract version is updated
+            assert_eq!(
+                get_contract_version(deps.as_ref().storage)?,
+                ContractVersion {
+                    contract: CONTRACT_NAME.to_string(),
+                    version: CONTRACT_VERSION.to_string(),
+                }
+            );
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+
+    Ok(())
+}
+
+// Test migrating twice at the same version
+#[test]
+fn test_migrate_twice_at_same_version() -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+
+    // Mock the wasm contract info query to return the contract admin
+    deps.querier.update_wasm(move |query| -> QuerierResult {
+        match query {
+            WasmQuery::ContractInfo { .. } => {
+                let mut contract_info = ContractInfoResponse::default();
+                contract_info.code_id = 1;
+                contract_info.creator = "creator".to_string();
+                contract_info.admin = Some("admin".to_string());
+
+                SystemResult::Ok(SystemContractResult::Ok(to_binary(&contract_info).unwrap()))
+            }
+            _ => panic!("Unsupported query: {:?}", query),
+        }
+    });
+
+    // Store the ibc transfer adapter contract address of an untracked contract
+    IBC_TRANSFER_CONTRACT_ADDRESS.save(
+        deps.as_mut().storage,
+        &Addr::unchecked("ibc_transfer_adapter"),
+    )?;
+
+    // Migrate the untracked contract to the current version
+    skip_api_entry_point::contract::migrate(deps.as_mut(), env.clone(), MigrateMsg {})?;
+
+    // Overwrite the migrated state so any repeated state migration is detectable
+    OWNER.save(deps.as_mut().storage, &Addr::unchecked("new_owner"))?;
+    IBC_TRANSFER_ADAPTER_MAP.save(
+        deps.as_mut().storage,
+        "default",
+        &Addr::unchecked("new_ibc_transfer_adapter"),
+    )?;
+
+    // Migrate again at the same version
+    let res = skip_api_entry_point::contract::migrate(deps.as_mut(), env, MigrateMsg {})?;
+
+    // Assert no state migrations were run
+    assert_eq!(
+        res.attributes
+            .iter()
+            .filter(|attr| attr.key == "action")
+            .map(|attr| attr.value.as_str())
+            .collect::<Vec<_>>(),
+        vec!["migrate"]
+    );
+    assert!(res
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "from_version" && attr.value == CONTRACT_VERSION));
+
+    // Assert the state is unchanged
+    assert_eq!(
+        OWNER.load(deps.as_ref().storage)?,
+        Addr::unchecked("new_owner")
+    );
+    assert_eq!(
+        IBC_TRANSFER_ADAPTER_MAP.load(deps.as_ref().storage, "default")?,
+        Addr::unchecked("new_ibc_transfer_adapter")
+    );
+    assert_eq!(
+        DEFAULT_IBC_TRANSFER_ADAPTER.load(deps.as_ref().storage)?,
+        "default"
+    );
+    assert!(!IBC_TRANSFER_CONTRACT_ADDRESS.exists(deps.as_ref().storage));
+
+    // Assert the stored contract version is unchanged
+    assert_eq!(
+        get_contract_version(deps.as_ref().storage)?,
+        ContractVersion {
+            contract: CONTRACT_NAME.to_string(),
+            version: CONTRACT_VERSION.to_string(),
+        }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,782 @@
+use cosmwasm_std::{
+    from_binary,
+    testing::{mock_dependencies_with_balances, mock_env, mock_info, MockApi, MockQuerier},
+    Addr, Coin, Env, MemoryStorage, OwnedDeps, Timestamp,
+};
+use skip::{
+    entry_point::{
+        Action, ActionType, ExecuteMsg, InstantiateMsg, PauseStateResponse, PauseTarget, QueryMsg,
+    },
+    ibc::{IbcFee, IbcInfo, IbcTransferAdapter},
+    swap::{Swap, SwapExactCoinIn, SwapExactCoinOut, SwapOperation, SwapVenue},
+};
+use skip_api_entry_point::error::ContractError;
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response
+    // Guardians
+    - Add Guardian
+    - Remove Guardian
+
+    // Pause
+    - Owner Pauses All
+    - Guardian Pauses All
+    - Guardian Pauses Swap Venue
+    - Guardian Pauses Action Type
+    - Pause Action Type Twice
+    - Owner Unpauses All
+    - Owner Unpauses Swap Venue
+    - Owner Unpauses Action Type
+    - Remove Paused Swap Venue Clears Pause
+
+Expect Error
+    - Non Guardian Pauses
+    - Guardian Unpauses
+    - Guardian Adds Guardian
+    - Remove Guardian That Does Not Exist
+    - Pause Swap Venue That Does Not Exist
+ */
+
+// Define test parameters
+struct Params {
+    msgs: Vec<(&'static str, ExecuteMsg)>,
+    expected_pause_state: PauseStateResponse,
+    expected_guardians: Vec<&'static str>,
+    expected_error: Option<ContractError>,
+}
+
+// Test the guardian and pause execute messages
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::AddGuardian {
+                address: "guardian_2".to_string(),
+            },
+        )],
+        expected_pause_state: PauseStateResponse {
+            all: false,
+            swap_venues: vec![],
+            actions: vec![],
+        },
+        expected_guardians: vec!["guardian", "guardian_2"],
+        expected_error: None,
+    };
+    "Add Guardian")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::RemoveGuardian {
+                address: "guardian".to_string(),
+            },
+        )],
+        expected_pause_state: PauseStateResponse {
+            all: false,
+            swap_venues: vec![],
+            actions: vec![],
+        },
+        expected_guardians: vec![],
+        expected_error: None,
+    };
+    "Remove Guardian")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::Pause {
+                target: PauseTarget::All {},
+            },
+        )],
+        expected_pause_state: PauseStateResponse {
+            all: true,
+            swap_venues: vec![],
+            actions: vec![],
+        },
+        expected_guardians: vec!["guardian"],
+        expected_error: None,
+    };
+    "Owner Pauses All")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "guardian",
+            ExecuteMsg::Pause {
+                target: PauseTarget::All {},
+            },
+        )],
+        expected_pause_state: PauseStateResponse {
+            all: true,
+            swap_venues: vec![],
+            actions: vec![],
+        },
+        expected_guardians: vec!["guardian"],
+        expected_error: None,
+    };
+    "Guardian Pauses All")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "guardian",
+            ExecuteMsg::Pause {
+                target: PauseTarget::SwapVenue {
+                    name: "astroport".to_string(),
+                },
+            },
+        )],
+        expected_pause_state: PauseStateResponse {
+            all: false,
+            swap_venues: vec!["astroport".to_string()],
+            actions: vec![],
+        },
+        expected_guardians: vec!["guardian"],
+        expected_error: None,
+    };
+    "Guardian Pauses Swap Venue")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "guardian",
+            ExecuteMsg::Pause {
+                target: PauseTarget::Action {
+                    action_type: ActionType::IbcTransfer,
+                },
+            },
+        )],
+        expected_pause_state: PauseStateResponse {
+            all: false,
+            swap_venues: vec![],
+            actions: vec![ActionType::IbcTransfer],
+        },
+        expected_guardians: vec!["guardian"],
+        expected_error: None,
+    };
+    "Guardian Pauses Action Type")]
+#[test_case(
+    Params {
+        msgs: vec![
+            (
+                "guardian",
+                ExecuteMsg::Pause {
+                    target: PauseTarget::Action {
+                        action_type: ActionType::ContractCall,
+                    },
+                },
+            ),
+            (
+                "owner",
+                ExecuteMsg::Pause {
+                    target: PauseTarget::Action {
+                        action_type: ActionType::ContractCall,
+                    },
+                },
+            ),
+        ],
+        expected_pause_state: PauseStateResponse {
+            all: false,
+            swap_venues: vec![],
+            actions: vec![ActionType::ContractCall],
+        },
+        expected_guardians: vec!["guardian"],
+        expected_error: None,
+    };
+    "Pause Action Type Twice")]
+#[test_case(
+    Params {
+        msgs: vec![
+            (
+                "guardian",
+                ExecuteMsg::Pause {
+                    target: PauseTarget::All {},
+                },
+            ),
+            (
+                "owner",
+                ExecuteMsg::Unpause {
+                    target: PauseTarget::All {},
+                },
+            ),
+        ],
+        expected_pause_state: PauseStateResponse {
+            all: false,
+            swap_venues: vec![],
+            actions: vec![],
+        },
+        expected_guardians: vec!["guardian"],
+        expected_error: None,
+    };
+    "Owner Unpauses All")]
+#[test_case(
+    Params {
+        msgs: vec![
+            (
+                "guardian",
+                ExecuteMsg::Pause {
+                    target: PauseTarget::SwapVenue {
+                        name: "astroport".to_string(),
+                    },
+                },
+            ),
+            (
+                "guardian",
+                ExecuteMsg::Pause {
+                    target: PauseTarget::SwapVenue {
+                        name: "osmosis".to_string(),
+                    },
+                },
+            ),
+            (
+                "owner",
+                ExecuteMsg::Unpause {
+                    target: PauseTarget::SwapVenue {
+                        name: "astroport".to_string(),
+                    },
+                },
+            ),
+        ],
+        expected_pause_state: PauseStateResponse {
+            all: false,
+            swap_venues: vec!["osmosis".to_string()],
+            actions: vec![],
+        },
+        expected_guardians: vec!["guardian"],
+        expected_error: None,
+    };
+    "Owner Unpauses Swap Venue")]
+#[test_case(
+    Params {
+        msgs: vec![
+            (
+                "guardian",
+                ExecuteMsg::Pause {
+                    target: PauseTarget::Action {
+                        action_type: ActionType::BankSend,
+                    },
+                },
+            ),
+            (
+                "guardian",
+                ExecuteMsg::Pause {
+                    target: PauseTarget::Action {
+                        action_type: ActionType::IbcTransfer,
+                    },
+                },
+            ),
+            (
+                "owner",
+                ExecuteMsg::Unpause {
+                    target: PauseTarget::Action {
+                        action_type: ActionType::BankSend,
+                    },
+                },
+            ),
+        ],
+        expected_pause_state: PauseStateResponse {
+            all: false,
+            swap_venues: vec![],
+            actions: vec![ActionType::IbcTransfer],
+        },
+        expected_guardians: vec!["guardian"],
+        expected_error: None,
+    };
+    "Owner Unpauses Action Type")]
+#[test_case(
+    Params {
+        msgs: vec![
+            (
+                "guardian",
+                ExecuteMsg::Pause {
+                    target: PauseTarget::SwapVenue {
+                        name: "astroport".to_string(),
+                    },
+                },
+            ),
+            (
+                "owner",
+                ExecuteMsg::RemoveSwapVenue {
+                    name: "astroport".to_string(),
+                },
+            ),
+        ],
+        expected_pause_state: PauseStateResponse {
+            all: false,
+            swap_venues: vec![],
+            actions: vec![],
+        },
+        expected_guardians: vec!["guardian"],
+        expected_error: None,
+    };
+    "Remove Paused Swap Venue Clears Pause")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "random",
+            ExecuteMsg::Pause {
+                target: PauseTarget::All {},
+            },
+        )],
+        expected_pause_state: PauseStateResponse {
+            all: false,
+            swap_venues: vec![],
+            actions: vec![],
+        },
+        expected_guardians: vec![],
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Non Guardian Pauses - Expect Error")]
+#[test_case(
+    Params {
+        msgs: vec![
+            (
+                "guardian",
+                ExecuteMsg::Pause {
+                    target: PauseTarget::All {},
+                },
+            ),
+            (
+                "guardian",
+                ExecuteMsg::Unpause {
+                    target: PauseTarget::All {},
+                },
+            ),
+        ],
+        expected_pause_state: PauseStateResponse {
+            all: false,
+            swap_venues: vec![],
+            actions: vec![],
+        },
+        expected_guardians: vec![],
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Guardian Unpauses - Expect Error")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "guardian",
+            ExecuteMsg::AddGuardian {
+                address: "guardian_2".to_string(),
+            },
+        )],
+        expected_pause_state: PauseStateResponse {
+            all: false,
+            swap_venues: vec![],
+            actions: vec![],
+        },
+        expected_guardians: vec![],
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Guardian Adds Guardian - Expect Error")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "owner",
+            ExecuteMsg::RemoveGuardian {
+                address: "guardian_2".to_string(),
+            },
+        )],
+        expected_pause_state: PauseStateResponse {
+            all: false,
+            swap_venues: vec![],
+            actions: vec![],
+        },
+        expected_guardians: vec![],
+        expected_error: Some(ContractError::GuardianNotFound),
+    };
+    "Remove Guardian That Does Not Exist - Expect Error")]
+#[test_case(
+    Params {
+        msgs: vec![(
+            "guardian",
+            ExecuteMsg::Pause {
+                target: PauseTarget::SwapVenue {
+                    name: "unknown".to_string(),
+                },
+            },
+        )],
+        expected_pause_state: PauseStateResponse {
+            all: false,
+            swap_venues: vec![],
+            actions: vec![],
+        },
+        expected_guardians: vec![],
+        expected_error: Some(ContractError::SwapVenueNotFound("unknown".to_string())),
+    };
+    "Pause Swap Venue That Does Not Exist - Expect Error")]
+fn test_execute_pause(params: Params) {
+    // Create mock dependencies
+    let mut deps = mock_dependencies_with_balances(&[]);
+
+    // Create mock env with the entry point contract address
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+
+    // Instantiate the contract and add a guardian
+    instantiate_with_guardian(&mut deps, &env);
+
+    // Call execute with the given test parameters, stopping at the first error
+    let res = params.msgs.into_iter().try_for_each(|(caller, msg)| {
+        skip_api_entry_point::contract::execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(caller, &[]),
+            msg,
+        )
+        .map(|_| ())
+    });
+
+    match res {
+        Ok(()) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the pause state is correct
+            let pause_state: PauseStateResponse = from_binary(
+                &skip_api_entry_point::contract::query(
+                    deps.as_ref(),
+                    env.clone(),
+                    QueryMsg::PauseState {},
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(pause_state, params.expected_pause_state);
+
+            // Assert the guardians are correct
+            let guardians: Vec<Addr> = from_binary(
+                &skip_api_entry_point::contract::query(deps.as_ref(), env, QueryMsg::Guardians {})
+                    .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(
+                guardians,
+                params
+                    .expected_guardians
+                    .into_iter()
+                    .map(Addr::unchecked)
+                    .collect::<Vec<_>>()
+            );
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
+
+/*
+Test Cases:
+
+Expect Response
+    - User Swap On Unpaused Swap Venue
+    - Post Swap Action Of Unpaused Action Type
+
+Expect Error
+    - Swap And Action While All Paused
+    - Swap And Action With Recover While All Paused
+    - User Swap On Paused Swap Venue
+    - Fee Swap On Paused Swap Venue
+    - Post Swap Action Of Paused Action Type
+ */
+
+// Define test parameters
+struct RouteParams {
+    pause_targets: Vec<PauseTarget>,
+    caller: &'static str,
+    info_funds: Vec<Coin>,
+    msg: ExecuteMsg,
+    expected_error: Option<ContractError>,
+}
+
+// Test that paused routes error with RoutePaused
+#[test_case(
+    RouteParams {
+        pause_targets: vec![PauseTarget::SwapVenue {
+            name: "osmosis".to_string(),
+        }],
+        caller: "entry_point",
+        info_funds: vec![],
+        msg: ExecuteMsg::UserSwap {
+            swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+                swap_venue_name: "astroport".to_string(),
+                operations: vec![SwapOperation {
+                    pool: "pool".to_string(),
+                    denom_in: "untrn".to_string(),
+                    denom_out: "osmo".to_string(),
+                }],
+            }),
+            min_coin: Coin::new(1_000_000, "osmo"),
+            remaining_coin: Coin::new(1_000_000, "untrn"),
+            affiliates: vec![],
+        },
+        expected_error: None,
+    };
+    "User Swap On Unpaused Swap Venue")]
+#[test_case(
+    RouteParams {
+        pause_targets: vec![PauseTarget::Action {
+            action_type: ActionType::IbcTransfer,
+        }],
+        caller: "entry_point",
+        info_funds: vec![],
+        msg: ExecuteMsg::PostSwapAction {
+            min_coin: Coin::new(1_000_000, "osmo"),
+            timeout_timestamp: 101,
+            post_swap_action: Action::BankSend {
+                to_address: "to_address".to_string(),
+            },
+            exact_out: false,
+            affiliates: vec![],
+            callback_address: None,
+        },
+        expected_error: None,
+    };
+    "Post Swap Action Of Unpaused Action Type")]
+#[test_case(
+    RouteParams {
+        pause_targets: vec![PauseTarget::All {}],
+        caller: "user",
+        info_funds: vec![Coin::new(1_000_000, "untrn")],
+        msg: ExecuteMsg::SwapAndAction {
+            pre_swap_action: None,
+            user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+                swap_venue_name: "astroport".to_string(),
+                operations: vec![],
+            }),
+            min_coin: Coin::new(1_000_000, "osmo"),
+            timeout_timestamp: 101,
+            post_swap_action: Action::BankSend {
+                to_address: "to_address".to_string(),
+            },
+            affiliates: vec![],
+            affiliate_fee_basis: None,
+            callback_address: None,
+        },
+        expected_error: Some(ContractError::RoutePaused(PauseTarget::All {})),
+    };
+    "Swap And Action While All Paused - Expect Error")]
+#[test_case(
+    RouteParams {
+        pause_targets: vec![PauseTarget::All {}],
+        caller: "user",
+        info_funds: vec![Coin::new(1_000_000, "untrn")],
+        msg: ExecuteMsg::SwapAndActionWithRecover {
+            pre_swap_action: None,
+            user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+                swap_venue_name: "astroport".to_string(),
+                operations: vec![],
+            }),
+            min_coin: Coin::new(1_000_000, "osmo"),
+            timeout_timestamp: 101,
+            post_swap_action: Action::BankSend {
+                to_address: "to_address".to_string(),
+            },
+            affiliates: vec![],
+            affiliate_fee_basis: None,
+            recovery_addr: Addr::unchecked("recovery_address"),
+            callback_address: None,
+            ibc_recovery: None,
+        },
+        expected_error: Some(ContractError::RoutePaused(PauseTarget::All {})),
+    };
+    "Swap And Action With Recover While All Paused - Expect Error")]
+#[test_case(
+    RouteParams {
+        pause_targets: vec![PauseTarget::SwapVenue {
+            name: "astroport".to_string(),
+        }],
+        caller: "entry_point",
+        info_funds: vec![],
+        msg: ExecuteMsg::UserSwap {
+            swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+                swap_venue_name: "astroport".to_string(),
+                operations: vec![SwapOperation {
+                    pool: "pool".to_string(),
+                    denom_in: "untrn".to_string(),
+                    denom_out: "osmo".to_string(),
+                }],
+            }),
+            min_coin: Coin::new(1_000_000, "osmo"),
+            remaining_coin: Coin::new(1_000_000, "untrn"),
+            affiliates: vec![],
+        },
+        expected_error: Some(ContractError::RoutePaused(PauseTarget::SwapVenue {
+            name: "astroport".to_string(),
+        })),
+    };
+    "User Swap On Paused Swap Venue - Expect Error")]
+#[test_case(
+    RouteParams {
+        pause_targets: vec![PauseTarget::SwapVenue {
+            name: "osmosis".to_string(),
+        }],
+        caller: "user",
+        info_funds: vec![Coin::new(1_000_000, "untrn")],
+        msg: ExecuteMsg::SwapAndAction {
+            pre_swap_action: None,
+            user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+                swap_venue_name: "astroport".to_string(),
+                operations: vec![],
+            }),
+            min_coin: Coin::new(1_000_000, "osmo"),
+            timeout_timestamp: 101,
+            post_swap_action: Action::IbcTransfer {
+                ibc_info: IbcInfo {
+                    source_channel: "channel-0".to_string(),
+                    receiver: "receiver".to_string(),
+                    memo: "".to_string(),
+                    fee: Some(IbcFee {
+                        recv_fee: vec![],
+                        ack_fee: vec![Coin::new(100_000, "uatom")],
+                        timeout_fee: vec![Coin::new(100_000, "uatom")],
+                    }),
+                    recover_address: "recover_address".to_string(),
+                },
+                fee_swap: Some(SwapExactCoinOut {
+                    swap_venue_name: "osmosis".to_string(),
+                    operations: vec![SwapOperation {
+                        pool: "pool".to_string(),
+                        denom_in: "untrn".to_string(),
+                        denom_out: "uatom".to_string(),
+                    }],
+                    refund_address: None,
+                }),
+                ibc_transfer_adapter: None,
+            },
+            affiliates: vec![],
+            affiliate_fee_basis: None,
+            callback_address: None,
+        },
+        expected_error: Some(ContractError::RoutePaused(PauseTarget::SwapVenue {
+            name: "osmosis".to_string(),
+        })),
+    };
+    "Fee Swap On Paused Swap Venue - Expect Error")]
+#[test_case(
+    RouteParams {
+        pause_targets: vec![PauseTarget::Action {
+            action_type: ActionType::BankSend,
+        }],
+        caller: "entry_point",
+        info_funds: vec![],
+        msg: ExecuteMsg::PostSwapAction {
+            min_coin: Coin::new(1_000_000, "osmo"),
+            timeout_timestamp: 101,
+            post_swap_action: Action::BankSend {
+                to_address: "to_address".to_string(),
+            },
+            exact_out: false,
+            affiliates: vec![],
+            callback_address: None,
+        },
+        expected_error: Some(ContractError::RoutePaused(PauseTarget::Action {
+            action_type: ActionType::BankSend,
+        })),
+    };
+    "Post Swap Action Of Paused Action Type - Expect Error")]
+fn test_paused_routes(params: RouteParams) {
+    // Create mock dependencies with the swap output in the entry point contract
+    let mut deps =
+        mock_dependencies_with_balances(&[("entry_point", &[Coin::new(1_000_000, "osmo")])]);
+
+    // Create mock env with parameters that make testing easier
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+    env.block.time = Timestamp::from_nanos(100);
+
+    // Instantiate the contract and add a guardian
+    instantiate_with_guardian(&mut deps, &env);
+
+    // Pause the given targets as the guardian
+    for target in params.pause_targets {
+        skip_api_entry_point::contract::execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("guardian", &[]),
+            ExecuteMsg::Pause { target },
+        )
+        .unwrap();
+    }
+
+    // Call execute with the given test parameters
+    let res = skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env,
+        mock_info(params.caller, &params.info_funds),
+        params.msg,
+    );
+
+    match res {
+        Ok(_) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
+
+// Instantiates the entry point contract with two swap venues and an ibc transfer adapter,
+// and adds a guardian
+fn instantiate_with_guardian(deps: &mut OwnedDeps<MemoryStorage, MockApi, MockQuerier>, env: &Env) {
+    skip_api_entry_point::contract::instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        InstantiateMsg {
+            owner: Some("owner".to_string()),
+            swap_venues: vec![
+                SwapVenue {
+                    name: "astroport".to_string(),
+                    adapter_contract_address: "astroport_adapter".to_string(),
+                },
+                SwapVenue {
+                    name: "osmosis".to_string(),
+                    adapter_contract_address: "osmosis_adapter".to_string(),
+                },
+            ],
+            ibc_transfer_adapters: vec![IbcTransferAdapter {
+                name: "ibc_transfer".to_string(),
+                adapter_contract_address: "ibc_transfer_adapter".to_string(),
+            }],
+            default_ibc_transfer_adapter: "ibc_transfer".to_string(),
+        },
+    )
+    .unwrap();
+
+    skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        ExecuteMsg::AddGuardian {
+            address: "guardian".to_string(),
+        },
+    )
+    .unwrap();
+}
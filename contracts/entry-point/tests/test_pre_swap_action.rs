@@ -0,0 +1,420 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies_with_balances, mock_env, mock_info},
+    to_binary, Addr, BankMsg, Coin,
+    ReplyOn::Never,
+    SubMsg, Timestamp, Uint128, WasmMsg,
+};
+use skip::{
+    entry_point::{Action, Affiliate, ExecuteMsg, PreSwapAction},
+    error::SkipError,
+    swap::{ExecuteMsg as SwapExecuteMsg, Swap, SwapExactCoinIn, SwapExactCoinOut, SwapOperation},
+};
+use skip_api_entry_point::{error::ContractError, state::SWAP_VENUE_MAP};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response
+    // Swap And Action
+    - Withdraw Liquidity And Swap Both Withdrawn Assets
+    - Withdraw Liquidity With Withdrawn Asset Already In The Min Coin Denom (Only the other asset is swapped)
+
+    // User Swap Withdrawn Assets
+    - User Swap Withdrawn Assets (Swaps each withdrawn asset found by the balance change, then pays the affiliates)
+    - User Swap Withdrawn Assets With Asset Not Withdrawn (Skips the swap of the asset with no balance change)
+
+Expect Error
+    // Swap And Action
+    - User Swap Is Not A Swap Exact Coin In
+    - User Swap And Other Asset Swap Swap The Same Withdrawn Asset
+    - Other Asset Swap Last Swap Operation Denom Out Is Not The Min Coin Denom
+
+    // User Swap Withdrawn Assets
+    - Unauthorized Caller
+ */
+
+// Define test parameters
+struct Params {
+    user_swap: Swap,
+    other_asset_swap: SwapExactCoinIn,
+    expected_messages: Vec<SubMsg>,
+    expected_error: Option<ContractError>,
+}
+
+// Test execute_swap_and_action with a withdraw liquidity pre swap action
+#[test_case(
+    Params {
+        user_swap: Swap::SwapExactCoinIn(swap("pool_2", "uatom")),
+        other_asset_swap: swap("pool_3", "untrn"),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter".to_string(),
+                    msg: to_binary(&SwapExecuteMsg::WithdrawLiquidity {
+                        pool: "pool".to_string(),
+                        denoms_out: vec!["uatom".to_string(), "untrn".to_string()],
+                    })
+                    .unwrap(),
+                    funds: vec![Coin::new(1_000_000, "lp_token")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "entry_point".to_string(),
+                    msg: to_binary(&ExecuteMsg::UserSwapWithdrawnAssets {
+                        swaps: vec![swap("pool_2", "uatom"), swap("pool_3", "untrn")],
+                        pre_balances: vec![Coin::new(0, "uatom"), Coin::new(1_000_000, "untrn")],
+                        min_coin: Coin::new(800_000, "osmo"),
+                        affiliates: vec![],
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            post_swap_action_msg(),
+        ],
+        expected_error: None,
+    };
+    "Withdraw Liquidity And Swap Both Withdrawn Assets")]
+#[test_case(
+    Params {
+        user_swap: Swap::SwapExactCoinIn(swap("pool_2", "uatom")),
+        other_asset_swap: SwapExactCoinIn {
+            swap_venue_name: "swap_venue_name".to_string(),
+            operations: vec![],
+        },
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter".to_string(),
+                    msg: to_binary(&SwapExecuteMsg::WithdrawLiquidity {
+                        pool: "pool".to_string(),
+                        denoms_out: vec!["uatom".to_string(), "osmo".to_string()],
+                    })
+                    .unwrap(),
+                    funds: vec![Coin::new(1_000_000, "lp_token")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "entry_point".to_string(),
+                    msg: to_binary(&ExecuteMsg::UserSwapWithdrawnAssets {
+                        swaps: vec![swap("pool_2", "uatom")],
+                        pre_balances: vec![Coin::new(0, "uatom")],
+                        min_coin: Coin::new(800_000, "osmo"),
+                        affiliates: vec![],
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            post_swap_action_msg(),
+        ],
+        expected_error: None,
+    };
+    "Withdraw Liquidity With Withdrawn Asset Already In The Min Coin Denom")]
+#[test_case(
+    Params {
+        user_swap: Swap::SwapExactCoinOut(SwapExactCoinOut {
+            swap_venue_name: "swap_venue_name".to_string(),
+            operations: swap("pool_2", "uatom").operations,
+            refund_address: None,
+        }),
+        other_asset_swap: swap("pool_3", "untrn"),
+        expected_messages: vec![],
+        expected_error: Some(ContractError::PreSwapActionUserSwapNotExactCoinIn),
+    };
+    "User Swap Is Not A Swap Exact Coin In - Expect Error")]
+#[test_case(
+    Params {
+        user_swap: Swap::SwapExactCoinIn(swap("pool_2", "uatom")),
+        other_asset_swap: swap("pool_3", "uatom"),
+        expected_messages: vec![],
+        expected_error: Some(ContractError::WithdrawnAssetSwapsSameDenom),
+    };
+    "User Swap And Other Asset Swap Swap The Same Withdrawn Asset - Expect Error")]
+#[test_case(
+    Params {
+        user_swap: Swap::SwapExactCoinIn(swap("pool_2", "uatom")),
+        other_asset_swap: SwapExactCoinIn {
+            swap_venue_name: "swap_venue_name".to_string(),
+            operations: vec![SwapOperation {
+                pool: "pool_3".to_string(),
+                denom_in: "untrn".to_string(),
+                denom_out: "uatom".to_string(),
+            }],
+        },
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Skip(SkipError::SwapOperationsCoinOutDenomMismatch)),
+    };
+    "Other Asset Swap Last Swap Operation Denom Out Is Not The Min Coin Denom - Expect Error")]
+fn test_execute_swap_and_action_with_pre_swap_action(params: Params) {
+    // Create mock dependencies
+    let mut deps = mock_dependencies_with_balances(&[(
+        "entry_point",
+        &[Coin::new(1_000_000, "osmo"), Coin::new(1_000_000, "untrn")],
+    )]);
+
+    // Create mock env with parameters that make testing easier
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+    env.block.time = Timestamp::from_nanos(100);
+
+    // Create mock info with the LP tokens sent
+    let info = mock_info("swapper", &[Coin::new(1_000_000, "lp_token")]);
+
+    // Store the swap venue adapter contract address
+    SWAP_VENUE_MAP
+        .save(
+            deps.as_mut().storage,
+            "swap_venue_name",
+            &Addr::unchecked("swap_venue_adapter"),
+        )
+        .unwrap();
+
+    // Call execute_swap_and_action with the given test case params
+    let res = skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::SwapAndAction {
+            pre_swap_action: Some(PreSwapAction::WithdrawLiquidity {
+                swap_venue_name: "swap_venue_name".to_string(),
+                pool: "pool".to_string(),
+                other_asset_swap: params.other_asset_swap,
+            }),
+            user_swap: params.user_swap,
+            min_coin: Coin::new(800_000, "osmo"),
+            timeout_timestamp: 101,
+            post_swap_action: Action::BankSend {
+                to_address: "to_address".to_string(),
+            },
+            affiliates: vec![],
+            affiliate_fee_basis: None,
+            callback_address: None,
+        },
+    );
+
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the messages in the response are correct
+            assert_eq!(res.messages, params.expected_messages);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
+
+// Define test parameters
+struct WithdrawnAssetsParams {
+    caller: String,
+    pre_balances: Vec<Coin>,
+    expected_messages: Vec<SubMsg>,
+    expected_error: Option<ContractError>,
+}
+
+// Test execute_user_swap_withdrawn_assets
+#[test_case(
+    WithdrawnAssetsParams {
+        caller: "entry_point".to_string(),
+        pre_balances: vec![Coin::new(0, "uatom"), Coin::new(1_000_000, "untrn")],
+        expected_messages: vec![
+            swap_msg(Coin::new(400_000, "uatom"), "pool_2"),
+            swap_msg(Coin::new(500_000, "untrn"), "pool_3"),
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "affiliate".to_string(),
+                    amount: vec![Coin::new(80_000, "osmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "User Swap Withdrawn Assets")]
+#[test_case(
+    WithdrawnAssetsParams {
+        caller: "entry_point".to_string(),
+        pre_balances: vec![Coin::new(0, "uatom"), Coin::new(1_500_000, "untrn")],
+        expected_messages: vec![
+            swap_msg(Coin::new(400_000, "uatom"), "pool_2"),
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "affiliate".to_string(),
+                    amount: vec![Coin::new(80_000, "osmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "User Swap Withdrawn Assets With Asset Not Withdrawn")]
+#[test_case(
+    WithdrawnAssetsParams {
+        caller: "random".to_string(),
+        pre_balances: vec![Coin::new(0, "uatom"), Coin::new(1_000_000, "untrn")],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Unauthorized Caller - Expect Error")]
+fn test_execute_user_swap_withdrawn_assets(params: WithdrawnAssetsParams) {
+    // Create mock dependencies with the withdrawn assets received
+    let mut deps = mock_dependencies_with_balances(&[(
+        "entry_point",
+        &[Coin::new(400_000, "uatom"), Coin::new(1_500_000, "untrn")],
+    )]);
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+
+    // Create mock info with the given caller
+    let info = mock_info(&params.caller, &[]);
+
+    // Store the swap venue adapter contract address
+    SWAP_VENUE_MAP
+        .save(
+            deps.as_mut().storage,
+            "swap_venue_name",
+            &Addr::unchecked("swap_venue_adapter"),
+        )
+        .unwrap();
+
+    // Call execute_user_swap_withdrawn_assets with the given test case params
+    let res = skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::UserSwapWithdrawnAssets {
+            swaps: vec![swap("pool_2", "uatom"), swap("pool_3", "untrn")],
+            pre_balances: params.pre_balances,
+            min_coin: Coin::new(800_000, "osmo"),
+            affiliates: vec![Affiliate {
+                address: "affiliate".to_string(),
+                basis_points_fee: Some(Uint128::new(1000)),
+                id: None,
+            }],
+        },
+    );
+
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the messages in the response are correct
+            assert_eq!(res.messages, params.expected_messages);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
+
+// Creates a swap exact coin in through the pool from the denom in to osmo
+fn swap(pool: &str, denom_in: &str) -> SwapExactCoinIn {
+    SwapExactCoinIn {
+        swap_venue_name: "swap_venue_name".to_string(),
+        operations: vec![SwapOperation {
+            pool: pool.to_string(),
+            denom_in: denom_in.to_string(),
+            denom_out: "osmo".to_string(),
+        }],
+    }
+}
+
+// Creates the swap adapter message swapping the coin in through the pool to osmo
+fn swap_msg(coin_in: Coin, pool: &str) -> SubMsg {
+    SubMsg {
+        id: 0,
+        msg: WasmMsg::Execute {
+            contract_addr: "swap_venue_adapter".to_string(),
+            msg: to_binary(&SwapExecuteMsg::Swap {
+                operations: swap(pool, &coin_in.denom).operations,
+                minimum_receive: None,
+                max_spread: None,
+            })
+            .unwrap(),
+            funds: vec![coin_in],
+        }
+        .into(),
+        gas_limit: None,
+        reply_on: Never,
+    }
+}
+
+// Creates the post swap action message bank sending the coin out to the to address
+fn post_swap_action_msg() -> SubMsg {
+    SubMsg {
+        id: 0,
+        msg: WasmMsg::Execute {
+            contract_addr: "entry_point".to_string(),
+            msg: to_binary(&ExecuteMsg::PostSwapAction {
+                min_coin: Coin::new(800_000, "osmo"),
+                timeout_timestamp: 101,
+                post_swap_action: Action::BankSend {
+                    to_address: "to_address".to_string(),
+                },
+                exact_out: false,
+                affiliates: vec![],
+                callback_address: None,
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into(),
+        gas_limit: None,
+        reply_on: Never,
+    }
+}
@@ -0,0 +1,464 @@
+use cosmwasm_std::{
+    from_binary,
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_binary, Addr, Binary, Coin, ContractResult, Decimal, QuerierResult, StdError, SystemResult,
+    Uint128, WasmQuery,
+};
+use skip::{
+    entry_point::{
+        ConfigResponse, ExecuteMsg, InstantiateMsg, PauseStateResponse, ProtocolFee, QueryMsg,
+        RegisteredAffiliate,
+    },
+    ibc::IbcTransferAdapter,
+    swap::{
+        QueryMsg as SwapQueryMsg, SimulateSwapWithMetadataResponse, SwapOperation,
+        SwapOperationMetadata, SwapVenue,
+    },
+};
+use skip_api_entry_point::state::{ACCRUED_AFFILIATE_FEES, AFFILIATE_FEE_ACCRUAL};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response
+    // Swap Venues
+    - Swap Venues With Default Limit
+    - Swap Venues With Limit
+    - Swap Venues Starting After
+
+    // Blocked Contract Addresses
+    - Blocked Contract Addresses With Default Limit
+    - Blocked Contract Addresses Starting After With Limit
+
+    // Config
+    - Config
+    - Protocol Fee
+    - Delegate Enabled
+
+    // Affiliates
+    - Registered Affiliate
+    - Registered Affiliates Starting After
+    - Max Total Affiliate Basis Points Fee
+    - Affiliate Fee Accrual
+    - Accrued Affiliate Fees Starting After
+
+    // Simulate Swap With Metadata
+    - Simulate Swap Exact Coin In With Metadata (Forwarded to the swap venue's adapter contract)
+    - Simulate Swap Exact Coin Out With Metadata (Forwarded to the swap venue's adapter contract)
+
+Expect Error
+    - Blocked Contract Addresses Starting After Invalid Address
+    - Simulate Swap With Metadata Swap Venue Not Found
+ */
+
+// Define test parameters
+struct Params {
+    query: QueryMsg,
+    expected_response: Binary,
+    expected_error: Option<StdError>,
+}
+
+// Test the entry point list and config queries
+#[test_case(
+    Params {
+        query: QueryMsg::SwapVenues {
+            start_after: None,
+            limit: None,
+        },
+        expected_response: to_binary(&vec![
+            swap_venue("astroport", "astroport_adapter"),
+            swap_venue("osmosis", "osmosis_adapter"),
+            swap_venue("white-whale", "white_whale_adapter"),
+        ]).unwrap(),
+        expected_error: None,
+    };
+    "Swap Venues With Default Limit")]
+#[test_case(
+    Params {
+        query: QueryMsg::SwapVenues {
+            start_after: None,
+            limit: Some(2),
+        },
+        expected_response: to_binary(&vec![
+            swap_venue("astroport", "astroport_adapter"),
+            swap_venue("osmosis", "osmosis_adapter"),
+        ]).unwrap(),
+        expected_error: None,
+    };
+    "Swap Venues With Limit")]
+#[test_case(
+    Params {
+        query: QueryMsg::SwapVenues {
+            start_after: Some("astroport".to_string()),
+            limit: None,
+        },
+        expected_response: to_binary(&vec![
+            swap_venue("osmosis", "osmosis_adapter"),
+            swap_venue("white-whale", "white_whale_adapter"),
+        ]).unwrap(),
+        expected_error: None,
+    };
+    "Swap Venues Starting After")]
+#[test_case(
+    Params {
+        query: QueryMsg::BlockedContractAddresses {
+            start_after: None,
+            limit: None,
+        },
+        expected_response: to_binary(&vec![
+            Addr::unchecked("astroport_adapter"),
+            Addr::unchecked("entry_point"),
+            Addr::unchecked("ibc_transfer_adapter"),
+            Addr::unchecked("osmosis_adapter"),
+            Addr::unchecked("white_whale_adapter"),
+        ]).unwrap(),
+        expected_error: None,
+    };
+    "Blocked Contract Addresses With Default Limit")]
+#[test_case(
+    Params {
+        query: QueryMsg::BlockedContractAddresses {
+            start_after: Some("entry_point".to_string()),
+            limit: Some(2),
+        },
+        expected_response: to_binary(&vec![
+            Addr::unchecked("ibc_transfer_adapter"),
+            Addr::unchecked("osmosis_adapter"),
+        ]).unwrap(),
+        expected_error: None,
+    };
+    "Blocked Contract Addresses Starting After With Limit")]
+#[test_case(
+    Params {
+        query: QueryMsg::Config {},
+        expected_response: to_binary(&ConfigResponse {
+            owner: Addr::unchecked("owner"),
+            pending_owner: Some(Addr::unchecked("new_owner")),
+            guardians: vec![Addr::unchecked("guardian")],
+            swap_venues: vec![
+                swap_venue("astroport", "astroport_adapter"),
+                swap_venue("osmosis", "osmosis_adapter"),
+                swap_venue("white-whale", "white_whale_adapter"),
+            ],
+            ibc_transfer_adapters: vec![IbcTransferAdapter {
+                name: "ibc_transfer".to_string(),
+                adapter_contract_address: "ibc_transfer_adapter".to_string(),
+            }],
+            default_ibc_transfer_adapter: "ibc_transfer".to_string(),
+            pause_state: PauseStateResponse {
+                all: false,
+                swap_venues: vec![],
+                actions: vec![],
+            },
+            protocol_fee: Some(set_protocol_fee()),
+            max_total_affiliate_basis_points_fee: Uint128::new(1000),
+            delegate_enabled: true,
+        }).unwrap(),
+        expected_error: None,
+    };
+    "Config")]
+#[test_case(
+    Params {
+        query: QueryMsg::ProtocolFee {},
+        expected_response: to_binary(&Some(set_protocol_fee())).unwrap(),
+        expected_error: None,
+    };
+    "Protocol Fee")]
+#[test_case(
+    Params {
+        query: QueryMsg::DelegateEnabled {},
+        expected_response: to_binary(&true).unwrap(),
+        expected_error: None,
+    };
+    "Delegate Enabled")]
+#[test_case(
+    Params {
+        query: QueryMsg::RegisteredAffiliate {
+            id: "partner-b".to_string(),
+        },
+        expected_response: to_binary(&registered_partner("partner-b", "partner_b")).unwrap(),
+        expected_error: None,
+    };
+    "Registered Affiliate")]
+#[test_case(
+    Params {
+        query: QueryMsg::RegisteredAffiliates {
+            start_after: Some("partner-a".to_string()),
+            limit: None,
+        },
+        expected_response: to_binary(&vec![
+            registered_partner("partner-b", "partner_b"),
+        ]).unwrap(),
+        expected_error: None,
+    };
+    "Registered Affiliates Starting After")]
+#[test_case(
+    Params {
+        query: QueryMsg::MaxTotalAffiliateBasisPointsFee {},
+        expected_response: to_binary(&Uint128::new(1000)).unwrap(),
+        expected_error: None,
+    };
+    "Max Total Affiliate Basis Points Fee")]
+#[test_case(
+    Params {
+        query: QueryMsg::AffiliateFeeAccrual {
+            address: "partner_a".to_string(),
+        },
+        expected_response: to_binary(&true).unwrap(),
+        expected_error: None,
+    };
+    "Affiliate Fee Accrual")]
+#[test_case(
+    Params {
+        query: QueryMsg::AccruedAffiliateFees {
+            address: "partner_a".to_string(),
+            start_after: Some("uatom".to_string()),
+            limit: None,
+        },
+        expected_response: to_binary(&vec![Coin::new(200, "untrn")]).unwrap(),
+        expected_error: None,
+    };
+    "Accrued Affiliate Fees Starting After")]
+#[test_case(
+    Params {
+        query: QueryMsg::BlockedContractAddresses {
+            start_after: Some("".to_string()),
+            limit: None,
+        },
+        expected_response: Binary::default(),
+        expected_error: Some(StdError::generic_err(
+            "Invalid input: human address too short for this mock implementation (must be >= 3).",
+        )),
+    };
+    "Blocked Contract Addresses Starting After Invalid Address - Expect Error")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactCoinInWithMetadata {
+            swap_venue_name: "osmosis".to_string(),
+            coin_in: Coin::new(100, "uatom"),
+            swap_operations: vec![swap_operation()],
+        },
+        expected_response: to_binary(&simulate_swap_with_metadata_response(
+            "osmosis_adapter",
+            Coin::new(100, "uatom"),
+            Coin::new(200, "uosmo"),
+        )).unwrap(),
+        expected_error: None,
+    };
+    "Simulate Swap Exact Coin In With Metadata")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactCoinOutWithMetadata {
+            swap_venue_name: "astroport".to_string(),
+            coin_out: Coin::new(200, "uosmo"),
+            swap_operations: vec![swap_operation()],
+        },
+        expected_response: to_binary(&simulate_swap_with_metadata_response(
+            "astroport_adapter",
+            Coin::new(100, "uatom"),
+            Coin::new(200, "uosmo"),
+        )).unwrap(),
+        expected_error: None,
+    };
+    "Simulate Swap Exact Coin Out With Metadata")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactCoinInWithMetadata {
+            swap_venue_name: "unknown".to_string(),
+            coin_in: Coin::new(100, "uatom"),
+            swap_operations: vec![swap_operation()],
+        },
+        expected_response: Binary::default(),
+        expected_error: Some(StdError::NotFound {
+            kind: "cosmwasm_std::addresses::Addr".to_string(),
+        }),
+    };
+    "Simulate Swap With Metadata Swap Venue Not Found - Expect Error")]
+fn test_queries(params: Params) {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock wasm handler to handle the swap adapter simulation with metadata queries
+    // Swaps at a price of 2 coin out per coin in, naming the pool after the adapter queried
+    let wasm_handler = |query: &WasmQuery| -> QuerierResult {
+        match query {
+            WasmQuery::Smart { contract_addr, msg } => {
+                let res = match from_binary(msg).unwrap() {
+                    SwapQueryMsg::SimulateSwapExactCoinInWithMetadata { coin_in, .. } => {
+                        simulate_swap_with_metadata_response(
+                            contract_addr,
+                            coin_in.clone(),
+                            Coin::new(coin_in.amount.u128() * 2, "uosmo"),
+                        )
+                    }
+                    SwapQueryMsg::SimulateSwapExactCoinOutWithMetadata { coin_out, .. } => {
+                        simulate_swap_with_metadata_response(
+                            contract_addr,
+                            Coin::new(coin_out.amount.u128() / 2, "uatom"),
+                            coin_out,
+                        )
+                    }
+                    query => panic!("Unsupported query: {:?}", query),
+                };
+                SystemResult::Ok(ContractResult::Ok(to_binary(&res).unwrap()))
+            }
+            _ => panic!("Unsupported query: {:?}", query),
+        }
+    };
+
+    // Update querier with mock wasm handler
+    deps.querier.update_wasm(wasm_handler);
+
+    // Create mock env with the entry point contract address
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+
+    // Instantiate the contract with three swap venues
+    skip_api_entry_point::contract::instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        InstantiateMsg {
+            owner: Some("owner".to_string()),
+            swap_venues: vec![
+                swap_venue("osmosis", "osmosis_adapter"),
+                swap_venue("astroport", "astroport_adapter"),
+                swap_venue("white-whale", "white_whale_adapter"),
+            ],
+            ibc_transfer_adapters: vec![IbcTransferAdapter {
+                name: "ibc_transfer".to_string(),
+                adapter_contract_address: "ibc_transfer_adapter".to_string(),
+            }],
+            default_ibc_transfer_adapter: "ibc_transfer".to_string(),
+        },
+    )
+    .unwrap();
+
+    // Add a guardian, set the protocol fee, register two affiliates, set the
+    // max total affiliate fee, enable delegate actions and start an ownership transfer
+    for msg in [
+        ExecuteMsg::AddGuardian {
+            address: "guardian".to_string(),
+        },
+        ExecuteMsg::SetProtocolFee {
+            protocol_fee: Some(set_protocol_fee()),
+        },
+        ExecuteMsg::RegisterAffiliate {
+            affiliate: registered_partner("partner-a", "partner_a"),
+        },
+        ExecuteMsg::RegisterAffiliate {
+            affiliate: registered_partner("partner-b", "partner_b"),
+        },
+        ExecuteMsg::SetMaxTotalAffiliateBasisPointsFee {
+            max_total_affiliate_basis_points_fee: Uint128::new(1000),
+        },
+        ExecuteMsg::SetDelegateEnabled { enabled: true },
+        ExecuteMsg::TransferOwnership {
+            new_owner: "new_owner".to_string(),
+        },
+    ] {
+        skip_api_entry_point::contract::execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            msg,
+        )
+        .unwrap();
+    }
+
+    // Store the affiliate fees accrued to an affiliate that opted in to accruing them
+    let partner_a = Addr::unchecked("partner_a");
+    AFFILIATE_FEE_ACCRUAL
+        .save(deps.as_mut().storage, &partner_a, &())
+        .unwrap();
+    for coin in [Coin::new(100, "uatom"), Coin::new(200, "untrn")] {
+        ACCRUED_AFFILIATE_FEES
+            .save(
+                deps.as_mut().storage,
+                (&partner_a, &coin.denom),
+                &coin.amount,
+            )
+            .unwrap();
+    }
+
+    // Call query with the given test parameters
+    let res = skip_api_entry_point::contract::query(deps.as_ref(), env, params.query);
+
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the response is correct
+            assert_eq!(res, params.expected_response);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
+
+// Creates a swap venue with the given name and adapter contract address
+fn swap_venue(name: &str, adapter_contract_address: &str) -> SwapVenue {
+    SwapVenue {
+        name: name.to_string(),
+        adapter_contract_address: adapter_contract_address.to_string(),
+    }
+}
+
+// Creates the protocol fee set by the owner
+fn set_protocol_fee() -> ProtocolFee {
+    ProtocolFee {
+        basis_points_fee: Uint128::new(25),
+        collector_address: "collector".to_string(),
+    }
+}
+
+// Creates an affiliate registered by the owner with the given id and address
+fn registered_partner(id: &str, address: &str) -> RegisteredAffiliate {
+    RegisteredAffiliate {
+        id: id.to_string(),
+        address: address.to_string(),
+        default_basis_points_fee: Uint128::new(50),
+        max_basis_points_fee: Uint128::new(100),
+    }
+}
+
+// Creates a swap operation from uatom to uosmo
+fn swap_operation() -> SwapOperation {
+    SwapOperation {
+        pool: "pool".to_string(),
+        denom_in: "uatom".to_string(),
+        denom_out: "uosmo".to_string(),
+    }
+}
+
+// Creates a simulate swap with metadata response with a spot price of 2
+// through a pool named after the swap adapter contract address
+fn simulate_swap_with_metadata_response(
+    swap_adapter_contract_address: &str,
+    coin_in: Coin,
+    coin_out: Coin,
+) -> SimulateSwapWithMetadataResponse {
+    SimulateSwapWithMetadataResponse::from_operations(vec![SwapOperationMetadata::new(
+        swap_adapter_contract_address.to_string(),
+        coin_in,
+        coin_out,
+        Decimal::from_ratio(2u128, 1u128),
+        vec![],
+    )])
+    .unwrap()
+}
@@ -1,13 +1,16 @@
 use cosmwasm_std::testing::{mock_dependencies_with_balances, mock_env};
 use cosmwasm_std::{
     to_binary, Addr, BankMsg, Coin, CosmosMsg, Reply, StdError, SubMsg, SubMsgResponse,
-    SubMsgResult,
+    SubMsgResult, WasmMsg,
 };
-use skip::entry_point::Action::BankSend;
+use skip::entry_point::{Action::BankSend, IbcRecovery};
+use skip::ibc::{ExecuteMsg as IbcTransferExecuteMsg, IbcFee, IbcInfo};
 use skip_api_entry_point::error::ContractError::Timeout;
 use skip_api_entry_point::error::ContractResult;
 use skip_api_entry_point::reply::RecoverTempStorage;
-use skip_api_entry_point::state::RECOVER_TEMP_STORAGE;
+use skip_api_entry_point::state::{
+    DEFAULT_IBC_TRANSFER_ADAPTER, IBC_TRANSFER_ADAPTER_MAP, RECOVER_TEMP_STORAGE,
+};
 
 pub struct Params {
     pub funds: Vec<Coin>,
@@ -28,6 +31,15 @@ pub fn test_reply(params: Params) -> ContractResult<()> {
     // Create mock env
     let env = mock_env();
 
+    // Store the ibc transfer adapter contract address as the default ibc transfer adapter
+    IBC_TRANSFER_ADAPTER_MAP.save(
+        deps.as_mut().storage,
+        "ibc_transfer_adapter",
+        &Addr::unchecked("ibc_transfer_adapter"),
+    )?;
+    DEFAULT_IBC_TRANSFER_ADAPTER
+        .save(deps.as_mut().storage, &"ibc_transfer_adapter".to_string())?;
+
     // Update storage
     if let Some(swap_action) = params.storage.clone() {
         RECOVER_TEMP_STORAGE.save(deps.as_mut().storage, &swap_action)?;
@@ -100,6 +112,7 @@ pub fn verify_funds_sent_on_slippage_error() {
         storage: Some(RecoverTempStorage {
             funds: vec![Coin::new(1_000_000, "osmo")],
             recovery_addr: Addr::unchecked("recovery_addr"),
+            ibc_recovery: None,
         }),
         expected_messages: vec![sub_msg],
     };
@@ -127,6 +140,7 @@ pub fn verify_funds_sent_on_timeout_error() {
         storage: Some(RecoverTempStorage {
             funds: vec![Coin::new(1_000_000, "osmo")],
             recovery_addr: Addr::unchecked("recovery_addr"),
+            ibc_recovery: None,
         }),
         expected_messages: vec![sub_msg],
     };
@@ -134,13 +148,120 @@ pub fn verify_funds_sent_on_timeout_error() {
     test_reply(params).unwrap();
 }
 
+#[test]
+pub fn verify_funds_sent_over_ibc_on_ibc_recovery() {
+    // The mock env block time plus the ibc recovery timeout
+    let timeout_timestamp = mock_env().block.time.plus_seconds(3600).nanos();
+
+    let sub_msgs = [Coin::new(1_000_000, "osmo"), Coin::new(500_000, "untrn")]
+        .into_iter()
+        .map(|coin| {
+            SubMsg::new(WasmMsg::Execute {
+                contract_addr: "ibc_transfer_adapter".to_string(),
+                msg: to_binary(&IbcTransferExecuteMsg::IbcTransfer {
+                    info: IbcInfo {
+                        source_channel: "channel-0".to_string(),
+                        receiver: "cosmos1original_sender".to_string(),
+                        memo: "".to_string(),
+                        fee: None,
+                        recover_address: "recovery_addr".to_string(),
+                    },
+                    coin: coin.clone(),
+                    timeout_timestamp,
+                    callback_address: None,
+                })
+                .unwrap(),
+                funds: vec![coin],
+            })
+        })
+        .collect();
+
+    let params = Params {
+        funds: vec![],
+        reply: Reply {
+            id: 1,
+            result: SubMsgResult::Err("Slippage tolerance exceeded".to_string()),
+        },
+        expected_error_string: "".to_string(),
+        storage: Some(RecoverTempStorage {
+            funds: vec![Coin::new(1_000_000, "osmo"), Coin::new(500_000, "untrn")],
+            recovery_addr: Addr::unchecked("recovery_addr"),
+            ibc_recovery: Some(IbcRecovery {
+                channel: "channel-0".to_string(),
+                original_sender: "cosmos1original_sender".to_string(),
+                fee: None,
+                timeout_seconds: None,
+            }),
+        }),
+        expected_messages: sub_msgs,
+    };
+
+    test_reply(params).unwrap();
+}
+
+#[test]
+pub fn verify_ibc_recovery_fee_and_timeout() {
+    // The mock env block time plus the ibc recovery's timeout seconds
+    let timeout_timestamp = mock_env().block.time.plus_seconds(600).nanos();
+
+    let ibc_fee = IbcFee {
+        recv_fee: vec![],
+        ack_fee: vec![Coin::new(1_000, "untrn")],
+        timeout_fee: vec![Coin::new(1_000, "untrn")],
+    };
+
+    let params = Params {
+        funds: vec![],
+        reply: Reply {
+            id: 1,
+            result: SubMsgResult::Err("Slippage tolerance exceeded".to_string()),
+        },
+        expected_error_string: "".to_string(),
+        storage: Some(RecoverTempStorage {
+            funds: vec![Coin::new(1_000_000, "untrn")],
+            recovery_addr: Addr::unchecked("recovery_addr"),
+            ibc_recovery: Some(IbcRecovery {
+                channel: "channel-0".to_string(),
+                original_sender: "cosmos1original_sender".to_string(),
+                fee: Some(ibc_fee.clone()),
+                timeout_seconds: Some(600),
+            }),
+        }),
+        expected_messages: vec![
+            SubMsg::new(BankMsg::Send {
+                to_address: "ibc_transfer_adapter".to_string(),
+                amount: vec![Coin::new(2_000, "untrn")],
+            }),
+            SubMsg::new(WasmMsg::Execute {
+                contract_addr: "ibc_transfer_adapter".to_string(),
+                msg: to_binary(&IbcTransferExecuteMsg::IbcTransfer {
+                    info: IbcInfo {
+                        source_channel: "channel-0".to_string(),
+                        receiver: "cosmos1original_sender".to_string(),
+                        memo: "".to_string(),
+                        fee: Some(ibc_fee),
+                        recover_address: "recovery_addr".to_string(),
+                    },
+                    coin: Coin::new(998_000, "untrn"),
+                    timeout_timestamp,
+                    callback_address: None,
+                })
+                .unwrap(),
+                funds: vec![Coin::new(998_000, "untrn")],
+            }),
+        ],
+    };
+
+    test_reply(params).unwrap();
+}
+
 #[test]
 pub fn invalid_reply_id_error() {
     let recovery_addr = Addr::unchecked("recovery_addr");
     let params = Params {
         funds: vec![],
         reply: Reply {
-            id: 2,
+            id: 3,
             result: SubMsgResult::Ok(SubMsgResponse {
                 events: vec![],
                 data: Some(
@@ -151,7 +272,7 @@ pub fn invalid_reply_id_error() {
                 ),
             }),
         },
-        expected_error_string: "Reply id: 2 not valid".to_string(),
+        expected_error_string: "Reply id: 3 not valid".to_string(),
         storage: None,
         expected_messages: vec![],
     };
@@ -176,9 +297,26 @@ pub fn success_case_no_funds_sent() {
         storage: Some(RecoverTempStorage {
             funds: vec![Coin::new(1_000_000, "osmo")],
             recovery_addr,
+            ibc_recovery: None,
         }),
         expected_messages: vec![],
     };
 
     test_reply(params).unwrap();
 }
+
+#[test]
+pub fn failed_skip_callback_ignored() {
+    let params = Params {
+        funds: vec![],
+        reply: Reply {
+            id: 2,
+            result: SubMsgResult::Err("Callback contract error".to_string()),
+        },
+        expected_error_string: "".to_string(),
+        storage: None,
+        expected_messages: vec![],
+    };
+
+    test_reply(params).unwrap();
+}
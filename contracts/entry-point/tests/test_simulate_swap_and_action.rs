@@ -0,0 +1,606 @@
+use cosmwasm_std::{
+    from_binary,
+    testing::{mock_dependencies, mock_env},
+    to_binary, Addr, BankMsg, Coin, ContractResult, QuerierResult, StdError, SystemResult,
+    Timestamp, Uint128, WasmMsg, WasmQuery,
+};
+use skip::{
+    entry_point::{
+        Action, Affiliate, AffiliateFee, AffiliateFeeBasis, PreSwapAction, ProtocolFee, QueryMsg,
+        SimulateSwapAndActionResponse,
+    },
+    ibc::{ExecuteMsg as IbcTransferExecuteMsg, IbcFee, IbcInfo},
+    swap::{
+        ExecuteMsg as SwapExecuteMsg, QueryMsg as SwapQueryMsg, Swap, SwapExactCoinIn,
+        SwapExactCoinOut, SwapOperation,
+    },
+};
+use skip_api_entry_point::{
+    error::ContractError,
+    state::{DEFAULT_IBC_TRANSFER_ADAPTER, IBC_TRANSFER_ADAPTER_MAP, PROTOCOL_FEE, SWAP_VENUE_MAP},
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response
+    - Swap Exact Coin In With Bank Send And Affiliate
+    - Swap Exact Coin In With Bank Send And Actual Out Affiliate (Fee is charged on the simulated coin out)
+    - Swap Exact Coin In With Bank Send, Affiliate And Protocol Fee
+    - Swap Exact Coin Out With Bank Send And Refund
+    - Fee Swap And Swap Exact Coin In With IBC Transfer
+    - Withdraw Liquidity Pre Swap Action With Bank Send And Affiliate (Swaps the simulated withdrawn assets)
+
+Expect Error
+    - Received Less From Swap Than Min Coin
+    - Current Block Time Greater Than Timeout Timestamp
+ */
+
+// Define test parameters
+struct Params {
+    coin_in: Coin,
+    pre_swap_action: Option<PreSwapAction>,
+    user_swap: Swap,
+    min_coin: Coin,
+    timeout_timestamp: u64,
+    post_swap_action: Action,
+    affiliates: Vec<Affiliate>,
+    affiliate_fee_basis: Option<AffiliateFeeBasis>,
+    protocol_fee: Option<ProtocolFee>,
+    expected_response: Option<SimulateSwapAndActionResponse>,
+    expected_error: Option<ContractError>,
+}
+
+// Test query_simulate_swap_and_action
+#[test_case(
+    Params {
+        coin_in: Coin::new(1_000_000, "untrn"),
+        pre_swap_action: None,
+        user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+            swap_venue_name: "swap_venue_name".to_string(),
+            operations: vec![swap_operation("untrn", "osmo")],
+        }),
+        min_coin: Coin::new(1_500_000, "osmo"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::BankSend {
+            to_address: "to_address".to_string(),
+        },
+        affiliates: vec![Affiliate {
+            address: "affiliate".to_string(),
+            basis_points_fee: Some(Uint128::new(1000)),
+            id: None,
+        }],
+        affiliate_fee_basis: None,
+        protocol_fee: None,
+        expected_response: Some(SimulateSwapAndActionResponse {
+            fee_swap_coin_in: None,
+            user_swap_coin_in: Coin::new(1_000_000, "untrn"),
+            user_swap_coin_out: Coin::new(2_000_000, "osmo"),
+            affiliate_fees: vec![AffiliateFee {
+                address: "affiliate".to_string(),
+                coin: Coin::new(150_000, "osmo"),
+            }],
+            protocol_fee: None,
+            refund: None,
+            coin_out: Coin::new(1_850_000, "osmo"),
+            messages: vec![
+                WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter".to_string(),
+                    msg: to_binary(&SwapExecuteMsg::Swap {
+                        operations: vec![swap_operation("untrn", "osmo")],
+                        minimum_receive: Some(Uint128::new(1_650_000)),
+                        max_spread: None,
+                    }).unwrap(),
+                    funds: vec![Coin::new(1_000_000, "untrn")],
+                }
+                .into(),
+                BankMsg::Send {
+                    to_address: "affiliate".to_string(),
+                    amount: vec![Coin::new(150_000, "osmo")],
+                }
+                .into(),
+                BankMsg::Send {
+                    to_address: "to_address".to_string(),
+                    amount: vec![Coin::new(1_850_000, "osmo")],
+                }
+                .into(),
+            ],
+        }),
+        expected_error: None,
+    };
+    "Swap Exact Coin In With Bank Send And Affiliate")]
+#[test_case(
+    Params {
+        coin_in: Coin::new(1_000_000, "untrn"),
+        pre_swap_action: None,
+        user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+            swap_venue_name: "swap_venue_name".to_string(),
+            operations: vec![swap_operation("untrn", "osmo")],
+        }),
+        min_coin: Coin::new(1_500_000, "osmo"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::BankSend {
+            to_address: "to_address".to_string(),
+        },
+        affiliates: vec![Affiliate {
+            address: "affiliate".to_string(),
+            basis_points_fee: Some(Uint128::new(1000)),
+            id: None,
+        }],
+        affiliate_fee_basis: Some(AffiliateFeeBasis::ActualOut),
+        protocol_fee: None,
+        expected_response: Some(SimulateSwapAndActionResponse {
+            fee_swap_coin_in: None,
+            user_swap_coin_in: Coin::new(1_000_000, "untrn"),
+            user_swap_coin_out: Coin::new(2_000_000, "osmo"),
+            affiliate_fees: vec![AffiliateFee {
+                address: "affiliate".to_string(),
+                coin: Coin::new(200_000, "osmo"),
+            }],
+            protocol_fee: None,
+            refund: None,
+            coin_out: Coin::new(1_800_000, "osmo"),
+            messages: vec![
+                WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter".to_string(),
+                    msg: to_binary(&SwapExecuteMsg::Swap {
+                        operations: vec![swap_operation("untrn", "osmo")],
+                        minimum_receive: Some(Uint128::new(1_500_000)),
+                        max_spread: None,
+                    }).unwrap(),
+                    funds: vec![Coin::new(1_000_000, "untrn")],
+                }
+                .into(),
+                BankMsg::Send {
+                    to_address: "to_address".to_string(),
+                    amount: vec![Coin::new(1_800_000, "osmo")],
+                }
+                .into(),
+                BankMsg::Send {
+                    to_address: "affiliate".to_string(),
+                    amount: vec![Coin::new(200_000, "osmo")],
+                }
+                .into(),
+            ],
+        }),
+        expected_error: None,
+    };
+    "Swap Exact Coin In With Bank Send And Actual Out Affiliate")]
+#[test_case(
+    Params {
+        coin_in: Coin::new(1_000_000, "untrn"),
+        pre_swap_action: None,
+        user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+            swap_venue_name: "swap_venue_name".to_string(),
+            operations: vec![swap_operation("untrn", "osmo")],
+        }),
+        min_coin: Coin::new(1_500_000, "osmo"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::BankSend {
+            to_address: "to_address".to_string(),
+        },
+        affiliates: vec![Affiliate {
+            address: "affiliate".to_string(),
+            basis_points_fee: Some(Uint128::new(1000)),
+            id: None,
+        }],
+        affiliate_fee_basis: None,
+        protocol_fee: Some(ProtocolFee {
+            basis_points_fee: Uint128::new(100),
+            collector_address: "collector".to_string(),
+        }),
+        expected_response: Some(SimulateSwapAndActionResponse {
+            fee_swap_coin_in: None,
+            user_swap_coin_in: Coin::new(1_000_000, "untrn"),
+            user_swap_coin_out: Coin::new(2_000_000, "osmo"),
+            affiliate_fees: vec![AffiliateFee {
+                address: "affiliate".to_string(),
+                coin: Coin::new(150_000, "osmo"),
+            }],
+            protocol_fee: Some(Coin::new(15_000, "osmo")),
+            refund: None,
+            coin_out: Coin::new(1_835_000, "osmo"),
+            messages: vec![
+                WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter".to_string(),
+                    msg: to_binary(&SwapExecuteMsg::Swap {
+                        operations: vec![swap_operation("untrn", "osmo")],
+                        minimum_receive: Some(Uint128::new(1_665_000)),
+                        max_spread: None,
+                    }).unwrap(),
+                    funds: vec![Coin::new(1_000_000, "untrn")],
+                }
+                .into(),
+                BankMsg::Send {
+                    to_address: "affiliate".to_string(),
+                    amount: vec![Coin::new(150_000, "osmo")],
+                }
+                .into(),
+                BankMsg::Send {
+                    to_address: "collector".to_string(),
+                    amount: vec![Coin::new(15_000, "osmo")],
+                }
+                .into(),
+                BankMsg::Send {
+                    to_address: "to_address".to_string(),
+                    amount: vec![Coin::new(1_835_000, "osmo")],
+                }
+                .into(),
+            ],
+        }),
+        expected_error: None,
+    };
+    "Swap Exact Coin In With Bank Send, Affiliate And Protocol Fee")]
+#[test_case(
+    Params {
+        coin_in: Coin::new(1_000_000, "untrn"),
+        pre_swap_action: None,
+        user_swap: Swap::SwapExactCoinOut(SwapExactCoinOut {
+            swap_venue_name: "swap_venue_name".to_string(),
+            operations: vec![swap_operation("untrn", "osmo")],
+            refund_address: Some("refund_address".to_string()),
+        }),
+        min_coin: Coin::new(1_000_000, "osmo"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::BankSend {
+            to_address: "to_address".to_string(),
+        },
+        affiliates: vec![],
+        affiliate_fee_basis: None,
+        protocol_fee: None,
+        expected_response: Some(SimulateSwapAndActionResponse {
+            fee_swap_coin_in: None,
+            user_swap_coin_in: Coin::new(500_000, "untrn"),
+            user_swap_coin_out: Coin::new(1_000_000, "osmo"),
+            affiliate_fees: vec![],
+            protocol_fee: None,
+            refund: Some(Coin::new(500_000, "untrn")),
+            coin_out: Coin::new(1_000_000, "osmo"),
+            messages: vec![
+                WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter".to_string(),
+                    msg: to_binary(&SwapExecuteMsg::SwapExactCoinOut {
+                        operations: vec![swap_operation("untrn", "osmo")],
+                        coin_out: Coin::new(1_000_000, "osmo"),
+                    }).unwrap(),
+                    funds: vec![Coin::new(1_000_000, "untrn")],
+                }
+                .into(),
+                BankMsg::Send {
+                    to_address: "to_address".to_string(),
+                    amount: vec![Coin::new(1_000_000, "osmo")],
+                }
+                .into(),
+            ],
+        }),
+        expected_error: None,
+    };
+    "Swap Exact Coin Out With Bank Send And Refund")]
+#[test_case(
+    Params {
+        coin_in: Coin::new(1_000_000, "osmo"),
+        pre_swap_action: None,
+        user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+            swap_venue_name: "swap_venue_name".to_string(),
+            operations: vec![swap_operation("osmo", "uatom")],
+        }),
+        min_coin: Coin::new(1_000_000, "uatom"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::IbcTransfer {
+            ibc_info: ibc_info(),
+            fee_swap: Some(SwapExactCoinOut {
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![swap_operation("osmo", "untrn")],
+                refund_address: None,
+            }),
+            ibc_transfer_adapter: None,
+        },
+        affiliates: vec![],
+        affiliate_fee_basis: None,
+        protocol_fee: None,
+        expected_response: Some(SimulateSwapAndActionResponse {
+            fee_swap_coin_in: Some(Coin::new(100_000, "osmo")),
+            user_swap_coin_in: Coin::new(900_000, "osmo"),
+            user_swap_coin_out: Coin::new(1_800_000, "uatom"),
+            affiliate_fees: vec![],
+            protocol_fee: None,
+            refund: None,
+            coin_out: Coin::new(1_800_000, "uatom"),
+            messages: vec![
+                WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter".to_string(),
+                    msg: to_binary(&SwapExecuteMsg::SwapExactCoinOut {
+                        operations: vec![swap_operation("osmo", "untrn")],
+                        coin_out: Coin::new(200_000, "untrn"),
+                    }).unwrap(),
+                    funds: vec![Coin::new(100_000, "osmo")],
+                }
+                .into(),
+                BankMsg::Send {
+                    to_address: "ibc_transfer_adapter".to_string(),
+                    amount: vec![Coin::new(200_000, "untrn")],
+                }
+                .into(),
+                WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter".to_string(),
+                    msg: to_binary(&SwapExecuteMsg::Swap {
+                        operations: vec![swap_operation("osmo", "uatom")],
+                        minimum_receive: Some(Uint128::new(1_000_000)),
+                        max_spread: None,
+                    }).unwrap(),
+                    funds: vec![Coin::new(900_000, "osmo")],
+                }
+                .into(),
+                WasmMsg::Execute {
+                    contract_addr: "ibc_transfer_adapter".to_string(),
+                    msg: to_binary(&IbcTransferExecuteMsg::IbcTransfer {
+                        info: ibc_info(),
+                        coin: Coin::new(1_800_000, "uatom"),
+                        timeout_timestamp: 101,
+                        callback_address: None,
+                    }).unwrap(),
+                    funds: vec![Coin::new(1_800_000, "uatom")],
+                }
+                .into(),
+            ],
+        }),
+        expected_error: None,
+    };
+    "Fee Swap And Swap Exact Coin In With IBC Transfer")]
+#[test_case(
+    Params {
+        coin_in: Coin::new(1_000_000, "gamm/pool/1"),
+        pre_swap_action: Some(PreSwapAction::WithdrawLiquidity {
+            swap_venue_name: "swap_venue_name".to_string(),
+            pool: "1".to_string(),
+            other_asset_swap: SwapExactCoinIn {
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![],
+            },
+        }),
+        user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+            swap_venue_name: "swap_venue_name".to_string(),
+            operations: vec![swap_operation("untrn", "osmo")],
+        }),
+        min_coin: Coin::new(1_500_000, "osmo"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::BankSend {
+            to_address: "to_address".to_string(),
+        },
+        affiliates: vec![Affiliate {
+            address: "affiliate".to_string(),
+            basis_points_fee: Some(Uint128::new(1000)),
+            id: None,
+        }],
+        affiliate_fee_basis: None,
+        protocol_fee: None,
+        expected_response: Some(SimulateSwapAndActionResponse {
+            fee_swap_coin_in: None,
+            user_swap_coin_in: Coin::new(1_000_000, "gamm/pool/1"),
+            user_swap_coin_out: Coin::new(2_000_000, "osmo"),
+            affiliate_fees: vec![AffiliateFee {
+                address: "affiliate".to_string(),
+                coin: Coin::new(150_000, "osmo"),
+            }],
+            protocol_fee: None,
+            refund: None,
+            coin_out: Coin::new(1_850_000, "osmo"),
+            messages: vec![
+                WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter".to_string(),
+                    msg: to_binary(&SwapExecuteMsg::WithdrawLiquidity {
+                        pool: "1".to_string(),
+                        denoms_out: vec!["untrn".to_string(), "osmo".to_string()],
+                    }).unwrap(),
+                    funds: vec![Coin::new(1_000_000, "gamm/pool/1")],
+                }
+                .into(),
+                WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter".to_string(),
+                    msg: to_binary(&SwapExecuteMsg::Swap {
+                        operations: vec![swap_operation("untrn", "osmo")],
+                        minimum_receive: None,
+                        max_spread: None,
+                    }).unwrap(),
+                    funds: vec![Coin::new(500_000, "untrn")],
+                }
+                .into(),
+                BankMsg::Send {
+                    to_address: "affiliate".to_string(),
+                    amount: vec![Coin::new(150_000, "osmo")],
+                }
+                .into(),
+                BankMsg::Send {
+                    to_address: "to_address".to_string(),
+                    amount: vec![Coin::new(1_850_000, "osmo")],
+                }
+                .into(),
+            ],
+        }),
+        expected_error: None,
+    };
+    "Withdraw Liquidity Pre Swap Action With Bank Send And Affiliate")]
+#[test_case(
+    Params {
+        coin_in: Coin::new(1_000_000, "untrn"),
+        pre_swap_action: None,
+        user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+            swap_venue_name: "swap_venue_name".to_string(),
+            operations: vec![swap_operation("untrn", "osmo")],
+        }),
+        min_coin: Coin::new(3_000_000, "osmo"),
+        timeout_timestamp: 101,
+        post_swap_action: Action::BankSend {
+            to_address: "to_address".to_string(),
+        },
+        affiliates: vec![],
+        affiliate_fee_basis: None,
+        protocol_fee: None,
+        expected_response: None,
+        expected_error: Some(ContractError::ReceivedLessCoinFromSwapsThanMinCoin),
+    };
+    "Received Less From Swap Than Min Coin - Expect Error")]
+#[test_case(
+    Params {
+        coin_in: Coin::new(1_000_000, "untrn"),
+        pre_swap_action: None,
+        user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+            swap_venue_name: "swap_venue_name".to_string(),
+            operations: vec![swap_operation("untrn", "osmo")],
+        }),
+        min_coin: Coin::new(1_000_000, "osmo"),
+        timeout_timestamp: 99,
+        post_swap_action: Action::BankSend {
+            to_address: "to_address".to_string(),
+        },
+        affiliates: vec![],
+        affiliate_fee_basis: None,
+        protocol_fee: None,
+        expected_response: None,
+        expected_error: Some(ContractError::Timeout),
+    };
+    "Current Block Time Greater Than Timeout Timestamp - Expect Error")]
+fn test_query_simulate_swap_and_action(params: Params) {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock wasm handler to handle the swap adapter simulation queries
+    // Swaps exact in at a price of 2 coin out per coin in, and exact out at
+    // a price of half a coin in per coin out
+    let wasm_handler = |query: &WasmQuery| -> QuerierResult {
+        match query {
+            WasmQuery::Smart { msg, .. } => {
+                let res = match from_binary(msg).unwrap() {
+                    SwapQueryMsg::SimulateSwapExactCoinIn {
+                        coin_in,
+                        swap_operations,
+                    } => to_binary(&Coin {
+                        denom: swap_operations.last().unwrap().denom_out.clone(),
+                        amount: coin_in.amount * Uint128::new(2),
+                    }),
+                    SwapQueryMsg::SimulateSwapExactCoinOut {
+                        coin_out,
+                        swap_operations,
+                    } => to_binary(&Coin {
+                        denom: swap_operations.first().unwrap().denom_in.clone(),
+                        amount: coin_out.amount / Uint128::new(2),
+                    }),
+                    // Withdraws half a untrn and one osmo per LP token
+                    SwapQueryMsg::SimulateWithdrawLiquidity { coin_in, .. } => to_binary(&vec![
+                        Coin::new(coin_in.amount.u128() / 2, "untrn"),
+                        Coin::new(coin_in.amount.u128(), "osmo"),
+                    ]),
+                    query => panic!("Unsupported query: {:?}", query),
+                };
+                SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+            }
+            _ => panic!("Unsupported query: {:?}", query),
+        }
+    };
+
+    // Update querier with mock wasm handler
+    deps.querier.update_wasm(wasm_handler);
+
+    // Create mock env with parameters that make testing easier
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+    env.block.time = Timestamp::from_nanos(100);
+
+    // Store the swap venue adapter contract address
+    SWAP_VENUE_MAP
+        .save(
+            deps.as_mut().storage,
+            "swap_venue_name",
+            &Addr::unchecked("swap_venue_adapter"),
+        )
+        .unwrap();
+
+    // Store the ibc transfer adapter contract address as the default ibc transfer adapter
+    IBC_TRANSFER_ADAPTER_MAP
+        .save(
+            deps.as_mut().storage,
+            "ibc_transfer_adapter",
+            &Addr::unchecked("ibc_transfer_adapter"),
+        )
+        .unwrap();
+    DEFAULT_IBC_TRANSFER_ADAPTER
+        .save(deps.as_mut().storage, &"ibc_transfer_adapter".to_string())
+        .unwrap();
+
+    // Store the protocol fee if one is given
+    if let Some(protocol_fee) = params.protocol_fee {
+        PROTOCOL_FEE
+            .save(deps.as_mut().storage, &protocol_fee)
+            .unwrap();
+    }
+
+    // Call query with the given test case params
+    let res = skip_api_entry_point::contract::query(
+        deps.as_ref(),
+        env,
+        QueryMsg::SimulateSwapAndAction {
+            coin_in: params.coin_in,
+            pre_swap_action: params.pre_swap_action,
+            user_swap: params.user_swap,
+            min_coin: params.min_coin,
+            timeout_timestamp: params.timeout_timestamp,
+            post_swap_action: params.post_swap_action,
+            affiliates: params.affiliates,
+            affiliate_fee_basis: params.affiliate_fee_basis,
+        },
+    );
+
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the response is correct
+            let res: SimulateSwapAndActionResponse = from_binary(&res).unwrap();
+            assert_eq!(res, params.expected_response.unwrap());
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(
+                err,
+                StdError::generic_err(params.expected_error.unwrap().to_string())
+            );
+        }
+    }
+}
+
+// Creates a swap operation from the denom in to the denom out
+fn swap_operation(denom_in: &str, denom_out: &str) -> SwapOperation {
+    SwapOperation {
+        pool: "pool".to_string(),
+        denom_in: denom_in.to_string(),
+        denom_out: denom_out.to_string(),
+    }
+}
+
+// Creates the ibc info with ibc fees of 200_000 untrn
+fn ibc_info() -> IbcInfo {
+    IbcInfo {
+        source_channel: "channel-0".to_string(),
+        receiver: "receiver".to_string(),
+        fee: Some(IbcFee {
+            recv_fee: vec![],
+            ack_fee: vec![Coin::new(100_000, "untrn")],
+            timeout_fee: vec![Coin::new(100_000, "untrn")],
+        }),
+        memo: "".to_string(),
+        recover_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string(),
+    }
+}
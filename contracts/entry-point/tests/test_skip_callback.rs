@@ -0,0 +1,411 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies_with_balances, mock_env, mock_info},
+    to_binary, Addr, BankMsg, Coin, ReplyOn, StdError, SubMsg, Timestamp, Uint128, WasmMsg,
+};
+use skip::{
+    callback::{
+        CallbackStatus, ExecuteMsg as CallbackExecuteMsg, SkipCallback, CALLBACK_GAS_LIMIT,
+    },
+    entry_point::{Action, ExecuteMsg, SequenceStep},
+    ibc::{ExecuteMsg as IbcTransferExecuteMsg, IbcInfo},
+    swap::{Swap, SwapExactCoinIn, SwapOperation},
+};
+use skip_api_entry_point::{
+    error::ContractError,
+    state::{BLOCKED_CONTRACT_ADDRESSES, DEFAULT_IBC_TRANSFER_ADAPTER, IBC_TRANSFER_ADAPTER_MAP},
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response
+    - Bank Send With Callback (Sends a success callback after the bank send)
+    - Ibc Transfer With Callback (Passes the callback address to the ibc transfer adapter and sends a pending callback)
+    - Sequence With Callback (Passes the callback address to the next steps, which send the callback from the last step)
+    - Bank Send Without Callback
+    - Two Step Sequence Callback Amount Out (The callback is sent after the last step with the coin received from the first step)
+
+Expect Error
+    - Blocked Callback Address (The callback address cannot be the entry point or an adapter contract)
+    - Invalid Callback Address
+
+ */
+
+// Define test parameters
+struct PostSwapActionParams {
+    post_swap_action: Action,
+    callback_address: Option<Addr>,
+    expected_messages: Vec<SubMsg>,
+}
+
+// Test execute_post_swap_action with a callback address
+#[test_case(
+    PostSwapActionParams {
+        post_swap_action: Action::BankSend {
+            to_address: "to_address".to_string(),
+        },
+        callback_address: Some(Addr::unchecked("callback_contract")),
+        expected_messages: vec![
+            SubMsg::new(BankMsg::Send {
+                to_address: "to_address".to_string(),
+                amount: vec![Coin::new(1_000_000, "osmo")],
+            }),
+            SubMsg {
+                id: 2,
+                msg: WasmMsg::Execute {
+                    contract_addr: "callback_contract".to_string(),
+                    msg: to_binary(&CallbackExecuteMsg::SkipCallback(SkipCallback {
+                        status: CallbackStatus::Success,
+                        amount_out: Coin::new(1_000_000, "osmo"),
+                        ack_id: None,
+                    }))
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: Some(CALLBACK_GAS_LIMIT),
+                reply_on: ReplyOn::Error,
+            },
+        ],
+    };
+    "Bank Send With Callback")]
+#[test_case(
+    PostSwapActionParams {
+        post_swap_action: Action::IbcTransfer {
+            ibc_info: IbcInfo {
+                source_channel: "channel-0".to_string(),
+                receiver: "receiver".to_string(),
+                memo: "".to_string(),
+                fee: None,
+                recover_address: "recover_address".to_string(),
+            },
+            fee_swap: None,
+            ibc_transfer_adapter: None,
+        },
+        callback_address: Some(Addr::unchecked("callback_contract")),
+        expected_messages: vec![
+            SubMsg::new(WasmMsg::Execute {
+                contract_addr: "ibc_transfer_adapter".to_string(),
+                msg: to_binary(&IbcTransferExecuteMsg::IbcTransfer {
+                    info: IbcInfo {
+                        source_channel: "channel-0".to_string(),
+                        receiver: "receiver".to_string(),
+                        memo: "".to_string(),
+                        fee: None,
+                        recover_address: "recover_address".to_string(),
+                    },
+                    coin: Coin::new(1_000_000, "osmo"),
+                    timeout_timestamp: 101,
+                    callback_address: Some("callback_contract".to_string()),
+                })
+                .unwrap(),
+                funds: vec![Coin::new(1_000_000, "osmo")],
+            }),
+            SubMsg {
+                id: 2,
+                msg: WasmMsg::Execute {
+                    contract_addr: "callback_contract".to_string(),
+                    msg: to_binary(&CallbackExecuteMsg::SkipCallback(SkipCallback {
+                        status: CallbackStatus::Pending,
+                        amount_out: Coin::new(1_000_000, "osmo"),
+                        ack_id: None,
+                    }))
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: Some(CALLBACK_GAS_LIMIT),
+                reply_on: ReplyOn::Error,
+            },
+        ],
+    };
+    "Ibc Transfer With Callback")]
+#[test_case(
+    PostSwapActionParams {
+        post_swap_action: Action::Sequence {
+            steps: vec![
+                SequenceStep {
+                    action: Action::ContractCall {
+                        contract_address: "contract_address".to_string(),
+                        msg: to_binary(&"contract_call_msg").unwrap(),
+                        msg_templates: None,
+                    },
+                    min_coin_out: Some(Coin::new(500_000, "untrn")),
+                },
+                SequenceStep {
+                    action: Action::BankSend {
+                        to_address: "to_address".to_string(),
+                    },
+                    min_coin_out: None,
+                },
+            ],
+        },
+        callback_address: Some(Addr::unchecked("callback_contract")),
+        expected_messages: vec![
+            SubMsg::new(WasmMsg::Execute {
+                contract_addr: "contract_address".to_string(),
+                msg: to_binary(&"contract_call_msg").unwrap(),
+                funds: vec![Coin::new(1_000_000, "osmo")],
+            }),
+            SubMsg::new(WasmMsg::Execute {
+                contract_addr: "entry_point".to_string(),
+                msg: to_binary(&ExecuteMsg::PostSwapActionStep {
+                    min_coin: Coin::new(500_000, "untrn"),
+                    pre_balance: Uint128::new(1_000_000),
+                    timeout_timestamp: 101,
+                    steps: vec![SequenceStep {
+                        action: Action::BankSend {
+                            to_address: "to_address".to_string(),
+                        },
+                        min_coin_out: None,
+                    }],
+                    callback_address: Some(Addr::unchecked("callback_contract")),
+                    send_callback: true,
+                })
+                .unwrap(),
+                funds: vec![],
+            }),
+        ],
+    };
+    "Sequence With Callback")]
+#[test_case(
+    PostSwapActionParams {
+        post_swap_action: Action::BankSend {
+            to_address: "to_address".to_string(),
+        },
+        callback_address: None,
+        expected_messages: vec![SubMsg::new(BankMsg::Send {
+            to_address: "to_address".to_string(),
+            amount: vec![Coin::new(1_000_000, "osmo")],
+        })],
+    };
+    "Bank Send Without Callback")]
+fn test_execute_post_swap_action_callback(params: PostSwapActionParams) {
+    // Create mock dependencies
+    let mut deps = mock_dependencies_with_balances(&[(
+        "entry_point",
+        &[Coin::new(1_000_000, "osmo"), Coin::new(1_000_000, "untrn")],
+    )]);
+
+    // Create mock env with parameters that make testing easier
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+    env.block.time = Timestamp::from_nanos(100);
+
+    // Create mock info with entry point contract address
+    let info = mock_info("entry_point", &[]);
+
+    // Store the ibc transfer adapter contract address as the default ibc transfer adapter
+    IBC_TRANSFER_ADAPTER_MAP
+        .save(
+            deps.as_mut().storage,
+            "ibc_transfer_adapter",
+            &Addr::unchecked("ibc_transfer_adapter"),
+        )
+        .unwrap();
+    DEFAULT_IBC_TRANSFER_ADAPTER
+        .save(deps.as_mut().storage, &"ibc_transfer_adapter".to_string())
+        .unwrap();
+
+    // Call execute_post_swap_action with the given test parameters
+    let res = skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::PostSwapAction {
+            min_coin: Coin::new(900_000, "osmo"),
+            timeout_timestamp: 101,
+            post_swap_action: params.post_swap_action,
+            exact_out: false,
+            affiliates: vec![],
+            callback_address: params.callback_address,
+        },
+    )
+    .unwrap();
+
+    // Assert the messages in the response are correct
+    assert_eq!(res.messages, params.expected_messages);
+}
+
+// Define test parameters
+struct SwapAndActionParams {
+    callback_address: String,
+    expected_error: ContractError,
+}
+
+// Test execute_swap_and_action errors with an invalid callback address before the swap
+#[test_case(
+    SwapAndActionParams {
+        callback_address: "ibc_transfer_adapter".to_string(),
+        expected_error: ContractError::CallbackAddressBlocked,
+    };
+    "Blocked Callback Address - Expect Error")]
+#[test_case(
+    SwapAndActionParams {
+        callback_address: "".to_string(),
+        expected_error: ContractError::Std(StdError::generic_err(
+            "Invalid input: human address too short for this mock implementation (must be >= 3).",
+        )),
+    };
+    "Invalid Callback Address - Expect Error")]
+fn test_execute_swap_and_action_callback_address(params: SwapAndActionParams) {
+    // Create mock dependencies
+    let mut deps = mock_dependencies_with_balances(&[]);
+
+    // Create mock env with parameters that make testing easier
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+    env.block.time = Timestamp::from_nanos(100);
+
+    // Create mock info with the coin to swap
+    let info = mock_info("swapper", &[Coin::new(1_000_000, "untrn")]);
+
+    // Store the ibc transfer adapter contract address in the blocked contract addresses map
+    BLOCKED_CONTRACT_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            &Addr::unchecked("ibc_transfer_adapter"),
+            &(),
+        )
+        .unwrap();
+
+    // Call execute_swap_and_action with the given callback address
+    let res = skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::SwapAndAction {
+            pre_swap_action: None,
+            user_swap: Swap::SwapExactCoinIn(SwapExactCoinIn {
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![SwapOperation {
+                    pool: "pool".to_string(),
+                    denom_in: "untrn".to_string(),
+                    denom_out: "osmo".to_string(),
+                }],
+            }),
+            min_coin: Coin::new(900_000, "osmo"),
+            timeout_timestamp: 101,
+            post_swap_action: Action::BankSend {
+                to_address: "to_address".to_string(),
+            },
+            affiliates: vec![],
+            affiliate_fee_basis: None,
+            callback_address: Some(params.callback_address),
+        },
+    );
+
+    // Assert the error is correct
+    assert_eq!(res.unwrap_err(), params.expected_error);
+}
+
+// Test a two step sequence sends the callback from the last step with the coin it is performed with
+#[test]
+fn test_sequence_callback_amount_out() {
+    // Create mock dependencies
+    let mut deps = mock_dependencies_with_balances(&[(
+        "entry_point",
+        &[Coin::new(1_000_000, "osmo"), Coin::new(1_000_000, "untrn")],
+    )]);
+
+    // Create mock env with parameters that make testing easier
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+    env.block.time = Timestamp::from_nanos(100);
+
+    // Create mock info with entry point contract address
+    let info = mock_info("entry_point", &[]);
+
+    // Call execute_post_swap_action with a two step sequence
+    let res = skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        ExecuteMsg::PostSwapAction {
+            min_coin: Coin::new(900_000, "osmo"),
+            timeout_timestamp: 101,
+            post_swap_action: Action::Sequence {
+                steps: vec![
+                    SequenceStep {
+                        action: Action::ContractCall {
+                            contract_address: "contract_address".to_string(),
+                            msg: to_binary(&"contract_call_msg").unwrap(),
+                            msg_templates: None,
+                        },
+                        min_coin_out: Some(Coin::new(500_000, "untrn")),
+                    },
+                    SequenceStep {
+                        action: Action::BankSend {
+                            to_address: "to_address".to_string(),
+                        },
+                        min_coin_out: None,
+                    },
+                ],
+            },
+            exact_out: false,
+            affiliates: vec![],
+            callback_address: Some(Addr::unchecked("callback_contract")),
+        },
+    )
+    .unwrap();
+
+    // Assert the callback is not sent with the first step
+    assert!(res
+        .messages
+        .iter()
+        .all(|sub_msg| sub_msg.reply_on != ReplyOn::Error));
+
+    // Simulate the first step returning 700_000 untrn to the contract
+    deps.querier
+        .update_balance("entry_point", vec![Coin::new(1_700_000, "untrn")]);
+
+    // Call execute_post_swap_action_step with the last step
+    let res = skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::PostSwapActionStep {
+            min_coin: Coin::new(500_000, "untrn"),
+            pre_balance: Uint128::new(1_000_000),
+            timeout_timestamp: 101,
+            steps: vec![SequenceStep {
+                action: Action::BankSend {
+                    to_address: "to_address".to_string(),
+                },
+                min_coin_out: None,
+            }],
+            callback_address: Some(Addr::unchecked("callback_contract")),
+            send_callback: true,
+        },
+    )
+    .unwrap();
+
+    // Assert the last step is performed with the coin received from the first step,
+    // and the callback is sent after it with that coin as the amount out
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg::new(BankMsg::Send {
+                to_address: "to_address".to_string(),
+                amount: vec![Coin::new(700_000, "untrn")],
+            }),
+            SubMsg {
+                id: 2,
+                msg: WasmMsg::Execute {
+                    contract_addr: "callback_contract".to_string(),
+                    msg: to_binary(&CallbackExecuteMsg::SkipCallback(SkipCallback {
+                        status: CallbackStatus::Success,
+                        amount_out: Coin::new(700_000, "untrn"),
+                        ack_id: None,
+                    }))
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: Some(CALLBACK_GAS_LIMIT),
+                reply_on: ReplyOn::Error,
+            },
+        ]
+    );
+}
@@ -0,0 +1,255 @@
+use cosmwasm_std::{
+    from_binary,
+    testing::{mock_dependencies_with_balances, mock_env, mock_info},
+    to_binary, Addr, Attribute, Coin, ContractResult, QuerierResult,
+    ReplyOn::Never,
+    StdError, SubMsg, SystemResult, Timestamp, Uint128, WasmMsg, WasmQuery,
+};
+use skip::{
+    entry_point::ExecuteMsg,
+    error::SkipError::{BestSwapRoutesEmpty, SwapOperationsCoinOutDenomMismatch},
+    swap::{
+        BestSwapExactCoinIn, ExecuteMsg as SwapExecuteMsg, QueryMsg as SwapQueryMsg, Swap,
+        SwapExactCoinIn, SwapOperation,
+    },
+};
+use skip_api_entry_point::{error::ContractError, state::SWAP_VENUE_MAP};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response
+    - Best Swap Exact Coin In Picks Route With Largest Simulated Coin Out
+    - Best Swap Exact Coin In Picks First Route On Tie
+    - Best Swap Exact Coin In Single Route
+
+Expect Error
+    - Best Swap Exact Coin In Empty Routes
+    - Best Swap Exact Coin In Route Last Swap Operation Denom Out Is Not The Same As Min Coin Out Denom
+    - Best Swap Exact Coin In Route With Unknown Swap Venue
+ */
+
+// Define test parameters
+struct Params {
+    routes: Vec<SwapExactCoinIn>,
+    expected_messages: Vec<SubMsg>,
+    expected_attributes: Vec<Attribute>,
+    expected_error: Option<ContractError>,
+}
+
+// Test execute_user_swap with a best swap exact coin in
+#[test_case(
+    Params {
+        routes: vec![
+            route("swap_venue_name", "pool", "osmo"),
+            route("swap_venue_name_2", "pool_2", "osmo"),
+            route("swap_venue_name_3", "pool_3", "osmo"),
+        ],
+        expected_messages: vec![swap_msg("swap_venue_adapter_2", "pool_2")],
+        expected_attributes: vec![
+            Attribute::new("action", "execute_user_swap"),
+            Attribute::new("route_index", "0"),
+            Attribute::new("simulated_amount_out", "400000"),
+            Attribute::new("route_index", "1"),
+            Attribute::new("simulated_amount_out", "600000"),
+            Attribute::new("route_index", "2"),
+            Attribute::new("simulated_amount_out", "600000"),
+            Attribute::new("action", "dispatch_user_best_swap_exact_coin_in"),
+            Attribute::new("best_route_index", "1"),
+        ],
+        expected_error: None,
+    };
+    "Best Swap Exact Coin In Picks Route With Largest Simulated Coin Out")]
+#[test_case(
+    Params {
+        routes: vec![
+            route("swap_venue_name_3", "pool_3", "osmo"),
+            route("swap_venue_name_2", "pool_2", "osmo"),
+        ],
+        expected_messages: vec![swap_msg("swap_venue_adapter_3", "pool_3")],
+        expected_attributes: vec![
+            Attribute::new("action", "execute_user_swap"),
+            Attribute::new("route_index", "0"),
+            Attribute::new("simulated_amount_out", "600000"),
+            Attribute::new("route_index", "1"),
+            Attribute::new("simulated_amount_out", "600000"),
+            Attribute::new("action", "dispatch_user_best_swap_exact_coin_in"),
+            Attribute::new("best_route_index", "0"),
+        ],
+        expected_error: None,
+    };
+    "Best Swap Exact Coin In Picks First Route On Tie")]
+#[test_case(
+    Params {
+        routes: vec![route("swap_venue_name", "pool", "osmo")],
+        expected_messages: vec![swap_msg("swap_venue_adapter", "pool")],
+        expected_attributes: vec![
+            Attribute::new("action", "execute_user_swap"),
+            Attribute::new("route_index", "0"),
+            Attribute::new("simulated_amount_out", "400000"),
+            Attribute::new("action", "dispatch_user_best_swap_exact_coin_in"),
+            Attribute::new("best_route_index", "0"),
+        ],
+        expected_error: None,
+    };
+    "Best Swap Exact Coin In Single Route")]
+#[test_case(
+    Params {
+        routes: vec![],
+        expected_messages: vec![],
+        expected_attributes: vec![],
+        expected_error: Some(ContractError::Skip(BestSwapRoutesEmpty)),
+    };
+    "Best Swap Exact Coin In Empty Routes - Expect Error")]
+#[test_case(
+    Params {
+        routes: vec![
+            route("swap_venue_name", "pool", "osmo"),
+            route("swap_venue_name_2", "pool_2", "uatom"),
+        ],
+        expected_messages: vec![],
+        expected_attributes: vec![],
+        expected_error: Some(ContractError::Skip(SwapOperationsCoinOutDenomMismatch)),
+    };
+    "Best Swap Exact Coin In Route Last Swap Operation Denom Out Is Not The Same As Min Coin Out Denom - Expect Error")]
+#[test_case(
+    Params {
+        routes: vec![
+            route("swap_venue_name", "pool", "osmo"),
+            route("unknown_swap_venue_name", "pool_2", "osmo"),
+        ],
+        expected_messages: vec![],
+        expected_attributes: vec![],
+        expected_error: Some(ContractError::Std(StdError::NotFound {
+            kind: "cosmwasm_std::addresses::Addr".to_string(),
+        })),
+    };
+    "Best Swap Exact Coin In Route With Unknown Swap Venue - Expect Error")]
+fn test_execute_user_best_swap(params: Params) {
+    // Create mock dependencies
+    let mut deps =
+        mock_dependencies_with_balances(&[("entry_point", &[Coin::new(1_000_000, "untrn")])]);
+
+    // Create mock wasm handler to handle the swap adapter contract simulation queries
+    // Returns 400_000 osmo for the first swap venue and 600_000 osmo for the others
+    let wasm_handler = |query: &WasmQuery| -> QuerierResult {
+        match query {
+            WasmQuery::Smart { contract_addr, msg } => match from_binary(msg).unwrap() {
+                SwapQueryMsg::SimulateSwapExactCoinIn { .. } => {
+                    let amount_out = if contract_addr == "swap_venue_adapter" {
+                        400_000
+                    } else {
+                        600_000
+                    };
+                    SystemResult::Ok(ContractResult::Ok(
+                        to_binary(&Coin::new(amount_out, "osmo")).unwrap(),
+                    ))
+                }
+                _ => panic!("Unsupported query: {:?}", query),
+            },
+            _ => panic!("Unsupported query: {:?}", query),
+        }
+    };
+
+    // Update querier with mock wasm handler
+    deps.querier.update_wasm(wasm_handler);
+
+    // Create mock env with parameters that make testing easier
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+    env.block.time = Timestamp::from_nanos(100);
+
+    // Create mock info with entry point contract address
+    let info = mock_info("entry_point", &[]);
+
+    // Store the swap venue adapter contract addresses
+    for (name, adapter) in [
+        ("swap_venue_name", "swap_venue_adapter"),
+        ("swap_venue_name_2", "swap_venue_adapter_2"),
+        ("swap_venue_name_3", "swap_venue_adapter_3"),
+    ] {
+        SWAP_VENUE_MAP
+            .save(deps.as_mut().storage, name, &Addr::unchecked(adapter))
+            .unwrap();
+    }
+
+    // Call execute_user_swap with the given test case params
+    let res = skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::UserSwap {
+            swap: Swap::BestSwapExactCoinIn(BestSwapExactCoinIn {
+                routes: params.routes,
+            }),
+            remaining_coin: Coin::new(1_000_000, "untrn"),
+            min_coin: Coin::new(300_000, "osmo"),
+            affiliates: vec![],
+        },
+    );
+
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the messages in the response are correct
+            assert_eq!(res.messages, params.expected_messages);
+
+            // Assert the attributes in the response are correct
+            assert_eq!(res.attributes, params.expected_attributes);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
+
+// Creates a single operation untrn swap route on the given swap venue
+fn route(swap_venue_name: &str, pool: &str, denom_out: &str) -> SwapExactCoinIn {
+    SwapExactCoinIn {
+        swap_venue_name: swap_venue_name.to_string(),
+        operations: vec![SwapOperation {
+            pool: pool.to_string(),
+            denom_in: "untrn".to_string(),
+            denom_out: denom_out.to_string(),
+        }],
+    }
+}
+
+// Creates the expected swap adapter message for a single operation untrn to osmo swap
+fn swap_msg(swap_venue_adapter: &str, pool: &str) -> SubMsg {
+    SubMsg {
+        id: 0,
+        msg: WasmMsg::Execute {
+            contract_addr: swap_venue_adapter.to_string(),
+            msg: to_binary(&SwapExecuteMsg::Swap {
+                operations: vec![SwapOperation {
+                    pool: pool.to_string(),
+                    denom_in: "untrn".to_string(),
+                    denom_out: "osmo".to_string(),
+                }],
+                minimum_receive: Some(Uint128::new(300_000)),
+                max_spread: None,
+            })
+            .unwrap(),
+            funds: vec![Coin::new(1_000_000, "untrn")],
+        }
+        .into(),
+        gas_limit: None,
+        reply_on: Never,
+    }
+}
@@ -1,18 +1,26 @@
 use cosmwasm_std::{
     testing::{mock_dependencies_with_balances, mock_env, mock_info},
-    to_binary, Addr, BankMsg, Coin, ContractResult, OverflowError, OverflowOperation,
+    to_binary, Addr, BankMsg, Coin, ContractResult, Decimal, OverflowError, OverflowOperation,
     QuerierResult,
     ReplyOn::Never,
     SubMsg, SystemResult, Timestamp, Uint128, WasmMsg, WasmQuery,
 };
+use cw20::Cw20ExecuteMsg;
 use skip::{
-    entry_point::{Affiliate, ExecuteMsg},
+    entry_point::{Affiliate, ExecuteMsg, ProtocolFee, RegisteredAffiliate},
     error::SkipError::{
-        SwapOperationsCoinInDenomMismatch, SwapOperationsCoinOutDenomMismatch, SwapOperationsEmpty,
+        SplitSwapLegsEmpty, SplitSwapSharesNotOne, SwapOperationsCoinInDenomMismatch,
+        SwapOperationsCoinOutDenomMismatch, SwapOperationsEmpty,
     },
-    swap::{ExecuteMsg as SwapExecuteMsg, Swap, SwapExactCoinIn, SwapExactCoinOut, SwapOperation},
+    swap::{
+        ExecuteMsg as SwapExecuteMsg, SplitSwapExactCoinIn, SplitSwapLeg, Swap, SwapExactCoinIn,
+        SwapExactCoinOut, SwapOperation,
+    },
+};
+use skip_api_entry_point::{
+    error::ContractError,
+    state::{PROTOCOL_FEE, REGISTERED_AFFILIATES, SWAP_VENUE_MAP},
 };
-use skip_api_entry_point::{error::ContractError, state::SWAP_VENUE_MAP};
 use test_case::test_case;
 
 /*
@@ -30,6 +38,20 @@ Expect Response
     - User Swap Exact Coin Out With Multiple Affiliates
     - User Swap Exact Coin Out With Refund Amount Zero (Ensure No Refund Message Included)
 
+    // Protocol Fee
+    - User Swap Exact Coin In With Single Affiliate And Protocol Fee (Adds the protocol fee to the minimum receive and sends it after the affiliate fee)
+    - User Swap Exact Coin Out With Protocol Fee (Grosses up the coin out by the protocol fee)
+
+    // Registered Affiliates
+    - User Swap Exact Coin In With Registered Affiliate Default Fee (Sends the default fee to the registered address)
+
+    // Split Swap Exact Coin In
+    - User Split Swap Exact Coin In Gives Rounding Remainder To Last Leg
+    - User Split Swap Exact Coin In Skips Legs Rounding Down To Zero
+
+    // CW20
+    - User Swap Exact Coin In With CW20 Coin In And Out And Single Affiliate (Sends CW20 coin in to the adapter and transfers CW20 affiliate fee)
+
 Expect Error
     // Swap Exact Coin In
     - User Swap Exact Coin In First Swap Operation Denom In Is Not The Same As Remaining Coin Received Denom
@@ -44,6 +66,18 @@ Expect Error
     - User Swap Exact Coin Out Where Coin In Denom Is Not The Same As Remaining Coin Received Denom
     - User Swap Exact Coin Out Where Coin In Amount More Than Remaining Coin Received Amount
 
+    // Split Swap Exact Coin In
+    - User Split Swap Exact Coin In Leg Last Swap Operation Denom Out Is Not The Same As Min Coin Out Denom
+    - User Split Swap Exact Coin In Leg Shares Do Not Sum To One
+    - User Split Swap Exact Coin In Empty Legs
+
+    // Affiliates
+    - Registered Affiliate Fee Above Its Max Fee
+    - Registered Affiliate Not Found
+    - Registered Affiliate Address Not The Registered Address
+    - Unregistered Affiliate Without Fee
+    - Total Affiliate Fee Above The Maximum
+
     // General
     - Unauthorized Caller
 
@@ -56,6 +90,7 @@ struct Params {
     remaining_coin: Coin,
     min_coin: Coin,
     affiliates: Vec<Affiliate>,
+    protocol_fee: Option<ProtocolFee>,
     expected_messages: Vec<SubMsg>,
     expected_error: Option<ContractError>,
 }
@@ -79,6 +114,7 @@ struct Params {
         remaining_coin: Coin::new(1_000_000, "untrn"),
         min_coin: Coin::new(1_000_000, "osmo"),
         affiliates: vec![],
+        protocol_fee: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -92,6 +128,8 @@ struct Params {
                                 denom_out: "osmo".to_string(),
                             }
                         ],
+                        minimum_receive: Some(Uint128::new(1_000_000)),
+                        max_spread: None,
                     }).unwrap(),
                     funds: vec![Coin::new(1_000_000, "untrn")], 
                 }
@@ -122,8 +160,10 @@ struct Params {
         min_coin: Coin::new(1_000_000, "osmo"),
         affiliates: vec![Affiliate {
             address: "affiliate".to_string(),
-            basis_points_fee: Uint128::new(1000),
+            basis_points_fee: Some(Uint128::new(1000)),
+            id: None,
         }],
+        protocol_fee: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -137,6 +177,8 @@ struct Params {
                                 denom_out: "osmo".to_string(),
                             }
                         ],
+                        minimum_receive: Some(Uint128::new(1_100_000)),
+                        max_spread: None,
                     }).unwrap(),
                     funds: vec![Coin::new(1_000_000, "untrn")], 
                 }
@@ -178,13 +220,16 @@ struct Params {
         affiliates: vec![
             Affiliate {
                 address: "affiliate_1".to_string(),
-                basis_points_fee: Uint128::new(1000),
+                basis_points_fee: Some(Uint128::new(1000)),
+                id: None,
             },
             Affiliate {
                 address: "affiliate_2".to_string(),
-                basis_points_fee: Uint128::new(1000),
+                basis_points_fee: Some(Uint128::new(1000)),
+                id: None,
             },
         ],
+        protocol_fee: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -198,6 +243,8 @@ struct Params {
                                 denom_out: "osmo".to_string(),
                             }
                         ],
+                        minimum_receive: Some(Uint128::new(1_200_000)),
+                        max_spread: None,
                     }).unwrap(),
                     funds: vec![Coin::new(1_000_000, "untrn")], 
                 }
@@ -248,8 +295,10 @@ struct Params {
         min_coin: Coin::new(1_000_000, "osmo"),
         affiliates: vec![Affiliate {
             address: "affiliate".to_string(),
-            basis_points_fee: Uint128::new(0),
+            basis_points_fee: Some(Uint128::new(0)),
+            id: None,
         }],
+        protocol_fee: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -263,6 +312,8 @@ struct Params {
                                 denom_out: "osmo".to_string(),
                             }
                         ],
+                        minimum_receive: Some(Uint128::new(1_000_000)),
+                        max_spread: None,
                     }).unwrap(),
                     funds: vec![Coin::new(1_000_000, "untrn")], 
                 }
@@ -293,22 +344,13 @@ struct Params {
         remaining_coin: Coin::new(1_000_000, "untrn"),
         min_coin: Coin::new(500_000, "osmo"),
         affiliates: vec![],
+        protocol_fee: None,
         expected_messages: vec![
-            SubMsg {
-                id: 0,
-                msg: BankMsg::Send {
-                    to_address: "refund_address".to_string(),
-                    amount: vec![Coin::new(500_000, "untrn")],
-                }
-                .into(),
-                gas_limit: None,
-                reply_on: Never,
-            },
             SubMsg {
                 id: 0,
                 msg: WasmMsg::Execute {
                     contract_addr: "swap_venue_adapter".to_string(), 
-                    msg: to_binary(&SwapExecuteMsg::Swap {
+                    msg: to_binary(&SwapExecuteMsg::SwapExactCoinOut {
                         operations: vec![
                             SwapOperation {
                                 pool: "pool".to_string(),
@@ -316,8 +358,23 @@ struct Params {
                                 denom_out: "osmo".to_string(),
                             }
                         ],
+                        coin_out: Coin::new(500_000, "osmo"),
                     }).unwrap(),
-                    funds: vec![Coin::new(500_000, "untrn")], 
+                    funds: vec![Coin::new(1_000_000, "untrn")], 
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "entry_point".to_string(),
+                    msg: to_binary(&ExecuteMsg::UserSwapRefund {
+                        pre_balance: Coin::new(0, "untrn"),
+                        refund_address: Some("refund_address".to_string()),
+                    }).unwrap(),
+                    funds: vec![],
                 }
                 .into(),
                 gas_limit: None,
@@ -348,15 +405,37 @@ struct Params {
         affiliates: vec![
             Affiliate {
                 address: "affiliate".to_string(),
-                basis_points_fee: Uint128::new(1000),
+                basis_points_fee: Some(Uint128::new(1000)),
+                id: None,
             },
         ],
+        protocol_fee: None,
         expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter".to_string(), 
+                    msg: to_binary(&SwapExecuteMsg::SwapExactCoinOut {
+                        operations: vec![
+                            SwapOperation {
+                                pool: "pool".to_string(),
+                                denom_in: "untrn".to_string(),
+                                denom_out: "osmo".to_string(),
+                            }
+                        ],
+                        coin_out: Coin::new(550_000, "osmo"),
+                    }).unwrap(),
+                    funds: vec![Coin::new(1_000_000, "untrn")], 
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
             SubMsg {
                 id: 0,
                 msg: BankMsg::Send {
-                    to_address: "refund_address".to_string(),
-                    amount: vec![Coin::new(500_000, "untrn")],
+                    to_address: "affiliate".to_string(),
+                    amount: vec![Coin::new(50_000, "osmo")],
                 }
                 .into(),
                 gas_limit: None,
@@ -365,7 +444,52 @@ struct Params {
             SubMsg {
                 id: 0,
                 msg: WasmMsg::Execute {
-                    contract_addr: "swap_venue_adapter".to_string(), 
+                    contract_addr: "entry_point".to_string(),
+                    msg: to_binary(&ExecuteMsg::UserSwapRefund {
+                        pre_balance: Coin::new(0, "untrn"),
+                        refund_address: Some("refund_address".to_string()),
+                    }).unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "User Swap Exact Coin Out With Single Affiliate")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        user_swap: Swap::SwapExactCoinIn (
+            SwapExactCoinIn{
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![
+                    SwapOperation {
+                        pool: "pool".to_string(),
+                        denom_in: "untrn".to_string(),
+                        denom_out: "osmo".to_string(),
+                    }
+                ],
+            }
+        ),
+        remaining_coin: Coin::new(1_000_000, "untrn"),
+        min_coin: Coin::new(1_000_000, "osmo"),
+        affiliates: vec![Affiliate {
+            address: "affiliate".to_string(),
+            basis_points_fee: Some(Uint128::new(1000)),
+            id: None,
+        }],
+        protocol_fee: Some(ProtocolFee {
+            basis_points_fee: Uint128::new(50),
+            collector_address: "collector".to_string(),
+        }),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter".to_string(),
                     msg: to_binary(&SwapExecuteMsg::Swap {
                         operations: vec![
                             SwapOperation {
@@ -374,8 +498,10 @@ struct Params {
                                 denom_out: "osmo".to_string(),
                             }
                         ],
+                        minimum_receive: Some(Uint128::new(1_105_000)),
+                        max_spread: None,
                     }).unwrap(),
-                    funds: vec![Coin::new(500_000, "untrn")], 
+                    funds: vec![Coin::new(1_000_000, "untrn")],
                 }
                 .into(),
                 gas_limit: None,
@@ -385,7 +511,17 @@ struct Params {
                 id: 0,
                 msg: BankMsg::Send {
                     to_address: "affiliate".to_string(),
-                    amount: vec![Coin::new(50_000, "osmo")],
+                    amount: vec![Coin::new(100_000, "osmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "collector".to_string(),
+                    amount: vec![Coin::new(5_000, "osmo")],
                 }
                 .into(),
                 gas_limit: None,
@@ -394,7 +530,7 @@ struct Params {
         ],
         expected_error: None,
     };
-    "User Swap Exact Coin Out With Single Affiliate")]
+    "User Swap Exact Coin In With Single Affiliate And Protocol Fee")]
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
@@ -413,32 +549,97 @@ struct Params {
         ),
         remaining_coin: Coin::new(1_000_000, "untrn"),
         min_coin: Coin::new(500_000, "osmo"),
-        affiliates: vec![
-            Affiliate {
-                address: "affiliate_1".to_string(),
-                basis_points_fee: Uint128::new(1000),
-            },
-            Affiliate {
-                address: "affiliate_2".to_string(),
-                basis_points_fee: Uint128::new(1000),
-            },
-        ],
+        affiliates: vec![],
+        protocol_fee: Some(ProtocolFee {
+            basis_points_fee: Uint128::new(100),
+            collector_address: "collector".to_string(),
+        }),
         expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter".to_string(),
+                    msg: to_binary(&SwapExecuteMsg::SwapExactCoinOut {
+                        operations: vec![
+                            SwapOperation {
+                                pool: "pool".to_string(),
+                                denom_in: "untrn".to_string(),
+                                denom_out: "osmo".to_string(),
+                            }
+                        ],
+                        coin_out: Coin::new(505_000, "osmo"),
+                    }).unwrap(),
+                    funds: vec![Coin::new(1_000_000, "untrn")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
             SubMsg {
                 id: 0,
                 msg: BankMsg::Send {
-                    to_address: "refund_address".to_string(),
-                    amount: vec![Coin::new(500_000, "untrn")],
+                    to_address: "collector".to_string(),
+                    amount: vec![Coin::new(5_000, "osmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "entry_point".to_string(),
+                    msg: to_binary(&ExecuteMsg::UserSwapRefund {
+                        pre_balance: Coin::new(0, "untrn"),
+                        refund_address: Some("refund_address".to_string()),
+                    }).unwrap(),
+                    funds: vec![],
                 }
                 .into(),
                 gas_limit: None,
                 reply_on: Never,
             },
+        ],
+        expected_error: None,
+    };
+    "User Swap Exact Coin Out With Protocol Fee")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        user_swap: Swap::SwapExactCoinOut (
+            SwapExactCoinOut{
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![
+                    SwapOperation {
+                        pool: "pool".to_string(),
+                        denom_in: "untrn".to_string(),
+                        denom_out: "osmo".to_string(),
+                    }
+                ],
+                refund_address: Some("refund_address".to_string()),
+            }
+        ),
+        remaining_coin: Coin::new(1_000_000, "untrn"),
+        min_coin: Coin::new(500_000, "osmo"),
+        affiliates: vec![
+            Affiliate {
+                address: "affiliate_1".to_string(),
+                basis_points_fee: Some(Uint128::new(1000)),
+                id: None,
+            },
+            Affiliate {
+                address: "affiliate_2".to_string(),
+                basis_points_fee: Some(Uint128::new(1000)),
+                id: None,
+            },
+        ],
+        protocol_fee: None,
+        expected_messages: vec![
             SubMsg {
                 id: 0,
                 msg: WasmMsg::Execute {
                     contract_addr: "swap_venue_adapter".to_string(), 
-                    msg: to_binary(&SwapExecuteMsg::Swap {
+                    msg: to_binary(&SwapExecuteMsg::SwapExactCoinOut {
                         operations: vec![
                             SwapOperation {
                                 pool: "pool".to_string(),
@@ -446,8 +647,9 @@ struct Params {
                                 denom_out: "osmo".to_string(),
                             }
                         ],
+                        coin_out: Coin::new(600_000, "osmo"),
                     }).unwrap(),
-                    funds: vec![Coin::new(500_000, "untrn")], 
+                    funds: vec![Coin::new(1_000_000, "untrn")], 
                 }
                 .into(),
                 gas_limit: None,
@@ -473,6 +675,20 @@ struct Params {
                 gas_limit: None,
                 reply_on: Never,
             },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "entry_point".to_string(),
+                    msg: to_binary(&ExecuteMsg::UserSwapRefund {
+                        pre_balance: Coin::new(0, "untrn"),
+                        refund_address: Some("refund_address".to_string()),
+                    }).unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
         ],
         expected_error: None,
     };
@@ -498,25 +714,17 @@ struct Params {
         affiliates: vec![
             Affiliate {
                 address: "affiliate".to_string(),
-                basis_points_fee: Uint128::new(0),
+                basis_points_fee: Some(Uint128::new(0)),
+                id: None,
             },
         ],
+        protocol_fee: None,
         expected_messages: vec![
-            SubMsg {
-                id: 0,
-                msg: BankMsg::Send {
-                    to_address: "refund_address".to_string(),
-                    amount: vec![Coin::new(500_000, "untrn")],
-                }
-                .into(),
-                gas_limit: None,
-                reply_on: Never,
-            },
             SubMsg {
                 id: 0,
                 msg: WasmMsg::Execute {
                     contract_addr: "swap_venue_adapter".to_string(), 
-                    msg: to_binary(&SwapExecuteMsg::Swap {
+                    msg: to_binary(&SwapExecuteMsg::SwapExactCoinOut {
                         operations: vec![
                             SwapOperation {
                                 pool: "pool".to_string(),
@@ -524,8 +732,23 @@ struct Params {
                                 denom_out: "osmo".to_string(),
                             }
                         ],
+                        coin_out: Coin::new(500_000, "osmo"),
                     }).unwrap(),
-                    funds: vec![Coin::new(500_000, "untrn")], 
+                    funds: vec![Coin::new(1_000_000, "untrn")], 
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "entry_point".to_string(),
+                    msg: to_binary(&ExecuteMsg::UserSwapRefund {
+                        pre_balance: Coin::new(0, "untrn"),
+                        refund_address: Some("refund_address".to_string()),
+                    }).unwrap(),
+                    funds: vec![],
                 }
                 .into(),
                 gas_limit: None,
@@ -554,12 +777,13 @@ struct Params {
         remaining_coin: Coin::new(500_000, "untrn"),
         min_coin: Coin::new(500_000, "osmo"),
         affiliates: vec![],
+        protocol_fee: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
                 msg: WasmMsg::Execute {
                     contract_addr: "swap_venue_adapter".to_string(), 
-                    msg: to_binary(&SwapExecuteMsg::Swap {
+                    msg: to_binary(&SwapExecuteMsg::SwapExactCoinOut {
                         operations: vec![
                             SwapOperation {
                                 pool: "pool".to_string(),
@@ -567,6 +791,7 @@ struct Params {
                                 denom_out: "osmo".to_string(),
                             }
                         ],
+                        coin_out: Coin::new(500_000, "osmo"),
                     }).unwrap(),
                     funds: vec![Coin::new(500_000, "untrn")], 
                 }
@@ -574,10 +799,91 @@ struct Params {
                 gas_limit: None,
                 reply_on: Never,
             },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "entry_point".to_string(),
+                    msg: to_binary(&ExecuteMsg::UserSwapRefund {
+                        pre_balance: Coin::new(500000, "untrn"),
+                        refund_address: Some("refund_address".to_string()),
+                    }).unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
         ],
         expected_error: None,
     };
     "User Swap Exact Coin Out With Refund Amount Zero")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        user_swap: Swap::SwapExactCoinIn (
+            SwapExactCoinIn{
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![
+                    SwapOperation {
+                        pool: "pool".to_string(),
+                        denom_in: "cw20:cw20_contract".to_string(),
+                        denom_out: "cw20:cw20_contract_2".to_string(),
+                    }
+                ],
+            }
+        ),
+        remaining_coin: Coin::new(1_000_000, "cw20:cw20_contract"),
+        min_coin: Coin::new(1_000_000, "cw20:cw20_contract_2"),
+        affiliates: vec![Affiliate {
+            address: "affiliate".to_string(),
+            basis_points_fee: Some(Uint128::new(1000)),
+            id: None,
+        }],
+        protocol_fee: None,
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "cw20_contract".to_string(),
+                    msg: to_binary(&Cw20ExecuteMsg::Send {
+                        contract: "swap_venue_adapter".to_string(),
+                        amount: Uint128::new(1_000_000),
+                        msg: to_binary(&SwapExecuteMsg::Swap {
+                            operations: vec![
+                                SwapOperation {
+                                    pool: "pool".to_string(),
+                                    denom_in: "cw20:cw20_contract".to_string(),
+                                    denom_out: "cw20:cw20_contract_2".to_string(),
+                                }
+                            ],
+                            minimum_receive: Some(Uint128::new(1_100_000)),
+                            max_spread: None,
+                        }).unwrap(),
+                    }).unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "cw20_contract_2".to_string(),
+                    msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                        recipient: "affiliate".to_string(),
+                        amount: Uint128::new(100_000),
+                    }).unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "User Swap Exact Coin In With CW20 Coin In And Out And Single Affiliate")]
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
@@ -596,6 +902,7 @@ struct Params {
         remaining_coin: Coin::new(1_000_000, "uosmo"),
         min_coin: Coin::new(100_000, "uatom"),
         affiliates: vec![],
+        protocol_fee: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Skip(SwapOperationsCoinInDenomMismatch)),
     };
@@ -618,6 +925,7 @@ struct Params {
         remaining_coin: Coin::new(1_000_000, "osmo"),
         min_coin: Coin::new(100_000, "uatom"),
         affiliates: vec![],
+        protocol_fee: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Skip(SwapOperationsCoinOutDenomMismatch)),
     };
@@ -634,6 +942,7 @@ struct Params {
         remaining_coin: Coin::new(1_000_000, "untrn"),
         min_coin: Coin::new(1_000_000, "osmo"),
         affiliates: vec![],
+        protocol_fee: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Skip(SwapOperationsEmpty)),
     };
@@ -657,6 +966,7 @@ struct Params {
         remaining_coin: Coin::new(1_000_000, "uosmo"),
         min_coin: Coin::new(100_000, "uatom"),
         affiliates: vec![],
+        protocol_fee: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Skip(SwapOperationsCoinInDenomMismatch)),
     };
@@ -680,6 +990,7 @@ struct Params {
         remaining_coin: Coin::new(1_000_000, "osmo"),
         min_coin: Coin::new(100_000, "uatom"),
         affiliates: vec![],
+        protocol_fee: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Skip(SwapOperationsCoinOutDenomMismatch)),
     };
@@ -697,6 +1008,7 @@ struct Params {
         remaining_coin: Coin::new(1_000_000, "untrn"),
         min_coin: Coin::new(1_000_000, "osmo"),
         affiliates: vec![],
+        protocol_fee: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Skip(SwapOperationsEmpty)),
     };
@@ -720,6 +1032,7 @@ struct Params {
         remaining_coin: Coin::new(1_000_000, "untrn"),
         min_coin: Coin::new(500_000, "osmo"),
         affiliates: vec![],
+        protocol_fee: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::NoRefundAddress),
     };
@@ -743,6 +1056,7 @@ struct Params {
         remaining_coin: Coin::new(1_000_000, "uatom"),
         min_coin: Coin::new(500_000, "osmo"),
         affiliates: vec![],
+        protocol_fee: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::UserSwapCoinInDenomMismatch),
     };
@@ -772,27 +1086,480 @@ struct Params {
             operand1: "499999".to_string(),
             operand2: "500000".to_string(),
         })),
+        protocol_fee: None,
     };
     "User Swap Exact Coin Out Where Coin In Amount More Than Remaining Coin Received Amount - Expect Error")]
 #[test_case(
     Params {
-        caller: "random".to_string(),
-        user_swap: Swap::SwapExactCoinIn (
-            SwapExactCoinIn {
-                swap_venue_name: "swap_venue_name".to_string(),
-                operations: vec![],
-            },
-        ),
-        remaining_coin: Coin::new(1_000_000, "osmo"),
-        min_coin: Coin::new(1_000_000, "osmo"),
-        affiliates: vec![],
-        expected_messages: vec![],
-        expected_error: Some(ContractError::Unauthorized),
-    };
-    "Unauthorized Caller - Expect Error")]
-fn test_execute_user_swap(params: Params) {
-    // Create mock dependencies
-    let mut deps = mock_dependencies_with_balances(&[(
+        caller: "entry_point".to_string(),
+        user_swap: Swap::SplitSwapExactCoinIn (
+            SplitSwapExactCoinIn {
+                legs: vec![
+                    SplitSwapLeg {
+                        swap_venue_name: "swap_venue_name".to_string(),
+                        operations: vec![
+                            SwapOperation {
+                                pool: "pool".to_string(),
+                                denom_in: "untrn".to_string(),
+                                denom_out: "osmo".to_string(),
+                            }
+                        ],
+                        share: Decimal::percent(60),
+                    },
+                    SplitSwapLeg {
+                        swap_venue_name: "swap_venue_name_2".to_string(),
+                        operations: vec![
+                            SwapOperation {
+                                pool: "pool_2".to_string(),
+                                denom_in: "untrn".to_string(),
+                                denom_out: "osmo".to_string(),
+                            }
+                        ],
+                        share: Decimal::percent(40),
+                    },
+                ],
+            }
+        ),
+        remaining_coin: Coin::new(1_000_001, "untrn"),
+        min_coin: Coin::new(1_000_000, "osmo"),
+        affiliates: vec![],
+        protocol_fee: None,
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter".to_string(),
+                    msg: to_binary(&SwapExecuteMsg::Swap {
+                        operations: vec![
+                            SwapOperation {
+                                pool: "pool".to_string(),
+                                denom_in: "untrn".to_string(),
+                                denom_out: "osmo".to_string(),
+                            }
+                        ],
+                        minimum_receive: None,
+                        max_spread: None,
+                    }).unwrap(),
+                    funds: vec![Coin::new(600_000, "untrn")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter_2".to_string(),
+                    msg: to_binary(&SwapExecuteMsg::Swap {
+                        operations: vec![
+                            SwapOperation {
+                                pool: "pool_2".to_string(),
+                                denom_in: "untrn".to_string(),
+                                denom_out: "osmo".to_string(),
+                            }
+                        ],
+                        minimum_receive: None,
+                        max_spread: None,
+                    }).unwrap(),
+                    funds: vec![Coin::new(400_001, "untrn")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "User Split Swap Exact Coin In Gives Rounding Remainder To Last Leg")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        user_swap: Swap::SplitSwapExactCoinIn (
+            SplitSwapExactCoinIn {
+                legs: vec![
+                    SplitSwapLeg {
+                        swap_venue_name: "swap_venue_name".to_string(),
+                        operations: vec![
+                            SwapOperation {
+                                pool: "pool".to_string(),
+                                denom_in: "untrn".to_string(),
+                                denom_out: "osmo".to_string(),
+                            }
+                        ],
+                        share: Decimal::percent(1),
+                    },
+                    SplitSwapLeg {
+                        swap_venue_name: "swap_venue_name_2".to_string(),
+                        operations: vec![
+                            SwapOperation {
+                                pool: "pool_2".to_string(),
+                                denom_in: "untrn".to_string(),
+                                denom_out: "osmo".to_string(),
+                            }
+                        ],
+                        share: Decimal::percent(99),
+                    },
+                ],
+            }
+        ),
+        remaining_coin: Coin::new(50, "untrn"),
+        min_coin: Coin::new(1_000_000, "osmo"),
+        affiliates: vec![],
+        protocol_fee: None,
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter_2".to_string(),
+                    msg: to_binary(&SwapExecuteMsg::Swap {
+                        operations: vec![
+                            SwapOperation {
+                                pool: "pool_2".to_string(),
+                                denom_in: "untrn".to_string(),
+                                denom_out: "osmo".to_string(),
+                            }
+                        ],
+                        minimum_receive: None,
+                        max_spread: None,
+                    }).unwrap(),
+                    funds: vec![Coin::new(50, "untrn")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "User Split Swap Exact Coin In Skips Legs Rounding Down To Zero")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        user_swap: Swap::SplitSwapExactCoinIn (
+            SplitSwapExactCoinIn {
+                legs: vec![
+                    SplitSwapLeg {
+                        swap_venue_name: "swap_venue_name".to_string(),
+                        operations: vec![
+                            SwapOperation {
+                                pool: "pool".to_string(),
+                                denom_in: "untrn".to_string(),
+                                denom_out: "osmo".to_string(),
+                            }
+                        ],
+                        share: Decimal::percent(60),
+                    },
+                    SplitSwapLeg {
+                        swap_venue_name: "swap_venue_name_2".to_string(),
+                        operations: vec![
+                            SwapOperation {
+                                pool: "pool_2".to_string(),
+                                denom_in: "untrn".to_string(),
+                                denom_out: "uatom".to_string(),
+                            }
+                        ],
+                        share: Decimal::percent(40),
+                    },
+                ],
+            }
+        ),
+        remaining_coin: Coin::new(1_000_000, "untrn"),
+        min_coin: Coin::new(1_000_000, "osmo"),
+        affiliates: vec![],
+        protocol_fee: None,
+        expected_messages: vec![
+        ],
+        expected_error: Some(ContractError::Skip(SwapOperationsCoinOutDenomMismatch)),
+    };
+    "User Split Swap Exact Coin In Leg Last Swap Operation Denom Out Is Not The Same As Min Coin Out Denom - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        user_swap: Swap::SplitSwapExactCoinIn (
+            SplitSwapExactCoinIn {
+                legs: vec![
+                    SplitSwapLeg {
+                        swap_venue_name: "swap_venue_name".to_string(),
+                        operations: vec![
+                            SwapOperation {
+                                pool: "pool".to_string(),
+                                denom_in: "untrn".to_string(),
+                                denom_out: "osmo".to_string(),
+                            }
+                        ],
+                        share: Decimal::percent(60),
+                    },
+                    SplitSwapLeg {
+                        swap_venue_name: "swap_venue_name_2".to_string(),
+                        operations: vec![
+                            SwapOperation {
+                                pool: "pool_2".to_string(),
+                                denom_in: "untrn".to_string(),
+                                denom_out: "osmo".to_string(),
+                            }
+                        ],
+                        share: Decimal::percent(30),
+                    },
+                ],
+            }
+        ),
+        remaining_coin: Coin::new(1_000_000, "untrn"),
+        min_coin: Coin::new(1_000_000, "osmo"),
+        affiliates: vec![],
+        protocol_fee: None,
+        expected_messages: vec![
+        ],
+        expected_error: Some(ContractError::Skip(SplitSwapSharesNotOne)),
+    };
+    "User Split Swap Exact Coin In Leg Shares Do Not Sum To One - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        user_swap: Swap::SplitSwapExactCoinIn (
+            SplitSwapExactCoinIn {
+                legs: vec![
+                ],
+            }
+        ),
+        remaining_coin: Coin::new(1_000_000, "untrn"),
+        min_coin: Coin::new(1_000_000, "osmo"),
+        affiliates: vec![],
+        protocol_fee: None,
+        expected_messages: vec![
+        ],
+        expected_error: Some(ContractError::Skip(SplitSwapLegsEmpty)),
+    };
+    "User Split Swap Exact Coin In Empty Legs - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        user_swap: Swap::SwapExactCoinIn (
+            SwapExactCoinIn{
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![
+                    SwapOperation {
+                        pool: "pool".to_string(),
+                        denom_in: "untrn".to_string(),
+                        denom_out: "osmo".to_string(),
+                    }
+                ],
+            }
+        ),
+        remaining_coin: Coin::new(1_000_000, "untrn"),
+        min_coin: Coin::new(1_000_000, "osmo"),
+        affiliates: vec![Affiliate {
+            address: "".to_string(),
+            basis_points_fee: None,
+            id: Some("partner".to_string()),
+        }],
+        protocol_fee: None,
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter".to_string(),
+                    msg: to_binary(&SwapExecuteMsg::Swap {
+                        operations: vec![
+                            SwapOperation {
+                                pool: "pool".to_string(),
+                                denom_in: "untrn".to_string(),
+                                denom_out: "osmo".to_string(),
+                            }
+                        ],
+                        minimum_receive: Some(Uint128::new(1_005_000)),
+                        max_spread: None,
+                    }).unwrap(),
+                    funds: vec![Coin::new(1_000_000, "untrn")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "partner_address".to_string(),
+                    amount: vec![Coin::new(5_000, "osmo")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "User Swap Exact Coin In With Registered Affiliate Default Fee")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        user_swap: Swap::SwapExactCoinIn (
+            SwapExactCoinIn{
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![
+                    SwapOperation {
+                        pool: "pool".to_string(),
+                        denom_in: "untrn".to_string(),
+                        denom_out: "osmo".to_string(),
+                    }
+                ],
+            }
+        ),
+        remaining_coin: Coin::new(1_000_000, "untrn"),
+        min_coin: Coin::new(1_000_000, "osmo"),
+        affiliates: vec![Affiliate {
+            address: "partner_address".to_string(),
+            basis_points_fee: Some(Uint128::new(101)),
+            id: Some("partner".to_string()),
+        }],
+        protocol_fee: None,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::RegisteredAffiliateFeeTooHigh(
+            "partner".to_string(),
+            Uint128::new(100),
+        )),
+    };
+    "Registered Affiliate Fee Above Its Max Fee - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        user_swap: Swap::SwapExactCoinIn (
+            SwapExactCoinIn{
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![
+                    SwapOperation {
+                        pool: "pool".to_string(),
+                        denom_in: "untrn".to_string(),
+                        denom_out: "osmo".to_string(),
+                    }
+                ],
+            }
+        ),
+        remaining_coin: Coin::new(1_000_000, "untrn"),
+        min_coin: Coin::new(1_000_000, "osmo"),
+        affiliates: vec![Affiliate {
+            address: "".to_string(),
+            basis_points_fee: None,
+            id: Some("unknown".to_string()),
+        }],
+        protocol_fee: None,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::RegisteredAffiliateNotFound(
+            "unknown".to_string(),
+        )),
+    };
+    "Registered Affiliate Not Found - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        user_swap: Swap::SwapExactCoinIn (
+            SwapExactCoinIn{
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![
+                    SwapOperation {
+                        pool: "pool".to_string(),
+                        denom_in: "untrn".to_string(),
+                        denom_out: "osmo".to_string(),
+                    }
+                ],
+            }
+        ),
+        remaining_coin: Coin::new(1_000_000, "untrn"),
+        min_coin: Coin::new(1_000_000, "osmo"),
+        affiliates: vec![Affiliate {
+            address: "other_address".to_string(),
+            basis_points_fee: None,
+            id: Some("partner".to_string()),
+        }],
+        protocol_fee: None,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::RegisteredAffiliateAddressMismatch(
+            "partner".to_string(),
+        )),
+    };
+    "Registered Affiliate Address Not The Registered Address - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        user_swap: Swap::SwapExactCoinIn (
+            SwapExactCoinIn{
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![
+                    SwapOperation {
+                        pool: "pool".to_string(),
+                        denom_in: "untrn".to_string(),
+                        denom_out: "osmo".to_string(),
+                    }
+                ],
+            }
+        ),
+        remaining_coin: Coin::new(1_000_000, "untrn"),
+        min_coin: Coin::new(1_000_000, "osmo"),
+        affiliates: vec![Affiliate {
+            address: "affiliate".to_string(),
+            basis_points_fee: None,
+            id: None,
+        }],
+        protocol_fee: None,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::AffiliateBasisPointsFeeRequired(
+            "affiliate".to_string(),
+        )),
+    };
+    "Unregistered Affiliate Without Fee - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        user_swap: Swap::SwapExactCoinIn (
+            SwapExactCoinIn{
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![
+                    SwapOperation {
+                        pool: "pool".to_string(),
+                        denom_in: "untrn".to_string(),
+                        denom_out: "osmo".to_string(),
+                    }
+                ],
+            }
+        ),
+        remaining_coin: Coin::new(1_000_000, "untrn"),
+        min_coin: Coin::new(1_000_000, "osmo"),
+        affiliates: vec![
+            Affiliate {
+                address: "affiliate".to_string(),
+                basis_points_fee: Some(Uint128::new(4951)),
+                id: None,
+            },
+            Affiliate {
+                address: "".to_string(),
+                basis_points_fee: None,
+                id: Some("partner".to_string()),
+            },
+        ],
+        protocol_fee: None,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::TotalAffiliateFeeTooHigh(Uint128::new(5000))),
+    };
+    "Total Affiliate Fee Above The Maximum - Expect Error")]
+#[test_case(
+    Params {
+        caller: "random".to_string(),
+        user_swap: Swap::SwapExactCoinIn (
+            SwapExactCoinIn {
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![],
+            },
+        ),
+        remaining_coin: Coin::new(1_000_000, "osmo"),
+        min_coin: Coin::new(1_000_000, "osmo"),
+        affiliates: vec![],
+        protocol_fee: None,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Unauthorized Caller - Expect Error")]
+fn test_execute_user_swap(params: Params) {
+    // Create mock dependencies
+    let mut deps = mock_dependencies_with_balances(&[(
         "entry_point",
         &[Coin::new(1_000_000, "osmo"), Coin::new(1_000_000, "untrn")],
     )]);
@@ -819,7 +1586,7 @@ fn test_execute_user_swap(params: Params) {
     // Create mock info with entry point contract address
     let info = mock_info(&params.caller, &[]);
 
-    // Store the ibc transfer adapter contract address
+    // Store the swap venue adapter contract addresses
     let swap_venue_adapter = Addr::unchecked("swap_venue_adapter");
     SWAP_VENUE_MAP
         .save(
@@ -828,6 +1595,35 @@ fn test_execute_user_swap(params: Params) {
             &swap_venue_adapter,
         )
         .unwrap();
+    let swap_venue_adapter_2 = Addr::unchecked("swap_venue_adapter_2");
+    SWAP_VENUE_MAP
+        .save(
+            deps.as_mut().storage,
+            "swap_venue_name_2",
+            &swap_venue_adapter_2,
+        )
+        .unwrap();
+
+    // Store a registered affiliate with a default fee of 50 and a max fee of 100 basis points
+    REGISTERED_AFFILIATES
+        .save(
+            deps.as_mut().storage,
+            "partner",
+            &RegisteredAffiliate {
+                id: "partner".to_string(),
+                address: "partner_address".to_string(),
+                default_basis_points_fee: Uint128::new(50),
+                max_basis_points_fee: Uint128::new(100),
+            },
+        )
+        .unwrap();
+
+    // Store the protocol fee if one is given
+    if let Some(protocol_fee) = params.protocol_fee {
+        PROTOCOL_FEE
+            .save(deps.as_mut().storage, &protocol_fee)
+            .unwrap();
+    }
 
     // Call execute_swap_and_action with the given test case params
     let res = skip_api_entry_point::contract::execute(
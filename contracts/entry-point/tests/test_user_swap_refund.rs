@@ -0,0 +1,273 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies_with_balances, mock_env, mock_info},
+    to_binary, Addr, BankMsg, Coin, ContractResult, QuerierResult,
+    ReplyOn::Never,
+    SubMsg, SystemResult, Timestamp, WasmMsg, WasmQuery,
+};
+use skip::{
+    entry_point::ExecuteMsg,
+    swap::{ExecuteMsg as SwapExecuteMsg, Swap, SwapExactCoinOut, SwapOperation},
+};
+use skip_api_entry_point::{error::ContractError, state::SWAP_VENUE_MAP};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response
+    - Exact Out Swap Used Less Than The Max Coin In (Refunds the balance change since before the swap)
+    - Exact Out Swap Used All Of The Max Coin In (Ensure No Refund Message Included)
+    - No Refund Address With No Unused Coin In
+    - Exact Out Swap At A Worse Price Than Simulated (Refunds less than the simulated refund)
+
+Expect Error
+    - No Refund Address With Unused Coin In
+    - Unauthorized Caller
+ */
+
+// Define test parameters
+struct Params {
+    caller: String,
+    pre_balance: Coin,
+    refund_address: Option<String>,
+    expected_messages: Vec<SubMsg>,
+    expected_error: Option<ContractError>,
+}
+
+// Test execute_user_swap_refund
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        pre_balance: Coin::new(400_000, "untrn"),
+        refund_address: Some("refund_address".to_string()),
+        expected_messages: vec![SubMsg {
+            id: 0,
+            msg: BankMsg::Send {
+                to_address: "refund_address".to_string(),
+                amount: vec![Coin::new(600_000, "untrn")],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }],
+        expected_error: None,
+    };
+    "Exact Out Swap Used Less Than The Max Coin In")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        pre_balance: Coin::new(1_000_000, "untrn"),
+        refund_address: Some("refund_address".to_string()),
+        expected_messages: vec![],
+        expected_error: None,
+    };
+    "Exact Out Swap Used All Of The Max Coin In")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        pre_balance: Coin::new(1_000_000, "untrn"),
+        refund_address: None,
+        expected_messages: vec![],
+        expected_error: None,
+    };
+    "No Refund Address With No Unused Coin In")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        pre_balance: Coin::new(400_000, "untrn"),
+        refund_address: None,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::NoRefundAddress),
+    };
+    "No Refund Address With Unused Coin In - Expect Error")]
+#[test_case(
+    Params {
+        caller: "random".to_string(),
+        pre_balance: Coin::new(400_000, "untrn"),
+        refund_address: Some("refund_address".to_string()),
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Unauthorized Caller - Expect Error")]
+fn test_execute_user_swap_refund(params: Params) {
+    // Create mock dependencies
+    let mut deps = mock_dependencies_with_balances(&[(
+        "entry_point",
+        &[Coin::new(1_000_000, "osmo"), Coin::new(1_000_000, "untrn")],
+    )]);
+
+    // Create mock env with parameters that make testing easier
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+    env.block.time = Timestamp::from_nanos(100);
+
+    // Create mock info with the given caller
+    let info = mock_info(&params.caller, &[]);
+
+    // Call execute_user_swap_refund with the given test parameters
+    let res = skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::UserSwapRefund {
+            pre_balance: params.pre_balance,
+            refund_address: params.refund_address,
+        },
+    );
+
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the messages in the response are correct
+            assert_eq!(res.messages, params.expected_messages);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
+
+// Test an exact out user swap executing at a worse price than simulated, but within the remaining coin
+#[test]
+fn test_exact_out_swap_at_worse_price_than_simulated() {
+    // Create mock dependencies, with the contract holding the remaining coin
+    let mut deps =
+        mock_dependencies_with_balances(&[("entry_point", &[Coin::new(1_000_000, "untrn")])]);
+
+    // Create mock wasm handler to handle the swap adapter contract query
+    // Simulates 500_000 untrn needed to receive the min coin
+    let wasm_handler = |query: &WasmQuery| -> QuerierResult {
+        match query {
+            WasmQuery::Smart { .. } => SystemResult::Ok(ContractResult::Ok(
+                to_binary(&Coin::new(500_000, "untrn")).unwrap(),
+            )),
+            _ => panic!("Unsupported query: {:?}", query),
+        }
+    };
+
+    // Update querier with mock wasm handler
+    deps.querier.update_wasm(wasm_handler);
+
+    // Create mock env with parameters that make testing easier
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+    env.block.time = Timestamp::from_nanos(100);
+
+    // Store the swap venue adapter contract address
+    SWAP_VENUE_MAP
+        .save(
+            deps.as_mut().storage,
+            "swap_venue_name",
+            &Addr::unchecked("swap_venue_adapter"),
+        )
+        .unwrap();
+
+    // Call execute_user_swap with an exact out user swap
+    let res = skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("entry_point", &[]),
+        ExecuteMsg::UserSwap {
+            swap: Swap::SwapExactCoinOut(SwapExactCoinOut {
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![SwapOperation {
+                    pool: "pool".to_string(),
+                    denom_in: "untrn".to_string(),
+                    denom_out: "osmo".to_string(),
+                }],
+                refund_address: Some("refund_address".to_string()),
+            }),
+            remaining_coin: Coin::new(1_000_000, "untrn"),
+            min_coin: Coin::new(500_000, "osmo"),
+            affiliates: vec![],
+        },
+    )
+    .unwrap();
+
+    // Assert the full remaining coin is sent as the max coin in, with the refund
+    // left to the UserSwapRefund message dispatched after the swap
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter".to_string(),
+                    msg: to_binary(&SwapExecuteMsg::SwapExactCoinOut {
+                        operations: vec![SwapOperation {
+                            pool: "pool".to_string(),
+                            denom_in: "untrn".to_string(),
+                            denom_out: "osmo".to_string(),
+                        }],
+                        coin_out: Coin::new(500_000, "osmo"),
+                    })
+                    .unwrap(),
+                    funds: vec![Coin::new(1_000_000, "untrn")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "entry_point".to_string(),
+                    msg: to_binary(&ExecuteMsg::UserSwapRefund {
+                        pre_balance: Coin::new(0, "untrn"),
+                        refund_address: Some("refund_address".to_string()),
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ]
+    );
+
+    // Mock the swap using 700_000 untrn, more than the 500_000 untrn simulated
+    deps.querier
+        .update_balance("entry_point", vec![Coin::new(300_000, "untrn")]);
+
+    // Call execute_user_swap_refund as dispatched after the swap
+    let res = skip_api_entry_point::contract::execute(
+        deps.as_mut(),
+        env,
+        mock_info("entry_point", &[]),
+        ExecuteMsg::UserSwapRefund {
+            pre_balance: Coin::new(0, "untrn"),
+            refund_address: Some("refund_address".to_string()),
+        },
+    )
+    .unwrap();
+
+    // Assert only the coin in left unused by the swap is refunded
+    assert_eq!(
+        res.messages,
+        vec![SubMsg {
+            id: 0,
+            msg: BankMsg::Send {
+                to_address: "refund_address".to_string(),
+                amount: vec![Coin::new(300_000, "untrn")],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }]
+    );
+}
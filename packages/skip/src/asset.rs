@@ -0,0 +1,191 @@
+use cosmwasm_std::{
+    to_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, QuerierWrapper, StdResult, WasmMsg,
+};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
+
+////////////////////
+/// COMMON TYPES ///
+////////////////////
+
+// Prefix of the denoms that name a CW20 token by its contract address, e.g. "cw20:neutron1...".
+// Coins with a CW20 denom are moved with CW20 messages instead of bank sends and funds.
+pub const CW20_DENOM_PREFIX: &str = "cw20:";
+
+////////////////////////
+/// COMMON FUNCTIONS ///
+////////////////////////
+
+// Returns the CW20 contract address named by the denom, or None if it is a native denom
+pub fn cw20_contract_address(denom: &str) -> Option<&str> {
+    denom.strip_prefix(CW20_DENOM_PREFIX)
+}
+
+// Returns the denom that names the CW20 token with the given contract address
+pub fn cw20_denom(contract_address: &str) -> String {
+    format!("{}{}", CW20_DENOM_PREFIX, contract_address)
+}
+
+// Creates the message transferring the coin to the given address, which is
+// a bank send for native denoms or a CW20 transfer for CW20 denoms
+pub fn transfer_msg(to_address: String, coin: Coin) -> StdResult<CosmosMsg> {
+    match cw20_contract_address(&coin.denom) {
+        Some(cw20_contract_address) => Ok(WasmMsg::Execute {
+            contract_addr: cw20_contract_address.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: to_address,
+                amount: coin.amount,
+            })?,
+            funds: vec![],
+        }
+        .into()),
+        None => Ok(BankMsg::Send {
+            to_address,
+            amount: vec![coin],
+        }
+        .into()),
+    }
+}
+
+// Creates the message executing the given contract with the coin, which is a contract call
+// with the coin as funds for native denoms or a CW20 send of the coin to the contract with
+// the message as the CW20 hook message for CW20 denoms
+pub fn execute_msg_with_coin(
+    contract_address: String,
+    msg: Binary,
+    coin: Coin,
+) -> StdResult<CosmosMsg> {
+    match cw20_contract_address(&coin.denom) {
+        Some(cw20_contract_address) => Ok(WasmMsg::Execute {
+            contract_addr: cw20_contract_address.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Send {
+                contract: contract_address,
+                amount: coin.amount,
+                msg,
+            })?,
+            funds: vec![],
+        }
+        .into()),
+        None => Ok(WasmMsg::Execute {
+            contract_addr: contract_address,
+            msg,
+            funds: vec![coin],
+        }
+        .into()),
+    }
+}
+
+// Queries the balance of the denom held by the given address, from the
+// bank module for native denoms or from the CW20 contract for CW20 denoms
+pub fn query_balance(querier: &QuerierWrapper, address: &Addr, denom: &str) -> StdResult<Coin> {
+    match cw20_contract_address(denom) {
+        Some(cw20_contract_address) => {
+            let res: BalanceResponse = querier.query_wasm_smart(
+                cw20_contract_address,
+                &Cw20QueryMsg::Balance {
+                    address: address.to_string(),
+                },
+            )?;
+
+            Ok(Coin {
+                denom: denom.to_string(),
+                amount: res.balance,
+            })
+        }
+        None => querier.query_balance(address, denom),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cw20_contract_address() {
+        // TEST CASE 1: CW20 Denom
+        assert_eq!(cw20_contract_address("cw20:neutron123"), Some("neutron123"));
+
+        // TEST CASE 2: Native Denom
+        assert_eq!(cw20_contract_address("untrn"), None);
+
+        // TEST CASE 3: Round Trip
+        assert_eq!(
+            cw20_contract_address(&cw20_denom("neutron123")),
+            Some("neutron123")
+        );
+    }
+
+    #[test]
+    fn test_transfer_msg() {
+        // TEST CASE 1: Native Denom
+        let msg = transfer_msg("recipient".to_string(), Coin::new(100, "untrn")).unwrap();
+
+        assert_eq!(
+            msg,
+            BankMsg::Send {
+                to_address: "recipient".to_string(),
+                amount: vec![Coin::new(100, "untrn")],
+            }
+            .into()
+        );
+
+        // TEST CASE 2: CW20 Denom
+        let msg = transfer_msg("recipient".to_string(), Coin::new(100, "cw20:neutron123")).unwrap();
+
+        assert_eq!(
+            msg,
+            WasmMsg::Execute {
+                contract_addr: "neutron123".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "recipient".to_string(),
+                    amount: 100u128.into(),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()
+        );
+    }
+
+    #[test]
+    fn test_execute_msg_with_coin() {
+        let msg = to_binary(&"hook").unwrap();
+
+        // TEST CASE 1: Native Denom
+        let res =
+            execute_msg_with_coin("contract".to_string(), msg.clone(), Coin::new(100, "untrn"))
+                .unwrap();
+
+        assert_eq!(
+            res,
+            WasmMsg::Execute {
+                contract_addr: "contract".to_string(),
+                msg: msg.clone(),
+                funds: vec![Coin::new(100, "untrn")],
+            }
+            .into()
+        );
+
+        // TEST CASE 2: CW20 Denom
+        let res = execute_msg_with_coin(
+            "contract".to_string(),
+            msg.clone(),
+            Coin::new(100, "cw20:neutron123"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            res,
+            WasmMsg::Execute {
+                contract_addr: "neutron123".to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Send {
+                    contract: "contract".to_string(),
+                    amount: 100u128.into(),
+                    msg,
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_binary, Coin, StdResult, SubMsg, WasmMsg};
+
+///////////////
+/// EXECUTE ///
+///////////////
+
+// The ExecuteMsg enum defines the execution message the entry point and IBC Transfer Adapter
+// contracts send to a callback address. Contracts receiving callbacks add a
+// SkipCallback(SkipCallback) variant to their own execute message to handle it.
+#[cw_serde]
+pub enum ExecuteMsg {
+    SkipCallback(SkipCallback),
+}
+
+////////////////////
+/// COMMON TYPES ///
+////////////////////
+
+// The SkipCallback struct is sent to the callback address of a SwapAndAction call.
+// The entry point sends it once the post swap action is dispatched, with a Success status,
+// or a Pending status if the post swap action includes IBC transfers. The IBC Transfer
+// Adapter contracts send it again for each IBC transfer once it is acknowledged or timed out,
+// with the ack id of the transfer. The amount out is the coin the post swap action
+// (or the IBC transfer) was performed with, which for a Sequence post swap action is the
+// coin of its last step, as the callback is sent once the last step is dispatched.
+#[cw_serde]
+pub struct SkipCallback {
+    pub status: CallbackStatus,
+    pub amount_out: Coin,
+    pub ack_id: Option<CallbackAckId>,
+}
+
+// The CallbackStatus enum defines the status of the post swap action or IBC transfer a
+// SkipCallback is sent for. Error and Timeout are only sent for IBC transfers, whose coin
+// is refunded to the recover address.
+#[cw_serde]
+pub enum CallbackStatus {
+    Success,
+    Pending,
+    Error,
+    Timeout,
+}
+
+// The CallbackAckId struct identifies the IBC transfer a SkipCallback is sent for
+// by its source channel id and packet sequence id.
+#[cw_serde]
+pub struct CallbackAckId {
+    pub channel_id: String,
+    pub sequence_id: u64,
+}
+
+// The IbcTransferCallback struct is stored by the IBC Transfer Adapter contracts for each
+// in progress IBC transfer that has a callback address, along with the coin transferred.
+#[cw_serde]
+pub struct IbcTransferCallback {
+    pub callback_address: String,
+    pub coin: Coin,
+}
+
+// The gas limit of a SkipCallback sub message. Replying on error does not catch running out of
+// gas, so the limit bounds the gas a callback address can use, leaving enough gas for the messages
+// dispatched with the callback (e.g. refunds) when a callback contract runs out of gas.
+pub const CALLBACK_GAS_LIMIT: u64 = 500_000;
+
+impl SkipCallback {
+    // Creates the sub message sending the callback to the callback address. The sub message
+    // replies on error with the given reply id, so the caller can ignore a failing callback
+    // instead of reverting the messages dispatched with it (e.g. refunds), and is limited to
+    // the callback gas limit, so an out of gas callback is also replied to as an error.
+    pub fn into_sub_msg(self, callback_address: String, reply_id: u64) -> StdResult<SubMsg> {
+        Ok(SubMsg::reply_on_error(
+            WasmMsg::Execute {
+                contract_addr: callback_address,
+                msg: to_binary(&ExecuteMsg::SkipCallback(self))?,
+                funds: vec![],
+            },
+            reply_id,
+        )
+        .with_gas_limit(CALLBACK_GAS_LIMIT))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{from_binary, CosmosMsg, ReplyOn};
+
+    #[test]
+    fn test_into_sub_msg() {
+        let callback = SkipCallback {
+            status: CallbackStatus::Timeout,
+            amount_out: Coin::new(100, "uatom"),
+            ack_id: Some(CallbackAckId {
+                channel_id: "channel-0".to_string(),
+                sequence_id: 1,
+            }),
+        };
+
+        let sub_msg = callback
+            .clone()
+            .into_sub_msg("callback_address".to_string(), 2)
+            .unwrap();
+
+        // TEST CASE 1: Sub Message Replies On Error With The Reply Id And The Callback Gas Limit
+        assert_eq!(sub_msg.id, 2);
+        assert_eq!(sub_msg.reply_on, ReplyOn::Error);
+        assert_eq!(sub_msg.gas_limit, Some(CALLBACK_GAS_LIMIT));
+
+        // TEST CASE 2: Sub Message Executes The Callback Address With The Callback
+        let CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds,
+        }) = sub_msg.msg
+        else {
+            panic!("expected a wasm execute message");
+        };
+        assert_eq!(contract_addr, "callback_address");
+        assert!(funds.is_empty());
+        assert_eq!(
+            from_binary::<ExecuteMsg>(&msg).unwrap(),
+            ExecuteMsg::SkipCallback(callback)
+        );
+
+        // TEST CASE 3: Callback Serializes Under The skip_callback Key
+        assert_eq!(
+            String::from_utf8(msg.to_vec()).unwrap(),
+            r#"{"skip_callback":{"status":"timeout","amount_out":{"denom":"uatom","amount":"100"},"ack_id":{"channel_id":"channel-0","sequence_id":1}}}"#
+        );
+    }
+}
@@ -1,20 +1,31 @@
 use crate::{
-    ibc::IbcInfo,
-    swap::{Swap, SwapExactCoinOut, SwapVenue},
+    ibc::{IbcFee, IbcInfo, IbcTransferAdapter},
+    swap::{
+        SimulateSwapWithMetadataResponse, Swap, SwapExactCoinIn, SwapExactCoinOut, SwapOperation,
+        SwapVenue,
+    },
+    template::MsgTemplate,
 };
 
+use std::fmt;
+
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Binary, Coin, Uint128};
+use cosmwasm_std::{Addr, Binary, Coin, CosmosMsg, Decimal, Uint128};
+use cw20::Cw20ReceiveMsg;
 
 ///////////////////
 /// INSTANTIATE ///
 ///////////////////
 
 // The InstantiateMsg struct defines the initialization parameters for the entry point contract.
+// If no owner is provided, the instantiator becomes the owner of the contract.
+// The default ibc transfer adapter must be one of the ibc transfer adapters provided.
 #[cw_serde]
 pub struct InstantiateMsg {
+    pub owner: Option<String>,
     pub swap_venues: Vec<SwapVenue>,
-    pub ibc_transfer_contract_address: String,
+    pub ibc_transfer_adapters: Vec<IbcTransferAdapter>,
+    pub default_ibc_transfer_adapter: String,
 }
 
 ///////////////
@@ -22,36 +33,159 @@ pub struct InstantiateMsg {
 ///////////////
 
 // The ExecuteMsg enum defines the execution messages that the entry point contract can handle.
-// Only the SwapAndAction message is callable by external users.
+// Only the SwapAndAction and Receive messages are callable by external users, the admin messages
+// are only callable by the owner (or pending owner for AcceptOwnership, or a guardian for Pause).
+// A SwapAndAction call with a callback address sends it a SkipCallback once the post swap action
+// is dispatched, and the IBC transfers of the post swap action send it one once they complete.
+// A SwapAndActionWithRecover call made through an ibc-hooks wasm memo can give an IBC recovery,
+// so the funds are refunded over IBC to the original sender on the origin chain on failure.
+// An affiliate that opts in with SetAffiliateFeeAccrual has its affiliate fees accrued in the
+// contract instead of sent on every swap, and withdraws them with ClaimAffiliateFees.
+// SetDelegateEnabled enables Delegate post swap actions, which must only be enabled on chains
+// with the liquid staking module, as the delegations are tokenized with MsgTokenizeShares.
 #[cw_serde]
 #[allow(clippy::large_enum_variant)]
 pub enum ExecuteMsg {
     SwapAndActionWithRecover {
+        pre_swap_action: Option<PreSwapAction>,
         user_swap: Swap,
         min_coin: Coin,
         timeout_timestamp: u64,
         post_swap_action: Action,
         affiliates: Vec<Affiliate>,
+        affiliate_fee_basis: Option<AffiliateFeeBasis>,
+        callback_address: Option<String>,
+        ibc_recovery: Option<IbcRecovery>,
         recovery_addr: Addr,
     },
     SwapAndAction {
+        pre_swap_action: Option<PreSwapAction>,
         user_swap: Swap,
         min_coin: Coin,
         timeout_timestamp: u64,
         post_swap_action: Action,
         affiliates: Vec<Affiliate>,
+        affiliate_fee_basis: Option<AffiliateFeeBasis>,
+        callback_address: Option<String>,
     },
+    Receive(Cw20ReceiveMsg),
     UserSwap {
         swap: Swap,
         min_coin: Coin,
         remaining_coin: Coin,
         affiliates: Vec<Affiliate>,
     },
+    UserSwapWithdrawnAssets {
+        swaps: Vec<SwapExactCoinIn>,
+        pre_balances: Vec<Coin>,
+        min_coin: Coin,
+        affiliates: Vec<Affiliate>,
+    },
+    UserSwapRefund {
+        pre_balance: Coin,
+        refund_address: Option<String>,
+    },
     PostSwapAction {
         min_coin: Coin,
         timeout_timestamp: u64,
         post_swap_action: Action,
         exact_out: bool,
+        affiliates: Vec<Affiliate>,
+        callback_address: Option<Addr>,
+    },
+    PostSwapActionStep {
+        min_coin: Coin,
+        pre_balance: Uint128,
+        timeout_timestamp: u64,
+        steps: Vec<SequenceStep>,
+        callback_address: Option<Addr>,
+        send_callback: bool,
+    },
+    TransferDelegationShares {
+        validator: String,
+        pre_balances: Vec<Coin>,
+        delegator_recipient: String,
+    },
+    TransferOwnership {
+        new_owner: String,
+    },
+    AcceptOwnership {},
+    AddSwapVenue {
+        swap_venue: SwapVenue,
+    },
+    UpdateSwapVenue {
+        swap_venue: SwapVenue,
+    },
+    RemoveSwapVenue {
+        name: String,
+    },
+    AddIbcTransferAdapter {
+        ibc_transfer_adapter: IbcTransferAdapter,
+    },
+    UpdateIbcTransferAdapter {
+        ibc_transfer_adapter: IbcTransferAdapter,
+    },
+    RemoveIbcTransferAdapter {
+        name: String,
+    },
+    SetDefaultIbcTransferAdapter {
+        name: String,
+    },
+    AddBlockedContractAddress {
+        address: String,
+    },
+    RemoveBlockedContractAddress {
+        address: String,
+    },
+    AddGuardian {
+        address: String,
+    },
+    RemoveGuardian {
+        address: String,
+    },
+    Pause {
+        target: PauseTarget,
+    },
+    Unpause {
+        target: PauseTarget,
+    },
+    SetProtocolFee {
+        protocol_fee: Option<ProtocolFee>,
+    },
+    RegisterAffiliate {
+        affiliate: RegisteredAffiliate,
+    },
+    DeregisterAffiliate {
+        id: String,
+    },
+    SetMaxTotalAffiliateBasisPointsFee {
+        max_total_affiliate_basis_points_fee: Uint128,
+    },
+    SetDelegateEnabled {
+        enabled: bool,
+    },
+    SetAffiliateFeeAccrual {
+        accrue: bool,
+    },
+    ClaimAffiliateFees {
+        denom: String,
+        destination: ClaimDestination,
+    },
+}
+
+// The Cw20HookMsg enum defines the messages the entry point contract accepts as
+// the hook message of a CW20 send, using the CW20 coin sent as the coin to swap.
+#[cw_serde]
+pub enum Cw20HookMsg {
+    SwapAndAction {
+        pre_swap_action: Option<PreSwapAction>,
+        user_swap: Swap,
+        min_coin: Coin,
+        timeout_timestamp: u64,
+        post_swap_action: Action,
+        affiliates: Vec<Affiliate>,
+        affiliate_fee_basis: Option<AffiliateFeeBasis>,
+        callback_address: Option<String>,
     },
 }
 
@@ -62,24 +196,200 @@ pub enum ExecuteMsg {
 // The QueryMsg enum defines the queries the entry point contract provides.
 #[cw_serde]
 #[derive(QueryResponses)]
+#[allow(clippy::large_enum_variant)]
 pub enum QueryMsg {
     // SwapVenueAdapterContract returns the address of the swap
     // adapter contract for the given swap venue name.
     #[returns(cosmwasm_std::Addr)]
     SwapVenueAdapterContract { name: String },
 
-    // IbcTransferAdapterContract returns the address of the IBC
-    // transfer adapter contract.
+    // SwapVenues returns the swap venues and their adapter contract addresses,
+    // ordered by name and paginated by start_after and limit.
+    #[returns(Vec<SwapVenue>)]
+    SwapVenues {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    // IbcTransferAdapterContract returns the address of the IBC transfer adapter
+    // contract for the given name, or of the default adapter if no name is given.
     #[returns(cosmwasm_std::Addr)]
-    IbcTransferAdapterContract {},
+    IbcTransferAdapterContract { name: Option<String> },
+
+    // DefaultIbcTransferAdapter returns the name of the default IBC transfer adapter.
+    #[returns(String)]
+    DefaultIbcTransferAdapter {},
+
+    // PauseState returns whether all routes are paused, and
+    // the paused swap venues and post swap action types.
+    #[returns(PauseStateResponse)]
+    PauseState {},
+
+    // Guardians returns the addresses allowed to pause the contract.
+    #[returns(Vec<cosmwasm_std::Addr>)]
+    Guardians {},
+
+    // ProtocolFee returns the protocol fee taken from every SwapAndAction call, if one is set.
+    #[returns(Option<ProtocolFee>)]
+    ProtocolFee {},
+
+    // RegisteredAffiliate returns the affiliate registered by the owner with the given id.
+    #[returns(RegisteredAffiliate)]
+    RegisteredAffiliate { id: String },
+
+    // RegisteredAffiliates returns the affiliates registered by the owner,
+    // ordered by id and paginated by start_after and limit.
+    #[returns(Vec<RegisteredAffiliate>)]
+    RegisteredAffiliates {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    // MaxTotalAffiliateBasisPointsFee returns the maximum total basis points
+    // fee of the affiliates of a single SwapAndAction call.
+    #[returns(Uint128)]
+    MaxTotalAffiliateBasisPointsFee {},
+
+    // DelegateEnabled returns whether Delegate post swap actions are enabled.
+    #[returns(bool)]
+    DelegateEnabled {},
+
+    // AffiliateFeeAccrual returns whether the affiliate fees of the given address are accrued.
+    #[returns(bool)]
+    AffiliateFeeAccrual { address: String },
+
+    // AccruedAffiliateFees returns the affiliate fees accrued to the given address,
+    // ordered by denom and paginated by start_after and limit.
+    #[returns(Vec<Coin>)]
+    AccruedAffiliateFees {
+        address: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    // BlockedContractAddresses returns the addresses that cannot be called
+    // by a contract call action, ordered and paginated by start_after and limit.
+    #[returns(Vec<cosmwasm_std::Addr>)]
+    BlockedContractAddresses {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    // Config returns all entry point settings in one response.
+    #[returns(ConfigResponse)]
+    Config {},
+
+    // SimulateSwapAndAction returns a dry run of a SwapAndAction call sending coin_in, using the
+    // swap adapters' simulation queries for the swap amounts and the pre swap action's withdrawals.
+    #[returns(SimulateSwapAndActionResponse)]
+    SimulateSwapAndAction {
+        coin_in: Coin,
+        pre_swap_action: Option<PreSwapAction>,
+        user_swap: Swap,
+        min_coin: Coin,
+        timeout_timestamp: u64,
+        post_swap_action: Action,
+        affiliates: Vec<Affiliate>,
+        affiliate_fee_basis: Option<AffiliateFeeBasis>,
+    },
+
+    // SimulateSwapExactCoinInWithMetadata forwards the swap exact coin in simulation
+    // with price and fee metadata to the given swap venue's adapter contract.
+    #[returns(SimulateSwapWithMetadataResponse)]
+    SimulateSwapExactCoinInWithMetadata {
+        swap_venue_name: String,
+        coin_in: Coin,
+        swap_operations: Vec<SwapOperation>,
+    },
+
+    // SimulateSwapExactCoinOutWithMetadata forwards the swap exact coin out simulation
+    // with price and fee metadata to the given swap venue's adapter contract.
+    #[returns(SimulateSwapWithMetadataResponse)]
+    SimulateSwapExactCoinOutWithMetadata {
+        swap_venue_name: String,
+        coin_out: Coin,
+        swap_operations: Vec<SwapOperation>,
+    },
+}
+
+// The ConfigResponse struct is the response to the Config query.
+#[cw_serde]
+pub struct ConfigResponse {
+    pub owner: Addr,
+    pub pending_owner: Option<Addr>,
+    pub guardians: Vec<Addr>,
+    pub swap_venues: Vec<SwapVenue>,
+    pub ibc_transfer_adapters: Vec<IbcTransferAdapter>,
+    pub default_ibc_transfer_adapter: String,
+    pub pause_state: PauseStateResponse,
+    pub protocol_fee: Option<ProtocolFee>,
+    pub max_total_affiliate_basis_points_fee: Uint128,
+    pub delegate_enabled: bool,
+}
+
+// The SimulateSwapAndActionResponse struct is the response to the SimulateSwapAndAction query.
+// The user swap coin in is the coin swapped (or the coin the pre swap action is dispatched with)
+// after the fee swap and ibc fees are deducted, and the coin out is the coin the post swap action
+// delivers. The messages are the ones the
+// SwapAndAction call and its UserSwap and PostSwapAction calls would dispatch.
+#[cw_serde]
+pub struct SimulateSwapAndActionResponse {
+    pub fee_swap_coin_in: Option<Coin>,
+    pub user_swap_coin_in: Coin,
+    pub user_swap_coin_out: Coin,
+    pub affiliate_fees: Vec<AffiliateFee>,
+    pub protocol_fee: Option<Coin>,
+    pub refund: Option<Coin>,
+    pub coin_out: Coin,
+    pub messages: Vec<CosmosMsg>,
+}
+
+// The PauseStateResponse struct is the response to the PauseState query.
+#[cw_serde]
+pub struct PauseStateResponse {
+    pub all: bool,
+    pub swap_venues: Vec<String>,
+    pub actions: Vec<ActionType>,
 }
 
 ////////////////////
 /// COMMON TYPES ///
 ////////////////////
 
+// The PreSwapAction enum is used to specify what to do with the coin sent before the swap.
+// WithdrawLiquidity withdraws liquidity from the two asset pool with the coin sent, which must be
+// the pool's LP token, through the swap venue's adapter. The user swap (which must be a swap exact
+// coin in) swaps the withdrawn asset of its first swap operation's denom in, and the other asset
+// swap swaps the other withdrawn asset. A swap without swap operations is used for a withdrawn
+// asset that is already the min coin denom. The combined coin out is checked against min coin.
+#[cw_serde]
+pub enum PreSwapAction {
+    WithdrawLiquidity {
+        swap_venue_name: String,
+        pool: String,
+        other_asset_swap: SwapExactCoinIn,
+    },
+}
+
 // The Action enum is used to specify what action to take after a swap.
+// IbcTransfer uses the named ibc transfer adapter, or the default adapter if none is given.
+// ContractCall substitutes the amount and denom of the coin it is called with into the
+// declared msg templates' paths of its msg, if any are given.
+// Multi splits the coin transferred out across multiple actions, sending any remainder
+// left after the legs are paid to the remainder address.
+// Sequence runs its steps in order, each step after the first using the coin received
+// from the step before it.
+// ProvideLiquidity provides liquidity to the two asset pool through the swap venue's adapter,
+// swapping part of the coin into the pool's other asset first, and sends the LP tokens to the
+// recipient. Without a recipient it can only be a Sequence step before the last, whose min
+// coin out is the LP token and whose next step sends the LP tokens. The slippage tolerance
+// bounds the swap and the LP tokens received against the adapter's simulation of them.
+// Delegate delegates the coin, which must be the bond denom, to the validator and tokenizes the
+// delegation through the liquid staking module, then sends the share tokens minted to the
+// contract on to the delegator recipient, who also owns the tokenize share record.
+// It can only be used once enabled by the owner, on chains with the liquid staking module.
 #[cw_serde]
+#[allow(clippy::large_enum_variant)]
 pub enum Action {
     BankSend {
         to_address: String,
@@ -87,17 +397,236 @@ pub enum Action {
     IbcTransfer {
         ibc_info: IbcInfo,
         fee_swap: Option<SwapExactCoinOut>,
+        ibc_transfer_adapter: Option<String>,
     },
     ContractCall {
         contract_address: String,
         msg: Binary,
+        msg_templates: Option<Vec<MsgTemplate>>,
+    },
+    Multi {
+        legs: Vec<MultiActionLeg>,
+        remainder_address: String,
+    },
+    Sequence {
+        steps: Vec<SequenceStep>,
+    },
+    ProvideLiquidity {
+        swap_venue_name: String,
+        pool: String,
+        recipient: Option<String>,
+        slippage_tolerance: Decimal,
+    },
+    Delegate {
+        validator: String,
+        delegator_recipient: String,
     },
 }
 
-// The Affiliate struct is used to specify an affiliate address and BPS fee taken
-// from the min_coin to send to that address.
+// The MultiActionLeg struct is a single action of a Multi action,
+// paid the leg amount out of the coin transferred out.
+#[cw_serde]
+pub struct MultiActionLeg {
+    pub amount: MultiActionAmount,
+    pub action: Action,
+}
+
+// The MultiActionAmount enum defines how much of the coin transferred out a Multi action
+// leg is paid. Fixed amounts are paid first, and each Share leg is paid its share of the
+// amount left after the fixed amounts, rounded down.
+#[cw_serde]
+pub enum MultiActionAmount {
+    Share(Decimal),
+    Fixed(Uint128),
+}
+
+// The SequenceStep struct is a single step of a Sequence action. Every step but the last
+// must set the min_coin_out it must receive, whose denom's balance change is used as the
+// coin in of the next step, and which is enforced the way min_coin is after the swap.
+#[cw_serde]
+pub struct SequenceStep {
+    pub action: Action,
+    pub min_coin_out: Option<Coin>,
+}
+
+// The ActionType enum identifies the type of a post swap action,
+// used to pause a single type of post swap action.
+#[cw_serde]
+pub enum ActionType {
+    BankSend,
+    IbcTransfer,
+    ContractCall,
+    Multi,
+    Sequence,
+    ProvideLiquidity,
+    Delegate,
+}
+
+// Converts an Action to its ActionType
+impl From<&Action> for ActionType {
+    fn from(action: &Action) -> Self {
+        match action {
+            Action::BankSend { .. } => ActionType::BankSend,
+            Action::IbcTransfer { .. } => ActionType::IbcTransfer,
+            Action::ContractCall { .. } => ActionType::ContractCall,
+            Action::Multi { .. } => ActionType::Multi,
+            Action::Sequence { .. } => ActionType::Sequence,
+            Action::ProvideLiquidity { .. } => ActionType::ProvideLiquidity,
+            Action::Delegate { .. } => ActionType::Delegate,
+        }
+    }
+}
+
+impl fmt::Display for ActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ActionType::BankSend => write!(f, "bank_send"),
+            ActionType::IbcTransfer => write!(f, "ibc_transfer"),
+            ActionType::ContractCall => write!(f, "contract_call"),
+            ActionType::Multi => write!(f, "multi"),
+            ActionType::Sequence => write!(f, "sequence"),
+            ActionType::ProvideLiquidity => write!(f, "provide_liquidity"),
+            ActionType::Delegate => write!(f, "delegate"),
+        }
+    }
+}
+
+// The PauseTarget enum defines what is paused or unpaused in the entry point contract:
+// all SwapAndAction calls, a single swap venue, or a single post swap action type.
+#[cw_serde]
+pub enum PauseTarget {
+    All {},
+    SwapVenue { name: String },
+    Action { action_type: ActionType },
+}
+
+impl fmt::Display for PauseTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PauseTarget::All {} => write!(f, "all"),
+            PauseTarget::SwapVenue { name } => write!(f, "swap_venue:{}", name),
+            PauseTarget::Action { action_type } => write!(f, "action:{}", action_type),
+        }
+    }
+}
+
+// The IbcRecovery struct is the origin chain recovery target of a SwapAndActionWithRecover call
+// made through an ibc-hooks wasm memo. The channel is the channel on this chain the call was
+// received on, and the original sender is the address on the origin chain that sent it, whose
+// ibc-hooks intermediate sender must be the caller. On failure the funds are IBC transferred back
+// over the channel to the original sender, with the recovery address as the local recover address.
+// The fee is the IBC fee of the recovery transfer, required where the default IBC transfer adapter
+// requires IBC fees (Neutron). It is paid out of the funds, so the call must send a single coin of
+// the fee denom that is more than the fee. The recovery transfer times out after the timeout
+// seconds, or one hour if not given, refunding the funds to the recovery address.
+#[cw_serde]
+pub struct IbcRecovery {
+    pub channel: String,
+    pub original_sender: String,
+    pub fee: Option<IbcFee>,
+    pub timeout_seconds: Option<u64>,
+}
+
+// The Affiliate struct is used to specify an affiliate and BPS fee taken from the min_coin
+// (or the actual coin out, see AffiliateFeeBasis) to send to it. If the id of an affiliate
+// registered by the owner is given, the fee is sent to the registered address, and the address
+// can be left empty or must be the registered address. The fee of a registered affiliate
+// defaults to its default fee if none is given and cannot be more than its max fee, the fee
+// of an unregistered affiliate must be given.
 #[cw_serde]
 pub struct Affiliate {
-    pub basis_points_fee: Uint128,
+    #[serde(default)]
+    pub basis_points_fee: Option<Uint128>,
+    #[serde(default)]
+    pub address: String,
+    #[serde(default)]
+    pub id: Option<String>,
+}
+
+// The AffiliateFeeBasis enum is used to specify the amount the affiliate fees of a SwapAndAction
+// call are charged on, which is the min_coin if none is given. MinCoin fees are paid by the user
+// swap, which must receive the min_coin plus the fees. ActualOut fees are charged on the coin
+// received from the swaps and paid by the post swap action, which errors if less than the
+// min_coin is left after the fees. ActualOut fees require an exact in user swap.
+#[cw_serde]
+pub enum AffiliateFeeBasis {
+    MinCoin,
+    ActualOut,
+}
+
+// The ClaimDestination enum is used to specify where the accrued affiliate fees of a denom are
+// claimed to. Local sends them to an address on the current chain, Ibc transfers them through
+// the named ibc transfer adapter, or the default adapter if none is given. The IBC fee, if any,
+// is deducted from the claimed fees and must be the same denom.
+#[cw_serde]
+pub enum ClaimDestination {
+    Local {
+        to_address: String,
+    },
+    Ibc {
+        ibc_info: IbcInfo,
+        ibc_transfer_adapter: Option<String>,
+        timeout_timestamp: u64,
+    },
+}
+
+// The maximum total affiliate fee of a single SwapAndAction call the owner can set, in basis
+// points. It is also the maximum total affiliate fee until the owner sets one.
+pub const MAX_TOTAL_AFFILIATE_BASIS_POINTS_FEE: Uint128 = Uint128::new(5000);
+
+// The RegisteredAffiliate struct is an affiliate partner registered by the owner, which
+// callers can refer to by id instead of address. The id cannot be a bech32 address.
+#[cw_serde]
+pub struct RegisteredAffiliate {
+    pub id: String,
+    pub address: String,
+    pub default_basis_points_fee: Uint128,
+    pub max_basis_points_fee: Uint128,
+}
+
+// The AffiliateFee struct is an affiliate fee paid to an affiliate address.
+#[cw_serde]
+pub struct AffiliateFee {
     pub address: String,
+    pub coin: Coin,
+}
+
+// The maximum protocol fee the owner can set, in basis points.
+pub const MAX_PROTOCOL_FEE_BASIS_POINTS: Uint128 = Uint128::new(100);
+
+// The ProtocolFee struct is the owner set BPS fee taken from the min_coin of every SwapAndAction
+// call and sent to the collector address, along with (and not removable like) the affiliate fees.
+#[cw_serde]
+pub struct ProtocolFee {
+    pub basis_points_fee: Uint128,
+    pub collector_address: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::from_slice;
+
+    #[test]
+    fn test_affiliate_deserialize() {
+        // TEST CASE 1: Affiliate Without An Id Deserializes As Before Registered Affiliates
+        assert_eq!(
+            from_slice::<Affiliate>(br#"{"basis_points_fee":"10","address":"affiliate"}"#).unwrap(),
+            Affiliate {
+                basis_points_fee: Some(Uint128::new(10)),
+                address: "affiliate".to_string(),
+                id: None,
+            }
+        );
+
+        // TEST CASE 2: Registered Affiliate With Only An Id Defaults The Fee And Address
+        assert_eq!(
+            from_slice::<Affiliate>(br#"{"id":"partner"}"#).unwrap(),
+            Affiliate {
+                basis_points_fee: None,
+                address: String::new(),
+                id: Some("partner".to_string()),
+            }
+        );
+    }
 }
@@ -13,6 +13,19 @@ pub enum SkipError {
     #[error("Unauthorized")]
     Unauthorized,
 
+    ///////////////
+    /// MIGRATE ///
+    ///////////////
+
+    #[error("Cannot Migrate From Contract {stored} To Contract {new}")]
+    MigrationContractMismatch { stored: String, new: String },
+
+    #[error("Cannot Migrate From Version {stored} To Older Version {new}")]
+    MigrationDowngrade { stored: String, new: String },
+
+    #[error("Invalid Contract Version {version}: {reason}")]
+    InvalidContractVersion { version: String, reason: String },
+
     ////////////
     /// SWAP ///
     ////////////
@@ -26,10 +39,90 @@ pub enum SkipError {
     #[error("Last Swap Operations' Denom Out Differs From Swap Coin Out Denom")]
     SwapOperationsCoinOutDenomMismatch,
 
+    #[error("Split Swap Legs Empty")]
+    SplitSwapLegsEmpty,
+
+    #[error("Split Swap Leg Share Must Be Greater Than Zero")]
+    SplitSwapLegShareZero,
+
+    #[error("Split Swap Leg Shares Must Sum To One")]
+    SplitSwapSharesNotOne,
+
+    #[error("Best Swap Routes Empty")]
+    BestSwapRoutesEmpty,
+
+    #[error("Provide Liquidity Pool Must Have Two Assets, One Of Which Is The Coin In Denom")]
+    ProvideLiquidityPoolDenomMismatch,
+
+    #[error("Provide Liquidity Coin In Amount Too Small To Swap Into Both Pool Assets")]
+    ProvideLiquidityCoinInTooSmall,
+
+    #[error("Provide Liquidity Slippage Tolerance Must Not Be Greater Than One")]
+    ProvideLiquiditySlippageToleranceTooHigh,
+
+    #[error("Provide Liquidity LP Tokens Out Less Than The Min LP Out")]
+    ProvideLiquidityLpOutLessThanMin,
+
+    #[error("Withdraw Liquidity Coin In Must Be The Pool's LP Token")]
+    WithdrawLiquidityCoinInNotPoolShare,
+
+    #[error("Withdraw Liquidity Pool Assets Must Be The Expected Denoms Out")]
+    WithdrawLiquidityPoolDenomMismatch,
+
     ///////////
     /// IBC ///
     ///////////
 
     #[error("Ibc Fees Are Not A Single Coin, Either Multiple Denoms Or No Coin Specified")]
     IbcFeesNotOneCoin,
+
+    #[error("Invalid Bech32 Address: {0}")]
+    InvalidBech32Address(String),
+
+    ////////////
+    /// MEMO ///
+    ////////////
+
+    #[error("Invalid Memo Json: {0}")]
+    InvalidMemoJson(String),
+
+    #[error("Memo Is Not A Json Object")]
+    MemoNotJsonObject,
+
+    #[error("Memo Cannot Both Forward And Call A Contract")]
+    MemoForwardAndWasm,
+
+    #[error("Memo Field {0} Cannot Be Empty")]
+    MemoEmptyField(String),
+
+    #[error("Invalid Memo Forward Channel: {0}")]
+    MemoInvalidChannel(String),
+
+    #[error("Invalid Memo Forward Timeout: {0}")]
+    MemoInvalidTimeout(String),
+
+    #[error("Memo Wasm Msg Is Not A Json Object")]
+    MemoWasmMsgNotObject,
+
+    ////////////////
+    /// TEMPLATE ///
+    ////////////////
+
+    #[error("Invalid Msg Template Json: {0}")]
+    InvalidMsgTemplateJson(String),
+
+    #[error("Msg Templates Cannot Be Empty")]
+    MsgTemplatesEmpty,
+
+    #[error("Msg Template Path Not Found: {0}")]
+    MsgTemplatePathNotFound(String),
+
+    #[error("Msg Template Path Is Not The Template Value's Placeholder: {0}")]
+    MsgTemplatePathNotPlaceholder(String),
+
+    #[error("Msg Template Path Declared More Than Once: {0}")]
+    MsgTemplatePathDuplicate(String),
+
+    #[error("Msg Has A Template Placeholder At An Undeclared Path")]
+    MsgTemplatePlaceholderNotDeclared,
 }
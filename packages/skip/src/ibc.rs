@@ -2,9 +2,15 @@ use crate::{error::SkipError, proto_coin::ProtoCoin};
 
 use std::convert::From;
 
+use bech32::{FromBase32, ToBase32, Variant};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Coin, Coins, StdError};
+use cosmwasm_std::{Addr, Coin, Coins, StdError};
 use neutron_proto::neutron::feerefunder::Fee as NeutronFee;
+use sha2::{Digest, Sha256};
+
+// The prefix the ibc-hooks module hashes with the packet's channel and original sender
+// to derive the intermediate sender of the wasm hooks it executes.
+pub const IBC_HOOKS_SENDER_PREFIX: &str = "ibc-wasm-hook-intermediary";
 
 ///////////////////
 /// INSTANTIATE ///
@@ -27,6 +33,7 @@ pub enum ExecuteMsg {
         info: IbcInfo,
         coin: Coin,
         timeout_timestamp: u64,
+        callback_address: Option<String>,
     },
 }
 
@@ -38,6 +45,9 @@ pub enum ExecuteMsg {
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
+    // Config returns the entry point contract address
+    #[returns(ConfigResponse)]
+    Config {},
     #[returns(String)]
     InProgressRecoverAddress {
         channel_id: String,
@@ -45,10 +55,24 @@ pub enum QueryMsg {
     },
 }
 
+// The ConfigResponse struct is the response to the Config query.
+#[cw_serde]
+pub struct ConfigResponse {
+    pub entry_point_contract_address: Addr,
+}
+
 ////////////////////
 /// COMMON TYPES ///
 ////////////////////
 
+// The IbcTransferAdapter struct defines the name and contract address of an
+// IBC Transfer Adapter contract registered in the entry point contract.
+#[cw_serde]
+pub struct IbcTransferAdapter {
+    pub name: String,
+    pub adapter_contract_address: String,
+}
+
 // The IbcFee struct defines the fees for an IBC transfer standardized across all IBC Transfer Adapter contracts.
 #[cw_serde]
 #[derive(Default)]
@@ -127,11 +151,13 @@ pub struct IbcInfo {
 }
 
 // The IbcTransfer struct defines the parameters for an IBC transfer standardized across all IBC Transfer Adapter contracts.
+// If a callback address is given, it is sent a SkipCallback once the transfer is acknowledged or times out.
 #[cw_serde]
 pub struct IbcTransfer {
     pub info: IbcInfo,
     pub coin: Coin,
     pub timeout_timestamp: u64,
+    pub callback_address: Option<String>,
 }
 
 // Converts an IbcTransfer struct to an ExecuteMsg::IbcTransfer enum
@@ -141,6 +167,7 @@ impl From<IbcTransfer> for ExecuteMsg {
             info: ibc_transfer.info,
             coin: ibc_transfer.coin,
             timeout_timestamp: ibc_transfer.timeout_timestamp,
+            callback_address: ibc_transfer.callback_address,
         }
     }
 }
@@ -173,11 +200,89 @@ pub enum IbcLifecycleComplete {
     },
 }
 
+// Derives the intermediate sender the ibc-hooks module executes a wasm hook with, for a packet
+// received on the channel (the channel on this chain) from the original sender on the origin
+// chain, the same way as the ibc-hooks module: bech32(prefix, sha256(sha256(IBC_HOOKS_SENDER_PREFIX)
+// || "{channel}/{original_sender}")).
+pub fn derive_ibc_hooks_sender(
+    channel: &str,
+    original_sender: &str,
+    bech32_prefix: &str,
+) -> Result<String, SkipError> {
+    let sender_prefix_hash = Sha256::digest(IBC_HOOKS_SENDER_PREFIX.as_bytes());
+
+    let sender_hash = Sha256::new()
+        .chain_update(sender_prefix_hash)
+        .chain_update(format!("{channel}/{original_sender}").as_bytes())
+        .finalize();
+
+    bech32::encode(bech32_prefix, sender_hash.to_base32(), Variant::Bech32)
+        .map_err(|err| SkipError::InvalidBech32Address(err.to_string()))
+}
+
+// Returns the bech32 prefix of the address, erroring if it is not a valid bech32 address
+pub fn bech32_prefix(address: &str) -> Result<String, SkipError> {
+    let (prefix, data, _) =
+        bech32::decode(address).map_err(|err| SkipError::InvalidBech32Address(err.to_string()))?;
+
+    // Verify the address data is valid base32
+    Vec::<u8>::from_base32(&data)
+        .map_err(|err| SkipError::InvalidBech32Address(err.to_string()))?;
+
+    Ok(prefix)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use cosmwasm_std::Uint128;
 
+    #[test]
+    fn test_derive_ibc_hooks_sender() {
+        // TEST CASE 1: Derives The Intermediate Sender
+        assert_eq!(
+            derive_ibc_hooks_sender(
+                "channel-0",
+                "cosmos1qnufjmd8vwm6j6d3q28wxqr4d8408f34f99rlj",
+                "osmo"
+            )
+            .unwrap(),
+            "osmo10ey3rve5ftljqadewalu3umgl73uetcf29jkqcjw05d3amarpyfs3s2d7z"
+        );
+
+        // TEST CASE 2: Different Channel Derives A Different Sender
+        assert_ne!(
+            derive_ibc_hooks_sender(
+                "channel-1",
+                "cosmos1qnufjmd8vwm6j6d3q28wxqr4d8408f34f99rlj",
+                "osmo"
+            )
+            .unwrap(),
+            "osmo10ey3rve5ftljqadewalu3umgl73uetcf29jkqcjw05d3amarpyfs3s2d7z"
+        );
+
+        // TEST CASE 3: Invalid Bech32 Prefix
+        assert!(matches!(
+            derive_ibc_hooks_sender("channel-0", "cosmos1sender", ""),
+            Err(SkipError::InvalidBech32Address(_))
+        ));
+    }
+
+    #[test]
+    fn test_bech32_prefix() {
+        // TEST CASE 1: Valid Bech32 Address
+        assert_eq!(
+            bech32_prefix("cosmos1qnufjmd8vwm6j6d3q28wxqr4d8408f34f99rlj").unwrap(),
+            "cosmos"
+        );
+
+        // TEST CASE 2: Invalid Bech32 Address
+        assert!(matches!(
+            bech32_prefix("entry_point"),
+            Err(SkipError::InvalidBech32Address(_))
+        ));
+    }
+
     #[test]
     fn test_from_ibc_fee_for_neutron_proto_fee() {
         let ibc_fee = IbcFee {
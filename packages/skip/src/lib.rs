@@ -1,6 +1,12 @@
+pub mod asset;
+pub mod callback;
 pub mod entry_point;
 pub mod error;
 pub mod ibc;
+pub mod memo;
+pub mod migrate;
 pub mod proto_coin;
+pub mod staking;
 pub mod sudo;
 pub mod swap;
+pub mod template;
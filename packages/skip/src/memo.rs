@@ -0,0 +1,495 @@
+use crate::error::SkipError;
+use cosmwasm_schema::serde::{Deserialize, Deserializer, Serialize};
+use serde_cw_value::Value;
+use std::collections::BTreeMap;
+
+// Go duration units accepted by the packet forward middleware timeout,
+// ordered so that multi character units are matched before their prefixes
+const DURATION_UNITS: [&str; 7] = ["ns", "us", "µs", "ms", "s", "m", "h"];
+
+////////////
+/// MEMO ///
+////////////
+
+// The Memo struct is the typed representation of the json memo included in
+// an ICS-20 transfer. It supports:
+// - Packet forward middleware forwards through the "forward" key
+// - IBC hooks contract calls through the "wasm" key
+// - IBC hooks ack / timeout callbacks through the "ibc_callback" key
+//
+// Any other keys (e.g. other middlewares' or integrators' keys) are not
+// validated and are kept untouched in extra.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(crate = "cosmwasm_schema::serde")]
+pub struct Memo {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forward: Option<ForwardMemo>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ibc_callback: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wasm: Option<WasmMemo>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl Memo {
+    // Creates a memo that forwards the transfer with the packet forward middleware
+    pub fn forward(forward: ForwardMemo) -> Self {
+        Memo {
+            forward: Some(forward),
+            ..Memo::default()
+        }
+    }
+
+    // Creates a memo that calls a contract with ibc hooks
+    pub fn wasm(wasm: WasmMemo) -> Self {
+        Memo {
+            wasm: Some(wasm),
+            ..Memo::default()
+        }
+    }
+
+    // Sets the contract that receives the ibc hooks ack / timeout callback
+    pub fn with_ibc_callback(mut self, contract_address: impl Into<String>) -> Self {
+        self.ibc_callback = Some(contract_address.into());
+        self
+    }
+
+    // Parses and validates a json memo string, an empty string is an empty memo
+    pub fn parse(memo: &str) -> Result<Self, SkipError> {
+        if memo.trim().is_empty() {
+            return Ok(Memo::default());
+        }
+
+        // Error if the memo is not a json object
+        if !is_json_object(memo) {
+            return Err(SkipError::MemoNotJsonObject);
+        }
+
+        let memo: Memo = serde_json_wasm::from_str(memo)
+            .map_err(|err| SkipError::InvalidMemoJson(err.to_string()))?;
+
+        memo.validate()?;
+
+        Ok(memo)
+    }
+
+    // Validates the memo and any memos nested in its forward
+    pub fn validate(&self) -> Result<(), SkipError> {
+        // Error if the memo both forwards the transfer and calls a contract,
+        // since only one of the middlewares can handle the received packet
+        if self.forward.is_some() && self.wasm.is_some() {
+            return Err(SkipError::MemoForwardAndWasm);
+        }
+
+        if let Some(forward) = &self.forward {
+            forward.validate()?;
+        }
+
+        if let Some(wasm) = &self.wasm {
+            wasm.validate()?;
+        }
+
+        if let Some(ibc_callback) = &self.ibc_callback {
+            validate_not_empty("ibc_callback", ibc_callback)?;
+        }
+
+        Ok(())
+    }
+
+    // Serializes the memo to a json string, an empty memo is an empty string.
+    // The memo is converted to a value first so the keys, including the extra
+    // keys, are serialized in the key ordering of a json map.
+    pub fn to_json_string(&self) -> Result<String, SkipError> {
+        if *self == Memo::default() {
+            return Ok(String::new());
+        }
+
+        let value = serde_cw_value::to_value(self)
+            .map_err(|err| SkipError::InvalidMemoJson(err.to_string()))?;
+
+        serde_json_wasm::to_string(&value)
+            .map_err(|err| SkipError::InvalidMemoJson(err.to_string()))
+    }
+}
+
+// Validates a raw memo string. Memos that are json objects are parsed and validated,
+// any other memo is a plain text memo, which ICS-20 passes through untouched.
+pub fn validate_memo(memo: &str) -> Result<(), SkipError> {
+    if is_json_object(memo) {
+        Memo::parse(memo)?;
+    }
+
+    Ok(())
+}
+
+///////////////
+/// FORWARD ///
+///////////////
+
+// The ForwardMemo struct is the packet forward middleware "forward" block.
+// - timeout is a go duration string (e.g. "10m"), the chain default is used if unset
+// - retries is the number of times the forward is retried on timeout
+// - next is the memo included in the forwarded transfer, allowing multi-hop transfers.
+//   It is accepted as a json object or a json string encoded memo, and serialized as an object
+// - extra holds any other keys, which are kept untouched
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "cosmwasm_schema::serde")]
+pub struct ForwardMemo {
+    pub channel: String,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_next",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub next: Option<Box<Memo>>,
+    pub port: String,
+    pub receiver: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl ForwardMemo {
+    // Creates a forward to the receiver over the given transfer channel
+    pub fn new(receiver: impl Into<String>, channel: impl Into<String>) -> Self {
+        ForwardMemo {
+            channel: channel.into(),
+            next: None,
+            port: "transfer".to_string(),
+            receiver: receiver.into(),
+            retries: None,
+            timeout: None,
+            extra: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_timeout(mut self, timeout: impl Into<String>) -> Self {
+        self.timeout = Some(timeout.into());
+        self
+    }
+
+    pub fn with_retries(mut self, retries: u8) -> Self {
+        self.retries = Some(retries);
+        self
+    }
+
+    pub fn with_next(mut self, next: Memo) -> Self {
+        self.next = Some(Box::new(next));
+        self
+    }
+
+    // Validates the forward and its next memo
+    pub fn validate(&self) -> Result<(), SkipError> {
+        validate_not_empty("forward.receiver", &self.receiver)?;
+        validate_not_empty("forward.port", &self.port)?;
+
+        // Error if the channel is not of the form "channel-<number>"
+        match self.channel.strip_prefix("channel-") {
+            Some(id) if !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()) => (),
+            _ => return Err(SkipError::MemoInvalidChannel(self.channel.clone())),
+        }
+
+        if let Some(timeout) = &self.timeout {
+            if !is_go_duration(timeout) {
+                return Err(SkipError::MemoInvalidTimeout(timeout.clone()));
+            }
+        }
+
+        if let Some(next) = &self.next {
+            next.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+////////////
+/// WASM ///
+////////////
+
+// The WasmMemo struct is the ibc hooks "wasm" block, executing
+// the msg on the contract when the transfer is received.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "cosmwasm_schema::serde", deny_unknown_fields)]
+pub struct WasmMemo {
+    pub contract: String,
+    pub msg: Value,
+}
+
+impl WasmMemo {
+    // Creates a wasm memo executing the serialized msg on the contract
+    pub fn new(contract: impl Into<String>, msg: &impl Serialize) -> Result<Self, SkipError> {
+        let wasm = WasmMemo {
+            contract: contract.into(),
+            msg: serde_cw_value::to_value(msg)
+                .map_err(|err| SkipError::InvalidMemoJson(err.to_string()))?,
+        };
+
+        wasm.validate()?;
+
+        Ok(wasm)
+    }
+
+    pub fn validate(&self) -> Result<(), SkipError> {
+        validate_not_empty("wasm.contract", &self.contract)?;
+
+        // Error if the msg is not a json object, ibc hooks can only execute contract msgs
+        if !matches!(self.msg, Value::Map(_)) {
+            return Err(SkipError::MemoWasmMsgNotObject);
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////
+/// HELPER FUNCTIONS ///
+////////////////////////
+
+fn is_json_object(memo: &str) -> bool {
+    memo.trim_start().starts_with('{')
+}
+
+// Deserializes a forward's next memo, given either as a json object
+// or as a json string encoding the memo (as the packet forward middleware accepts)
+fn deserialize_next<'de, D>(deserializer: D) -> Result<Option<Box<Memo>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(crate = "cosmwasm_schema::serde", untagged)]
+    enum Next {
+        Memo(Box<Memo>),
+        Json(String),
+    }
+
+    match Option::<Next>::deserialize(deserializer)? {
+        Some(Next::Memo(memo)) => Ok(Some(memo)),
+        Some(Next::Json(json)) => serde_json_wasm::from_str(&json)
+            .map(Some)
+            .map_err(cosmwasm_schema::serde::de::Error::custom),
+        None => Ok(None),
+    }
+}
+
+fn validate_not_empty(field: &str, value: &str) -> Result<(), SkipError> {
+    if value.is_empty() {
+        return Err(SkipError::MemoEmptyField(field.to_string()));
+    }
+
+    Ok(())
+}
+
+// Returns true if the string is a go duration, a sequence of
+// decimal numbers each followed by a unit (e.g. "10m", "1h30m", "1.5s")
+fn is_go_duration(duration: &str) -> bool {
+    let mut rest = duration;
+
+    if rest.is_empty() {
+        return false;
+    }
+
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(rest.len());
+        let number = &rest[..number_len];
+
+        if !number.chars().any(|c| c.is_ascii_digit()) || number.matches('.').count() > 1 {
+            return false;
+        }
+
+        rest = &rest[number_len..];
+
+        match DURATION_UNITS.iter().find(|unit| rest.starts_with(*unit)) {
+            Some(unit) => rest = &rest[unit.len()..],
+            None => return false,
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_memo_parse() {
+        // TEST CASE 1: Empty Memo
+        assert_eq!(Memo::parse("").unwrap(), Memo::default());
+
+        // TEST CASE 2: Forward Memo With Nested Wasm Memo
+        let memo = Memo::parse(
+            r#"{"forward":{"channel":"channel-1","next":{"wasm":{"contract":"contract","msg":{"swap":{}}}},"port":"transfer","receiver":"receiver","retries":2,"timeout":"10m"}}"#,
+        )
+        .unwrap();
+
+        let mut swap_msg = BTreeMap::new();
+        swap_msg.insert(
+            Value::String("swap".to_string()),
+            Value::Map(BTreeMap::new()),
+        );
+
+        assert_eq!(
+            memo,
+            Memo::forward(
+                ForwardMemo::new("receiver", "channel-1")
+                    .with_timeout("10m")
+                    .with_retries(2)
+                    .with_next(Memo::wasm(WasmMemo {
+                        contract: "contract".to_string(),
+                        msg: Value::Map(swap_msg),
+                    }))
+            )
+        );
+
+        // TEST CASE 3: Invalid Json
+        assert!(matches!(
+            Memo::parse("{invalid}"),
+            Err(SkipError::InvalidMemoJson(_))
+        ));
+
+        // TEST CASE 4: Not A Json Object
+        assert_eq!(Memo::parse("memo"), Err(SkipError::MemoNotJsonObject));
+
+        // TEST CASE 5: Unknown Key
+        let mut extra = BTreeMap::new();
+        extra.insert("autopilot".to_string(), Value::String("value".to_string()));
+        assert_eq!(
+            Memo::parse(r#"{"autopilot":"value"}"#).unwrap(),
+            Memo {
+                extra,
+                ..Memo::default()
+            }
+        );
+
+        // TEST CASE 6: Forward And Wasm
+        assert_eq!(
+            Memo::parse(
+                r#"{"forward":{"channel":"channel-1","port":"transfer","receiver":"receiver"},"wasm":{"contract":"contract","msg":{}}}"#
+            ),
+            Err(SkipError::MemoForwardAndWasm)
+        );
+
+        // TEST CASE 7: Invalid Channel In Nested Forward
+        assert_eq!(
+            Memo::parse(
+                r#"{"forward":{"channel":"channel-1","next":{"forward":{"channel":"transfer-2","port":"transfer","receiver":"receiver"}},"port":"transfer","receiver":"receiver"}}"#
+            ),
+            Err(SkipError::MemoInvalidChannel("transfer-2".to_string()))
+        );
+
+        // TEST CASE 8: Wasm Msg Not A Json Object
+        assert_eq!(
+            Memo::parse(r#"{"wasm":{"contract":"contract","msg":"swap"}}"#),
+            Err(SkipError::MemoWasmMsgNotObject)
+        );
+
+        // TEST CASE 9: Empty Receiver
+        assert_eq!(
+            Memo::parse(r#"{"forward":{"channel":"channel-1","port":"transfer","receiver":""}}"#),
+            Err(SkipError::MemoEmptyField("forward.receiver".to_string()))
+        );
+
+        // TEST CASE 10: Forward With Json String Encoded Next
+        assert_eq!(
+            Memo::parse(
+                r#"{"forward":{"channel":"channel-1","next":"{\"forward\":{\"channel\":\"channel-2\",\"port\":\"transfer\",\"receiver\":\"receiver_2\"}}","port":"transfer","receiver":"receiver"}}"#
+            )
+            .unwrap(),
+            Memo::forward(
+                ForwardMemo::new("receiver", "channel-1")
+                    .with_next(Memo::forward(ForwardMemo::new("receiver_2", "channel-2")))
+            )
+        );
+
+        // TEST CASE 11: Invalid Channel In Json String Encoded Next
+        assert_eq!(
+            Memo::parse(
+                r#"{"forward":{"channel":"channel-1","next":"{\"forward\":{\"channel\":\"transfer-2\",\"port\":\"transfer\",\"receiver\":\"receiver_2\"}}","port":"transfer","receiver":"receiver"}}"#
+            ),
+            Err(SkipError::MemoInvalidChannel("transfer-2".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_memo_to_json_string() {
+        // TEST CASE 1: Empty Memo
+        assert_eq!(Memo::default().to_json_string().unwrap(), "");
+
+        // TEST CASE 2: Ibc Callback
+        assert_eq!(
+            Memo::default()
+                .with_ibc_callback("contract")
+                .to_json_string()
+                .unwrap(),
+            r#"{"ibc_callback":"contract"}"#
+        );
+
+        // TEST CASE 3: Forward Memo Round Trip
+        let memo = Memo::forward(
+            ForwardMemo::new("receiver", "channel-1")
+                .with_retries(2)
+                .with_next(Memo::forward(ForwardMemo::new("receiver_2", "channel-2"))),
+        )
+        .with_ibc_callback("contract");
+
+        let json = memo.to_json_string().unwrap();
+        assert_eq!(
+            json,
+            r#"{"forward":{"channel":"channel-1","next":{"forward":{"channel":"channel-2","port":"transfer","receiver":"receiver_2"}},"port":"transfer","receiver":"receiver","retries":2},"ibc_callback":"contract"}"#
+        );
+        assert_eq!(Memo::parse(&json).unwrap(), memo);
+
+        // TEST CASE 4: Unknown Keys Round Trip
+        let json = r#"{"autopilot":{"receiver":"receiver","stakeibc":{"action":"LiquidStake"}},"dest_callback":{"address":"contract"},"forward":{"channel":"channel-1","custom":[1,"two"],"port":"transfer","receiver":"receiver"},"src_callback":{"address":"contract","gas_limit":"100000"}}"#;
+        assert_eq!(Memo::parse(json).unwrap().to_json_string().unwrap(), json);
+
+        // TEST CASE 5: Unknown Keys Kept When Setting The Ibc Callback
+        assert_eq!(
+            Memo::parse(
+                r#"{"src_callback":{"address":"contract"},"wasm":{"contract":"contract","msg":{}}}"#
+            )
+            .unwrap()
+            .with_ibc_callback("adapter")
+            .to_json_string()
+            .unwrap(),
+            r#"{"ibc_callback":"adapter","src_callback":{"address":"contract"},"wasm":{"contract":"contract","msg":{}}}"#
+        );
+    }
+
+    #[test]
+    fn test_validate_memo() {
+        // TEST CASE 1: Plain Text Memo
+        assert_eq!(validate_memo("memo"), Ok(()));
+
+        // TEST CASE 2: Valid Json Memo
+        assert_eq!(validate_memo(r#"{"ibc_callback":"contract"}"#), Ok(()));
+
+        // TEST CASE 3: Invalid Json Memo
+        assert!(matches!(
+            validate_memo("{invalid}"),
+            Err(SkipError::InvalidMemoJson(_))
+        ));
+    }
+
+    #[test]
+    fn test_is_go_duration() {
+        // TEST CASE 1: Valid Durations
+        for duration in ["10m", "1h30m", "1.5s", "300ms", "100µs", "0s"] {
+            assert!(is_go_duration(duration), "{duration}");
+        }
+
+        // TEST CASE 2: Invalid Durations
+        for duration in ["", "10", "m", "1.2.3s", "10 minutes", "-1s"] {
+            assert!(!is_go_duration(duration), "{duration}");
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use crate::error::SkipError;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Storage;
+use cw2::CONTRACT;
+use semver::Version;
+
+// The version deployed before the contracts started storing their cw2 contract
+// name and version. Contracts deployed at that version have no cw2 info in storage.
+pub const UNTRACKED_CONTRACT_VERSION: &str = "0.2.1";
+
+///////////////
+/// MIGRATE ///
+///////////////
+
+// The MigrateMsg struct defines the migration parameters for all Skip API contracts.
+#[cw_serde]
+pub struct MigrateMsg {}
+
+////////////////////////
+/// COMMON FUNCTIONS ///
+////////////////////////
+
+// Validates a migration to the given contract name and version, returning the version
+// being migrated from. Errors if the stored contract name differs from the new contract
+// name or if the new version is older than the stored version.
+//
+// Contracts deployed before cw2 version tracking have no contract info stored, so they
+// are assumed to be on UNTRACKED_CONTRACT_VERSION if `has_untracked_state` is true
+// (i.e. the caller recognized its own state in storage), otherwise the migration errors.
+pub fn validate_migration(
+    storage: &dyn Storage,
+    contract_name: &str,
+    contract_version: &str,
+    has_untracked_state: bool,
+) -> Result<Version, SkipError> {
+    let new_version = parse_version(contract_version)?;
+
+    // Get the stored contract info, falling back to the untracked version if allowed
+    let Some(stored) = CONTRACT.may_load(storage)? else {
+        if !has_untracked_state {
+            return Err(SkipError::MigrationContractMismatch {
+                stored: "unknown".to_string(),
+                new: contract_name.to_string(),
+            });
+        }
+
+        return parse_version(UNTRACKED_CONTRACT_VERSION);
+    };
+
+    // Error if the contract being migrated is a different contract
+    if stored.contract != contract_name {
+        return Err(SkipError::MigrationContractMismatch {
+            stored: stored.contract,
+            new: contract_name.to_string(),
+        });
+    }
+
+    // Error if the new version is older than the stored version
+    let stored_version = parse_version(&stored.version)?;
+    if new_version < stored_version {
+        return Err(SkipError::MigrationDowngrade {
+            stored: stored.version,
+            new: contract_version.to_string(),
+        });
+    }
+
+    Ok(stored_version)
+}
+
+// Parses a semver version, converting the semver error into a SkipError
+fn parse_version(version: &str) -> Result<Version, SkipError> {
+    Version::parse(version).map_err(|err| SkipError::InvalidContractVersion {
+        version: version.to_string(),
+        reason: err.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cosmwasm_std::testing::MockStorage;
+    use cw2::set_contract_version;
+
+    #[test]
+    fn test_validate_migration() {
+        // TEST CASE 1: Upgrade From Older Version
+        let mut storage = MockStorage::new();
+        set_contract_version(&mut storage, "skip-api-entry-point", "0.2.1").unwrap();
+
+        let result = validate_migration(&storage, "skip-api-entry-point", "0.3.0", false);
+
+        assert_eq!(result.unwrap(), Version::new(0, 2, 1));
+
+        // TEST CASE 2: Same Version
+        let result = validate_migration(&storage, "skip-api-entry-point", "0.2.1", false);
+
+        assert_eq!(result.unwrap(), Version::new(0, 2, 1));
+
+        // TEST CASE 3: Downgrade
+        let result = validate_migration(&storage, "skip-api-entry-point", "0.2.0", false);
+
+        assert_eq!(
+            result.unwrap_err(),
+            SkipError::MigrationDowngrade {
+                stored: "0.2.1".to_string(),
+                new: "0.2.0".to_string(),
+            }
+        );
+
+        // TEST CASE 4: Different Contract
+        let result =
+            validate_migration(&storage, "skip-api-swap-adapter-astroport", "0.3.0", false);
+
+        assert_eq!(
+            result.unwrap_err(),
+            SkipError::MigrationContractMismatch {
+                stored: "skip-api-entry-point".to_string(),
+                new: "skip-api-swap-adapter-astroport".to_string(),
+            }
+        );
+
+        // TEST CASE 5: Untracked Contract With Recognized State
+        let storage = MockStorage::new();
+
+        let result = validate_migration(&storage, "skip-api-entry-point", "0.3.0", true);
+
+        assert_eq!(result.unwrap(), Version::new(0, 2, 1));
+
+        // TEST CASE 6: Untracked Contract Without Recognized State
+        let result = validate_migration(&storage, "skip-api-entry-point", "0.2.1", false);
+
+        assert_eq!(
+            result.unwrap_err(),
+            SkipError::MigrationContractMismatch {
+                stored: "unknown".to_string(),
+                new: "skip-api-entry-point".to_string(),
+            }
+        );
+
+        // TEST CASE 7: Invalid Stored Version
+        let mut storage = MockStorage::new();
+        set_contract_version(&mut storage, "skip-api-entry-point", "v1").unwrap();
+
+        let result = validate_migration(&storage, "skip-api-entry-point", "0.2.1", false);
+
+        assert!(matches!(
+            result.unwrap_err(),
+            SkipError::InvalidContractVersion { .. }
+        ));
+    }
+}
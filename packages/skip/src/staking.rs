@@ -0,0 +1,55 @@
+use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as CosmosSdkCoin;
+use cosmwasm_std::CosmosMsg;
+use prost::Message;
+
+pub const MSG_TOKENIZE_SHARES_TYPE_URL: &str = "/cosmos.staking.v1beta1.MsgTokenizeShares";
+
+// The MsgTokenizeShares struct is the liquid staking module message that tokenizes the delegator's
+// delegation to the validator into liquid staking share tokens owned by the tokenized share owner.
+// It is not part of the cosmos sdk protos, as only chains with the liquid staking module support it.
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgTokenizeShares {
+    #[prost(string, tag = "1")]
+    pub delegator_address: String,
+    #[prost(string, tag = "2")]
+    pub validator_address: String,
+    #[prost(message, optional, tag = "3")]
+    pub amount: Option<CosmosSdkCoin>,
+    #[prost(string, tag = "4")]
+    pub tokenized_share_owner: String,
+}
+
+// Converts a MsgTokenizeShares to a stargate CosmosMsg
+impl From<MsgTokenizeShares> for CosmosMsg {
+    fn from(msg: MsgTokenizeShares) -> Self {
+        CosmosMsg::Stargate {
+            type_url: MSG_TOKENIZE_SHARES_TYPE_URL.to_string(),
+            value: msg.encode_to_vec().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_msg_tokenize_shares_for_cosmos_msg() {
+        let msg = MsgTokenizeShares {
+            delegator_address: "delegator".to_string(),
+            validator_address: "validator".to_string(),
+            amount: Some(CosmosSdkCoin {
+                denom: "uatom".to_string(),
+                amount: "100".to_string(),
+            }),
+            tokenized_share_owner: "owner".to_string(),
+        };
+
+        // TEST CASE 1: Stargate Message Decodes To The Tokenize Shares Message
+        let CosmosMsg::Stargate { type_url, value } = CosmosMsg::from(msg.clone()) else {
+            panic!("expected a stargate message");
+        };
+        assert_eq!(type_url, MSG_TOKENIZE_SHARES_TYPE_URL);
+        assert_eq!(MsgTokenizeShares::decode(value.as_slice()).unwrap(), msg);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::error::SkipError;
+use crate::{asset::cw20_contract_address, error::SkipError};
 
 use std::{
     convert::{From, TryFrom},
@@ -7,11 +7,18 @@ use std::{
 
 use astroport::{asset::AssetInfo, router::SwapOperation as AstroportSwapOperation};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, BankMsg, Coin, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{
+    Addr, BankMsg, Coin, Decimal, DepsMut, Env, MessageInfo, Response, StdError, Uint128, Uint256,
+};
+use cw20::Cw20ReceiveMsg;
 use osmosis_std::types::osmosis::poolmanager::v1beta1::{
     SwapAmountInRoute as OsmosisSwapAmountInRoute, SwapAmountOutRoute as OsmosisSwapAmountOutRoute,
 };
 
+// The number of swap simulations used to search for the swap amount that balances
+// the two assets provided as liquidity, each halving the range the amount is in
+const PROVIDE_LIQUIDITY_SEARCH_STEPS: u32 = 12;
+
 ///////////////////
 /// INSTANTIATE ///
 ///////////////////
@@ -38,11 +45,71 @@ pub struct NeutronInstantiateMsg {
 /////////////////////////
 
 // The ExecuteMsg enum defines the execution message that the swap adapter contracts can handle.
-// Only the Swap message is callable by external users.
+// Only the Swap, SwapExactCoinOut and ProvideLiquidity messages are callable by external users,
+// and Receive accepts a CW20 coin in with either swap message as the CW20 hook message. The optional
+// minimum_receive is enforced by the swap venue on the coin out, and max_spread is only used
+// by Astroport swap adapters. SwapExactCoinOut swaps at most the coin sent to receive exactly
+// coin_out, returning the unused coin in. Swap adapters without native exact out support swap
+// the simulated coin in needed instead, using the coin_out amount as the minimum receive.
+// ProvideLiquidity swaps part of the coin sent into the other asset of the two asset pool, and
+// then provides liquidity with both assets through ProvideLiquidityWithBalances, sending the
+// LP tokens to the recipient if one is given, or else back to the caller. The slippage
+// tolerance sets the minimum receive of the swap from its simulated coin out, and bounds the
+// LP tokens received, by the pair's own slippage tolerance on Astroport swap adapters and by
+// the min_lp_out set from the simulated single asset join on Osmosis swap adapters.
+// WithdrawLiquidity withdraws liquidity from the two asset pool with the LP tokens sent,
+// erroring if the pool's assets are not the denoms out, and sends both withdrawn assets
+// back to the caller.
 #[cw_serde]
 pub enum ExecuteMsg {
-    Swap { operations: Vec<SwapOperation> },
-    TransferFundsBack { swapper: Addr },
+    Swap {
+        operations: Vec<SwapOperation>,
+        minimum_receive: Option<Uint128>,
+        max_spread: Option<Decimal>,
+    },
+    SwapExactCoinOut {
+        operations: Vec<SwapOperation>,
+        coin_out: Coin,
+    },
+    ProvideLiquidity {
+        pool: String,
+        recipient: Option<String>,
+        slippage_tolerance: Decimal,
+    },
+    WithdrawLiquidity {
+        pool: String,
+        denoms_out: Vec<String>,
+    },
+    Receive(Cw20ReceiveMsg),
+    TransferFundsBack {
+        swapper: Addr,
+    },
+    ProvideLiquidityWithBalances {
+        pool: String,
+        denoms: Vec<String>,
+        swapper: Addr,
+        slippage_tolerance: Decimal,
+        min_lp_out: Option<Uint128>,
+    },
+}
+
+// The Cw20HookMsg enum defines the CW20 hook messages the swap adapter contracts accept
+// with a CW20 coin in. The variants serialize the same as the ExecuteMsg variants.
+#[cw_serde]
+pub enum Cw20HookMsg {
+    Swap {
+        operations: Vec<SwapOperation>,
+        minimum_receive: Option<Uint128>,
+        max_spread: Option<Decimal>,
+    },
+    SwapExactCoinOut {
+        operations: Vec<SwapOperation>,
+        coin_out: Coin,
+    },
+    WithdrawLiquidity {
+        pool: String,
+        denoms_out: Vec<String>,
+    },
 }
 
 // Converts a SwapExactCoinIn used in the entry point contract
@@ -51,16 +118,20 @@ impl From<SwapExactCoinIn> for ExecuteMsg {
     fn from(swap: SwapExactCoinIn) -> Self {
         ExecuteMsg::Swap {
             operations: swap.operations,
+            minimum_receive: None,
+            max_spread: None,
         }
     }
 }
 
-// Converts a SwapExactCoinOut used in the entry point contract
+// Converts a SplitSwapLeg used in the entry point contract
 // to a swap adapter Swap execute message
-impl From<SwapExactCoinOut> for ExecuteMsg {
-    fn from(swap: SwapExactCoinOut) -> Self {
+impl From<SplitSwapLeg> for ExecuteMsg {
+    fn from(leg: SplitSwapLeg) -> Self {
         ExecuteMsg::Swap {
-            operations: swap.operations,
+            operations: leg.operations,
+            minimum_receive: None,
+            max_spread: None,
         }
     }
 }
@@ -74,6 +145,10 @@ impl From<SwapExactCoinOut> for ExecuteMsg {
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
+    // Config returns the entry point contract address, and the router
+    // contract address for swap adapters that use a router contract
+    #[returns(ConfigResponse)]
+    Config {},
     // RouterContractAddress returns the address of the router contract
     #[returns(Addr)]
     RouterContractAddress {},
@@ -89,6 +164,120 @@ pub enum QueryMsg {
         coin_in: Coin,
         swap_operations: Vec<SwapOperation>,
     },
+    // SimulateSwapExactCoinOutWithMetadata returns the coin in necessary to receive the
+    // specified coin out, with the prices and venue fees of each swap operation
+    #[returns(SimulateSwapWithMetadataResponse)]
+    SimulateSwapExactCoinOutWithMetadata {
+        coin_out: Coin,
+        swap_operations: Vec<SwapOperation>,
+    },
+    // SimulateSwapExactCoinInWithMetadata returns the coin out received from the
+    // specified coin in, with the prices and venue fees of each swap operation
+    #[returns(SimulateSwapWithMetadataResponse)]
+    SimulateSwapExactCoinInWithMetadata {
+        coin_in: Coin,
+        swap_operations: Vec<SwapOperation>,
+    },
+    // SimulateWithdrawLiquidity returns the assets withdrawn from the two asset pool
+    // by withdrawing liquidity with the specified coin in of the pool's LP token
+    #[returns(Vec<Coin>)]
+    SimulateWithdrawLiquidity { pool: String, coin_in: Coin },
+}
+
+// The ConfigResponse struct is the response to the Config query.
+#[cw_serde]
+pub struct ConfigResponse {
+    pub entry_point_contract_address: Addr,
+    pub router_contract_address: Option<Addr>,
+}
+
+// The SimulateSwapWithMetadataResponse struct is the response to the simulation with metadata
+// queries. Prices are in denom out per denom in, the spot price is the product of the spot
+// prices of each swap operation, and the price impact is how much lower the execution price
+// is than the spot price, as a fraction of the spot price.
+#[cw_serde]
+pub struct SimulateSwapWithMetadataResponse {
+    pub coin_in: Coin,
+    pub coin_out: Coin,
+    pub execution_price: Decimal,
+    pub spot_price: Decimal,
+    pub price_impact: Decimal,
+    pub operations: Vec<SwapOperationMetadata>,
+}
+
+impl SimulateSwapWithMetadataResponse {
+    // Creates the response from the metadata of each swap operation, in swap order
+    pub fn from_operations(operations: Vec<SwapOperationMetadata>) -> Result<Self, SkipError> {
+        // Get the coin in of the first swap operation and the coin out of the last
+        let (Some(first_op), Some(last_op)) = (operations.first(), operations.last()) else {
+            return Err(SkipError::SwapOperationsEmpty);
+        };
+        let coin_in = first_op.coin_in.clone();
+        let coin_out = last_op.coin_out.clone();
+
+        // Get the spot price of the swap by multiplying the spot price of each swap operation
+        let spot_price = operations
+            .iter()
+            .try_fold(Decimal::one(), |spot_price, op| {
+                spot_price.checked_mul(op.spot_price)
+            })
+            .map_err(StdError::from)?;
+
+        let execution_price = execution_price(&coin_in, &coin_out);
+
+        Ok(SimulateSwapWithMetadataResponse {
+            price_impact: price_impact(execution_price, spot_price),
+            coin_in,
+            coin_out,
+            execution_price,
+            spot_price,
+            operations,
+        })
+    }
+}
+
+// The SwapOperationMetadata struct holds the simulated coins, prices and venue fees of
+// a swap operation. The price impact is relative to the spot price of the pool.
+#[cw_serde]
+pub struct SwapOperationMetadata {
+    pub pool: String,
+    pub coin_in: Coin,
+    pub coin_out: Coin,
+    pub execution_price: Decimal,
+    pub spot_price: Decimal,
+    pub price_impact: Decimal,
+    pub fees: Vec<SwapFee>,
+}
+
+impl SwapOperationMetadata {
+    // Creates the swap operation metadata, calculating the execution price and price impact
+    pub fn new(
+        pool: String,
+        coin_in: Coin,
+        coin_out: Coin,
+        spot_price: Decimal,
+        fees: Vec<SwapFee>,
+    ) -> Self {
+        let execution_price = execution_price(&coin_in, &coin_out);
+
+        SwapOperationMetadata {
+            pool,
+            coin_in,
+            coin_out,
+            execution_price,
+            spot_price,
+            price_impact: price_impact(execution_price, spot_price),
+            fees,
+        }
+    }
+}
+
+// The SwapFee struct is a fee charged by the swap venue on a swap operation,
+// named by the swap venue's fee type (e.g. "commission" or "spread_factor").
+#[cw_serde]
+pub struct SwapFee {
+    pub name: String,
+    pub coin: Coin,
 }
 
 ////////////////////
@@ -119,16 +308,23 @@ impl From<SwapOperation> for AstroportSwapOperation {
     fn from(swap_operation: SwapOperation) -> Self {
         // Convert the swap operation to an astroport swap operation and return it
         AstroportSwapOperation::AstroSwap {
-            offer_asset_info: AssetInfo::NativeToken {
-                denom: swap_operation.denom_in,
-            },
-            ask_asset_info: AssetInfo::NativeToken {
-                denom: swap_operation.denom_out,
-            },
+            offer_asset_info: astroport_asset_info(swap_operation.denom_in),
+            ask_asset_info: astroport_asset_info(swap_operation.denom_out),
         }
     }
 }
 
+// Converts a denom to an astroport asset info, which is a token
+// for CW20 denoms and a native token for all other denoms
+pub fn astroport_asset_info(denom: String) -> AssetInfo {
+    match cw20_contract_address(&denom) {
+        Some(cw20_contract_address) => AssetInfo::Token {
+            contract_addr: Addr::unchecked(cw20_contract_address),
+        },
+        None => AssetInfo::NativeToken { denom },
+    }
+}
+
 // OSMOSIS CONVERSIONS
 
 // Converts a skip swap operation to an osmosis swap amount in route
@@ -186,10 +382,35 @@ pub struct SwapExactCoinIn {
     pub operations: Vec<SwapOperation>,
 }
 
+// Swap leg of a split swap that swaps the given share of the coin in
+// through the swap operations on the given swap venue
+#[cw_serde]
+pub struct SplitSwapLeg {
+    pub swap_venue_name: String,
+    pub operations: Vec<SwapOperation>,
+    pub share: Decimal,
+}
+
+// Swap object that splits the remaining coin received across multiple swap legs,
+// all of which must swap into the same denom. The last leg receives any rounding remainder.
+#[cw_serde]
+pub struct SplitSwapExactCoinIn {
+    pub legs: Vec<SplitSwapLeg>,
+}
+
+// Swap object that simulates each candidate route and swaps the remaining coin
+// received through the route with the largest simulated coin out
+#[cw_serde]
+pub struct BestSwapExactCoinIn {
+    pub routes: Vec<SwapExactCoinIn>,
+}
+
 #[cw_serde]
 pub enum Swap {
     SwapExactCoinIn(SwapExactCoinIn),
     SwapExactCoinOut(SwapExactCoinOut),
+    SplitSwapExactCoinIn(SplitSwapExactCoinIn),
+    BestSwapExactCoinIn(BestSwapExactCoinIn),
 }
 
 ////////////////////////
@@ -208,10 +429,17 @@ pub fn execute_transfer_funds_back(
         return Err(SkipError::Unauthorized);
     }
 
+    // Query the contract's balances, returning early if there is nothing to transfer back,
+    // which happens when the swap sent a CW20 coin out directly to the swapper
+    let balances = deps.querier.query_all_balances(env.contract.address)?;
+    if balances.is_empty() {
+        return Ok(Response::new().add_attribute("action", "transfer_funds_back_no_balance"));
+    }
+
     // Create the bank message send to transfer the contract funds back to the caller
     let transfer_funds_back_msg = BankMsg::Send {
         to_address: swapper.to_string(),
-        amount: deps.querier.query_all_balances(env.contract.address)?,
+        amount: balances,
     };
 
     Ok(Response::new()
@@ -219,6 +447,20 @@ pub fn execute_transfer_funds_back(
         .add_attribute("action", "dispatch_transfer_funds_back_bank_send"))
 }
 
+// Returns the execution price of a swap in coin out per coin in, or zero if the coin in is zero
+pub fn execution_price(coin_in: &Coin, coin_out: &Coin) -> Decimal {
+    Decimal::checked_from_ratio(coin_out.amount, coin_in.amount).unwrap_or_default()
+}
+
+// Returns the price impact of a swap, which is how much lower the execution price is than
+// the spot price as a fraction of the spot price, or zero if the execution price is higher
+pub fn price_impact(execution_price: Decimal, spot_price: Decimal) -> Decimal {
+    execution_price
+        .checked_div(spot_price)
+        .map(|ratio| Decimal::one().saturating_sub(ratio))
+        .unwrap_or_default()
+}
+
 // Validates the swap operations
 pub fn validate_swap_operations(
     swap_operations: &[SwapOperation],
@@ -243,10 +485,212 @@ pub fn validate_swap_operations(
     Ok(())
 }
 
+// Validates the split swap legs, verifying each leg's swap operations
+// and that the leg shares are non zero and sum to one
+pub fn validate_split_swap_legs(
+    legs: &[SplitSwapLeg],
+    coin_in_denom: &str,
+    coin_out_denom: &str,
+) -> Result<(), SkipError> {
+    // Verify the split swap legs are not empty
+    if legs.is_empty() {
+        return Err(SkipError::SplitSwapLegsEmpty);
+    }
+
+    let mut total_share = Decimal::zero();
+    for leg in legs {
+        // Verify the leg's swap operations go from the coin in denom to the coin out denom
+        validate_swap_operations(&leg.operations, coin_in_denom, coin_out_denom)?;
+
+        // Verify the leg's share is not zero
+        if leg.share.is_zero() {
+            return Err(SkipError::SplitSwapLegShareZero);
+        }
+
+        total_share = total_share.checked_add(leg.share).map_err(StdError::from)?;
+    }
+
+    // Verify the leg shares sum to one
+    if total_share != Decimal::one() {
+        return Err(SkipError::SplitSwapSharesNotOne);
+    }
+
+    Ok(())
+}
+
+// Returns the reserve of the coin in denom and the reserve of the other asset of a two asset pool,
+// erroring if the pool does not have exactly two assets or the coin in denom is not one of them
+pub fn provide_liquidity_reserves(
+    pool_reserves: Vec<Coin>,
+    coin_in_denom: &str,
+) -> Result<(Uint128, Coin), SkipError> {
+    let [first, second]: [Coin; 2] = pool_reserves
+        .try_into()
+        .map_err(|_| SkipError::ProvideLiquidityPoolDenomMismatch)?;
+
+    if first.denom == coin_in_denom && second.denom != coin_in_denom {
+        Ok((first.amount, second))
+    } else if second.denom == coin_in_denom && first.denom != coin_in_denom {
+        Ok((second.amount, first))
+    } else {
+        Err(SkipError::ProvideLiquidityPoolDenomMismatch)
+    }
+}
+
+// Validates the pool asset denoms are the denoms out expected from withdrawing liquidity
+pub fn validate_withdraw_liquidity_denoms(
+    mut pool_denoms: Vec<String>,
+    mut denoms_out: Vec<String>,
+) -> Result<(), SkipError> {
+    pool_denoms.sort();
+    denoms_out.sort();
+
+    if pool_denoms != denoms_out {
+        return Err(SkipError::WithdrawLiquidityPoolDenomMismatch);
+    }
+
+    Ok(())
+}
+
+// Returns the amount of the coin in to swap into the pool's other asset before providing
+// liquidity with both. Starting from half of the coin in, the amount is found by binary
+// search using the swap simulation, so that the coin in left and the simulated coin out
+// are in the ratio of the pool reserves after the swap.
+pub fn provide_liquidity_swap_amount<E>(
+    coin_in_amount: Uint128,
+    reserve_in: Uint128,
+    reserve_out: Uint128,
+    simulate_swap: impl Fn(Uint128) -> Result<Uint128, E>,
+) -> Result<Uint128, E> {
+    let mut low = Uint128::zero();
+    let mut high = coin_in_amount;
+
+    for _ in 0..PROVIDE_LIQUIDITY_SEARCH_STEPS {
+        let swap_amount = low + (high - low) / Uint128::new(2);
+        if swap_amount == low {
+            break;
+        }
+
+        let amount_out = simulate_swap(swap_amount)?;
+
+        // Compare the coin in left and the coin out, each multiplied
+        // by the reserve of the other asset after the swap
+        let coin_in_left = Uint256::from(coin_in_amount - swap_amount)
+            .saturating_mul(Uint256::from(reserve_out.saturating_sub(amount_out)));
+        let coin_out = Uint256::from(amount_out)
+            .saturating_mul(Uint256::from(reserve_in).saturating_add(Uint256::from(swap_amount)));
+
+        // Swap more if the coin in left is worth more than the coin out, otherwise swap less
+        if coin_in_left > coin_out {
+            low = swap_amount;
+        } else {
+            high = swap_amount;
+        }
+    }
+
+    Ok(low)
+}
+
+// Returns the minimum amount accepted for the given expected amount with the slippage
+// tolerance, erroring if the slippage tolerance is greater than one
+pub fn provide_liquidity_min_amount(
+    expected_amount: Uint128,
+    slippage_tolerance: Decimal,
+) -> Result<Uint128, SkipError> {
+    let max_slippage = Decimal::one()
+        .checked_sub(slippage_tolerance)
+        .map_err(|_| SkipError::ProvideLiquiditySlippageToleranceTooHigh)?;
+
+    Ok(expected_amount * max_slippage)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use std::str::FromStr;
+
+    #[test]
+    fn test_price_impact() {
+        // TEST CASE 1: Execution Price Lower Than Spot Price
+        assert_eq!(
+            price_impact(
+                Decimal::from_str("1.5").unwrap(),
+                Decimal::from_str("2").unwrap()
+            ),
+            Decimal::from_str("0.25").unwrap()
+        );
+
+        // TEST CASE 2: Execution Price Higher Than Spot Price
+        assert_eq!(
+            price_impact(
+                Decimal::from_str("2.5").unwrap(),
+                Decimal::from_str("2").unwrap()
+            ),
+            Decimal::zero()
+        );
+
+        // TEST CASE 3: Zero Spot Price
+        assert_eq!(
+            price_impact(Decimal::from_str("1.5").unwrap(), Decimal::zero()),
+            Decimal::zero()
+        );
+    }
+
+    #[test]
+    fn test_simulate_swap_with_metadata_response_from_operations() {
+        // TEST CASE 1: Two Swap Operations
+        let operations = vec![
+            SwapOperationMetadata::new(
+                "pool_1".to_string(),
+                Coin::new(100, "uatom"),
+                Coin::new(180, "untrn"),
+                Decimal::from_str("2").unwrap(),
+                vec![SwapFee {
+                    name: "commission".to_string(),
+                    coin: Coin::new(1, "untrn"),
+                }],
+            ),
+            SwapOperationMetadata::new(
+                "pool_2".to_string(),
+                Coin::new(180, "untrn"),
+                Coin::new(90, "uosmo"),
+                Decimal::from_str("0.5").unwrap(),
+                vec![],
+            ),
+        ];
+
+        assert_eq!(
+            operations[0].execution_price,
+            Decimal::from_str("1.8").unwrap()
+        );
+        assert_eq!(
+            operations[0].price_impact,
+            Decimal::from_str("0.1").unwrap()
+        );
+        assert_eq!(operations[1].price_impact, Decimal::zero());
+
+        let res = SimulateSwapWithMetadataResponse::from_operations(operations.clone()).unwrap();
+
+        assert_eq!(
+            res,
+            SimulateSwapWithMetadataResponse {
+                coin_in: Coin::new(100, "uatom"),
+                coin_out: Coin::new(90, "uosmo"),
+                execution_price: Decimal::from_str("0.9").unwrap(),
+                spot_price: Decimal::one(),
+                price_impact: Decimal::from_str("0.1").unwrap(),
+                operations,
+            }
+        );
+
+        // TEST CASE 2: No Swap Operations
+        assert_eq!(
+            SimulateSwapWithMetadataResponse::from_operations(vec![]),
+            Err(SkipError::SwapOperationsEmpty)
+        );
+    }
+
     #[test]
     fn test_from_swap_operation_to_astropot_swap_operation() {
         let swap_operation = SwapOperation {
@@ -268,6 +712,27 @@ mod tests {
                 }
             }
         );
+
+        // CW20 denoms are converted to astroport tokens
+        let swap_operation = SwapOperation {
+            pool: "1".to_string(),
+            denom_in: "cw20:neutron123".to_string(),
+            denom_out: "uosmo".to_string(),
+        };
+
+        let astroport_swap_operation: AstroportSwapOperation = swap_operation.into();
+
+        assert_eq!(
+            astroport_swap_operation,
+            AstroportSwapOperation::AstroSwap {
+                offer_asset_info: AssetInfo::Token {
+                    contract_addr: Addr::unchecked("neutron123")
+                },
+                ask_asset_info: AssetInfo::NativeToken {
+                    denom: "uosmo".to_string()
+                }
+            }
+        );
     }
 
     #[test]
@@ -511,4 +976,204 @@ mod tests {
             SkipError::SwapOperationsCoinOutDenomMismatch
         );
     }
+
+    #[test]
+    fn test_validate_split_swap_legs() {
+        let split_swap_leg = |pool: &str, denom_out: &str, share: &str| SplitSwapLeg {
+            swap_venue_name: "swap_venue_name".to_string(),
+            operations: vec![SwapOperation {
+                pool: pool.to_string(),
+                denom_in: "uatom".to_string(),
+                denom_out: denom_out.to_string(),
+            }],
+            share: share.parse().unwrap(),
+        };
+
+        // TEST CASE 1: Valid Split Swap Legs
+        let legs = vec![
+            split_swap_leg("1", "untrn", "0.6"),
+            split_swap_leg("2", "untrn", "0.4"),
+        ];
+
+        let result = validate_split_swap_legs(&legs, "uatom", "untrn");
+
+        assert!(result.is_ok());
+
+        // TEST CASE 2: Empty Split Swap Legs
+        let result = validate_split_swap_legs(&[], "uatom", "untrn");
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), SkipError::SplitSwapLegsEmpty);
+
+        // TEST CASE 3: Leg Swap Operations Denom Out Mismatch
+        let legs = vec![
+            split_swap_leg("1", "untrn", "0.6"),
+            split_swap_leg("2", "uosmo", "0.4"),
+        ];
+
+        let result = validate_split_swap_legs(&legs, "uatom", "untrn");
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            SkipError::SwapOperationsCoinOutDenomMismatch
+        );
+
+        // TEST CASE 4: Leg Share Zero
+        let legs = vec![
+            split_swap_leg("1", "untrn", "1"),
+            split_swap_leg("2", "untrn", "0"),
+        ];
+
+        let result = validate_split_swap_legs(&legs, "uatom", "untrn");
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), SkipError::SplitSwapLegShareZero);
+
+        // TEST CASE 5: Leg Shares Do Not Sum To One
+        let legs = vec![
+            split_swap_leg("1", "untrn", "0.6"),
+            split_swap_leg("2", "untrn", "0.3"),
+        ];
+
+        let result = validate_split_swap_legs(&legs, "uatom", "untrn");
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), SkipError::SplitSwapSharesNotOne);
+    }
+
+    #[test]
+    fn test_provide_liquidity_reserves() {
+        // TEST CASE 1: Coin In Denom Is The Second Pool Asset
+        assert_eq!(
+            provide_liquidity_reserves(
+                vec![Coin::new(2_000, "uosmo"), Coin::new(1_000, "uatom")],
+                "uatom"
+            ),
+            Ok((Uint128::new(1_000), Coin::new(2_000, "uosmo")))
+        );
+
+        // TEST CASE 2: Coin In Denom Is Not A Pool Asset
+        assert_eq!(
+            provide_liquidity_reserves(
+                vec![Coin::new(2_000, "uosmo"), Coin::new(1_000, "uatom")],
+                "untrn"
+            ),
+            Err(SkipError::ProvideLiquidityPoolDenomMismatch)
+        );
+
+        // TEST CASE 3: Pool With Three Assets
+        assert_eq!(
+            provide_liquidity_reserves(
+                vec![
+                    Coin::new(2_000, "uosmo"),
+                    Coin::new(1_000, "uatom"),
+                    Coin::new(1_000, "untrn")
+                ],
+                "uatom"
+            ),
+            Err(SkipError::ProvideLiquidityPoolDenomMismatch)
+        );
+    }
+
+    #[test]
+    fn test_provide_liquidity_swap_amount() {
+        // Constant product pool simulation without fees
+        let simulate_swap = |reserve_in: u128, reserve_out: u128| {
+            move |amount_in: Uint128| -> Result<Uint128, SkipError> {
+                Ok(Uint128::new(reserve_out)
+                    .multiply_ratio(amount_in, reserve_in + amount_in.u128()))
+            }
+        };
+
+        // TEST CASE 1: Coin In Equal To The Reserve In
+        // The balanced swap amount is sqrt(reserve_in^2 + coin_in * reserve_in) - reserve_in = 414_213
+        let swap_amount = provide_liquidity_swap_amount(
+            Uint128::new(1_000_000),
+            Uint128::new(1_000_000),
+            Uint128::new(4_000_000),
+            simulate_swap(1_000_000, 4_000_000),
+        )
+        .unwrap();
+        assert!(
+            swap_amount.abs_diff(Uint128::new(414_213)) < Uint128::new(250),
+            "{swap_amount}"
+        );
+
+        // TEST CASE 2: Coin In Small Relative To The Reserve In Swaps About Half
+        let swap_amount = provide_liquidity_swap_amount(
+            Uint128::new(1_000),
+            Uint128::new(1_000_000_000),
+            Uint128::new(1_000_000_000),
+            simulate_swap(1_000_000_000, 1_000_000_000),
+        )
+        .unwrap();
+        assert_eq!(swap_amount, Uint128::new(500));
+
+        // TEST CASE 3: Simulation Error
+        assert_eq!(
+            provide_liquidity_swap_amount(
+                Uint128::new(1_000),
+                Uint128::new(1_000),
+                Uint128::new(1_000),
+                |_| Err(SkipError::SwapOperationsEmpty),
+            ),
+            Err(SkipError::SwapOperationsEmpty)
+        );
+    }
+
+    #[test]
+    fn test_provide_liquidity_min_amount() {
+        // TEST CASE 1: One Percent Slippage Tolerance
+        assert_eq!(
+            provide_liquidity_min_amount(Uint128::new(1_000), Decimal::percent(1)),
+            Ok(Uint128::new(990))
+        );
+
+        // TEST CASE 2: Zero Slippage Tolerance
+        assert_eq!(
+            provide_liquidity_min_amount(Uint128::new(1_000), Decimal::zero()),
+            Ok(Uint128::new(1_000))
+        );
+
+        // TEST CASE 3: Slippage Tolerance Greater Than One
+        assert_eq!(
+            provide_liquidity_min_amount(Uint128::new(1_000), Decimal::percent(101)),
+            Err(SkipError::ProvideLiquiditySlippageToleranceTooHigh)
+        );
+    }
+
+    #[test]
+    fn test_validate_withdraw_liquidity_denoms() {
+        // TEST CASE 1: Denoms Out In A Different Order Than The Pool Assets
+        assert_eq!(
+            validate_withdraw_liquidity_denoms(
+                vec!["uosmo".to_string(), "uatom".to_string()],
+                vec!["uatom".to_string(), "uosmo".to_string()]
+            ),
+            Ok(())
+        );
+
+        // TEST CASE 2: Denom Out Not A Pool Asset
+        assert_eq!(
+            validate_withdraw_liquidity_denoms(
+                vec!["uosmo".to_string(), "uatom".to_string()],
+                vec!["uatom".to_string(), "untrn".to_string()]
+            ),
+            Err(SkipError::WithdrawLiquidityPoolDenomMismatch)
+        );
+
+        // TEST CASE 3: Pool With More Assets Than The Denoms Out
+        assert_eq!(
+            validate_withdraw_liquidity_denoms(
+                vec![
+                    "uosmo".to_string(),
+                    "uatom".to_string(),
+                    "untrn".to_string()
+                ],
+                vec!["uatom".to_string(), "uosmo".to_string()]
+            ),
+            Err(SkipError::WithdrawLiquidityPoolDenomMismatch)
+        );
+    }
 }
@@ -0,0 +1,266 @@
+use crate::error::SkipError;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Binary, Coin};
+use serde_cw_value::Value;
+
+// The placeholders that mark the json paths of a templated contract call message.
+// A placeholder must be the whole json string value at a declared template path.
+pub const AMOUNT_PLACEHOLDER: &str = "{{amount}}";
+pub const DENOM_PLACEHOLDER: &str = "{{denom}}";
+
+////////////////
+/// TEMPLATE ///
+////////////////
+
+// The MsgTemplate struct declares a json path of a contract call message that the entry point
+// substitutes with the amount or denom of the coin the contract call is performed with.
+// The path is the list of object keys (or array indices) leading to the value, which must be
+// the placeholder of the declared template value in the message.
+#[cw_serde]
+pub struct MsgTemplate {
+    pub path: Vec<String>,
+    pub value: TemplateValue,
+}
+
+// The TemplateValue enum defines what a MsgTemplate path is substituted with. The amount is
+// substituted as a json string, the way a Uint128 is serialized.
+#[cw_serde]
+pub enum TemplateValue {
+    Amount,
+    Denom,
+}
+
+impl TemplateValue {
+    // Returns the placeholder marking the template value in the message
+    pub fn placeholder(&self) -> &'static str {
+        match self {
+            TemplateValue::Amount => AMOUNT_PLACEHOLDER,
+            TemplateValue::Denom => DENOM_PLACEHOLDER,
+        }
+    }
+
+    // Returns the value substituted into the message for the given coin
+    fn substitute(&self, coin: &Coin) -> String {
+        match self {
+            TemplateValue::Amount => coin.amount.to_string(),
+            TemplateValue::Denom => coin.denom.clone(),
+        }
+    }
+}
+
+// Validates the message templates before the coin is known, so a malformed template errors
+// before the swap instead of after it
+pub fn validate_msg_templates(msg: &Binary, templates: &[MsgTemplate]) -> Result<(), SkipError> {
+    apply_msg_templates(msg, templates, &Coin::new(0, "")).map(|_| ())
+}
+
+// Substitutes the coin's amount and denom into the declared template paths of the message.
+// Errors if the templates are empty, a path is declared twice, a path does not lead to the
+// placeholder of its template value, or the message has a placeholder at an undeclared path,
+// so no part of the message other than the declared paths can change.
+pub fn apply_msg_templates(
+    msg: &Binary,
+    templates: &[MsgTemplate],
+    coin: &Coin,
+) -> Result<Binary, SkipError> {
+    // Error if no templates are declared
+    if templates.is_empty() {
+        return Err(SkipError::MsgTemplatesEmpty);
+    }
+
+    // Parse the message as json
+    let mut value: Value = serde_json_wasm::from_slice(msg.as_slice())
+        .map_err(|err| SkipError::InvalidMsgTemplateJson(err.to_string()))?;
+
+    // Substitute each template path, which must not have been substituted already
+    let mut substituted_paths: Vec<&Vec<String>> = Vec::with_capacity(templates.len());
+    for template in templates {
+        let path = template.path.join(".");
+
+        // Error if the path is declared more than once
+        if substituted_paths.contains(&&template.path) {
+            return Err(SkipError::MsgTemplatePathDuplicate(path));
+        }
+
+        // Get the value at the path, erroring if it does not exist
+        let path_value = value_at_path(&mut value, &template.path)
+            .ok_or_else(|| SkipError::MsgTemplatePathNotFound(path.clone()))?;
+
+        // Error if the value at the path is not the template value's placeholder
+        if *path_value != Value::String(template.value.placeholder().to_string()) {
+            return Err(SkipError::MsgTemplatePathNotPlaceholder(path));
+        }
+
+        *path_value = Value::String(template.value.substitute(coin));
+        substituted_paths.push(&template.path);
+    }
+
+    // Error if a placeholder is left at an undeclared path
+    if contains_placeholder(&value) {
+        return Err(SkipError::MsgTemplatePlaceholderNotDeclared);
+    }
+
+    // Serialize the substituted message back into json
+    let msg = serde_json_wasm::to_vec(&value)
+        .map_err(|err| SkipError::InvalidMsgTemplateJson(err.to_string()))?;
+
+    Ok(msg.into())
+}
+
+// Returns the value at the path of object keys or array indices, if it exists
+fn value_at_path<'a>(value: &'a mut Value, path: &[String]) -> Option<&'a mut Value> {
+    path.iter().try_fold(value, |value, key| match value {
+        Value::Map(map) => map.get_mut(&Value::String(key.clone())),
+        Value::Seq(seq) => key
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| seq.get_mut(index)),
+        _ => None,
+    })
+}
+
+// Returns whether the value or any value nested in it is a placeholder
+fn contains_placeholder(value: &Value) -> bool {
+    match value {
+        Value::String(string) => string == AMOUNT_PLACEHOLDER || string == DENOM_PLACEHOLDER,
+        Value::Map(map) => map
+            .iter()
+            .any(|(key, value)| contains_placeholder(key) || contains_placeholder(value)),
+        Value::Seq(seq) => seq.iter().any(contains_placeholder),
+        Value::Option(Some(value)) | Value::Newtype(value) => contains_placeholder(value),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template(path: &[&str], value: TemplateValue) -> MsgTemplate {
+        MsgTemplate {
+            path: path.iter().map(|key| key.to_string()).collect(),
+            value,
+        }
+    }
+
+    #[test]
+    fn test_apply_msg_templates() {
+        let coin = Coin::new(1_000, "uosmo");
+
+        // TEST CASE 1: Substitutes Amount And Denom, Including In Arrays
+        let msg = Binary::from(
+            br#"{"deposit":{"assets":[{"amount":"{{amount}}","denom":"{{denom}}"}],"receiver":"receiver"}}"#,
+        );
+        let templates = vec![
+            template(&["deposit", "assets", "0", "amount"], TemplateValue::Amount),
+            template(&["deposit", "assets", "0", "denom"], TemplateValue::Denom),
+        ];
+        assert_eq!(
+            apply_msg_templates(&msg, &templates, &coin).unwrap(),
+            Binary::from(
+                br#"{"deposit":{"assets":[{"amount":"1000","denom":"uosmo"}],"receiver":"receiver"}}"#
+            )
+        );
+
+        // TEST CASE 2: Empty Templates
+        assert_eq!(
+            apply_msg_templates(&msg, &[], &coin),
+            Err(SkipError::MsgTemplatesEmpty)
+        );
+
+        // TEST CASE 3: Invalid Json Message
+        assert!(matches!(
+            apply_msg_templates(&Binary::from(b"not json"), &templates, &coin),
+            Err(SkipError::InvalidMsgTemplateJson(_))
+        ));
+
+        // TEST CASE 4: Path Not Found
+        assert_eq!(
+            apply_msg_templates(
+                &msg,
+                &[template(
+                    &["deposit", "assets", "1", "amount"],
+                    TemplateValue::Amount
+                )],
+                &coin
+            ),
+            Err(SkipError::MsgTemplatePathNotFound(
+                "deposit.assets.1.amount".to_string()
+            ))
+        );
+
+        // TEST CASE 5: Path Is Not The Declared Placeholder
+        assert_eq!(
+            apply_msg_templates(
+                &msg,
+                &[
+                    template(&["deposit", "assets", "0", "amount"], TemplateValue::Denom),
+                    template(&["deposit", "assets", "0", "denom"], TemplateValue::Amount),
+                ],
+                &coin
+            ),
+            Err(SkipError::MsgTemplatePathNotPlaceholder(
+                "deposit.assets.0.amount".to_string()
+            ))
+        );
+        assert_eq!(
+            apply_msg_templates(
+                &msg,
+                &[template(&["deposit", "receiver"], TemplateValue::Denom)],
+                &coin
+            ),
+            Err(SkipError::MsgTemplatePathNotPlaceholder(
+                "deposit.receiver".to_string()
+            ))
+        );
+
+        // TEST CASE 6: Duplicate Path
+        assert_eq!(
+            apply_msg_templates(
+                &msg,
+                &[
+                    template(&["deposit", "assets", "0", "amount"], TemplateValue::Amount),
+                    template(&["deposit", "assets", "0", "amount"], TemplateValue::Amount),
+                ],
+                &coin
+            ),
+            Err(SkipError::MsgTemplatePathDuplicate(
+                "deposit.assets.0.amount".to_string()
+            ))
+        );
+
+        // TEST CASE 7: Placeholder At An Undeclared Path
+        assert_eq!(
+            apply_msg_templates(
+                &msg,
+                &[template(
+                    &["deposit", "assets", "0", "amount"],
+                    TemplateValue::Amount
+                )],
+                &coin
+            ),
+            Err(SkipError::MsgTemplatePlaceholderNotDeclared)
+        );
+    }
+
+    #[test]
+    fn test_validate_msg_templates() {
+        let msg = Binary::from(br#"{"send":{"amount":"{{amount}}"}}"#);
+
+        // TEST CASE 1: Valid Templates
+        assert!(validate_msg_templates(
+            &msg,
+            &[template(&["send", "amount"], TemplateValue::Amount)]
+        )
+        .is_ok());
+
+        // TEST CASE 2: Invalid Templates
+        assert_eq!(
+            validate_msg_templates(&msg, &[template(&["send", "amount"], TemplateValue::Denom)]),
+            Err(SkipError::MsgTemplatePathNotPlaceholder(
+                "send.amount".to_string()
+            ))
+        );
+    }
+}
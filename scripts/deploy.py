@@ -147,7 +147,13 @@ def main():
                     "adapter_contract_address": swap_adapter_contract_address,
                 }
             ],
-            "ibc_transfer_contract_address": ibc_transfer_adapter_contract_address,
+            "ibc_transfer_adapters": [
+                {
+                    "name": "default",
+                    "adapter_contract_address": ibc_transfer_adapter_contract_address,
+                }
+            ],
+            "default_ibc_transfer_adapter": "default",
         },
         label="Skip Swap Entry Point",
         name="entry_point",